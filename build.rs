@@ -0,0 +1,33 @@
+#[cfg(feature = "ffi")]
+extern crate cbindgen;
+
+// `include/dicepass.h` is checked in, so it's only regenerated on request rather than written into
+// the source tree by every build
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=DICEPASS_GENERATE_HEADER");
+    if std::env::var_os("DICEPASS_GENERATE_HEADER").is_none() {
+        return;
+    }
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir))
+        .expect("couldn't read cbindgen.toml");
+
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("couldn't generate C bindings")
+        .write_to_file(format!("{}/include/dicepass.h", crate_dir));
+}
+
+#[cfg(not(feature = "ffi"))]
+fn generate_header() {}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    generate_header();
+}
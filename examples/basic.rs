@@ -6,7 +6,7 @@ use lib_dicepass_gen::WordCount::*;
 fn main() {
     // the eff's short list (with unique prefixes)
     let mut config = PassGenConfig::from_eff_short_2(Default);
-    println!("Your passphrase is: \n\t[{}]\n", generate(config));
+    println!("Your passphrase is: \n\t[{}]\n", generate(config.clone()));
 
     config.word_count = Custom(10);
     println!(
@@ -16,7 +16,10 @@ fn main() {
 
     // the eff's long word list
     let mut config = PassGenConfig::from_eff_long(Default);
-    println!("Your long-word passphrase is: \n\t[{}]\n", generate(config));
+    println!(
+        "Your long-word passphrase is: \n\t[{}]\n",
+        generate(config.clone())
+    );
 
     config.word_count = Custom(10);
     println!(
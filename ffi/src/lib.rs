@@ -0,0 +1,8 @@
+//!
+//! Builds the `extern "C"` functions in `lib_dicepass_gen::ffi` as a C library: `libdicepass.so`
+//! (or `.dylib`/`.dll`) and `libdicepass.a`.  The declarations are in `include/dicepass.h`.
+//!
+
+extern crate lib_dicepass_gen;
+
+pub use lib_dicepass_gen::ffi::*;
@@ -0,0 +1,107 @@
+#ifndef DICEPASS_H
+#define DICEPASS_H
+
+/* This file is generated by cbindgen from src/ffi.rs.  Do not edit it by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+//
+// Status codes returned by the `dicepass_*` functions.
+//
+typedef enum DicepassStatus {
+  // The call succeeded.
+  DICEPASS_STATUS_OK = 0,
+  // A required pointer argument was null.
+  DICEPASS_STATUS_NULL_POINTER = 1,
+  // The caller-provided buffer was too small to hold the passphrase and its terminating NUL.
+  DICEPASS_STATUS_BUFFER_TOO_SMALL = 2,
+  // The word list argument wasn't one of the `DicepassWordList` values.
+  DICEPASS_STATUS_INVALID_WORD_LIST = 3,
+  // The word count argument produced an invalid configuration.
+  DICEPASS_STATUS_INVALID_CONFIG = 4,
+  // Input (such as dice rolls or a word list) was malformed.
+  DICEPASS_STATUS_INVALID_INPUT = 5,
+  // An unexpected internal error occurred.
+  DICEPASS_STATUS_INTERNAL = 6,
+} DicepassStatus;
+
+//
+// The word lists available to the `dicepass_*` functions.
+//
+typedef enum DicepassWordList {
+  // The EFF's "long" word list (7776 words, ~12.9 bits/word).
+  DICEPASS_WORD_LIST_EFF_LONG = 0,
+  // The EFF's standard "short" word list (1296 words, ~10.3 bits/word).
+  DICEPASS_WORD_LIST_EFF_SHORT = 1,
+  // The EFF's "special" short word list with unique three character prefixes.
+  DICEPASS_WORD_LIST_EFF_SHORT2 = 2,
+} DicepassWordList;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+//
+// Generates a passphrase into a caller-provided buffer.
+//
+// `list` is one of the `DicepassWordList` values and `word_count` is the number of words to
+// generate, or 0 to use the list's default.  On success the NUL-terminated passphrase is
+// written to `buf` and its length (excluding the NUL) is stored in `*written`.  If `buf_len` is
+// too small, nothing is written to `buf`, `*written` is set to the buffer size required and
+// `DicepassStatus::BufferTooSmall` is returned.
+//
+// # Safety
+//
+// `buf` must be valid for writes of `buf_len` bytes and `written` must be valid for a write of
+// one `size_t`.
+//
+enum DicepassStatus dicepass_generate(uint32_t list,
+                                      uint32_t word_count,
+                                      char *buf,
+                                      size_t buf_len,
+                                      size_t *written);
+
+//
+// Generates a passphrase and returns it as a newly allocated NUL-terminated string.
+//
+// `list` and `word_count` are interpreted as for `dicepass_generate`.  Returns null on failure,
+// in which case the reason is stored in `*status` if `status` isn't null.  The returned string
+// must be released with `dicepass_string_free`.
+//
+// # Safety
+//
+// `status` must be null or valid for a write of one `DicepassStatus`.
+//
+char *dicepass_generate_string(uint32_t list, uint32_t word_count, enum DicepassStatus *status);
+
+//
+// Releases a string returned by `dicepass_generate_string`.  Passing null does nothing.
+//
+// # Safety
+//
+// `s` must be null or a pointer returned by `dicepass_generate_string` which hasn't already
+// been freed.
+//
+void dicepass_string_free(char *s);
+
+//
+// Returns the default number of words for `list`, or 0 if `list` isn't a `DicepassWordList`
+// value.
+//
+uint32_t dicepass_default_word_count(uint32_t list);
+
+//
+// Returns a static, NUL-terminated description of a `DicepassStatus` value.  The returned string
+// must not be freed.
+//
+const char *dicepass_status_message(uint32_t status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DICEPASS_H */
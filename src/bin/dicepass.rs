@@ -0,0 +1,194 @@
+//!
+//! The `dicepass` command: generates a passphrase and prints it.
+//!
+//! Defaults come from the configuration file and `DICEPASS_*` environment variables (see
+//! `lib_dicepass_gen::settings`), and command line flags override both.
+//!
+
+extern crate lib_dicepass_gen;
+
+use std::env;
+#[cfg(feature = "qr")]
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use lib_dicepass_gen::settings::{self, Settings};
+use lib_dicepass_gen::{generate_passphrase, Error, Passphrase};
+
+const USAGE: &str = "\
+usage: dicepass [options]
+
+options:
+  -l, --list <name|path>     word list: eff_long, eff_short, eff_short_2, pseudo, a name from
+                             the config file's [lists], or a file with one word per line
+  -w, --words <count>        number of words
+  -e, --entropy <bits>       minimum entropy, instead of a number of words
+  -s, --separator <text>     text placed between words
+  -c, --casing <casing>      lower, upper or capitalized
+      --config <path>        read defaults from this file instead of
+                             ~/.config/dicepass/config.toml (or $DICEPASS_CONFIG)
+      --print-config         print the effective configuration instead of a passphrase
+      --qr <output>          also render the passphrase as a QR code: terminal (for a dark
+                             background), terminal-light, or a path ending in .svg or .png
+                             (requires the qr feature)
+  -h, --help                 print this message
+";
+
+struct Args {
+    flags: Settings,
+    config_path: Option<PathBuf>,
+    print_config: bool,
+    qr: Option<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, Error> {
+    let mut parsed = Args {
+        flags: Settings::default(),
+        config_path: None,
+        print_config: false,
+        qr: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.find('=') {
+            Some(at) if arg.starts_with("--") => {
+                (arg[..at].to_string(), Some(arg[at + 1..].to_string()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let key = match flag.as_str() {
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "--print-config" => {
+                parsed.print_config = true;
+                continue;
+            }
+            "--config" => "config",
+            "--qr" => "qr",
+            "-l" | "--list" => "list",
+            "-w" | "--words" => "words",
+            "-e" | "--entropy" => "entropy",
+            "-s" | "--separator" => "separator",
+            "-c" | "--casing" => "casing",
+            _ => {
+                return Err(Error::InvalidSetting {
+                    origin: "command line".to_string(),
+                    reason: format!("unknown option \"{}\"", flag),
+                })
+            }
+        };
+        let value = match inline.or_else(|| args.next()) {
+            Some(value) => value,
+            None => {
+                return Err(Error::InvalidSetting {
+                    origin: flag,
+                    reason: "a value is required".to_string(),
+                })
+            }
+        };
+        if key == "config" {
+            parsed.config_path = Some(PathBuf::from(value));
+        } else if key == "qr" {
+            check_qr(&value)?;
+            parsed.qr = Some(value);
+        } else {
+            parsed.flags.set(key, &value, &flag)?;
+        }
+    }
+    Ok(parsed)
+}
+
+fn effective_settings(args: Args) -> Result<Settings, Error> {
+    let explicit = args
+        .config_path
+        .or_else(|| env::var_os(settings::CONFIG_VAR).map(PathBuf::from));
+    let mut merged = match explicit {
+        Some(path) => Settings::from_file(&path)?,
+        None => match Settings::default_path() {
+            Some(ref path) if path.exists() => Settings::from_file(path)?,
+            _ => Settings::default(),
+        },
+    };
+    merged.merge(Settings::from_env(env::vars())?);
+    merged.merge(args.flags);
+    Ok(merged)
+}
+
+#[cfg(feature = "qr")]
+fn check_qr(output: &str) -> Result<(), Error> {
+    match output {
+        "terminal" | "terminal-light" => Ok(()),
+        path if path.ends_with(".svg") || path.ends_with(".png") => Ok(()),
+        _ => Err(Error::InvalidSetting {
+            origin: "--qr".to_string(),
+            reason: format!(
+                "\"{}\" is not terminal, terminal-light, or a .svg or .png path",
+                output
+            ),
+        }),
+    }
+}
+
+#[cfg(not(feature = "qr"))]
+fn check_qr(_: &str) -> Result<(), Error> {
+    Err(Error::InvalidSetting {
+        origin: "--qr".to_string(),
+        reason: "dicepass was built without the qr feature".to_string(),
+    })
+}
+
+#[cfg(feature = "qr")]
+fn print_qr(pass: &Passphrase, output: &str) -> Result<(), Error> {
+    let code = pass.qr_code()?;
+    let contents = match output {
+        "terminal" | "terminal-light" => {
+            print!("{}", code.to_terminal(output == "terminal"));
+            return Ok(());
+        }
+        path if path.ends_with(".svg") => code.to_svg(8).into_bytes(),
+        _ => code.to_png(8),
+    };
+    fs::write(output, contents).map_err(|err| Error::InvalidSetting {
+        origin: "--qr".to_string(),
+        reason: format!("couldn't write {}: {}", output, err),
+    })
+}
+
+#[cfg(not(feature = "qr"))]
+fn print_qr(_: &Passphrase, _: &str) -> Result<(), Error> {
+    unreachable!("--qr is rejected without the qr feature")
+}
+
+fn run() -> Result<(), Error> {
+    let args = parse_args(env::args().skip(1))?;
+    let print_config = args.print_config;
+    let qr = args.qr.clone();
+    let settings = effective_settings(args)?;
+    let config = settings.config()?;
+
+    if print_config {
+        print!("{}", settings.resolved()?.to_toml());
+        println!(
+            "# {} words, {:.1} bits of entropy",
+            config.words(),
+            config.entropy()
+        );
+    } else {
+        let pass = generate_passphrase(config);
+        println!("{}", pass.as_str());
+        if let Some(ref output) = qr {
+            print_qr(&pass, output)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("dicepass: {}", err);
+        process::exit(2);
+    }
+}
@@ -0,0 +1,145 @@
+//!
+//! Contains logic and structures for excluding words from generated passphrases.
+//!
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use defaults;
+
+///
+/// A set of words that must never appear in a generated passphrase.
+///
+/// Excluded words are removed from the word list before sampling: a dice roll that lands on an
+/// excluded word is discarded and rolled again.  This keeps the selection uniform over the words
+/// that remain, but it also means each word carries slightly less entropy than the full list
+/// would provide (see `PassGenConfig::bits_per_word`).
+///
+/// Words are compared case-insensitively and surrounding whitespace is ignored.
+///
+/// ```
+/// use lib_dicepass_gen::*;
+///
+/// let mut blocklist = Blocklist::sensitive();
+/// blocklist.insert("ACME");
+///
+/// assert!(blocklist.contains("acme"));
+/// assert!(blocklist.contains("poison"));
+/// assert!(!blocklist.contains("otter"));
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec<String>", into = "Vec<String>"))]
+pub struct Blocklist {
+    words: BTreeSet<String>,
+}
+
+impl Blocklist {
+    ///
+    /// Returns an empty `Blocklist` which excludes nothing.
+    ///
+    pub fn new() -> Blocklist {
+        Blocklist {
+            words: BTreeSet::new(),
+        }
+    }
+
+    ///
+    /// Returns a `Blocklist` containing the bundled sensitive-word list (see
+    /// `defaults::sensitive`).
+    ///
+    pub fn sensitive() -> Blocklist {
+        let mut blocklist = Blocklist::new();
+        blocklist.extend(defaults::sensitive::WORDS.iter().cloned());
+        blocklist
+    }
+
+    ///
+    /// Adds a word to the `Blocklist`.
+    ///
+    /// Returns `true` if the word was not already excluded.
+    ///
+    pub fn insert(&mut self, word: &str) -> bool {
+        self.words.insert(normalize(word))
+    }
+
+    ///
+    /// Adds every word yielded by `words` to the `Blocklist`.
+    ///
+    pub fn extend<'a, I: IntoIterator<Item = &'a str>>(&mut self, words: I) {
+        for word in words {
+            self.insert(word);
+        }
+    }
+
+    ///
+    /// Returns `true` if `word` is excluded by this `Blocklist`.
+    ///
+    pub fn contains(&self, word: &str) -> bool {
+        !self.words.is_empty() && self.words.contains(&normalize(word))
+    }
+
+    ///
+    /// Returns the number of words in this `Blocklist`.
+    ///
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    ///
+    /// Returns `true` if this `Blocklist` excludes nothing.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl From<Vec<String>> for Blocklist {
+    fn from(words: Vec<String>) -> Blocklist {
+        let mut blocklist = Blocklist::new();
+        blocklist.extend(words.iter().map(|word| word.as_str()));
+        blocklist
+    }
+}
+
+impl From<Blocklist> for Vec<String> {
+    fn from(blocklist: Blocklist) -> Vec<String> {
+        blocklist.words.into_iter().collect()
+    }
+}
+
+fn normalize(word: &str) -> String {
+    word.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let blocklist = Blocklist::new();
+        assert!(blocklist.is_empty());
+        assert!(!blocklist.contains("acid"));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let mut blocklist = Blocklist::new();
+        assert!(blocklist.insert(" Acid "));
+        assert!(!blocklist.insert("acid"));
+        assert!(blocklist.contains("ACID"));
+        assert_eq!(blocklist.len(), 1);
+    }
+
+    #[test]
+    fn sensitive() {
+        let blocklist = Blocklist::sensitive();
+        assert_eq!(blocklist.len(), defaults::sensitive::WORDS.len());
+        for word in defaults::sensitive::WORDS.iter() {
+            assert!(blocklist.contains(word));
+        }
+    }
+}
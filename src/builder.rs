@@ -0,0 +1,422 @@
+//!
+//! Contains a builder for creating and validating `PassGenConfig` objects.
+//!
+
+use alloc::string::{String, ToString};
+
+use blocklist::Blocklist;
+use error::ConfigError;
+use math;
+use pass_gen::{Casing, PassGenConfig, Policy, RngSource, Sampling, WordCount, WordList};
+
+///
+/// The largest number of words a built `PassGenConfig` may generate.  Even a two word list
+/// provides 1024 bits of entropy with this many words.
+///
+pub const MAX_WORDS: u32 = 1024;
+
+///
+/// Builds a `PassGenConfig` one option at a time and validates the options together.
+///
+/// Any option that isn't set keeps the value used by `PassGenConfig::from_eff_long`, except that
+/// `sampling` defaults to `Sampling::Uniform` when the word list is a `WordList::Custom` or
+/// `WordList::Pseudo`.
+///
+/// ```
+/// use lib_dicepass_gen::*;
+///
+/// let result = PassGenConfig::builder()
+///     .word_list(WordList::EffShort)
+///     .word_count(WordCount::Custom(4))
+///     .policy(Policy {
+///         min_entropy: Some(60.0),
+///         ..Policy::default()
+///     })
+///     .build();
+///
+/// // 4 words from the EffShort list only provide ~41.4 bits
+/// assert!(result.is_err());
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct PassGenConfigBuilder {
+    word_list: WordList,
+    length: Length,
+    sampling: Option<Sampling>,
+    blocklist: Blocklist,
+    separator: String,
+    casing: Casing,
+    policy: Policy,
+    rng: RngSource,
+    checksum: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Length {
+    Words(WordCount),
+    Entropy(f64),
+}
+
+impl PassGenConfigBuilder {
+    ///
+    /// Returns a `PassGenConfigBuilder` with every option set to its default.
+    ///
+    pub fn new() -> PassGenConfigBuilder {
+        PassGenConfigBuilder {
+            word_list: WordList::EffLong,
+            length: Length::Words(WordCount::Default),
+            sampling: None,
+            blocklist: Blocklist::new(),
+            separator: " ".to_string(),
+            casing: Casing::Lower,
+            policy: Policy::default(),
+            rng: RngSource::Os,
+            checksum: false,
+        }
+    }
+
+    ///
+    /// Sets the word list to generate the passphrase from.
+    ///
+    pub fn word_list(mut self, word_list: WordList) -> PassGenConfigBuilder {
+        self.word_list = word_list;
+        self
+    }
+
+    ///
+    /// Sets the number of words in the passphrase.  This replaces any previously set entropy
+    /// target.
+    ///
+    pub fn word_count(mut self, word_count: WordCount) -> PassGenConfigBuilder {
+        self.length = Length::Words(word_count);
+        self
+    }
+
+    ///
+    /// Sets the number of words in the passphrase to the fewest needed to provide at least
+    /// `bits` bits of entropy, after accounting for the blocklist and policy.  This replaces any
+    /// previously set word count.
+    ///
+    pub fn entropy(mut self, bits: f64) -> PassGenConfigBuilder {
+        self.length = Length::Entropy(bits);
+        self
+    }
+
+    ///
+    /// Sets how words are selected from the word list.
+    ///
+    pub fn sampling(mut self, sampling: Sampling) -> PassGenConfigBuilder {
+        self.sampling = Some(sampling);
+        self
+    }
+
+    ///
+    /// Sets the words which must never appear in the passphrase.
+    ///
+    pub fn blocklist(mut self, blocklist: Blocklist) -> PassGenConfigBuilder {
+        self.blocklist = blocklist;
+        self
+    }
+
+    ///
+    /// Sets the text placed between words.  It must not appear inside any word that may be
+    /// generated (after casing is applied), so that the passphrase can be split back into its
+    /// words; for example "-" can't be used with the EFF lists, which contain "yo-yo".
+    ///
+    pub fn separator<S: Into<String>>(mut self, separator: S) -> PassGenConfigBuilder {
+        self.separator = separator.into();
+        self
+    }
+
+    ///
+    /// Sets how the letters of each word are capitalized.
+    ///
+    pub fn casing(mut self, casing: Casing) -> PassGenConfigBuilder {
+        self.casing = casing;
+        self
+    }
+
+    ///
+    /// Sets the constraints the passphrase must satisfy.
+    ///
+    pub fn policy(mut self, policy: Policy) -> PassGenConfigBuilder {
+        self.policy = policy;
+        self
+    }
+
+    ///
+    /// Sets the source of randomness used to select words.
+    ///
+    pub fn rng(mut self, rng: RngSource) -> PassGenConfigBuilder {
+        self.rng = rng;
+        self
+    }
+
+    ///
+    /// Sets whether to append a checksum word to the passphrase.
+    ///
+    pub fn checksum(mut self, checksum: bool) -> PassGenConfigBuilder {
+        self.checksum = checksum;
+        self
+    }
+
+    ///
+    /// Validates the options and returns the resulting `PassGenConfig`.
+    ///
+    /// Returns an error if the passphrase would contain no words or more than `MAX_WORDS`, if
+    /// the blocklist and policy exclude every word in the word list, if the separator appears
+    /// inside a word, or if the passphrase wouldn't satisfy the policy (see
+    /// `PassGenConfig::validate`).
+    ///
+    pub fn build(self) -> Result<PassGenConfig, ConfigError> {
+        let sampling = self.sampling.unwrap_or(match self.word_list {
+            WordList::Custom(_) | WordList::Pseudo(_) => Sampling::Uniform,
+            _ => Sampling::default(),
+        });
+
+        let mut config = PassGenConfig {
+            word_list: self.word_list,
+            word_count: WordCount::Default,
+            sampling,
+            blocklist: self.blocklist,
+            separator: self.separator,
+            casing: self.casing,
+            policy: self.policy,
+            rng: self.rng,
+            checksum: self.checksum,
+        };
+
+        config.word_count = match self.length {
+            Length::Words(word_count) => word_count,
+            Length::Entropy(bits) => {
+                if !bits.is_finite() || bits <= 0.0 {
+                    return Err(ConfigError::InvalidEntropyTarget(bits));
+                }
+                if config.available_words() == 0 {
+                    return Err(ConfigError::NoWordsAvailable);
+                }
+                let bits_per_word = config.bits_per_word();
+                if bits_per_word == 0.0 {
+                    return Err(ConfigError::InsufficientEntropy {
+                        required: bits,
+                        actual: 0.0,
+                    });
+                }
+                // saturates for absurd targets, which are then rejected as too many words
+                WordCount::Custom(math::ceil(bits / bits_per_word) as u32)
+            }
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl PassGenConfig {
+    ///
+    /// Checks that passphrases can be generated from this config and satisfy its policy.
+    ///
+    /// Configs created with `PassGenConfig::builder` and deserialized configs are always checked;
+    /// call this after changing a config's fields directly.  Returns an error if the passphrase
+    /// would contain no words or more than `MAX_WORDS`, if the blocklist and policy exclude every
+    /// word in the word list, if the separator appears inside a word that may be generated
+    /// (including any checksum word), or if the passphrase wouldn't satisfy the policy.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    ///
+    /// let mut config = PassGenConfig::from_eff_long(WordCount::Default);
+    /// assert!(config.validate().is_ok());
+    ///
+    /// // "yo-yo" is in the list
+    /// config.separator = "-".to_string();
+    /// assert!(config.validate().is_err());
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let (Some(min), Some(max)) = (self.policy.min_word_length, self.policy.max_word_length) {
+            if min > max {
+                return Err(ConfigError::InvalidWordLengths { min, max });
+            }
+        }
+        if let Some(bits) = self.policy.min_entropy {
+            if !bits.is_finite() || bits < 0.0 {
+                return Err(ConfigError::InvalidEntropyTarget(bits));
+            }
+        }
+
+        if self.available_words() == 0 {
+            return Err(ConfigError::NoWordsAvailable);
+        }
+
+        if !self.separator.is_empty() {
+            // the checksum word is chosen from the whole list
+            let clash = self
+                .word_list
+                .iter()
+                .filter(|word| self.checksum || self.allows(word))
+                .find(|word| self.casing.apply(word).contains(&*self.separator));
+            if let Some(word) = clash {
+                return Err(ConfigError::SeparatorInWord {
+                    separator: self.separator.clone(),
+                    word: word.to_string(),
+                });
+            }
+        }
+
+        match self.words() {
+            0 => return Err(ConfigError::ZeroWords),
+            words if words > MAX_WORDS => return Err(ConfigError::TooManyWords(words)),
+            _ => {}
+        }
+
+        if let Some(required) = self.policy.min_entropy {
+            let actual = self.entropy();
+            if actual < required {
+                return Err(ConfigError::InsufficientEntropy { required, actual });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for PassGenConfigBuilder {
+    fn default() -> PassGenConfigBuilder {
+        PassGenConfigBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use custom_list::CustomList;
+    use dice::Die;
+
+    #[test]
+    fn defaults() {
+        let config = PassGenConfigBuilder::new().build().unwrap();
+        assert_eq!(config.word_list, WordList::EffLong);
+        assert_eq!(config.word_count, WordCount::Default);
+        assert_eq!(config.sampling, Sampling::Dice(Die::D6));
+        assert_eq!(config.separator, " ");
+
+        let list = CustomList::new(vec!["a", "b", "c"]).unwrap();
+        let config = PassGenConfigBuilder::new()
+            .word_list(WordList::Custom(list))
+            .build()
+            .unwrap();
+        assert_eq!(config.sampling, Sampling::Uniform);
+    }
+
+    #[test]
+    fn entropy_target() {
+        let config = PassGenConfigBuilder::new().entropy(64.0).build().unwrap();
+        assert_eq!(config.word_count, WordCount::Custom(5));
+
+        let config = PassGenConfigBuilder::new().entropy(65.0).build().unwrap();
+        assert_eq!(config.word_count, WordCount::Custom(6));
+
+        let config = PassGenConfigBuilder::new()
+            .entropy(64.0)
+            .word_count(WordCount::Custom(3))
+            .build()
+            .unwrap();
+        assert_eq!(config.word_count, WordCount::Custom(3));
+    }
+
+    #[test]
+    fn invalid() {
+        let err = PassGenConfigBuilder::new()
+            .word_count(WordCount::Custom(0))
+            .build();
+        assert_eq!(err.unwrap_err(), ConfigError::ZeroWords);
+
+        let err = PassGenConfigBuilder::new().entropy(-1.0).build();
+        assert_eq!(err.unwrap_err(), ConfigError::InvalidEntropyTarget(-1.0));
+        let err = PassGenConfigBuilder::new().entropy(f64::INFINITY).build();
+        assert!(err.is_err());
+        let err = PassGenConfigBuilder::new().entropy(1e12).build();
+        assert_eq!(err.unwrap_err(), ConfigError::TooManyWords(u32::MAX));
+        let err = PassGenConfigBuilder::new()
+            .word_count(WordCount::Custom(MAX_WORDS + 1))
+            .build();
+        assert_eq!(err.unwrap_err(), ConfigError::TooManyWords(MAX_WORDS + 1));
+
+        let policy = Policy {
+            min_entropy: Some(f64::NAN),
+            ..Policy::default()
+        };
+        let err = PassGenConfigBuilder::new().policy(policy).build();
+        assert!(matches!(
+            err.unwrap_err(),
+            ConfigError::InvalidEntropyTarget(bits) if bits.is_nan()
+        ));
+
+        let policy = Policy {
+            min_word_length: Some(6),
+            max_word_length: Some(4),
+            ..Policy::default()
+        };
+        let err = PassGenConfigBuilder::new().policy(policy).build();
+        assert_eq!(
+            err.unwrap_err(),
+            ConfigError::InvalidWordLengths { min: 6, max: 4 }
+        );
+
+        let policy = Policy {
+            min_word_length: Some(20),
+            ..Policy::default()
+        };
+        let err = PassGenConfigBuilder::new().policy(policy).build();
+        assert_eq!(err.unwrap_err(), ConfigError::NoWordsAvailable);
+    }
+
+    #[test]
+    fn separator_in_word() {
+        let err = PassGenConfigBuilder::new()
+            .word_list(WordList::EffShort2)
+            .separator("-")
+            .build();
+        assert_eq!(
+            err.unwrap_err(),
+            ConfigError::SeparatorInWord {
+                separator: "-".to_string(),
+                word: "yo-yo".to_string(),
+            }
+        );
+
+        // the clash only matters for words which can actually be generated
+        let mut blocklist = Blocklist::new();
+        blocklist.insert("yo-yo");
+        let config = PassGenConfigBuilder::new()
+            .word_list(WordList::EffShort2)
+            .blocklist(blocklist.clone())
+            .separator("-")
+            .build();
+        assert!(config.is_ok());
+
+        // unless the checksum word, which ignores the blocklist, could be that word
+        let config = PassGenConfigBuilder::new()
+            .word_list(WordList::EffShort2)
+            .blocklist(blocklist)
+            .separator("-")
+            .checksum(true)
+            .build();
+        assert_eq!(
+            config.unwrap_err(),
+            ConfigError::SeparatorInWord {
+                separator: "-".to_string(),
+                word: "yo-yo".to_string(),
+            }
+        );
+
+        // casing is applied before checking
+        let list = CustomList::new(vec!["ox", "yak"]).unwrap();
+        let builder = PassGenConfigBuilder::new()
+            .word_list(WordList::Custom(list))
+            .separator("X");
+        assert!(builder.clone().build().is_ok());
+        assert!(builder.casing(Casing::Upper).build().is_err());
+    }
+}
@@ -0,0 +1,731 @@
+//!
+//! Contains logic for generating a passphrase collaboratively, so that no single participant
+//! controls (or can predict) the result.
+//!
+//! Each participant prepares a `Contribution`: one word index per word of the passphrase, taken
+//! from their own dice rolls or random bytes, plus a random nonce.  The ceremony runs in two
+//! phases:
+//!
+//!   1. Every participant publishes a `Commitment`, a SHA-256 hash of their name and
+//!      contribution, without revealing the contribution itself.
+//!   2. Once the commitment phase is closed, every participant reveals their contribution, which
+//!      is checked against their commitment.
+//!
+//! The passphrase's word indices are the sums of the participants' indices modulo the length of
+//! the word list.  Since every contribution is fixed before any is revealed, the result is
+//! uniformly random as long as at least one participant's contribution is.
+//!
+//! The resulting `Transcript` records every commitment and contribution so that an auditor can
+//! repeat the computation with `Transcript::verify`.  It necessarily contains everything needed
+//! to recompute the passphrase, so it must be protected as carefully as the passphrase itself.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::ceremony::{self, Ceremony, Contribution};
+//! use lib_dicepass_gen::dice::Die;
+//!
+//! let mut ceremony = Ceremony::new(WordList::EffShort, 3).unwrap();
+//!
+//! // each participant prepares a contribution in private and publishes only its commitment
+//! let alice = Contribution::from_rolls(
+//!     &WordList::EffShort,
+//!     Die::D6,
+//!     &[1, 2, 3, 4, 5, 6, 1, 2, 3, 4, 5, 6],
+//!     ceremony::generate_nonce(),
+//! )
+//! .unwrap();
+//! let bob = Contribution::from_bytes(&WordList::EffShort, b"bob's random bytes", ceremony::generate_nonce());
+//! ceremony.commit("alice", alice.commitment("alice")).unwrap();
+//! ceremony.commit("bob", bob.commitment("bob")).unwrap();
+//!
+//! // then everyone reveals
+//! ceremony.close().unwrap();
+//! ceremony.reveal("alice", alice).unwrap();
+//! ceremony.reveal("bob", bob).unwrap();
+//!
+//! let transcript = ceremony.finish().unwrap();
+//! assert_eq!(transcript.words().len(), 3);
+//! assert!(transcript.verify().is_ok());
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use sha2::{Digest, Sha256};
+
+use dice::{DicePlan, Die};
+use error::{CeremonyError, ConfigError, Error};
+use manual::Source;
+use pass_gen::WordList;
+
+const DOMAIN: &[u8] = b"lib_dicepass_gen/ceremony/v1";
+
+///
+/// The length (in bytes) of a contribution's nonce.
+///
+pub const NONCE_LEN: usize = 32;
+
+///
+/// A SHA-256 commitment to a participant's `Contribution`.
+///
+/// Commitments are displayed and parsed as 64 lower case hex digits so they can be read aloud or
+/// written down.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Commitment([u8; 32]);
+
+impl fmt::Display for Commitment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, &self.0)
+    }
+}
+
+impl FromStr for Commitment {
+    type Err = Error;
+
+    fn from_str(hex: &str) -> Result<Commitment, Error> {
+        parse_hex(hex).map(Commitment)
+    }
+}
+
+impl TryFrom<String> for Commitment {
+    type Error = Error;
+
+    fn try_from(hex: String) -> Result<Commitment, Error> {
+        hex.parse()
+    }
+}
+
+impl From<Commitment> for String {
+    fn from(commitment: Commitment) -> String {
+        commitment.to_string()
+    }
+}
+
+///
+/// One participant's contribution to a `Ceremony`: a word index for each word of the passphrase
+/// and a random nonce which keeps the indices from being guessed from the commitment.
+///
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Contribution {
+    ///
+    /// The word indices contributed, in order.  Only the first `word_count` are used.
+    ///
+    pub indices: Vec<usize>,
+
+    ///
+    /// A random nonce.  Must be kept secret until the contribution is revealed.
+    ///
+    pub nonce: [u8; NONCE_LEN],
+}
+
+impl Contribution {
+    ///
+    /// Returns a `Contribution` of the given word indices.
+    ///
+    pub fn from_indices(indices: Vec<usize>, nonce: [u8; NONCE_LEN]) -> Contribution {
+        Contribution { indices, nonce }
+    }
+
+    ///
+    /// Returns a `Contribution` from physical dice rolls, read in groups as described by
+    /// `DicePlan::new(word_list.len(), die)`.  Groups which don't correspond to a word are
+    /// skipped.
+    ///
+    /// Returns an error if a roll isn't a face of `die` or the number of rolls isn't a multiple
+    /// of the group size.
+    ///
+    pub fn from_rolls(
+        word_list: &WordList,
+        die: Die,
+        rolls: &[u32],
+        nonce: [u8; NONCE_LEN],
+    ) -> Result<Contribution, Error> {
+        let plan = DicePlan::new(word_list.len(), die);
+        if !rolls.len().is_multiple_of(plan.rolls()) {
+            return Err(Error::WrongRollCount {
+                expected: (rolls.len() / plan.rolls() + 1) * plan.rolls(),
+                actual: rolls.len(),
+            });
+        }
+
+        let mut indices = Vec::new();
+        for group in rolls.chunks(plan.rolls()) {
+            if let Some(index) = plan.index(group)? {
+                indices.push(index);
+            }
+        }
+        Ok(Contribution::from_indices(indices, nonce))
+    }
+
+    ///
+    /// Returns a `Contribution` from random bytes, such as the output of a hardware random
+    /// number generator.  The bytes are converted to uniformly distributed word indices as
+    /// `manual::Source::HexDigits` does, so more bytes than strictly needed may be required.
+    ///
+    pub fn from_bytes(word_list: &WordList, bytes: &[u8], nonce: [u8; NONCE_LEN]) -> Contribution {
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        let decoded = Source::HexDigits
+            .decode(&hex, word_list.len())
+            .expect("hex encoding is valid input");
+        Contribution::from_indices(decoded.indices, nonce)
+    }
+
+    ///
+    /// Returns this contribution's commitment for the participant named `participant`.
+    ///
+    pub fn commitment(&self, participant: &str) -> Commitment {
+        let mut hasher = Sha256::new();
+        hasher.update(DOMAIN);
+        hasher.update((participant.len() as u64).to_be_bytes());
+        hasher.update(participant.as_bytes());
+        hasher.update(self.nonce);
+        hasher.update((self.indices.len() as u64).to_be_bytes());
+        for &index in &self.indices {
+            hasher.update((index as u64).to_be_bytes());
+        }
+        Commitment(hasher.finalize().into())
+    }
+}
+
+impl fmt::Debug for Contribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Contribution")
+            .field("indices", &"<redacted>")
+            .field("nonce", &"<redacted>")
+            .finish()
+    }
+}
+
+///
+/// A collaborative passphrase generation ceremony.  See the module documentation.
+///
+#[derive(Debug, Clone)]
+pub struct Ceremony {
+    word_list: WordList,
+    word_count: u32,
+    participants: Vec<Participant>,
+    closed: bool,
+}
+
+impl Ceremony {
+    ///
+    /// Starts a ceremony for a passphrase of `word_count` words from `word_list`.
+    ///
+    pub fn new(word_list: WordList, word_count: u32) -> Result<Ceremony, Error> {
+        if word_count == 0 {
+            return Err(ConfigError::ZeroWords.into());
+        }
+        Ok(Ceremony {
+            word_list,
+            word_count,
+            participants: Vec::new(),
+            closed: false,
+        })
+    }
+
+    ///
+    /// Records `participant`'s commitment.
+    ///
+    pub fn commit(&mut self, participant: &str, commitment: Commitment) -> Result<(), Error> {
+        if self.closed {
+            return Err(CeremonyError::CommitmentsClosed.into());
+        }
+        if self.participant(participant).is_some() {
+            return Err(CeremonyError::DuplicateParticipant(participant.to_string()).into());
+        }
+        self.participants.push(Participant {
+            name: participant.to_string(),
+            commitment,
+            contribution: None,
+        });
+        Ok(())
+    }
+
+    ///
+    /// Ends the commitment phase.  No further commitments are accepted, and contributions may be
+    /// revealed.
+    ///
+    pub fn close(&mut self) -> Result<(), Error> {
+        if self.participants.is_empty() {
+            return Err(CeremonyError::NoParticipants.into());
+        }
+        self.closed = true;
+        Ok(())
+    }
+
+    ///
+    /// Records `participant`'s contribution after checking it against their commitment.
+    ///
+    pub fn reveal(&mut self, participant: &str, contribution: Contribution) -> Result<(), Error> {
+        if !self.closed {
+            return Err(CeremonyError::CommitmentsOpen.into());
+        }
+
+        let list_len = self.word_list.len();
+        let word_count = self.word_count as usize;
+        let entry = self
+            .participants
+            .iter_mut()
+            .find(|entry| entry.name == participant)
+            .ok_or_else(|| CeremonyError::UnknownParticipant(participant.to_string()))?;
+        if contribution.commitment(participant) != entry.commitment {
+            return Err(CeremonyError::CommitmentMismatch(participant.to_string()).into());
+        }
+        if !valid(&contribution, list_len, word_count) {
+            return Err(CeremonyError::InvalidContribution(participant.to_string()).into());
+        }
+
+        entry.contribution = Some(contribution);
+        Ok(())
+    }
+
+    ///
+    /// Combines the revealed contributions and returns the ceremony's transcript, from which the
+    /// passphrase can be read with `Transcript::words`.
+    ///
+    /// Returns an error if the commitment phase is still open or anyone hasn't revealed.
+    ///
+    pub fn finish(self) -> Result<Transcript, Error> {
+        if !self.closed {
+            return Err(CeremonyError::CommitmentsOpen.into());
+        }
+        if let Some(missing) = self.participants.iter().find(|p| p.contribution.is_none()) {
+            return Err(CeremonyError::MissingReveal(missing.name.clone()).into());
+        }
+
+        let mut transcript = Transcript {
+            word_list: self.word_list,
+            word_count: self.word_count,
+            participants: self.participants,
+            result: Vec::new(),
+        };
+        transcript.result = transcript.combine();
+        Ok(transcript)
+    }
+
+    fn participant(&self, name: &str) -> Option<&Participant> {
+        self.participants.iter().find(|entry| entry.name == name)
+    }
+}
+
+///
+/// A participant's entry in a `Ceremony` or `Transcript`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Participant {
+    ///
+    /// The participant's name.
+    ///
+    pub name: String,
+
+    ///
+    /// The participant's commitment.
+    ///
+    pub commitment: Commitment,
+
+    ///
+    /// The participant's contribution, once revealed.
+    ///
+    pub contribution: Option<Contribution>,
+}
+
+///
+/// The record of a finished `Ceremony`.
+///
+/// A transcript contains every participant's contribution and so is as sensitive as the
+/// passphrase.  The contributions and result are left out of its `Debug` output.
+///
+/// When deserialized, a transcript is rejected if its result has the wrong number of indices or
+/// an index past the end of its word list; use `verify` to check the rest.
+///
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TranscriptData"))]
+pub struct Transcript {
+    ///
+    /// The word list the passphrase was generated from.
+    ///
+    pub word_list: WordList,
+
+    ///
+    /// The number of words in the passphrase.
+    ///
+    pub word_count: u32,
+
+    ///
+    /// The participants, in the order they committed.
+    ///
+    pub participants: Vec<Participant>,
+
+    ///
+    /// The combined word indices of the passphrase.
+    ///
+    pub result: Vec<usize>,
+}
+
+impl Transcript {
+    ///
+    /// Returns the passphrase's words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `result` has been changed to contain an index past the end of `word_list`.
+    /// Transcripts returned by `Ceremony::finish` or deserialized never do.
+    ///
+    pub fn words(&self) -> Vec<&str> {
+        self.result
+            .iter()
+            .map(|&index| {
+                self.word_list
+                    .word(index)
+                    .expect("result indices are within the word list")
+            })
+            .collect()
+    }
+
+    ///
+    /// Checks every contribution against its commitment and recomputes the result.
+    ///
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.participants.is_empty() {
+            return Err(CeremonyError::NoParticipants.into());
+        }
+        for participant in &self.participants {
+            let contribution = participant
+                .contribution
+                .as_ref()
+                .ok_or_else(|| CeremonyError::MissingReveal(participant.name.clone()))?;
+            if contribution.commitment(&participant.name) != participant.commitment {
+                return Err(CeremonyError::CommitmentMismatch(participant.name.clone()).into());
+            }
+            if !valid(contribution, self.word_list.len(), self.word_count as usize) {
+                return Err(CeremonyError::InvalidContribution(participant.name.clone()).into());
+            }
+        }
+        if !self.result_in_range() || self.combine() != self.result {
+            return Err(CeremonyError::ResultMismatch.into());
+        }
+        Ok(())
+    }
+
+    fn result_in_range(&self) -> bool {
+        let list_len = self.word_list.len();
+        self.result.len() == self.word_count as usize
+            && self.result.iter().all(|&index| index < list_len)
+    }
+
+    fn combine(&self) -> Vec<usize> {
+        let list_len = self.word_list.len();
+        (0..self.word_count as usize)
+            .map(|position| {
+                self.participants
+                    .iter()
+                    .filter_map(|participant| participant.contribution.as_ref())
+                    .fold(0, |sum, contribution| {
+                        (sum + contribution.indices[position]) % list_len
+                    })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Transcript")
+            .field("word_list", &self.word_list)
+            .field("word_count", &self.word_count)
+            .field("participants", &self.participants)
+            .field("result", &"<redacted>")
+            .finish()
+    }
+}
+
+///
+/// The serialized form of a `Transcript`, checked before it's accepted.
+///
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct TranscriptData {
+    word_list: WordList,
+    word_count: u32,
+    participants: Vec<Participant>,
+    result: Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TranscriptData> for Transcript {
+    type Error = Error;
+
+    fn try_from(data: TranscriptData) -> Result<Transcript, Error> {
+        let transcript = Transcript {
+            word_list: data.word_list,
+            word_count: data.word_count,
+            participants: data.participants,
+            result: data.result,
+        };
+        if !transcript.result_in_range() {
+            return Err(CeremonyError::ResultMismatch.into());
+        }
+        Ok(transcript)
+    }
+}
+
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "word list: {} words", self.word_list.len())?;
+        writeln!(f, "word count: {}", self.word_count)?;
+        for participant in &self.participants {
+            writeln!(f, "participant: {}", participant.name)?;
+            writeln!(f, "  commitment: {}", participant.commitment)?;
+            if let Some(ref contribution) = participant.contribution {
+                write!(f, "  nonce: ")?;
+                write_hex(f, &contribution.nonce)?;
+                writeln!(f)?;
+                write!(f, "  indices:")?;
+                for index in &contribution.indices {
+                    write!(f, " {}", index)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        write!(f, "result:")?;
+        for index in &self.result {
+            write!(f, " {}", index)?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Returns a random nonce from the operating system's secure random number generator.
+///
+#[cfg(feature = "std")]
+pub fn generate_nonce() -> [u8; NONCE_LEN] {
+    use rand::Rng;
+
+    let mut nonce = [0; NONCE_LEN];
+    ::pass_gen::os_rng().fill_bytes(&mut nonce);
+    nonce
+}
+
+fn valid(contribution: &Contribution, list_len: usize, word_count: usize) -> bool {
+    contribution.indices.len() >= word_count
+        && contribution.indices[..word_count]
+            .iter()
+            .all(|&index| index < list_len)
+}
+
+fn write_hex(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    for byte in bytes {
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}
+
+fn parse_hex(hex: &str) -> Result<[u8; 32], Error> {
+    let invalid = || Error::InvalidInput(hex.to_string());
+    let digits: Vec<u8> = hex
+        .trim()
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8).ok_or_else(invalid))
+        .collect::<Result<_, _>>()?;
+    if digits.len() != 64 {
+        return Err(invalid());
+    }
+
+    let mut bytes = [0; 32];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        *byte = pair[0] << 4 | pair[1];
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn contribution(indices: &[usize], nonce: u8) -> Contribution {
+        Contribution::from_indices(indices.to_vec(), [nonce; NONCE_LEN])
+    }
+
+    fn run(contributions: &[(&str, Contribution)]) -> Result<Transcript, Error> {
+        let mut ceremony = Ceremony::new(WordList::EffShort, 3)?;
+        for &(name, ref contribution) in contributions {
+            ceremony.commit(name, contribution.commitment(name))?;
+        }
+        ceremony.close()?;
+        for &(name, ref contribution) in contributions {
+            ceremony.reveal(name, contribution.clone())?;
+        }
+        ceremony.finish()
+    }
+
+    #[test]
+    fn indices_are_summed() {
+        let transcript = run(&[
+            ("alice", contribution(&[0, 1000, 1295], 1)),
+            ("bob", contribution(&[5, 1000, 1, 7], 2)),
+        ])
+        .unwrap();
+        assert_eq!(transcript.result, vec![5, 704, 0]);
+        assert_eq!(transcript.words(), vec!["affix", "nacho", "acid"]);
+        assert!(transcript.verify().is_ok());
+    }
+
+    #[test]
+    fn from_rolls_and_bytes() {
+        let list = WordList::EffShort;
+        let rolls = Contribution::from_rolls(&list, Die::D6, &[1, 1, 1, 1, 6, 6, 6, 6], [0; 32]);
+        assert_eq!(rolls.unwrap().indices, vec![0, 1295]);
+
+        // 1296 isn't a power of 20, so the last of these groups is rejected
+        let rolls = Contribution::from_rolls(&list, Die::D20, &[1, 1, 1, 20, 20, 20], [0; 32]);
+        assert_eq!(rolls.unwrap().indices, vec![0]);
+        assert_eq!(
+            Contribution::from_rolls(&list, Die::D6, &[1, 1, 1], [0; 32]),
+            Err(Error::WrongRollCount {
+                expected: 4,
+                actual: 3,
+            })
+        );
+
+        let bytes = Contribution::from_bytes(&list, &[0; 8], [0; 32]);
+        assert_eq!(bytes.indices, vec![0; 6]);
+    }
+
+    #[test]
+    fn commitments_are_checked() {
+        let alice = contribution(&[1, 2, 3], 1);
+        let mut ceremony = Ceremony::new(WordList::EffShort, 3).unwrap();
+        ceremony.commit("alice", alice.commitment("alice")).unwrap();
+
+        assert_eq!(
+            ceremony.reveal("alice", alice.clone()),
+            Err(CeremonyError::CommitmentsOpen.into())
+        );
+        assert_eq!(
+            ceremony.commit("alice", alice.commitment("alice")),
+            Err(CeremonyError::DuplicateParticipant("alice".to_string()).into())
+        );
+        ceremony.close().unwrap();
+        assert_eq!(
+            ceremony.commit("bob", alice.commitment("bob")),
+            Err(CeremonyError::CommitmentsClosed.into())
+        );
+
+        // changing the indices after committing is detected
+        assert_eq!(
+            ceremony.reveal("alice", contribution(&[1, 2, 4], 1)),
+            Err(CeremonyError::CommitmentMismatch("alice".to_string()).into())
+        );
+        // as is revealing under another name
+        assert_eq!(
+            ceremony.reveal("bob", alice.clone()),
+            Err(CeremonyError::UnknownParticipant("bob".to_string()).into())
+        );
+        ceremony.reveal("alice", alice).unwrap();
+        assert!(ceremony.finish().is_ok());
+    }
+
+    #[test]
+    fn incomplete_ceremonies() {
+        assert_eq!(run(&[]).unwrap_err(), CeremonyError::NoParticipants.into());
+        assert_eq!(
+            run(&[("alice", contribution(&[1, 2], 1))]).unwrap_err(),
+            CeremonyError::InvalidContribution("alice".to_string()).into()
+        );
+        assert_eq!(
+            run(&[("alice", contribution(&[1, 2, 1296], 1))]).unwrap_err(),
+            CeremonyError::InvalidContribution("alice".to_string()).into()
+        );
+
+        let mut ceremony = Ceremony::new(WordList::EffShort, 3).unwrap();
+        let alice = contribution(&[1, 2, 3], 1);
+        ceremony.commit("alice", alice.commitment("alice")).unwrap();
+        ceremony.close().unwrap();
+        assert_eq!(
+            ceremony.finish().unwrap_err(),
+            CeremonyError::MissingReveal("alice".to_string()).into()
+        );
+    }
+
+    #[test]
+    fn tampered_transcript() {
+        let mut transcript = run(&[
+            ("alice", contribution(&[1, 2, 3], 1)),
+            ("bob", contribution(&[4, 5, 6], 2)),
+        ])
+        .unwrap();
+        transcript.result[0] = 0;
+        assert_eq!(
+            transcript.verify(),
+            Err(CeremonyError::ResultMismatch.into())
+        );
+        transcript.result[0] = 5000;
+        assert_eq!(
+            transcript.verify(),
+            Err(CeremonyError::ResultMismatch.into())
+        );
+
+        transcript.result[0] = 5;
+        assert!(transcript.verify().is_ok());
+        transcript.participants[1].contribution = Some(contribution(&[0, 5, 6], 2));
+        assert_eq!(
+            transcript.verify(),
+            Err(CeremonyError::CommitmentMismatch("bob".to_string()).into())
+        );
+    }
+
+    #[test]
+    fn commitment_hex() {
+        let commitment = contribution(&[1, 2, 3], 1).commitment("alice");
+        let hex = commitment.to_string();
+        assert_eq!(hex.len(), 64);
+        assert_eq!(hex.parse(), Ok(commitment));
+        assert_eq!(
+            "abc".parse::<Commitment>(),
+            Err(Error::InvalidInput("abc".to_string()))
+        );
+    }
+
+    #[test]
+    fn transcript_display() {
+        let transcript = run(&[("alice", contribution(&[1, 2, 3], 1))]).unwrap();
+        let text = transcript.to_string();
+        assert!(text.contains("participant: alice"));
+        assert!(text.contains("  indices: 1 2 3"));
+        assert!(text.ends_with("result: 1 2 3"));
+
+        let debug = format!("{:?}", transcript);
+        assert!(!debug.contains("1, 2, 3"));
+        assert!(debug.contains("<redacted>"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_checks_result() {
+        let transcript = run(&[("alice", contribution(&[1, 2, 3], 1))]).unwrap();
+        let json = ::serde_json::to_string(&transcript).unwrap();
+        let parsed: Transcript = ::serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, transcript);
+
+        let out_of_range = json.replace("\"result\":[1,2,3]", "\"result\":[1,2,5000]");
+        assert!(::serde_json::from_str::<Transcript>(&out_of_range).is_err());
+        let too_short = json.replace("\"result\":[1,2,3]", "\"result\":[1,2]");
+        assert!(::serde_json::from_str::<Transcript>(&too_short).is_err());
+    }
+}
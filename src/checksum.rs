@@ -0,0 +1,352 @@
+//!
+//! Contains logic for adding a checksum word to a passphrase and for finding and correcting a
+//! mistyped word using it.
+//!
+//! The checksum word is chosen from the same word list by hashing the indices of the other words,
+//! so it adds no entropy but lets a passphrase that was written down and typed back in be checked
+//! before it's relied on.  When the check fails, `validate` suggests replacements for a single
+//! wrong word: list words within `MAX_DISTANCE` edits of the word that was entered which make the
+//! checksum match.
+//!
+//! Corrections are most reliable with `WordList::EffShort2`, whose words are all at least three
+//! edits apart, so a word with one typo is closer to the intended word than to any other.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::checksum::{self, Validation};
+//!
+//! let config = PassGenConfig::builder()
+//!     .word_list(WordList::EffShort2)
+//!     .checksum(true)
+//!     .build()
+//!     .unwrap();
+//! let pass = generate_passphrase(config);
+//! let words: Vec<&str> = pass.as_str().split(' ').collect();
+//! assert!(checksum::validate(&WordList::EffShort2, &words).is_valid());
+//!
+//! // drop the last letter of the first word
+//! let mut typo = words[0].to_string();
+//! typo.pop();
+//! let mut mistyped = words.clone();
+//! mistyped[0] = &typo;
+//!
+//! match checksum::validate(&WordList::EffShort2, &mistyped) {
+//!     Validation::Invalid { unknown, corrections } => {
+//!         assert_eq!(unknown, vec![0]);
+//!         assert_eq!(corrections[0].replacement, words[0]);
+//!     }
+//!     Validation::Valid => panic!("the typo wasn't detected"),
+//! }
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use distance::edit_distance;
+use error::Error;
+use pass_gen::WordList;
+
+///
+/// The largest edit distance between an entered word and a suggested replacement.
+///
+pub const MAX_DISTANCE: usize = 2;
+
+///
+/// The result of checking a passphrase against its checksum word.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validation<'a> {
+    ///
+    /// Every word is in the word list and the checksum word matches.
+    ///
+    Valid,
+
+    ///
+    /// The passphrase failed the check.
+    ///
+    Invalid {
+        ///
+        /// The positions of words which aren't in the word list.
+        ///
+        unknown: Vec<usize>,
+
+        ///
+        /// Single word replacements which would make the passphrase valid, closest first.  Empty
+        /// if none were found within `MAX_DISTANCE` or more than one word is unknown.
+        ///
+        corrections: Vec<Correction<'a>>,
+    },
+}
+
+impl Validation<'_> {
+    ///
+    /// Returns `true` if the passphrase passed the check.
+    ///
+    pub fn is_valid(&self) -> bool {
+        *self == Validation::Valid
+    }
+}
+
+///
+/// A suggested replacement for one word of a passphrase.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Correction<'a> {
+    ///
+    /// The position of the word to replace (the checksum word is last).
+    ///
+    pub position: usize,
+
+    ///
+    /// The word from the word list to use instead.
+    ///
+    pub replacement: &'a str,
+
+    ///
+    /// The edit distance between the entered word and `replacement`.
+    ///
+    pub distance: usize,
+}
+
+///
+/// Returns the checksum word for `words`, which must all be from `word_list`.
+///
+/// ```
+/// use lib_dicepass_gen::*;
+/// use lib_dicepass_gen::checksum;
+///
+/// let words = ["acid", "acorn", "acre"];
+/// let check = checksum::checksum_word(&WordList::EffLong, &words).unwrap();
+/// assert!(checksum::validate(&WordList::EffLong, &["acid", "acorn", "acre", check]).is_valid());
+/// ```
+///
+pub fn checksum_word<'a, S: AsRef<str>>(
+    word_list: &'a WordList,
+    words: &[S],
+) -> Result<&'a str, Error> {
+    let list = Lookup::new(word_list);
+    let indices = words
+        .iter()
+        .map(|word| {
+            list.index(word.as_ref())
+                .ok_or_else(|| Error::UnknownWord(word.as_ref().to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(list.words[checksum(list.words.len(), &indices)])
+}
+
+///
+/// Checks `words`, whose last word is a checksum word, and suggests corrections if the check
+/// fails.
+///
+/// Words are matched against `word_list` ignoring case.  If exactly one word isn't in the list,
+/// only replacements for that word are suggested; if several aren't, none are.
+///
+pub fn validate<'a, S: AsRef<str>>(word_list: &'a WordList, words: &[S]) -> Validation<'a> {
+    let list = Lookup::new(word_list);
+    let indices: Vec<Option<usize>> = words.iter().map(|word| list.index(word.as_ref())).collect();
+    let unknown: Vec<usize> = (0..indices.len())
+        .filter(|&position| indices[position].is_none())
+        .collect();
+
+    if words.is_empty() {
+        return Validation::Invalid {
+            unknown,
+            corrections: Vec::new(),
+        };
+    }
+    if unknown.is_empty() {
+        let known: Vec<usize> = indices.iter().map(|index| index.unwrap()).collect();
+        if valid(list.words.len(), &known) {
+            return Validation::Valid;
+        }
+    }
+
+    let positions: Vec<usize> = match unknown.len() {
+        0 => (0..words.len()).collect(),
+        1 => unknown.clone(),
+        _ => Vec::new(),
+    };
+    let mut corrections = Vec::new();
+    for position in positions {
+        let entered = words[position].as_ref().to_lowercase();
+        let mut trial: Vec<usize> = indices.iter().map(|index| index.unwrap_or(0)).collect();
+        for (index, candidate) in list.words.iter().enumerate() {
+            if indices[position] == Some(index) {
+                continue;
+            }
+            let distance = edit_distance(&entered, &candidate.to_lowercase());
+            if distance > MAX_DISTANCE {
+                continue;
+            }
+            trial[position] = index;
+            if valid(list.words.len(), &trial) {
+                corrections.push(Correction {
+                    position,
+                    replacement: candidate,
+                    distance,
+                });
+            }
+        }
+    }
+    corrections.sort_by_key(|correction| (correction.distance, correction.position));
+
+    Validation::Invalid {
+        unknown,
+        corrections,
+    }
+}
+
+struct Lookup<'a> {
+    words: Vec<&'a str>,
+    indices: BTreeMap<String, usize>,
+}
+
+impl<'a> Lookup<'a> {
+    fn new(word_list: &'a WordList) -> Lookup<'a> {
+        let words = word_list.words();
+        let indices = words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (word.to_lowercase(), index))
+            .collect();
+        Lookup { words, indices }
+    }
+
+    fn index(&self, word: &str) -> Option<usize> {
+        self.indices.get(&word.to_lowercase()).cloned()
+    }
+}
+
+/// Returns `true` if the last of `indices` is the checksum of the others.
+fn valid(list_len: usize, indices: &[usize]) -> bool {
+    let (check, rest) = indices.split_last().expect("indices is not empty");
+    checksum(list_len, rest) == *check
+}
+
+/// Returns the index of the checksum word: a 64-bit FNV-1a hash of the list length and the word
+/// indices, reduced modulo the list length.
+fn checksum(list_len: usize, indices: &[usize]) -> usize {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut update = |value: usize| {
+        for byte in &(value as u32).to_be_bytes() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    update(list_len);
+    for &index in indices {
+        update(index);
+    }
+    (hash % list_len as u64) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn with_checksum<'a>(word_list: &'a WordList, words: &[&'a str]) -> Vec<&'a str> {
+        let mut words = words.to_vec();
+        words.push(checksum_word(word_list, &words).unwrap());
+        words
+    }
+
+    fn corrections<'a>(validation: Validation<'a>) -> Vec<Correction<'a>> {
+        match validation {
+            Validation::Invalid { corrections, .. } => corrections,
+            Validation::Valid => panic!("expected an invalid passphrase"),
+        }
+    }
+
+    #[test]
+    fn checksum_is_deterministic() {
+        let list = WordList::EffLong;
+        let words = with_checksum(&list, &["acid", "acorn", "acre"]);
+        assert_eq!(words, with_checksum(&list, &["acid", "acorn", "acre"]));
+        assert!(validate(&list, &words).is_valid());
+
+        // case is ignored
+        let upper: Vec<String> = words.iter().map(|word| word.to_uppercase()).collect();
+        assert!(validate(&list, &upper).is_valid());
+
+        assert_eq!(
+            checksum_word(&list, &["acid", "notaword"]),
+            Err(Error::UnknownWord("notaword".to_string()))
+        );
+    }
+
+    #[test]
+    fn swapped_words_are_detected() {
+        let list = WordList::EffLong;
+        let mut words = with_checksum(&list, &["acid", "acorn", "acre"]);
+        words.swap(0, 1);
+        assert!(!validate(&list, &words).is_valid());
+    }
+
+    #[test]
+    fn unknown_word_is_corrected() {
+        let list = WordList::EffShort2;
+        let all = list.words();
+        let words = with_checksum(&list, &[all[10], all[500], all[1000]]);
+
+        let mut typo = all[500].to_string();
+        typo.insert(1, 'q');
+        let mut mistyped = words.clone();
+        mistyped[1] = &typo;
+
+        match validate(&list, &mistyped) {
+            Validation::Invalid {
+                unknown,
+                corrections,
+            } => {
+                assert_eq!(unknown, vec![1]);
+                assert_eq!(
+                    corrections[0],
+                    Correction {
+                        position: 1,
+                        replacement: all[500],
+                        distance: 1,
+                    }
+                );
+            }
+            Validation::Valid => panic!("the typo wasn't detected"),
+        }
+    }
+
+    #[test]
+    fn wrong_list_word_is_corrected() {
+        // a typo which turns one list word into another can only be found with the checksum
+        let list = WordList::EffLong;
+        let words = with_checksum(&list, &["acid", "acorn", "coat"]);
+        let mut mistyped = words.clone();
+        mistyped[2] = "boat";
+
+        let found = corrections(validate(&list, &mistyped));
+        assert!(found.contains(&Correction {
+            position: 2,
+            replacement: "coat",
+            distance: 1,
+        }));
+    }
+
+    #[test]
+    fn several_unknown_words() {
+        let list = WordList::EffLong;
+        let validation = validate(&list, &["acid", "notaword", "zzzz", "acre"]);
+        assert_eq!(
+            validation,
+            Validation::Invalid {
+                unknown: vec![1, 2],
+                corrections: Vec::new(),
+            }
+        );
+        assert!(!validate::<&str>(&list, &[]).is_valid());
+    }
+}
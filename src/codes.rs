@@ -0,0 +1,445 @@
+//!
+//! Contains logic for generating numeric PINs and grouped alphanumeric codes.
+//!
+//! PINs and codes are generated one symbol at a time, like words in a passphrase: each digit or
+//! character is chosen by simulated dice rolls (`Sampling::Dice`, rejecting sequences which don't
+//! correspond to a symbol) or uniformly (`Sampling::Uniform`).  They're returned as a
+//! `Passphrase` whose `word_count` is the number of symbols, so their entropy and strength are
+//! reported the same way.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::codes::{self, CodeConfig, PinConfig};
+//!
+//! let pin = codes::generate_pin(&PinConfig::default()).unwrap();
+//! assert_eq!(pin.as_str().len(), 6);
+//!
+//! let code = codes::generate_code(&CodeConfig::default()).unwrap();
+//! println!("{} ({} bits)", code.as_str(), code.entropy);
+//! // should result in something like:
+//! // "7KQ2-X9MD-H4TR (60 bits)"
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+use dice::DicePlan;
+use error::Error;
+use math;
+#[cfg(feature = "std")]
+use pass_gen::os_rng;
+use pass_gen::{self, Passphrase, Sampling};
+
+///
+/// The symbols used in PINs.
+///
+pub const DIGITS: &str = "0123456789";
+
+///
+/// Crockford's base 32 alphabet, used in codes.  It leaves out I, L and O, which are easily
+/// confused with 1 and 0, and U, so codes can't spell common obscenities.  Each symbol provides
+/// 5 bits of entropy.
+///
+pub const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+///
+/// A config object describing how to generate a numeric PIN.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PinConfig {
+    ///
+    /// The number of digits.  Defaults to 6.
+    ///
+    pub length: u32,
+
+    ///
+    /// Whether PINs which `is_weak_pin` are rejected and generated again.  This reduces the
+    /// entropy slightly, which `entropy` accounts for.  Defaults to `true`.
+    ///
+    pub reject_weak: bool,
+
+    ///
+    /// How each digit is chosen.  Defaults to simulated rolls of a d6.
+    ///
+    pub sampling: Sampling,
+}
+
+impl Default for PinConfig {
+    fn default() -> PinConfig {
+        PinConfig {
+            length: 6,
+            reject_weak: true,
+            sampling: Sampling::default(),
+        }
+    }
+}
+
+impl PinConfig {
+    ///
+    /// Returns the number of PINs of this length which `is_weak_pin`.
+    ///
+    pub fn weak_count(&self) -> u64 {
+        let n = self.length;
+        let repeated = if n >= 2 { 10 } else { 0 };
+        let sequences = if (3..=10).contains(&n) {
+            2 * u64::from(11 - n)
+        } else {
+            0
+        };
+        let alternating = if n >= 4 { 90 } else { 0 };
+        repeated + sequences + alternating
+    }
+
+    ///
+    /// Returns the total entropy (in bits) of a PIN generated from this config.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::codes::PinConfig;
+    ///
+    /// let mut config = PinConfig::default();
+    /// config.reject_weak = false;
+    /// assert!((config.entropy() - 19.93).abs() < 0.01);
+    ///
+    /// // 110 of the million 6 digit PINs are weak
+    /// config.reject_weak = true;
+    /// assert_eq!(config.weak_count(), 110);
+    /// assert!(config.entropy() < 19.932);
+    /// ```
+    ///
+    pub fn entropy(&self) -> f64 {
+        let all = f64::from(self.length) * math::log2(10.0);
+        if !self.reject_weak || self.length == 0 {
+            return all;
+        }
+        // log2(10^n - weak) = n log2(10) + log2(1 - weak / 10^n); 10^n is only formed as a float,
+        // which becomes infinite for very long PINs, where the correction is negligible anyway
+        let mut total = 1.0;
+        for _ in 0..self.length {
+            total *= 10.0;
+        }
+        all + math::log2(1.0 - self.weak_count() as f64 / total)
+    }
+}
+
+///
+/// Returns whether `pin` follows an easily guessed pattern:
+///
+///   1. the same digit repeated ("0000"), for two or more digits
+///   2. a run of consecutive digits up or down ("1234", "9876"), for three or more digits
+///   3. two different digits alternating ("1212"), for four or more digits
+///
+/// ```
+/// use lib_dicepass_gen::codes::is_weak_pin;
+///
+/// assert!(is_weak_pin("111111"));
+/// assert!(is_weak_pin("456789"));
+/// assert!(is_weak_pin("909090"));
+/// assert!(!is_weak_pin("290417"));
+/// ```
+///
+pub fn is_weak_pin(pin: &str) -> bool {
+    let digits: Vec<i32> = pin
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|digit| digit as i32)
+        .collect();
+    let n = digits.len();
+    let steps: Vec<i32> = digits.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+    let repeated = n >= 2 && steps.iter().all(|&step| step == 0);
+    let sequence =
+        n >= 3 && (steps.iter().all(|&step| step == 1) || steps.iter().all(|&step| step == -1));
+    let alternating = n >= 4
+        && digits[0] != digits[1]
+        && digits
+            .iter()
+            .enumerate()
+            .all(|(i, &digit)| digit == digits[i % 2]);
+    repeated || sequence || alternating
+}
+
+///
+/// A config object describing how to generate a grouped code such as `XXXX-XXXX-XXXX`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CodeConfig {
+    ///
+    /// The number of groups.  Defaults to 3.
+    ///
+    pub groups: u32,
+
+    ///
+    /// The number of characters in each group.  Defaults to 4.
+    ///
+    pub group_length: u32,
+
+    ///
+    /// The string placed between groups.  Defaults to "-".
+    ///
+    pub separator: String,
+
+    ///
+    /// How each character is chosen.  Defaults to simulated rolls of a d6.
+    ///
+    pub sampling: Sampling,
+}
+
+impl Default for CodeConfig {
+    fn default() -> CodeConfig {
+        CodeConfig {
+            groups: 3,
+            group_length: 4,
+            separator: "-".to_string(),
+            sampling: Sampling::default(),
+        }
+    }
+}
+
+impl CodeConfig {
+    ///
+    /// Returns the total number of characters, not counting separators, or `None` if it doesn't
+    /// fit in a `u32`.
+    ///
+    pub fn length(&self) -> Option<u32> {
+        self.groups.checked_mul(self.group_length)
+    }
+
+    ///
+    /// Returns the total entropy (in bits) of a code generated from this config: 5 bits per
+    /// character.
+    ///
+    pub fn entropy(&self) -> f64 {
+        f64::from(self.groups) * f64::from(self.group_length) * math::log2(CROCKFORD.len() as f64)
+    }
+}
+
+///
+/// Normalizes a code typed in by a person so it can be compared with the generated one.
+///
+/// Letters are upper cased, `O` is read as `0`, `I` and `L` as `1`, and separators (hyphens and
+/// whitespace) are removed.  Returns an error for any other character outside `CROCKFORD`.
+///
+/// ```
+/// use lib_dicepass_gen::codes::normalize_code;
+///
+/// assert_eq!(normalize_code("7kq2-x9md-h4tr").unwrap(), "7KQ2X9MDH4TR");
+/// assert_eq!(normalize_code("O1L0 i").unwrap(), "01101");
+/// assert!(normalize_code("UUUU").is_err());
+/// ```
+///
+pub fn normalize_code(input: &str) -> Result<String, Error> {
+    let mut code = String::new();
+    for c in input.chars() {
+        if c == '-' || c.is_whitespace() {
+            continue;
+        }
+        let c = match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        if !CROCKFORD.contains(c) {
+            return Err(Error::InvalidInput(c.to_string()));
+        }
+        code.push(c);
+    }
+    Ok(code)
+}
+
+///
+/// Generates a PIN using the operating system's secure random number generator.
+///
+/// Returns `Error::InvalidLength` if `config.length` is 0.
+///
+#[cfg(feature = "std")]
+pub fn generate_pin(config: &PinConfig) -> Result<Passphrase, Error> {
+    generate_pin_with(config, &mut os_rng())
+}
+
+///
+/// Generates a PIN using `rng`.
+///
+/// Returns `Error::InvalidLength` if `config.length` is 0.
+///
+pub fn generate_pin_with<R: Rng>(config: &PinConfig, rng: &mut R) -> Result<Passphrase, Error> {
+    if config.length == 0 {
+        return Err(Error::InvalidLength(0));
+    }
+    let pin = loop {
+        let pin = symbols(DIGITS, config.length, config.sampling, rng);
+        if !config.reject_weak || !is_weak_pin(&pin) {
+            break pin;
+        }
+    };
+    Ok(Passphrase::new(pin, config.length, config.entropy()))
+}
+
+///
+/// Generates a code using the operating system's secure random number generator.
+///
+/// Returns `Error::InvalidLength` if `config.groups` or `config.group_length` is 0, or if the
+/// code would have more than `u32::MAX` characters.
+///
+#[cfg(feature = "std")]
+pub fn generate_code(config: &CodeConfig) -> Result<Passphrase, Error> {
+    generate_code_with(config, &mut os_rng())
+}
+
+///
+/// Generates a code using `rng`.
+///
+/// Returns `Error::InvalidLength` if `config.groups` or `config.group_length` is 0, or if the
+/// code would have more than `u32::MAX` characters.
+///
+pub fn generate_code_with<R: Rng>(config: &CodeConfig, rng: &mut R) -> Result<Passphrase, Error> {
+    let length = match config.length() {
+        Some(length) if length > 0 => length,
+        _ => {
+            let length = u64::from(config.groups) * u64::from(config.group_length);
+            return Err(Error::InvalidLength(length));
+        }
+    };
+    let code = (0..config.groups)
+        .map(|_| symbols(CROCKFORD, config.group_length, config.sampling, rng))
+        .collect::<Vec<_>>()
+        .join(&config.separator);
+    Ok(Passphrase::new(code, length, config.entropy()))
+}
+
+fn symbols<R: Rng>(alphabet: &str, count: u32, sampling: Sampling, rng: &mut R) -> String {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    (0..count)
+        .map(|_| {
+            let index = match sampling {
+                Sampling::Dice(die) => {
+                    let plan = DicePlan::new(alphabet.len(), die);
+                    loop {
+                        if let Ok(Some(index)) = plan.index(&plan.roll(rng)) {
+                            break index;
+                        }
+                    }
+                }
+                Sampling::Uniform => pass_gen::gen_index(alphabet.len(), rng),
+            };
+            alphabet[index]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use dice::Die;
+    use rand::chacha::ChaChaRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn weak_counts_match_patterns() {
+        for length in 0..6 {
+            let config = PinConfig {
+                length,
+                ..PinConfig::default()
+            };
+            let mut total = 1;
+            for _ in 0..length {
+                total *= 10;
+            }
+            let weak = (0..total)
+                .filter(|pin| is_weak_pin(&format!("{:0width$}", pin, width = length as usize)))
+                .count();
+            assert_eq!(weak as u64, config.weak_count(), "length {}", length);
+        }
+        assert!(is_weak_pin("0123456789"));
+        assert!(!is_weak_pin("12"));
+        assert!(!is_weak_pin("121"));
+    }
+
+    #[test]
+    fn gen_pins() {
+        let mut rng = ChaChaRng::from_seed(&[5; 8]);
+        for &sampling in &[
+            Sampling::Dice(Die::D6),
+            Sampling::Dice(Die::D10),
+            Sampling::Uniform,
+        ] {
+            let config = PinConfig {
+                length: 4,
+                reject_weak: true,
+                sampling,
+            };
+            for _ in 0..200 {
+                let pin = generate_pin_with(&config, &mut rng).unwrap();
+                assert_eq!(pin.as_str().len(), 4);
+                assert!(pin.as_str().chars().all(|c| c.is_ascii_digit()));
+                assert!(!is_weak_pin(pin.as_str()));
+                assert_eq!(pin.word_count, 4);
+                assert!((pin.entropy - math::log2(10_000.0 - 114.0)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn gen_codes() {
+        let mut rng = ChaChaRng::from_seed(&[6; 8]);
+        let config = CodeConfig::default();
+        let code = generate_code_with(&config, &mut rng).unwrap();
+        let groups: Vec<&str> = code.as_str().split('-').collect();
+        assert_eq!(groups.len(), 3);
+        assert!(groups
+            .iter()
+            .all(|group| group.len() == 4 && group.chars().all(|c| CROCKFORD.contains(c))));
+        assert_eq!(code.entropy, 60.0);
+        assert_eq!(code.bits_per_word, 5.0);
+        assert_eq!(
+            normalize_code(&code.as_str().to_lowercase()).unwrap(),
+            code.as_str().replace('-', "")
+        );
+    }
+
+    #[test]
+    fn invalid_lengths() {
+        let mut rng = ChaChaRng::from_seed(&[7; 8]);
+        let pin = PinConfig {
+            length: 0,
+            ..PinConfig::default()
+        };
+        assert_eq!(
+            generate_pin_with(&pin, &mut rng),
+            Err(Error::InvalidLength(0))
+        );
+
+        for &(groups, group_length) in &[(0, 4), (3, 0), (0, 0)] {
+            let code = CodeConfig {
+                groups,
+                group_length,
+                ..CodeConfig::default()
+            };
+            assert_eq!(
+                generate_code_with(&code, &mut rng),
+                Err(Error::InvalidLength(0))
+            );
+        }
+
+        let code = CodeConfig {
+            groups: u32::MAX,
+            group_length: 2,
+            ..CodeConfig::default()
+        };
+        assert_eq!(code.length(), None);
+        assert_eq!(
+            generate_code_with(&code, &mut rng),
+            Err(Error::InvalidLength(u64::from(u32::MAX) * 2))
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//!
+//! Contains logic for completing and correcting words as they're typed, and for expanding
+//! passphrases entered as word prefixes.
+//!
+//! A `Completer` indexes a word list in a prefix trie.  `WordList::EffShort2` is designed for
+//! this: every word is identified by its first three letters, so a passphrase from it can be
+//! entered as three-letter prefixes and expanded back into full words.
+//!
+//! ```
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::complete::Completer;
+//!
+//! let list = WordList::EffShort2;
+//! let completer = Completer::new(&list);
+//! assert_eq!(completer.prefix_len(), 3);
+//!
+//! let words = list.words();
+//! let typed = format!("{} {}", &words[0][..3], &words[1][..3]);
+//! assert_eq!(completer.expand_phrase(&typed).unwrap(), vec![words[0], words[1]]);
+//! ```
+//!
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use distance::edit_distance;
+use error::Error;
+use pass_gen::WordList;
+
+///
+/// The largest edit distance between a typed word and a correction returned by
+/// `Completer::suggest`.
+///
+pub const MAX_DISTANCE: usize = 2;
+
+///
+/// Completes, corrects and expands words from a word list.
+///
+/// Matching ignores case, but words are always returned as they appear in the list.
+///
+#[derive(Debug, Clone)]
+pub struct Completer<'a> {
+    words: Vec<&'a str>,
+    root: Node,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: BTreeMap<char, Node>,
+    word: Option<usize>,
+}
+
+impl<'a> Completer<'a> {
+    ///
+    /// Returns a `Completer` for the words in `word_list`.
+    ///
+    pub fn new(word_list: &'a WordList) -> Completer<'a> {
+        let words = word_list.words();
+        let mut root = Node::default();
+        for (index, word) in words.iter().enumerate() {
+            let mut node = &mut root;
+            for c in word.to_lowercase().chars() {
+                node = node.children.entry(c).or_default();
+            }
+            node.word = Some(index);
+        }
+        Completer { words, root }
+    }
+
+    ///
+    /// Returns the words starting with `prefix`, in alphabetical order.
+    ///
+    pub fn complete(&self, prefix: &str) -> Vec<&'a str> {
+        let mut completions = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            self.collect(node, &mut completions);
+        }
+        completions
+    }
+
+    ///
+    /// Returns the words within `max_distance` edits of `word`, nearest first.  Words at the same
+    /// distance are in alphabetical order.
+    ///
+    pub fn correct(&self, word: &str, max_distance: usize) -> Vec<&'a str> {
+        let typed = word.to_lowercase();
+        let mut corrections: Vec<(usize, String, &'a str)> = self
+            .words
+            .iter()
+            .filter_map(|&candidate| {
+                let lower = candidate.to_lowercase();
+                let distance = edit_distance(&typed, &lower);
+                if distance <= max_distance {
+                    Some((distance, lower, candidate))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        corrections.sort();
+        corrections
+            .into_iter()
+            .map(|(_, _, candidate)| candidate)
+            .collect()
+    }
+
+    ///
+    /// Returns the words `word` could be: its completions if there are any, otherwise the words
+    /// within `MAX_DISTANCE` edits of it.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    /// use lib_dicepass_gen::complete::Completer;
+    ///
+    /// let completer = Completer::new(&WordList::EffLong);
+    /// assert_eq!(completer.suggest("abd"), vec!["abdomen", "abdominal"]);
+    /// assert_eq!(completer.suggest("abdoment")[0], "abdomen");
+    /// ```
+    ///
+    pub fn suggest(&self, word: &str) -> Vec<&'a str> {
+        let completions = self.complete(word);
+        if completions.is_empty() {
+            self.correct(word, MAX_DISTANCE)
+        } else {
+            completions
+        }
+    }
+
+    ///
+    /// Returns the word `prefix` identifies: the word equal to it, or else the only word starting
+    /// with it.
+    ///
+    /// Returns an error if no word starts with `prefix` or more than one does.
+    ///
+    pub fn expand(&self, prefix: &str) -> Result<&'a str, Error> {
+        let node = self
+            .find(prefix)
+            .ok_or_else(|| Error::UnknownWord(prefix.to_string()))?;
+        if let Some(index) = node.word {
+            return Ok(self.words[index]);
+        }
+        match self.complete(prefix).as_slice() {
+            [word] => Ok(word),
+            _ => Err(Error::AmbiguousPrefix(prefix.to_string())),
+        }
+    }
+
+    ///
+    /// Expands each whitespace separated prefix in `phrase` with `expand`.
+    ///
+    pub fn expand_phrase(&self, phrase: &str) -> Result<Vec<&'a str>, Error> {
+        phrase
+            .split_whitespace()
+            .map(|prefix| self.expand(prefix))
+            .collect()
+    }
+
+    ///
+    /// Returns the shortest prefix length which identifies every word: typing that many letters
+    /// of any word (or the whole word, if it's shorter) is enough for `expand`.
+    ///
+    pub fn prefix_len(&self) -> usize {
+        let mut words: Vec<Vec<char>> = self
+            .words
+            .iter()
+            .map(|word| word.to_lowercase().chars().collect())
+            .collect();
+        words.sort();
+
+        let common = |a: &[char], b: &[char]| a.iter().zip(b).take_while(|(x, y)| x == y).count();
+        (0..words.len())
+            .map(|i| {
+                let before = if i > 0 {
+                    common(&words[i - 1], &words[i])
+                } else {
+                    0
+                };
+                let after = words.get(i + 1).map_or(0, |next| common(&words[i], next));
+                words[i].len().min(before.max(after) + 1)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in prefix.to_lowercase().chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    fn collect(&self, node: &Node, words: &mut Vec<&'a str>) {
+        if let Some(index) = node.word {
+            words.push(self.words[index]);
+        }
+        for child in node.children.values() {
+            self.collect(child, words);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use custom_list::CustomList;
+
+    #[test]
+    fn complete() {
+        let list = WordList::EffLong;
+        let completer = Completer::new(&list);
+        assert_eq!(completer.complete("acor"), vec!["acorn"]);
+        assert_eq!(
+            completer.complete("ACR"),
+            vec!["acre", "acrobat", "acronym"]
+        );
+        assert!(completer.complete("zzz").is_empty());
+        assert_eq!(completer.complete("").len(), 7776);
+    }
+
+    #[test]
+    fn correct() {
+        let list = WordList::EffLong;
+        let completer = Completer::new(&list);
+        assert_eq!(completer.correct("acron", 1), vec!["acorn", "apron"]);
+        assert_eq!(completer.correct("coat", 0), vec!["coat"]);
+        assert_eq!(completer.correct("xcoat", 1), vec!["coat"]);
+        assert!(completer.correct("zzzzzzzz", 2).is_empty());
+    }
+
+    #[test]
+    fn expand() {
+        let list = CustomList::new(vec!["cat", "catalog", "dog"]).unwrap();
+        let list = WordList::Custom(list);
+        let completer = Completer::new(&list);
+        assert_eq!(completer.expand("do"), Ok("dog"));
+        assert_eq!(completer.expand("Cat"), Ok("cat"));
+        assert_eq!(completer.expand("cata"), Ok("catalog"));
+        assert_eq!(
+            completer.expand("ca"),
+            Err(Error::AmbiguousPrefix("ca".to_string()))
+        );
+        assert_eq!(
+            completer.expand("cow"),
+            Err(Error::UnknownWord("cow".to_string()))
+        );
+        assert_eq!(completer.prefix_len(), 4);
+    }
+
+    #[test]
+    fn eff_short_2_prefixes() {
+        let list = WordList::EffShort2;
+        let completer = Completer::new(&list);
+        assert_eq!(completer.prefix_len(), 3);
+
+        let words = list.words();
+        let prefixes: Vec<&str> = words.iter().map(|word| &word[..3]).collect();
+        assert_eq!(completer.expand_phrase(&prefixes.join(" ")).unwrap(), words);
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! Contains logic and structures for generating passphrases from user-supplied word lists.
+//!
+
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use error::Error;
+
+///
+/// A user-supplied word list of arbitrary size.
+///
+/// Unlike the EFF word lists, a `CustomList` need not contain a power of six words.  Passphrases
+/// are generated from it with `Sampling::Uniform` by default, which picks words uniformly by
+/// index rather than by simulated dice rolls.
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use lib_dicepass_gen::*;
+///
+/// let list = CustomList::new(vec!["red", "green", "blue", "cyan", "magenta"]).unwrap();
+/// let config = PassGenConfig::from_custom(list, WordCount::Custom(4));
+/// let pass = generate(config);
+///
+/// assert_eq!(pass.split_whitespace().count(), 4);
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "Vec<String>", into = "Vec<String>")
+)]
+pub struct CustomList {
+    words: Vec<String>,
+}
+
+impl CustomList {
+    ///
+    /// Creates a `CustomList` from the given words, in order.
+    ///
+    /// Surrounding whitespace is trimmed from each word.  Returns an error if the list contains
+    /// fewer than two words, a word that is empty or contains whitespace, or a duplicate word.
+    /// Words are compared ignoring case, since passphrases may be recased and are looked up
+    /// case-insensitively.
+    ///
+    pub fn new<I, S>(words: I) -> Result<CustomList, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut seen = BTreeSet::new();
+        let mut list = Vec::new();
+
+        for word in words {
+            let word = word.as_ref().trim();
+            if word.is_empty() || word.contains(char::is_whitespace) {
+                return Err(Error::InvalidWord(word.to_string()));
+            }
+            if !seen.insert(word.to_lowercase()) {
+                return Err(Error::DuplicateWord(word.to_string()));
+            }
+            list.push(word.to_string());
+        }
+
+        if list.len() < 2 {
+            return Err(Error::WordListTooSmall(list.len()));
+        }
+
+        Ok(CustomList { words: list })
+    }
+
+    ///
+    /// Parses a `CustomList` from text containing one word per line.
+    ///
+    /// Blank lines are ignored.  Lines in the format used by the EFF's published lists (a dice
+    /// sequence followed by whitespace and then the word) are also accepted; only the last field
+    /// of each line is used.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    ///
+    /// let list = CustomList::parse("11\tapple\n12\tbanana\n\n13\tcherry\n").unwrap();
+    /// assert_eq!(list.words(), vec!["apple", "banana", "cherry"]);
+    /// ```
+    ///
+    pub fn parse(text: &str) -> Result<CustomList, Error> {
+        let words = text
+            .lines()
+            .filter_map(|line| line.split_whitespace().last());
+        CustomList::new(words)
+    }
+
+    ///
+    /// Returns the words in this list, in order.
+    ///
+    pub fn words(&self) -> Vec<&str> {
+        self.words.iter().map(|word| word.as_str()).collect()
+    }
+
+    ///
+    /// Returns the word at `index`, or `None` if it's out of range.
+    ///
+    pub fn word(&self, index: usize) -> Option<&str> {
+        self.words.get(index).map(|word| word.as_str())
+    }
+
+    ///
+    /// Returns the number of words in this list.
+    ///
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    ///
+    /// Always returns `false`; a `CustomList` contains at least two words.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl TryFrom<Vec<String>> for CustomList {
+    type Error = Error;
+
+    fn try_from(words: Vec<String>) -> Result<CustomList, Error> {
+        CustomList::new(words)
+    }
+}
+
+impl From<CustomList> for Vec<String> {
+    fn from(list: CustomList) -> Vec<String> {
+        list.words
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn too_small() {
+        assert_eq!(
+            CustomList::new(Vec::<&str>::new()),
+            Err(Error::WordListTooSmall(0))
+        );
+        assert_eq!(
+            CustomList::new(vec!["lonely"]),
+            Err(Error::WordListTooSmall(1))
+        );
+    }
+
+    #[test]
+    fn duplicate() {
+        assert_eq!(
+            CustomList::new(vec!["one", "two", " one"]),
+            Err(Error::DuplicateWord("one".to_string()))
+        );
+        assert_eq!(
+            CustomList::new(vec!["Red", "green", "red"]),
+            Err(Error::DuplicateWord("red".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            CustomList::new(vec!["one", "two words"]),
+            Err(Error::InvalidWord("two words".to_string()))
+        );
+        assert_eq!(
+            CustomList::new(vec!["one", "  "]),
+            Err(Error::InvalidWord("".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_eff_format() {
+        let list = CustomList::parse("1111\tacid\n1112\tacorn\r\n1113\tacre\n").unwrap();
+        assert_eq!(list.words(), vec!["acid", "acorn", "acre"]);
+    }
+}
@@ -2,6 +2,7 @@
 //! The specification for the `EffLong` word list.
 //!
 
+#[cfg(feature = "std")]
 use std::collections::HashMap;
 
 ///
@@ -14,7788 +15,7795 @@ pub static WORD_COUNT: u32 = 5;
 ///
 pub static ROLLS_PER_WORD: u32 = 5;
 
+///
+/// The `EffLong` word list as `(dice sequence, word)` pairs in dice-sequence order.
+///
+/// The word for the sequence `11111` is at index 0 and the word for `66666` is at index 7775.
+///
+pub static ENTRIES: &[(&str, &str)] = &[
+    ("11111", "abacus"),
+    ("11112", "abdomen"),
+    ("11113", "abdominal"),
+    ("11114", "abide"),
+    ("11115", "abiding"),
+    ("11116", "ability"),
+    ("11121", "ablaze"),
+    ("11122", "able"),
+    ("11123", "abnormal"),
+    ("11124", "abrasion"),
+    ("11125", "abrasive"),
+    ("11126", "abreast"),
+    ("11131", "abridge"),
+    ("11132", "abroad"),
+    ("11133", "abruptly"),
+    ("11134", "absence"),
+    ("11135", "absentee"),
+    ("11136", "absently"),
+    ("11141", "absinthe"),
+    ("11142", "absolute"),
+    ("11143", "absolve"),
+    ("11144", "abstain"),
+    ("11145", "abstract"),
+    ("11146", "absurd"),
+    ("11151", "accent"),
+    ("11152", "acclaim"),
+    ("11153", "acclimate"),
+    ("11154", "accompany"),
+    ("11155", "account"),
+    ("11156", "accuracy"),
+    ("11161", "accurate"),
+    ("11162", "accustom"),
+    ("11163", "acetone"),
+    ("11164", "achiness"),
+    ("11165", "aching"),
+    ("11166", "acid"),
+    ("11211", "acorn"),
+    ("11212", "acquaint"),
+    ("11213", "acquire"),
+    ("11214", "acre"),
+    ("11215", "acrobat"),
+    ("11216", "acronym"),
+    ("11221", "acting"),
+    ("11222", "action"),
+    ("11223", "activate"),
+    ("11224", "activator"),
+    ("11225", "active"),
+    ("11226", "activism"),
+    ("11231", "activist"),
+    ("11232", "activity"),
+    ("11233", "actress"),
+    ("11234", "acts"),
+    ("11235", "acutely"),
+    ("11236", "acuteness"),
+    ("11241", "aeration"),
+    ("11242", "aerobics"),
+    ("11243", "aerosol"),
+    ("11244", "aerospace"),
+    ("11245", "afar"),
+    ("11246", "affair"),
+    ("11251", "affected"),
+    ("11252", "affecting"),
+    ("11253", "affection"),
+    ("11254", "affidavit"),
+    ("11255", "affiliate"),
+    ("11256", "affirm"),
+    ("11261", "affix"),
+    ("11262", "afflicted"),
+    ("11263", "affluent"),
+    ("11264", "afford"),
+    ("11265", "affront"),
+    ("11266", "aflame"),
+    ("11311", "afloat"),
+    ("11312", "aflutter"),
+    ("11313", "afoot"),
+    ("11314", "afraid"),
+    ("11315", "afterglow"),
+    ("11316", "afterlife"),
+    ("11321", "aftermath"),
+    ("11322", "aftermost"),
+    ("11323", "afternoon"),
+    ("11324", "aged"),
+    ("11325", "ageless"),
+    ("11326", "agency"),
+    ("11331", "agenda"),
+    ("11332", "agent"),
+    ("11333", "aggregate"),
+    ("11334", "aghast"),
+    ("11335", "agile"),
+    ("11336", "agility"),
+    ("11341", "aging"),
+    ("11342", "agnostic"),
+    ("11343", "agonize"),
+    ("11344", "agonizing"),
+    ("11345", "agony"),
+    ("11346", "agreeable"),
+    ("11351", "agreeably"),
+    ("11352", "agreed"),
+    ("11353", "agreeing"),
+    ("11354", "agreement"),
+    ("11355", "aground"),
+    ("11356", "ahead"),
+    ("11361", "ahoy"),
+    ("11362", "aide"),
+    ("11363", "aids"),
+    ("11364", "aim"),
+    ("11365", "ajar"),
+    ("11366", "alabaster"),
+    ("11411", "alarm"),
+    ("11412", "albatross"),
+    ("11413", "album"),
+    ("11414", "alfalfa"),
+    ("11415", "algebra"),
+    ("11416", "algorithm"),
+    ("11421", "alias"),
+    ("11422", "alibi"),
+    ("11423", "alienable"),
+    ("11424", "alienate"),
+    ("11425", "aliens"),
+    ("11426", "alike"),
+    ("11431", "alive"),
+    ("11432", "alkaline"),
+    ("11433", "alkalize"),
+    ("11434", "almanac"),
+    ("11435", "almighty"),
+    ("11436", "almost"),
+    ("11441", "aloe"),
+    ("11442", "aloft"),
+    ("11443", "aloha"),
+    ("11444", "alone"),
+    ("11445", "alongside"),
+    ("11446", "aloof"),
+    ("11451", "alphabet"),
+    ("11452", "alright"),
+    ("11453", "although"),
+    ("11454", "altitude"),
+    ("11455", "alto"),
+    ("11456", "aluminum"),
+    ("11461", "alumni"),
+    ("11462", "always"),
+    ("11463", "amaretto"),
+    ("11464", "amaze"),
+    ("11465", "amazingly"),
+    ("11466", "amber"),
+    ("11511", "ambiance"),
+    ("11512", "ambiguity"),
+    ("11513", "ambiguous"),
+    ("11514", "ambition"),
+    ("11515", "ambitious"),
+    ("11516", "ambulance"),
+    ("11521", "ambush"),
+    ("11522", "amendable"),
+    ("11523", "amendment"),
+    ("11524", "amends"),
+    ("11525", "amenity"),
+    ("11526", "amiable"),
+    ("11531", "amicably"),
+    ("11532", "amid"),
+    ("11533", "amigo"),
+    ("11534", "amino"),
+    ("11535", "amiss"),
+    ("11536", "ammonia"),
+    ("11541", "ammonium"),
+    ("11542", "amnesty"),
+    ("11543", "amniotic"),
+    ("11544", "among"),
+    ("11545", "amount"),
+    ("11546", "amperage"),
+    ("11551", "ample"),
+    ("11552", "amplifier"),
+    ("11553", "amplify"),
+    ("11554", "amply"),
+    ("11555", "amuck"),
+    ("11556", "amulet"),
+    ("11561", "amusable"),
+    ("11562", "amused"),
+    ("11563", "amusement"),
+    ("11564", "amuser"),
+    ("11565", "amusing"),
+    ("11566", "anaconda"),
+    ("11611", "anaerobic"),
+    ("11612", "anagram"),
+    ("11613", "anatomist"),
+    ("11614", "anatomy"),
+    ("11615", "anchor"),
+    ("11616", "anchovy"),
+    ("11621", "ancient"),
+    ("11622", "android"),
+    ("11623", "anemia"),
+    ("11624", "anemic"),
+    ("11625", "aneurism"),
+    ("11626", "anew"),
+    ("11631", "angelfish"),
+    ("11632", "angelic"),
+    ("11633", "anger"),
+    ("11634", "angled"),
+    ("11635", "angler"),
+    ("11636", "angles"),
+    ("11641", "angling"),
+    ("11642", "angrily"),
+    ("11643", "angriness"),
+    ("11644", "anguished"),
+    ("11645", "angular"),
+    ("11646", "animal"),
+    ("11651", "animate"),
+    ("11652", "animating"),
+    ("11653", "animation"),
+    ("11654", "animator"),
+    ("11655", "anime"),
+    ("11656", "animosity"),
+    ("11661", "ankle"),
+    ("11662", "annex"),
+    ("11663", "annotate"),
+    ("11664", "announcer"),
+    ("11665", "annoying"),
+    ("11666", "annually"),
+    ("12111", "annuity"),
+    ("12112", "anointer"),
+    ("12113", "another"),
+    ("12114", "answering"),
+    ("12115", "antacid"),
+    ("12116", "antarctic"),
+    ("12121", "anteater"),
+    ("12122", "antelope"),
+    ("12123", "antennae"),
+    ("12124", "anthem"),
+    ("12125", "anthill"),
+    ("12126", "anthology"),
+    ("12131", "antibody"),
+    ("12132", "antics"),
+    ("12133", "antidote"),
+    ("12134", "antihero"),
+    ("12135", "antiquely"),
+    ("12136", "antiques"),
+    ("12141", "antiquity"),
+    ("12142", "antirust"),
+    ("12143", "antitoxic"),
+    ("12144", "antitrust"),
+    ("12145", "antiviral"),
+    ("12146", "antivirus"),
+    ("12151", "antler"),
+    ("12152", "antonym"),
+    ("12153", "antsy"),
+    ("12154", "anvil"),
+    ("12155", "anybody"),
+    ("12156", "anyhow"),
+    ("12161", "anymore"),
+    ("12162", "anyone"),
+    ("12163", "anyplace"),
+    ("12164", "anything"),
+    ("12165", "anytime"),
+    ("12166", "anyway"),
+    ("12211", "anywhere"),
+    ("12212", "aorta"),
+    ("12213", "apache"),
+    ("12214", "apostle"),
+    ("12215", "appealing"),
+    ("12216", "appear"),
+    ("12221", "appease"),
+    ("12222", "appeasing"),
+    ("12223", "appendage"),
+    ("12224", "appendix"),
+    ("12225", "appetite"),
+    ("12226", "appetizer"),
+    ("12231", "applaud"),
+    ("12232", "applause"),
+    ("12233", "apple"),
+    ("12234", "appliance"),
+    ("12235", "applicant"),
+    ("12236", "applied"),
+    ("12241", "apply"),
+    ("12242", "appointee"),
+    ("12243", "appraisal"),
+    ("12244", "appraiser"),
+    ("12245", "apprehend"),
+    ("12246", "approach"),
+    ("12251", "approval"),
+    ("12252", "approve"),
+    ("12253", "apricot"),
+    ("12254", "april"),
+    ("12255", "apron"),
+    ("12256", "aptitude"),
+    ("12261", "aptly"),
+    ("12262", "aqua"),
+    ("12263", "aqueduct"),
+    ("12264", "arbitrary"),
+    ("12265", "arbitrate"),
+    ("12266", "ardently"),
+    ("12311", "area"),
+    ("12312", "arena"),
+    ("12313", "arguable"),
+    ("12314", "arguably"),
+    ("12315", "argue"),
+    ("12316", "arise"),
+    ("12321", "armadillo"),
+    ("12322", "armband"),
+    ("12323", "armchair"),
+    ("12324", "armed"),
+    ("12325", "armful"),
+    ("12326", "armhole"),
+    ("12331", "arming"),
+    ("12332", "armless"),
+    ("12333", "armoire"),
+    ("12334", "armored"),
+    ("12335", "armory"),
+    ("12336", "armrest"),
+    ("12341", "army"),
+    ("12342", "aroma"),
+    ("12343", "arose"),
+    ("12344", "around"),
+    ("12345", "arousal"),
+    ("12346", "arrange"),
+    ("12351", "array"),
+    ("12352", "arrest"),
+    ("12353", "arrival"),
+    ("12354", "arrive"),
+    ("12355", "arrogance"),
+    ("12356", "arrogant"),
+    ("12361", "arson"),
+    ("12362", "art"),
+    ("12363", "ascend"),
+    ("12364", "ascension"),
+    ("12365", "ascent"),
+    ("12366", "ascertain"),
+    ("12411", "ashamed"),
+    ("12412", "ashen"),
+    ("12413", "ashes"),
+    ("12414", "ashy"),
+    ("12415", "aside"),
+    ("12416", "askew"),
+    ("12421", "asleep"),
+    ("12422", "asparagus"),
+    ("12423", "aspect"),
+    ("12424", "aspirate"),
+    ("12425", "aspire"),
+    ("12426", "aspirin"),
+    ("12431", "astonish"),
+    ("12432", "astound"),
+    ("12433", "astride"),
+    ("12434", "astrology"),
+    ("12435", "astronaut"),
+    ("12436", "astronomy"),
+    ("12441", "astute"),
+    ("12442", "atlantic"),
+    ("12443", "atlas"),
+    ("12444", "atom"),
+    ("12445", "atonable"),
+    ("12446", "atop"),
+    ("12451", "atrium"),
+    ("12452", "atrocious"),
+    ("12453", "atrophy"),
+    ("12454", "attach"),
+    ("12455", "attain"),
+    ("12456", "attempt"),
+    ("12461", "attendant"),
+    ("12462", "attendee"),
+    ("12463", "attention"),
+    ("12464", "attentive"),
+    ("12465", "attest"),
+    ("12466", "attic"),
+    ("12511", "attire"),
+    ("12512", "attitude"),
+    ("12513", "attractor"),
+    ("12514", "attribute"),
+    ("12515", "atypical"),
+    ("12516", "auction"),
+    ("12521", "audacious"),
+    ("12522", "audacity"),
+    ("12523", "audible"),
+    ("12524", "audibly"),
+    ("12525", "audience"),
+    ("12526", "audio"),
+    ("12531", "audition"),
+    ("12532", "augmented"),
+    ("12533", "august"),
+    ("12534", "authentic"),
+    ("12535", "author"),
+    ("12536", "autism"),
+    ("12541", "autistic"),
+    ("12542", "autograph"),
+    ("12543", "automaker"),
+    ("12544", "automated"),
+    ("12545", "automatic"),
+    ("12546", "autopilot"),
+    ("12551", "available"),
+    ("12552", "avalanche"),
+    ("12553", "avatar"),
+    ("12554", "avenge"),
+    ("12555", "avenging"),
+    ("12556", "avenue"),
+    ("12561", "average"),
+    ("12562", "aversion"),
+    ("12563", "avert"),
+    ("12564", "aviation"),
+    ("12565", "aviator"),
+    ("12566", "avid"),
+    ("12611", "avoid"),
+    ("12612", "await"),
+    ("12613", "awaken"),
+    ("12614", "award"),
+    ("12615", "aware"),
+    ("12616", "awhile"),
+    ("12621", "awkward"),
+    ("12622", "awning"),
+    ("12623", "awoke"),
+    ("12624", "awry"),
+    ("12625", "axis"),
+    ("12626", "babble"),
+    ("12631", "babbling"),
+    ("12632", "babied"),
+    ("12633", "baboon"),
+    ("12634", "backache"),
+    ("12635", "backboard"),
+    ("12636", "backboned"),
+    ("12641", "backdrop"),
+    ("12642", "backed"),
+    ("12643", "backer"),
+    ("12644", "backfield"),
+    ("12645", "backfire"),
+    ("12646", "backhand"),
+    ("12651", "backing"),
+    ("12652", "backlands"),
+    ("12653", "backlash"),
+    ("12654", "backless"),
+    ("12655", "backlight"),
+    ("12656", "backlit"),
+    ("12661", "backlog"),
+    ("12662", "backpack"),
+    ("12663", "backpedal"),
+    ("12664", "backrest"),
+    ("12665", "backroom"),
+    ("12666", "backshift"),
+    ("13111", "backside"),
+    ("13112", "backslid"),
+    ("13113", "backspace"),
+    ("13114", "backspin"),
+    ("13115", "backstab"),
+    ("13116", "backstage"),
+    ("13121", "backtalk"),
+    ("13122", "backtrack"),
+    ("13123", "backup"),
+    ("13124", "backward"),
+    ("13125", "backwash"),
+    ("13126", "backwater"),
+    ("13131", "backyard"),
+    ("13132", "bacon"),
+    ("13133", "bacteria"),
+    ("13134", "bacterium"),
+    ("13135", "badass"),
+    ("13136", "badge"),
+    ("13141", "badland"),
+    ("13142", "badly"),
+    ("13143", "badness"),
+    ("13144", "baffle"),
+    ("13145", "baffling"),
+    ("13146", "bagel"),
+    ("13151", "bagful"),
+    ("13152", "baggage"),
+    ("13153", "bagged"),
+    ("13154", "baggie"),
+    ("13155", "bagginess"),
+    ("13156", "bagging"),
+    ("13161", "baggy"),
+    ("13162", "bagpipe"),
+    ("13163", "baguette"),
+    ("13164", "baked"),
+    ("13165", "bakery"),
+    ("13166", "bakeshop"),
+    ("13211", "baking"),
+    ("13212", "balance"),
+    ("13213", "balancing"),
+    ("13214", "balcony"),
+    ("13215", "balmy"),
+    ("13216", "balsamic"),
+    ("13221", "bamboo"),
+    ("13222", "banana"),
+    ("13223", "banish"),
+    ("13224", "banister"),
+    ("13225", "banjo"),
+    ("13226", "bankable"),
+    ("13231", "bankbook"),
+    ("13232", "banked"),
+    ("13233", "banker"),
+    ("13234", "banking"),
+    ("13235", "banknote"),
+    ("13236", "bankroll"),
+    ("13241", "banner"),
+    ("13242", "bannister"),
+    ("13243", "banshee"),
+    ("13244", "banter"),
+    ("13245", "barbecue"),
+    ("13246", "barbed"),
+    ("13251", "barbell"),
+    ("13252", "barber"),
+    ("13253", "barcode"),
+    ("13254", "barge"),
+    ("13255", "bargraph"),
+    ("13256", "barista"),
+    ("13261", "baritone"),
+    ("13262", "barley"),
+    ("13263", "barmaid"),
+    ("13264", "barman"),
+    ("13265", "barn"),
+    ("13266", "barometer"),
+    ("13311", "barrack"),
+    ("13312", "barracuda"),
+    ("13313", "barrel"),
+    ("13314", "barrette"),
+    ("13315", "barricade"),
+    ("13316", "barrier"),
+    ("13321", "barstool"),
+    ("13322", "bartender"),
+    ("13323", "barterer"),
+    ("13324", "bash"),
+    ("13325", "basically"),
+    ("13326", "basics"),
+    ("13331", "basil"),
+    ("13332", "basin"),
+    ("13333", "basis"),
+    ("13334", "basket"),
+    ("13335", "batboy"),
+    ("13336", "batch"),
+    ("13341", "bath"),
+    ("13342", "baton"),
+    ("13343", "bats"),
+    ("13344", "battalion"),
+    ("13345", "battered"),
+    ("13346", "battering"),
+    ("13351", "battery"),
+    ("13352", "batting"),
+    ("13353", "battle"),
+    ("13354", "bauble"),
+    ("13355", "bazooka"),
+    ("13356", "blabber"),
+    ("13361", "bladder"),
+    ("13362", "blade"),
+    ("13363", "blah"),
+    ("13364", "blame"),
+    ("13365", "blaming"),
+    ("13366", "blanching"),
+    ("13411", "blandness"),
+    ("13412", "blank"),
+    ("13413", "blaspheme"),
+    ("13414", "blasphemy"),
+    ("13415", "blast"),
+    ("13416", "blatancy"),
+    ("13421", "blatantly"),
+    ("13422", "blazer"),
+    ("13423", "blazing"),
+    ("13424", "bleach"),
+    ("13425", "bleak"),
+    ("13426", "bleep"),
+    ("13431", "blemish"),
+    ("13432", "blend"),
+    ("13433", "bless"),
+    ("13434", "blighted"),
+    ("13435", "blimp"),
+    ("13436", "bling"),
+    ("13441", "blinked"),
+    ("13442", "blinker"),
+    ("13443", "blinking"),
+    ("13444", "blinks"),
+    ("13445", "blip"),
+    ("13446", "blissful"),
+    ("13451", "blitz"),
+    ("13452", "blizzard"),
+    ("13453", "bloated"),
+    ("13454", "bloating"),
+    ("13455", "blob"),
+    ("13456", "blog"),
+    ("13461", "bloomers"),
+    ("13462", "blooming"),
+    ("13463", "blooper"),
+    ("13464", "blot"),
+    ("13465", "blouse"),
+    ("13466", "blubber"),
+    ("13511", "bluff"),
+    ("13512", "bluish"),
+    ("13513", "blunderer"),
+    ("13514", "blunt"),
+    ("13515", "blurb"),
+    ("13516", "blurred"),
+    ("13521", "blurry"),
+    ("13522", "blurt"),
+    ("13523", "blush"),
+    ("13524", "blustery"),
+    ("13525", "boaster"),
+    ("13526", "boastful"),
+    ("13531", "boasting"),
+    ("13532", "boat"),
+    ("13533", "bobbed"),
+    ("13534", "bobbing"),
+    ("13535", "bobble"),
+    ("13536", "bobcat"),
+    ("13541", "bobsled"),
+    ("13542", "bobtail"),
+    ("13543", "bodacious"),
+    ("13544", "body"),
+    ("13545", "bogged"),
+    ("13546", "boggle"),
+    ("13551", "bogus"),
+    ("13552", "boil"),
+    ("13553", "bok"),
+    ("13554", "bolster"),
+    ("13555", "bolt"),
+    ("13556", "bonanza"),
+    ("13561", "bonded"),
+    ("13562", "bonding"),
+    ("13563", "bondless"),
+    ("13564", "boned"),
+    ("13565", "bonehead"),
+    ("13566", "boneless"),
+    ("13611", "bonelike"),
+    ("13612", "boney"),
+    ("13613", "bonfire"),
+    ("13614", "bonnet"),
+    ("13615", "bonsai"),
+    ("13616", "bonus"),
+    ("13621", "bony"),
+    ("13622", "boogeyman"),
+    ("13623", "boogieman"),
+    ("13624", "book"),
+    ("13625", "boondocks"),
+    ("13626", "booted"),
+    ("13631", "booth"),
+    ("13632", "bootie"),
+    ("13633", "booting"),
+    ("13634", "bootlace"),
+    ("13635", "bootleg"),
+    ("13636", "boots"),
+    ("13641", "boozy"),
+    ("13642", "borax"),
+    ("13643", "boring"),
+    ("13644", "borough"),
+    ("13645", "borrower"),
+    ("13646", "borrowing"),
+    ("13651", "boss"),
+    ("13652", "botanical"),
+    ("13653", "botanist"),
+    ("13654", "botany"),
+    ("13655", "botch"),
+    ("13656", "both"),
+    ("13661", "bottle"),
+    ("13662", "bottling"),
+    ("13663", "bottom"),
+    ("13664", "bounce"),
+    ("13665", "bouncing"),
+    ("13666", "bouncy"),
+    ("14111", "bounding"),
+    ("14112", "boundless"),
+    ("14113", "bountiful"),
+    ("14114", "bovine"),
+    ("14115", "boxcar"),
+    ("14116", "boxer"),
+    ("14121", "boxing"),
+    ("14122", "boxlike"),
+    ("14123", "boxy"),
+    ("14124", "breach"),
+    ("14125", "breath"),
+    ("14126", "breeches"),
+    ("14131", "breeching"),
+    ("14132", "breeder"),
+    ("14133", "breeding"),
+    ("14134", "breeze"),
+    ("14135", "breezy"),
+    ("14136", "brethren"),
+    ("14141", "brewery"),
+    ("14142", "brewing"),
+    ("14143", "briar"),
+    ("14144", "bribe"),
+    ("14145", "brick"),
+    ("14146", "bride"),
+    ("14151", "bridged"),
+    ("14152", "brigade"),
+    ("14153", "bright"),
+    ("14154", "brilliant"),
+    ("14155", "brim"),
+    ("14156", "bring"),
+    ("14161", "brink"),
+    ("14162", "brisket"),
+    ("14163", "briskly"),
+    ("14164", "briskness"),
+    ("14165", "bristle"),
+    ("14166", "brittle"),
+    ("14211", "broadband"),
+    ("14212", "broadcast"),
+    ("14213", "broaden"),
+    ("14214", "broadly"),
+    ("14215", "broadness"),
+    ("14216", "broadside"),
+    ("14221", "broadways"),
+    ("14222", "broiler"),
+    ("14223", "broiling"),
+    ("14224", "broken"),
+    ("14225", "broker"),
+    ("14226", "bronchial"),
+    ("14231", "bronco"),
+    ("14232", "bronze"),
+    ("14233", "bronzing"),
+    ("14234", "brook"),
+    ("14235", "broom"),
+    ("14236", "brought"),
+    ("14241", "browbeat"),
+    ("14242", "brownnose"),
+    ("14243", "browse"),
+    ("14244", "browsing"),
+    ("14245", "bruising"),
+    ("14246", "brunch"),
+    ("14251", "brunette"),
+    ("14252", "brunt"),
+    ("14253", "brush"),
+    ("14254", "brussels"),
+    ("14255", "brute"),
+    ("14256", "brutishly"),
+    ("14261", "bubble"),
+    ("14262", "bubbling"),
+    ("14263", "bubbly"),
+    ("14264", "buccaneer"),
+    ("14265", "bucked"),
+    ("14266", "bucket"),
+    ("14311", "buckle"),
+    ("14312", "buckshot"),
+    ("14313", "buckskin"),
+    ("14314", "bucktooth"),
+    ("14315", "buckwheat"),
+    ("14316", "buddhism"),
+    ("14321", "buddhist"),
+    ("14322", "budding"),
+    ("14323", "buddy"),
+    ("14324", "budget"),
+    ("14325", "buffalo"),
+    ("14326", "buffed"),
+    ("14331", "buffer"),
+    ("14332", "buffing"),
+    ("14333", "buffoon"),
+    ("14334", "buggy"),
+    ("14335", "bulb"),
+    ("14336", "bulge"),
+    ("14341", "bulginess"),
+    ("14342", "bulgur"),
+    ("14343", "bulk"),
+    ("14344", "bulldog"),
+    ("14345", "bulldozer"),
+    ("14346", "bullfight"),
+    ("14351", "bullfrog"),
+    ("14352", "bullhorn"),
+    ("14353", "bullion"),
+    ("14354", "bullish"),
+    ("14355", "bullpen"),
+    ("14356", "bullring"),
+    ("14361", "bullseye"),
+    ("14362", "bullwhip"),
+    ("14363", "bully"),
+    ("14364", "bunch"),
+    ("14365", "bundle"),
+    ("14366", "bungee"),
+    ("14411", "bunion"),
+    ("14412", "bunkbed"),
+    ("14413", "bunkhouse"),
+    ("14414", "bunkmate"),
+    ("14415", "bunny"),
+    ("14416", "bunt"),
+    ("14421", "busboy"),
+    ("14422", "bush"),
+    ("14423", "busily"),
+    ("14424", "busload"),
+    ("14425", "bust"),
+    ("14426", "busybody"),
+    ("14431", "buzz"),
+    ("14432", "cabana"),
+    ("14433", "cabbage"),
+    ("14434", "cabbie"),
+    ("14435", "cabdriver"),
+    ("14436", "cable"),
+    ("14441", "caboose"),
+    ("14442", "cache"),
+    ("14443", "cackle"),
+    ("14444", "cacti"),
+    ("14445", "cactus"),
+    ("14446", "caddie"),
+    ("14451", "caddy"),
+    ("14452", "cadet"),
+    ("14453", "cadillac"),
+    ("14454", "cadmium"),
+    ("14455", "cage"),
+    ("14456", "cahoots"),
+    ("14461", "cake"),
+    ("14462", "calamari"),
+    ("14463", "calamity"),
+    ("14464", "calcium"),
+    ("14465", "calculate"),
+    ("14466", "calculus"),
+    ("14511", "caliber"),
+    ("14512", "calibrate"),
+    ("14513", "calm"),
+    ("14514", "caloric"),
+    ("14515", "calorie"),
+    ("14516", "calzone"),
+    ("14521", "camcorder"),
+    ("14522", "cameo"),
+    ("14523", "camera"),
+    ("14524", "camisole"),
+    ("14525", "camper"),
+    ("14526", "campfire"),
+    ("14531", "camping"),
+    ("14532", "campsite"),
+    ("14533", "campus"),
+    ("14534", "canal"),
+    ("14535", "canary"),
+    ("14536", "cancel"),
+    ("14541", "candied"),
+    ("14542", "candle"),
+    ("14543", "candy"),
+    ("14544", "cane"),
+    ("14545", "canine"),
+    ("14546", "canister"),
+    ("14551", "cannabis"),
+    ("14552", "canned"),
+    ("14553", "canning"),
+    ("14554", "cannon"),
+    ("14555", "cannot"),
+    ("14556", "canola"),
+    ("14561", "canon"),
+    ("14562", "canopener"),
+    ("14563", "canopy"),
+    ("14564", "canteen"),
+    ("14565", "canyon"),
+    ("14566", "capable"),
+    ("14611", "capably"),
+    ("14612", "capacity"),
+    ("14613", "cape"),
+    ("14614", "capillary"),
+    ("14615", "capital"),
+    ("14616", "capitol"),
+    ("14621", "capped"),
+    ("14622", "capricorn"),
+    ("14623", "capsize"),
+    ("14624", "capsule"),
+    ("14625", "caption"),
+    ("14626", "captivate"),
+    ("14631", "captive"),
+    ("14632", "captivity"),
+    ("14633", "capture"),
+    ("14634", "caramel"),
+    ("14635", "carat"),
+    ("14636", "caravan"),
+    ("14641", "carbon"),
+    ("14642", "cardboard"),
+    ("14643", "carded"),
+    ("14644", "cardiac"),
+    ("14645", "cardigan"),
+    ("14646", "cardinal"),
+    ("14651", "cardstock"),
+    ("14652", "carefully"),
+    ("14653", "caregiver"),
+    ("14654", "careless"),
+    ("14655", "caress"),
+    ("14656", "caretaker"),
+    ("14661", "cargo"),
+    ("14662", "caring"),
+    ("14663", "carless"),
+    ("14664", "carload"),
+    ("14665", "carmaker"),
+    ("14666", "carnage"),
+    ("15111", "carnation"),
+    ("15112", "carnival"),
+    ("15113", "carnivore"),
+    ("15114", "carol"),
+    ("15115", "carpenter"),
+    ("15116", "carpentry"),
+    ("15121", "carpool"),
+    ("15122", "carport"),
+    ("15123", "carried"),
+    ("15124", "carrot"),
+    ("15125", "carrousel"),
+    ("15126", "carry"),
+    ("15131", "cartel"),
+    ("15132", "cartload"),
+    ("15133", "carton"),
+    ("15134", "cartoon"),
+    ("15135", "cartridge"),
+    ("15136", "cartwheel"),
+    ("15141", "carve"),
+    ("15142", "carving"),
+    ("15143", "carwash"),
+    ("15144", "cascade"),
+    ("15145", "case"),
+    ("15146", "cash"),
+    ("15151", "casing"),
+    ("15152", "casino"),
+    ("15153", "casket"),
+    ("15154", "cassette"),
+    ("15155", "casually"),
+    ("15156", "casualty"),
+    ("15161", "catacomb"),
+    ("15162", "catalog"),
+    ("15163", "catalyst"),
+    ("15164", "catalyze"),
+    ("15165", "catapult"),
+    ("15166", "cataract"),
+    ("15211", "catatonic"),
+    ("15212", "catcall"),
+    ("15213", "catchable"),
+    ("15214", "catcher"),
+    ("15215", "catching"),
+    ("15216", "catchy"),
+    ("15221", "caterer"),
+    ("15222", "catering"),
+    ("15223", "catfight"),
+    ("15224", "catfish"),
+    ("15225", "cathedral"),
+    ("15226", "cathouse"),
+    ("15231", "catlike"),
+    ("15232", "catnap"),
+    ("15233", "catnip"),
+    ("15234", "catsup"),
+    ("15235", "cattail"),
+    ("15236", "cattishly"),
+    ("15241", "cattle"),
+    ("15242", "catty"),
+    ("15243", "catwalk"),
+    ("15244", "caucasian"),
+    ("15245", "caucus"),
+    ("15246", "causal"),
+    ("15251", "causation"),
+    ("15252", "cause"),
+    ("15253", "causing"),
+    ("15254", "cauterize"),
+    ("15255", "caution"),
+    ("15256", "cautious"),
+    ("15261", "cavalier"),
+    ("15262", "cavalry"),
+    ("15263", "caviar"),
+    ("15264", "cavity"),
+    ("15265", "cedar"),
+    ("15266", "celery"),
+    ("15311", "celestial"),
+    ("15312", "celibacy"),
+    ("15313", "celibate"),
+    ("15314", "celtic"),
+    ("15315", "cement"),
+    ("15316", "census"),
+    ("15321", "ceramics"),
+    ("15322", "ceremony"),
+    ("15323", "certainly"),
+    ("15324", "certainty"),
+    ("15325", "certified"),
+    ("15326", "certify"),
+    ("15331", "cesarean"),
+    ("15332", "cesspool"),
+    ("15333", "chafe"),
+    ("15334", "chaffing"),
+    ("15335", "chain"),
+    ("15336", "chair"),
+    ("15341", "chalice"),
+    ("15342", "challenge"),
+    ("15343", "chamber"),
+    ("15344", "chamomile"),
+    ("15345", "champion"),
+    ("15346", "chance"),
+    ("15351", "change"),
+    ("15352", "channel"),
+    ("15353", "chant"),
+    ("15354", "chaos"),
+    ("15355", "chaperone"),
+    ("15356", "chaplain"),
+    ("15361", "chapped"),
+    ("15362", "chaps"),
+    ("15363", "chapter"),
+    ("15364", "character"),
+    ("15365", "charbroil"),
+    ("15366", "charcoal"),
+    ("15411", "charger"),
+    ("15412", "charging"),
+    ("15413", "chariot"),
+    ("15414", "charity"),
+    ("15415", "charm"),
+    ("15416", "charred"),
+    ("15421", "charter"),
+    ("15422", "charting"),
+    ("15423", "chase"),
+    ("15424", "chasing"),
+    ("15425", "chaste"),
+    ("15426", "chastise"),
+    ("15431", "chastity"),
+    ("15432", "chatroom"),
+    ("15433", "chatter"),
+    ("15434", "chatting"),
+    ("15435", "chatty"),
+    ("15436", "cheating"),
+    ("15441", "cheddar"),
+    ("15442", "cheek"),
+    ("15443", "cheer"),
+    ("15444", "cheese"),
+    ("15445", "cheesy"),
+    ("15446", "chef"),
+    ("15451", "chemicals"),
+    ("15452", "chemist"),
+    ("15453", "chemo"),
+    ("15454", "cherisher"),
+    ("15455", "cherub"),
+    ("15456", "chess"),
+    ("15461", "chest"),
+    ("15462", "chevron"),
+    ("15463", "chevy"),
+    ("15464", "chewable"),
+    ("15465", "chewer"),
+    ("15466", "chewing"),
+    ("15511", "chewy"),
+    ("15512", "chief"),
+    ("15513", "chihuahua"),
+    ("15514", "childcare"),
+    ("15515", "childhood"),
+    ("15516", "childish"),
+    ("15521", "childless"),
+    ("15522", "childlike"),
+    ("15523", "chili"),
+    ("15524", "chill"),
+    ("15525", "chimp"),
+    ("15526", "chip"),
+    ("15531", "chirping"),
+    ("15532", "chirpy"),
+    ("15533", "chitchat"),
+    ("15534", "chivalry"),
+    ("15535", "chive"),
+    ("15536", "chloride"),
+    ("15541", "chlorine"),
+    ("15542", "choice"),
+    ("15543", "chokehold"),
+    ("15544", "choking"),
+    ("15545", "chomp"),
+    ("15546", "chooser"),
+    ("15551", "choosing"),
+    ("15552", "choosy"),
+    ("15553", "chop"),
+    ("15554", "chosen"),
+    ("15555", "chowder"),
+    ("15556", "chowtime"),
+    ("15561", "chrome"),
+    ("15562", "chubby"),
+    ("15563", "chuck"),
+    ("15564", "chug"),
+    ("15565", "chummy"),
+    ("15566", "chump"),
+    ("15611", "chunk"),
+    ("15612", "churn"),
+    ("15613", "chute"),
+    ("15614", "cider"),
+    ("15615", "cilantro"),
+    ("15616", "cinch"),
+    ("15621", "cinema"),
+    ("15622", "cinnamon"),
+    ("15623", "circle"),
+    ("15624", "circling"),
+    ("15625", "circular"),
+    ("15626", "circulate"),
+    ("15631", "circus"),
+    ("15632", "citable"),
+    ("15633", "citadel"),
+    ("15634", "citation"),
+    ("15635", "citizen"),
+    ("15636", "citric"),
+    ("15641", "citrus"),
+    ("15642", "city"),
+    ("15643", "civic"),
+    ("15644", "civil"),
+    ("15645", "clad"),
+    ("15646", "claim"),
+    ("15651", "clambake"),
+    ("15652", "clammy"),
+    ("15653", "clamor"),
+    ("15654", "clamp"),
+    ("15655", "clamshell"),
+    ("15656", "clang"),
+    ("15661", "clanking"),
+    ("15662", "clapped"),
+    ("15663", "clapper"),
+    ("15664", "clapping"),
+    ("15665", "clarify"),
+    ("15666", "clarinet"),
+    ("16111", "clarity"),
+    ("16112", "clash"),
+    ("16113", "clasp"),
+    ("16114", "class"),
+    ("16115", "clatter"),
+    ("16116", "clause"),
+    ("16121", "clavicle"),
+    ("16122", "claw"),
+    ("16123", "clay"),
+    ("16124", "clean"),
+    ("16125", "clear"),
+    ("16126", "cleat"),
+    ("16131", "cleaver"),
+    ("16132", "cleft"),
+    ("16133", "clench"),
+    ("16134", "clergyman"),
+    ("16135", "clerical"),
+    ("16136", "clerk"),
+    ("16141", "clever"),
+    ("16142", "clicker"),
+    ("16143", "client"),
+    ("16144", "climate"),
+    ("16145", "climatic"),
+    ("16146", "cling"),
+    ("16151", "clinic"),
+    ("16152", "clinking"),
+    ("16153", "clip"),
+    ("16154", "clique"),
+    ("16155", "cloak"),
+    ("16156", "clobber"),
+    ("16161", "clock"),
+    ("16162", "clone"),
+    ("16163", "cloning"),
+    ("16164", "closable"),
+    ("16165", "closure"),
+    ("16166", "clothes"),
+    ("16211", "clothing"),
+    ("16212", "cloud"),
+    ("16213", "clover"),
+    ("16214", "clubbed"),
+    ("16215", "clubbing"),
+    ("16216", "clubhouse"),
+    ("16221", "clump"),
+    ("16222", "clumsily"),
+    ("16223", "clumsy"),
+    ("16224", "clunky"),
+    ("16225", "clustered"),
+    ("16226", "clutch"),
+    ("16231", "clutter"),
+    ("16232", "coach"),
+    ("16233", "coagulant"),
+    ("16234", "coastal"),
+    ("16235", "coaster"),
+    ("16236", "coasting"),
+    ("16241", "coastland"),
+    ("16242", "coastline"),
+    ("16243", "coat"),
+    ("16244", "coauthor"),
+    ("16245", "cobalt"),
+    ("16246", "cobbler"),
+    ("16251", "cobweb"),
+    ("16252", "cocoa"),
+    ("16253", "coconut"),
+    ("16254", "cod"),
+    ("16255", "coeditor"),
+    ("16256", "coerce"),
+    ("16261", "coexist"),
+    ("16262", "coffee"),
+    ("16263", "cofounder"),
+    ("16264", "cognition"),
+    ("16265", "cognitive"),
+    ("16266", "cogwheel"),
+    ("16311", "coherence"),
+    ("16312", "coherent"),
+    ("16313", "cohesive"),
+    ("16314", "coil"),
+    ("16315", "coke"),
+    ("16316", "cola"),
+    ("16321", "cold"),
+    ("16322", "coleslaw"),
+    ("16323", "coliseum"),
+    ("16324", "collage"),
+    ("16325", "collapse"),
+    ("16326", "collar"),
+    ("16331", "collected"),
+    ("16332", "collector"),
+    ("16333", "collide"),
+    ("16334", "collie"),
+    ("16335", "collision"),
+    ("16336", "colonial"),
+    ("16341", "colonist"),
+    ("16342", "colonize"),
+    ("16343", "colony"),
+    ("16344", "colossal"),
+    ("16345", "colt"),
+    ("16346", "coma"),
+    ("16351", "come"),
+    ("16352", "comfort"),
+    ("16353", "comfy"),
+    ("16354", "comic"),
+    ("16355", "coming"),
+    ("16356", "comma"),
+    ("16361", "commence"),
+    ("16362", "commend"),
+    ("16363", "comment"),
+    ("16364", "commerce"),
+    ("16365", "commode"),
+    ("16366", "commodity"),
+    ("16411", "commodore"),
+    ("16412", "common"),
+    ("16413", "commotion"),
+    ("16414", "commute"),
+    ("16415", "commuting"),
+    ("16416", "compacted"),
+    ("16421", "compacter"),
+    ("16422", "compactly"),
+    ("16423", "compactor"),
+    ("16424", "companion"),
+    ("16425", "company"),
+    ("16426", "compare"),
+    ("16431", "compel"),
+    ("16432", "compile"),
+    ("16433", "comply"),
+    ("16434", "component"),
+    ("16435", "composed"),
+    ("16436", "composer"),
+    ("16441", "composite"),
+    ("16442", "compost"),
+    ("16443", "composure"),
+    ("16444", "compound"),
+    ("16445", "compress"),
+    ("16446", "comprised"),
+    ("16451", "computer"),
+    ("16452", "computing"),
+    ("16453", "comrade"),
+    ("16454", "concave"),
+    ("16455", "conceal"),
+    ("16456", "conceded"),
+    ("16461", "concept"),
+    ("16462", "concerned"),
+    ("16463", "concert"),
+    ("16464", "conch"),
+    ("16465", "concierge"),
+    ("16466", "concise"),
+    ("16511", "conclude"),
+    ("16512", "concrete"),
+    ("16513", "concur"),
+    ("16514", "condense"),
+    ("16515", "condiment"),
+    ("16516", "condition"),
+    ("16521", "condone"),
+    ("16522", "conducive"),
+    ("16523", "conductor"),
+    ("16524", "conduit"),
+    ("16525", "cone"),
+    ("16526", "confess"),
+    ("16531", "confetti"),
+    ("16532", "confidant"),
+    ("16533", "confident"),
+    ("16534", "confider"),
+    ("16535", "confiding"),
+    ("16536", "configure"),
+    ("16541", "confined"),
+    ("16542", "confining"),
+    ("16543", "confirm"),
+    ("16544", "conflict"),
+    ("16545", "conform"),
+    ("16546", "confound"),
+    ("16551", "confront"),
+    ("16552", "confused"),
+    ("16553", "confusing"),
+    ("16554", "confusion"),
+    ("16555", "congenial"),
+    ("16556", "congested"),
+    ("16561", "congrats"),
+    ("16562", "congress"),
+    ("16563", "conical"),
+    ("16564", "conjoined"),
+    ("16565", "conjure"),
+    ("16566", "conjuror"),
+    ("16611", "connected"),
+    ("16612", "connector"),
+    ("16613", "consensus"),
+    ("16614", "consent"),
+    ("16615", "console"),
+    ("16616", "consoling"),
+    ("16621", "consonant"),
+    ("16622", "constable"),
+    ("16623", "constant"),
+    ("16624", "constrain"),
+    ("16625", "constrict"),
+    ("16626", "construct"),
+    ("16631", "consult"),
+    ("16632", "consumer"),
+    ("16633", "consuming"),
+    ("16634", "contact"),
+    ("16635", "container"),
+    ("16636", "contempt"),
+    ("16641", "contend"),
+    ("16642", "contented"),
+    ("16643", "contently"),
+    ("16644", "contents"),
+    ("16645", "contest"),
+    ("16646", "context"),
+    ("16651", "contort"),
+    ("16652", "contour"),
+    ("16653", "contrite"),
+    ("16654", "control"),
+    ("16655", "contusion"),
+    ("16656", "convene"),
+    ("16661", "convent"),
+    ("16662", "copartner"),
+    ("16663", "cope"),
+    ("16664", "copied"),
+    ("16665", "copier"),
+    ("16666", "copilot"),
+    ("21111", "coping"),
+    ("21112", "copious"),
+    ("21113", "copper"),
+    ("21114", "copy"),
+    ("21115", "coral"),
+    ("21116", "cork"),
+    ("21121", "cornball"),
+    ("21122", "cornbread"),
+    ("21123", "corncob"),
+    ("21124", "cornea"),
+    ("21125", "corned"),
+    ("21126", "corner"),
+    ("21131", "cornfield"),
+    ("21132", "cornflake"),
+    ("21133", "cornhusk"),
+    ("21134", "cornmeal"),
+    ("21135", "cornstalk"),
+    ("21136", "corny"),
+    ("21141", "coronary"),
+    ("21142", "coroner"),
+    ("21143", "corporal"),
+    ("21144", "corporate"),
+    ("21145", "corral"),
+    ("21146", "correct"),
+    ("21151", "corridor"),
+    ("21152", "corrode"),
+    ("21153", "corroding"),
+    ("21154", "corrosive"),
+    ("21155", "corsage"),
+    ("21156", "corset"),
+    ("21161", "cortex"),
+    ("21162", "cosigner"),
+    ("21163", "cosmetics"),
+    ("21164", "cosmic"),
+    ("21165", "cosmos"),
+    ("21166", "cosponsor"),
+    ("21211", "cost"),
+    ("21212", "cottage"),
+    ("21213", "cotton"),
+    ("21214", "couch"),
+    ("21215", "cough"),
+    ("21216", "could"),
+    ("21221", "countable"),
+    ("21222", "countdown"),
+    ("21223", "counting"),
+    ("21224", "countless"),
+    ("21225", "country"),
+    ("21226", "county"),
+    ("21231", "courier"),
+    ("21232", "covenant"),
+    ("21233", "cover"),
+    ("21234", "coveted"),
+    ("21235", "coveting"),
+    ("21236", "coyness"),
+    ("21241", "cozily"),
+    ("21242", "coziness"),
+    ("21243", "cozy"),
+    ("21244", "crabbing"),
+    ("21245", "crabgrass"),
+    ("21246", "crablike"),
+    ("21251", "crabmeat"),
+    ("21252", "cradle"),
+    ("21253", "cradling"),
+    ("21254", "crafter"),
+    ("21255", "craftily"),
+    ("21256", "craftsman"),
+    ("21261", "craftwork"),
+    ("21262", "crafty"),
+    ("21263", "cramp"),
+    ("21264", "cranberry"),
+    ("21265", "crane"),
+    ("21266", "cranial"),
+    ("21311", "cranium"),
+    ("21312", "crank"),
+    ("21313", "crate"),
+    ("21314", "crave"),
+    ("21315", "craving"),
+    ("21316", "crawfish"),
+    ("21321", "crawlers"),
+    ("21322", "crawling"),
+    ("21323", "crayfish"),
+    ("21324", "crayon"),
+    ("21325", "crazed"),
+    ("21326", "crazily"),
+    ("21331", "craziness"),
+    ("21332", "crazy"),
+    ("21333", "creamed"),
+    ("21334", "creamer"),
+    ("21335", "creamlike"),
+    ("21336", "crease"),
+    ("21341", "creasing"),
+    ("21342", "creatable"),
+    ("21343", "create"),
+    ("21344", "creation"),
+    ("21345", "creative"),
+    ("21346", "creature"),
+    ("21351", "credible"),
+    ("21352", "credibly"),
+    ("21353", "credit"),
+    ("21354", "creed"),
+    ("21355", "creme"),
+    ("21356", "creole"),
+    ("21361", "crepe"),
+    ("21362", "crept"),
+    ("21363", "crescent"),
+    ("21364", "crested"),
+    ("21365", "cresting"),
+    ("21366", "crestless"),
+    ("21411", "crevice"),
+    ("21412", "crewless"),
+    ("21413", "crewman"),
+    ("21414", "crewmate"),
+    ("21415", "crib"),
+    ("21416", "cricket"),
+    ("21421", "cried"),
+    ("21422", "crier"),
+    ("21423", "crimp"),
+    ("21424", "crimson"),
+    ("21425", "cringe"),
+    ("21426", "cringing"),
+    ("21431", "crinkle"),
+    ("21432", "crinkly"),
+    ("21433", "crisped"),
+    ("21434", "crisping"),
+    ("21435", "crisply"),
+    ("21436", "crispness"),
+    ("21441", "crispy"),
+    ("21442", "criteria"),
+    ("21443", "critter"),
+    ("21444", "croak"),
+    ("21445", "crock"),
+    ("21446", "crook"),
+    ("21451", "croon"),
+    ("21452", "crop"),
+    ("21453", "cross"),
+    ("21454", "crouch"),
+    ("21455", "crouton"),
+    ("21456", "crowbar"),
+    ("21461", "crowd"),
+    ("21462", "crown"),
+    ("21463", "crucial"),
+    ("21464", "crudely"),
+    ("21465", "crudeness"),
+    ("21466", "cruelly"),
+    ("21511", "cruelness"),
+    ("21512", "cruelty"),
+    ("21513", "crumb"),
+    ("21514", "crummiest"),
+    ("21515", "crummy"),
+    ("21516", "crumpet"),
+    ("21521", "crumpled"),
+    ("21522", "cruncher"),
+    ("21523", "crunching"),
+    ("21524", "crunchy"),
+    ("21525", "crusader"),
+    ("21526", "crushable"),
+    ("21531", "crushed"),
+    ("21532", "crusher"),
+    ("21533", "crushing"),
+    ("21534", "crust"),
+    ("21535", "crux"),
+    ("21536", "crying"),
+    ("21541", "cryptic"),
+    ("21542", "crystal"),
+    ("21543", "cubbyhole"),
+    ("21544", "cube"),
+    ("21545", "cubical"),
+    ("21546", "cubicle"),
+    ("21551", "cucumber"),
+    ("21552", "cuddle"),
+    ("21553", "cuddly"),
+    ("21554", "cufflink"),
+    ("21555", "culinary"),
+    ("21556", "culminate"),
+    ("21561", "culpable"),
+    ("21562", "culprit"),
+    ("21563", "cultivate"),
+    ("21564", "cultural"),
+    ("21565", "culture"),
+    ("21566", "cupbearer"),
+    ("21611", "cupcake"),
+    ("21612", "cupid"),
+    ("21613", "cupped"),
+    ("21614", "cupping"),
+    ("21615", "curable"),
+    ("21616", "curator"),
+    ("21621", "curdle"),
+    ("21622", "cure"),
+    ("21623", "curfew"),
+    ("21624", "curing"),
+    ("21625", "curled"),
+    ("21626", "curler"),
+    ("21631", "curliness"),
+    ("21632", "curling"),
+    ("21633", "curly"),
+    ("21634", "curry"),
+    ("21635", "curse"),
+    ("21636", "cursive"),
+    ("21641", "cursor"),
+    ("21642", "curtain"),
+    ("21643", "curtly"),
+    ("21644", "curtsy"),
+    ("21645", "curvature"),
+    ("21646", "curve"),
+    ("21651", "curvy"),
+    ("21652", "cushy"),
+    ("21653", "cusp"),
+    ("21654", "cussed"),
+    ("21655", "custard"),
+    ("21656", "custodian"),
+    ("21661", "custody"),
+    ("21662", "customary"),
+    ("21663", "customer"),
+    ("21664", "customize"),
+    ("21665", "customs"),
+    ("21666", "cut"),
+    ("22111", "cycle"),
+    ("22112", "cyclic"),
+    ("22113", "cycling"),
+    ("22114", "cyclist"),
+    ("22115", "cylinder"),
+    ("22116", "cymbal"),
+    ("22121", "cytoplasm"),
+    ("22122", "cytoplast"),
+    ("22123", "dab"),
+    ("22124", "dad"),
+    ("22125", "daffodil"),
+    ("22126", "dagger"),
+    ("22131", "daily"),
+    ("22132", "daintily"),
+    ("22133", "dainty"),
+    ("22134", "dairy"),
+    ("22135", "daisy"),
+    ("22136", "dallying"),
+    ("22141", "dance"),
+    ("22142", "dancing"),
+    ("22143", "dandelion"),
+    ("22144", "dander"),
+    ("22145", "dandruff"),
+    ("22146", "dandy"),
+    ("22151", "danger"),
+    ("22152", "dangle"),
+    ("22153", "dangling"),
+    ("22154", "daredevil"),
+    ("22155", "dares"),
+    ("22156", "daringly"),
+    ("22161", "darkened"),
+    ("22162", "darkening"),
+    ("22163", "darkish"),
+    ("22164", "darkness"),
+    ("22165", "darkroom"),
+    ("22166", "darling"),
+    ("22211", "darn"),
+    ("22212", "dart"),
+    ("22213", "darwinism"),
+    ("22214", "dash"),
+    ("22215", "dastardly"),
+    ("22216", "data"),
+    ("22221", "datebook"),
+    ("22222", "dating"),
+    ("22223", "daughter"),
+    ("22224", "daunting"),
+    ("22225", "dawdler"),
+    ("22226", "dawn"),
+    ("22231", "daybed"),
+    ("22232", "daybreak"),
+    ("22233", "daycare"),
+    ("22234", "daydream"),
+    ("22235", "daylight"),
+    ("22236", "daylong"),
+    ("22241", "dayroom"),
+    ("22242", "daytime"),
+    ("22243", "dazzler"),
+    ("22244", "dazzling"),
+    ("22245", "deacon"),
+    ("22246", "deafening"),
+    ("22251", "deafness"),
+    ("22252", "dealer"),
+    ("22253", "dealing"),
+    ("22254", "dealmaker"),
+    ("22255", "dealt"),
+    ("22256", "dean"),
+    ("22261", "debatable"),
+    ("22262", "debate"),
+    ("22263", "debating"),
+    ("22264", "debit"),
+    ("22265", "debrief"),
+    ("22266", "debtless"),
+    ("22311", "debtor"),
+    ("22312", "debug"),
+    ("22313", "debunk"),
+    ("22314", "decade"),
+    ("22315", "decaf"),
+    ("22316", "decal"),
+    ("22321", "decathlon"),
+    ("22322", "decay"),
+    ("22323", "deceased"),
+    ("22324", "deceit"),
+    ("22325", "deceiver"),
+    ("22326", "deceiving"),
+    ("22331", "december"),
+    ("22332", "decency"),
+    ("22333", "decent"),
+    ("22334", "deception"),
+    ("22335", "deceptive"),
+    ("22336", "decibel"),
+    ("22341", "decidable"),
+    ("22342", "decimal"),
+    ("22343", "decimeter"),
+    ("22344", "decipher"),
+    ("22345", "deck"),
+    ("22346", "declared"),
+    ("22351", "decline"),
+    ("22352", "decode"),
+    ("22353", "decompose"),
+    ("22354", "decorated"),
+    ("22355", "decorator"),
+    ("22356", "decoy"),
+    ("22361", "decrease"),
+    ("22362", "decree"),
+    ("22363", "dedicate"),
+    ("22364", "dedicator"),
+    ("22365", "deduce"),
+    ("22366", "deduct"),
+    ("22411", "deed"),
+    ("22412", "deem"),
+    ("22413", "deepen"),
+    ("22414", "deeply"),
+    ("22415", "deepness"),
+    ("22416", "deface"),
+    ("22421", "defacing"),
+    ("22422", "defame"),
+    ("22423", "default"),
+    ("22424", "defeat"),
+    ("22425", "defection"),
+    ("22426", "defective"),
+    ("22431", "defendant"),
+    ("22432", "defender"),
+    ("22433", "defense"),
+    ("22434", "defensive"),
+    ("22435", "deferral"),
+    ("22436", "deferred"),
+    ("22441", "defiance"),
+    ("22442", "defiant"),
+    ("22443", "defile"),
+    ("22444", "defiling"),
+    ("22445", "define"),
+    ("22446", "definite"),
+    ("22451", "deflate"),
+    ("22452", "deflation"),
+    ("22453", "deflator"),
+    ("22454", "deflected"),
+    ("22455", "deflector"),
+    ("22456", "defog"),
+    ("22461", "deforest"),
+    ("22462", "defraud"),
+    ("22463", "defrost"),
+    ("22464", "deftly"),
+    ("22465", "defuse"),
+    ("22466", "defy"),
+    ("22511", "degraded"),
+    ("22512", "degrading"),
+    ("22513", "degrease"),
+    ("22514", "degree"),
+    ("22515", "dehydrate"),
+    ("22516", "deity"),
+    ("22521", "dejected"),
+    ("22522", "delay"),
+    ("22523", "delegate"),
+    ("22524", "delegator"),
+    ("22525", "delete"),
+    ("22526", "deletion"),
+    ("22531", "delicacy"),
+    ("22532", "delicate"),
+    ("22533", "delicious"),
+    ("22534", "delighted"),
+    ("22535", "delirious"),
+    ("22536", "delirium"),
+    ("22541", "deliverer"),
+    ("22542", "delivery"),
+    ("22543", "delouse"),
+    ("22544", "delta"),
+    ("22545", "deluge"),
+    ("22546", "delusion"),
+    ("22551", "deluxe"),
+    ("22552", "demanding"),
+    ("22553", "demeaning"),
+    ("22554", "demeanor"),
+    ("22555", "demise"),
+    ("22556", "democracy"),
+    ("22561", "democrat"),
+    ("22562", "demote"),
+    ("22563", "demotion"),
+    ("22564", "demystify"),
+    ("22565", "denatured"),
+    ("22566", "deniable"),
+    ("22611", "denial"),
+    ("22612", "denim"),
+    ("22613", "denote"),
+    ("22614", "dense"),
+    ("22615", "density"),
+    ("22616", "dental"),
+    ("22621", "dentist"),
+    ("22622", "denture"),
+    ("22623", "deny"),
+    ("22624", "deodorant"),
+    ("22625", "deodorize"),
+    ("22626", "departed"),
+    ("22631", "departure"),
+    ("22632", "depict"),
+    ("22633", "deplete"),
+    ("22634", "depletion"),
+    ("22635", "deplored"),
+    ("22636", "deploy"),
+    ("22641", "deport"),
+    ("22642", "depose"),
+    ("22643", "depraved"),
+    ("22644", "depravity"),
+    ("22645", "deprecate"),
+    ("22646", "depress"),
+    ("22651", "deprive"),
+    ("22652", "depth"),
+    ("22653", "deputize"),
+    ("22654", "deputy"),
+    ("22655", "derail"),
+    ("22656", "deranged"),
+    ("22661", "derby"),
+    ("22662", "derived"),
+    ("22663", "desecrate"),
+    ("22664", "deserve"),
+    ("22665", "deserving"),
+    ("22666", "designate"),
+    ("23111", "designed"),
+    ("23112", "designer"),
+    ("23113", "designing"),
+    ("23114", "deskbound"),
+    ("23115", "desktop"),
+    ("23116", "deskwork"),
+    ("23121", "desolate"),
+    ("23122", "despair"),
+    ("23123", "despise"),
+    ("23124", "despite"),
+    ("23125", "destiny"),
+    ("23126", "destitute"),
+    ("23131", "destruct"),
+    ("23132", "detached"),
+    ("23133", "detail"),
+    ("23134", "detection"),
+    ("23135", "detective"),
+    ("23136", "detector"),
+    ("23141", "detention"),
+    ("23142", "detergent"),
+    ("23143", "detest"),
+    ("23144", "detonate"),
+    ("23145", "detonator"),
+    ("23146", "detoxify"),
+    ("23151", "detract"),
+    ("23152", "deuce"),
+    ("23153", "devalue"),
+    ("23154", "deviancy"),
+    ("23155", "deviant"),
+    ("23156", "deviate"),
+    ("23161", "deviation"),
+    ("23162", "deviator"),
+    ("23163", "device"),
+    ("23164", "devious"),
+    ("23165", "devotedly"),
+    ("23166", "devotee"),
+    ("23211", "devotion"),
+    ("23212", "devourer"),
+    ("23213", "devouring"),
+    ("23214", "devoutly"),
+    ("23215", "dexterity"),
+    ("23216", "dexterous"),
+    ("23221", "diabetes"),
+    ("23222", "diabetic"),
+    ("23223", "diabolic"),
+    ("23224", "diagnoses"),
+    ("23225", "diagnosis"),
+    ("23226", "diagram"),
+    ("23231", "dial"),
+    ("23232", "diameter"),
+    ("23233", "diaper"),
+    ("23234", "diaphragm"),
+    ("23235", "diary"),
+    ("23236", "dice"),
+    ("23241", "dicing"),
+    ("23242", "dictate"),
+    ("23243", "dictation"),
+    ("23244", "dictator"),
+    ("23245", "difficult"),
+    ("23246", "diffused"),
+    ("23251", "diffuser"),
+    ("23252", "diffusion"),
+    ("23253", "diffusive"),
+    ("23254", "dig"),
+    ("23255", "dilation"),
+    ("23256", "diligence"),
+    ("23261", "diligent"),
+    ("23262", "dill"),
+    ("23263", "dilute"),
+    ("23264", "dime"),
+    ("23265", "diminish"),
+    ("23266", "dimly"),
+    ("23311", "dimmed"),
+    ("23312", "dimmer"),
+    ("23313", "dimness"),
+    ("23314", "dimple"),
+    ("23315", "diner"),
+    ("23316", "dingbat"),
+    ("23321", "dinghy"),
+    ("23322", "dinginess"),
+    ("23323", "dingo"),
+    ("23324", "dingy"),
+    ("23325", "dining"),
+    ("23326", "dinner"),
+    ("23331", "diocese"),
+    ("23332", "dioxide"),
+    ("23333", "diploma"),
+    ("23334", "dipped"),
+    ("23335", "dipper"),
+    ("23336", "dipping"),
+    ("23341", "directed"),
+    ("23342", "direction"),
+    ("23343", "directive"),
+    ("23344", "directly"),
+    ("23345", "directory"),
+    ("23346", "direness"),
+    ("23351", "dirtiness"),
+    ("23352", "disabled"),
+    ("23353", "disagree"),
+    ("23354", "disallow"),
+    ("23355", "disarm"),
+    ("23356", "disarray"),
+    ("23361", "disaster"),
+    ("23362", "disband"),
+    ("23363", "disbelief"),
+    ("23364", "disburse"),
+    ("23365", "discard"),
+    ("23366", "discern"),
+    ("23411", "discharge"),
+    ("23412", "disclose"),
+    ("23413", "discolor"),
+    ("23414", "discount"),
+    ("23415", "discourse"),
+    ("23416", "discover"),
+    ("23421", "discuss"),
+    ("23422", "disdain"),
+    ("23423", "disengage"),
+    ("23424", "disfigure"),
+    ("23425", "disgrace"),
+    ("23426", "dish"),
+    ("23431", "disinfect"),
+    ("23432", "disjoin"),
+    ("23433", "disk"),
+    ("23434", "dislike"),
+    ("23435", "disliking"),
+    ("23436", "dislocate"),
+    ("23441", "dislodge"),
+    ("23442", "disloyal"),
+    ("23443", "dismantle"),
+    ("23444", "dismay"),
+    ("23445", "dismiss"),
+    ("23446", "dismount"),
+    ("23451", "disobey"),
+    ("23452", "disorder"),
+    ("23453", "disown"),
+    ("23454", "disparate"),
+    ("23455", "disparity"),
+    ("23456", "dispatch"),
+    ("23461", "dispense"),
+    ("23462", "dispersal"),
+    ("23463", "dispersed"),
+    ("23464", "disperser"),
+    ("23465", "displace"),
+    ("23466", "display"),
+    ("23511", "displease"),
+    ("23512", "disposal"),
+    ("23513", "dispose"),
+    ("23514", "disprove"),
+    ("23515", "dispute"),
+    ("23516", "disregard"),
+    ("23521", "disrupt"),
+    ("23522", "dissuade"),
+    ("23523", "distance"),
+    ("23524", "distant"),
+    ("23525", "distaste"),
+    ("23526", "distill"),
+    ("23531", "distinct"),
+    ("23532", "distort"),
+    ("23533", "distract"),
+    ("23534", "distress"),
+    ("23535", "district"),
+    ("23536", "distrust"),
+    ("23541", "ditch"),
+    ("23542", "ditto"),
+    ("23543", "ditzy"),
+    ("23544", "dividable"),
+    ("23545", "divided"),
+    ("23546", "dividend"),
+    ("23551", "dividers"),
+    ("23552", "dividing"),
+    ("23553", "divinely"),
+    ("23554", "diving"),
+    ("23555", "divinity"),
+    ("23556", "divisible"),
+    ("23561", "divisibly"),
+    ("23562", "division"),
+    ("23563", "divisive"),
+    ("23564", "divorcee"),
+    ("23565", "dizziness"),
+    ("23566", "dizzy"),
+    ("23611", "doable"),
+    ("23612", "docile"),
+    ("23613", "dock"),
+    ("23614", "doctrine"),
+    ("23615", "document"),
+    ("23616", "dodge"),
+    ("23621", "dodgy"),
+    ("23622", "doily"),
+    ("23623", "doing"),
+    ("23624", "dole"),
+    ("23625", "dollar"),
+    ("23626", "dollhouse"),
+    ("23631", "dollop"),
+    ("23632", "dolly"),
+    ("23633", "dolphin"),
+    ("23634", "domain"),
+    ("23635", "domelike"),
+    ("23636", "domestic"),
+    ("23641", "dominion"),
+    ("23642", "dominoes"),
+    ("23643", "donated"),
+    ("23644", "donation"),
+    ("23645", "donator"),
+    ("23646", "donor"),
+    ("23651", "donut"),
+    ("23652", "doodle"),
+    ("23653", "doorbell"),
+    ("23654", "doorframe"),
+    ("23655", "doorknob"),
+    ("23656", "doorman"),
+    ("23661", "doormat"),
+    ("23662", "doornail"),
+    ("23663", "doorpost"),
+    ("23664", "doorstep"),
+    ("23665", "doorstop"),
+    ("23666", "doorway"),
+    ("24111", "doozy"),
+    ("24112", "dork"),
+    ("24113", "dormitory"),
+    ("24114", "dorsal"),
+    ("24115", "dosage"),
+    ("24116", "dose"),
+    ("24121", "dotted"),
+    ("24122", "doubling"),
+    ("24123", "douche"),
+    ("24124", "dove"),
+    ("24125", "down"),
+    ("24126", "dowry"),
+    ("24131", "doze"),
+    ("24132", "drab"),
+    ("24133", "dragging"),
+    ("24134", "dragonfly"),
+    ("24135", "dragonish"),
+    ("24136", "dragster"),
+    ("24141", "drainable"),
+    ("24142", "drainage"),
+    ("24143", "drained"),
+    ("24144", "drainer"),
+    ("24145", "drainpipe"),
+    ("24146", "dramatic"),
+    ("24151", "dramatize"),
+    ("24152", "drank"),
+    ("24153", "drapery"),
+    ("24154", "drastic"),
+    ("24155", "draw"),
+    ("24156", "dreaded"),
+    ("24161", "dreadful"),
+    ("24162", "dreadlock"),
+    ("24163", "dreamboat"),
+    ("24164", "dreamily"),
+    ("24165", "dreamland"),
+    ("24166", "dreamless"),
+    ("24211", "dreamlike"),
+    ("24212", "dreamt"),
+    ("24213", "dreamy"),
+    ("24214", "drearily"),
+    ("24215", "dreary"),
+    ("24216", "drench"),
+    ("24221", "dress"),
+    ("24222", "drew"),
+    ("24223", "dribble"),
+    ("24224", "dried"),
+    ("24225", "drier"),
+    ("24226", "drift"),
+    ("24231", "driller"),
+    ("24232", "drilling"),
+    ("24233", "drinkable"),
+    ("24234", "drinking"),
+    ("24235", "dripping"),
+    ("24236", "drippy"),
+    ("24241", "drivable"),
+    ("24242", "driven"),
+    ("24243", "driver"),
+    ("24244", "driveway"),
+    ("24245", "driving"),
+    ("24246", "drizzle"),
+    ("24251", "drizzly"),
+    ("24252", "drone"),
+    ("24253", "drool"),
+    ("24254", "droop"),
+    ("24255", "drop-down"),
+    ("24256", "dropbox"),
+    ("24261", "dropkick"),
+    ("24262", "droplet"),
+    ("24263", "dropout"),
+    ("24264", "dropper"),
+    ("24265", "drove"),
+    ("24266", "drown"),
+    ("24311", "drowsily"),
+    ("24312", "drudge"),
+    ("24313", "drum"),
+    ("24314", "dry"),
+    ("24315", "dubbed"),
+    ("24316", "dubiously"),
+    ("24321", "duchess"),
+    ("24322", "duckbill"),
+    ("24323", "ducking"),
+    ("24324", "duckling"),
+    ("24325", "ducktail"),
+    ("24326", "ducky"),
+    ("24331", "duct"),
+    ("24332", "dude"),
+    ("24333", "duffel"),
+    ("24334", "dugout"),
+    ("24335", "duh"),
+    ("24336", "duke"),
+    ("24341", "duller"),
+    ("24342", "dullness"),
+    ("24343", "duly"),
+    ("24344", "dumping"),
+    ("24345", "dumpling"),
+    ("24346", "dumpster"),
+    ("24351", "duo"),
+    ("24352", "dupe"),
+    ("24353", "duplex"),
+    ("24354", "duplicate"),
+    ("24355", "duplicity"),
+    ("24356", "durable"),
+    ("24361", "durably"),
+    ("24362", "duration"),
+    ("24363", "duress"),
+    ("24364", "during"),
+    ("24365", "dusk"),
+    ("24366", "dust"),
+    ("24411", "dutiful"),
+    ("24412", "duty"),
+    ("24413", "duvet"),
+    ("24414", "dwarf"),
+    ("24415", "dweeb"),
+    ("24416", "dwelled"),
+    ("24421", "dweller"),
+    ("24422", "dwelling"),
+    ("24423", "dwindle"),
+    ("24424", "dwindling"),
+    ("24425", "dynamic"),
+    ("24426", "dynamite"),
+    ("24431", "dynasty"),
+    ("24432", "dyslexia"),
+    ("24433", "dyslexic"),
+    ("24434", "each"),
+    ("24435", "eagle"),
+    ("24436", "earache"),
+    ("24441", "eardrum"),
+    ("24442", "earflap"),
+    ("24443", "earful"),
+    ("24444", "earlobe"),
+    ("24445", "early"),
+    ("24446", "earmark"),
+    ("24451", "earmuff"),
+    ("24452", "earphone"),
+    ("24453", "earpiece"),
+    ("24454", "earplugs"),
+    ("24455", "earring"),
+    ("24456", "earshot"),
+    ("24461", "earthen"),
+    ("24462", "earthlike"),
+    ("24463", "earthling"),
+    ("24464", "earthly"),
+    ("24465", "earthworm"),
+    ("24466", "earthy"),
+    ("24511", "earwig"),
+    ("24512", "easeful"),
+    ("24513", "easel"),
+    ("24514", "easiest"),
+    ("24515", "easily"),
+    ("24516", "easiness"),
+    ("24521", "easing"),
+    ("24522", "eastbound"),
+    ("24523", "eastcoast"),
+    ("24524", "easter"),
+    ("24525", "eastward"),
+    ("24526", "eatable"),
+    ("24531", "eaten"),
+    ("24532", "eatery"),
+    ("24533", "eating"),
+    ("24534", "eats"),
+    ("24535", "ebay"),
+    ("24536", "ebony"),
+    ("24541", "ebook"),
+    ("24542", "ecard"),
+    ("24543", "eccentric"),
+    ("24544", "echo"),
+    ("24545", "eclair"),
+    ("24546", "eclipse"),
+    ("24551", "ecologist"),
+    ("24552", "ecology"),
+    ("24553", "economic"),
+    ("24554", "economist"),
+    ("24555", "economy"),
+    ("24556", "ecosphere"),
+    ("24561", "ecosystem"),
+    ("24562", "edge"),
+    ("24563", "edginess"),
+    ("24564", "edging"),
+    ("24565", "edgy"),
+    ("24566", "edition"),
+    ("24611", "editor"),
+    ("24612", "educated"),
+    ("24613", "education"),
+    ("24614", "educator"),
+    ("24615", "eel"),
+    ("24616", "effective"),
+    ("24621", "effects"),
+    ("24622", "efficient"),
+    ("24623", "effort"),
+    ("24624", "eggbeater"),
+    ("24625", "egging"),
+    ("24626", "eggnog"),
+    ("24631", "eggplant"),
+    ("24632", "eggshell"),
+    ("24633", "egomaniac"),
+    ("24634", "egotism"),
+    ("24635", "egotistic"),
+    ("24636", "either"),
+    ("24641", "eject"),
+    ("24642", "elaborate"),
+    ("24643", "elastic"),
+    ("24644", "elated"),
+    ("24645", "elbow"),
+    ("24646", "eldercare"),
+    ("24651", "elderly"),
+    ("24652", "eldest"),
+    ("24653", "electable"),
+    ("24654", "election"),
+    ("24655", "elective"),
+    ("24656", "elephant"),
+    ("24661", "elevate"),
+    ("24662", "elevating"),
+    ("24663", "elevation"),
+    ("24664", "elevator"),
+    ("24665", "eleven"),
+    ("24666", "elf"),
+    ("25111", "eligible"),
+    ("25112", "eligibly"),
+    ("25113", "eliminate"),
+    ("25114", "elite"),
+    ("25115", "elitism"),
+    ("25116", "elixir"),
+    ("25121", "elk"),
+    ("25122", "ellipse"),
+    ("25123", "elliptic"),
+    ("25124", "elm"),
+    ("25125", "elongated"),
+    ("25126", "elope"),
+    ("25131", "eloquence"),
+    ("25132", "eloquent"),
+    ("25133", "elsewhere"),
+    ("25134", "elude"),
+    ("25135", "elusive"),
+    ("25136", "elves"),
+    ("25141", "email"),
+    ("25142", "embargo"),
+    ("25143", "embark"),
+    ("25144", "embassy"),
+    ("25145", "embattled"),
+    ("25146", "embellish"),
+    ("25151", "ember"),
+    ("25152", "embezzle"),
+    ("25153", "emblaze"),
+    ("25154", "emblem"),
+    ("25155", "embody"),
+    ("25156", "embolism"),
+    ("25161", "emboss"),
+    ("25162", "embroider"),
+    ("25163", "emcee"),
+    ("25164", "emerald"),
+    ("25165", "emergency"),
+    ("25166", "emission"),
+    ("25211", "emit"),
+    ("25212", "emote"),
+    ("25213", "emoticon"),
+    ("25214", "emotion"),
+    ("25215", "empathic"),
+    ("25216", "empathy"),
+    ("25221", "emperor"),
+    ("25222", "emphases"),
+    ("25223", "emphasis"),
+    ("25224", "emphasize"),
+    ("25225", "emphatic"),
+    ("25226", "empirical"),
+    ("25231", "employed"),
+    ("25232", "employee"),
+    ("25233", "employer"),
+    ("25234", "emporium"),
+    ("25235", "empower"),
+    ("25236", "emptier"),
+    ("25241", "emptiness"),
+    ("25242", "empty"),
+    ("25243", "emu"),
+    ("25244", "enable"),
+    ("25245", "enactment"),
+    ("25246", "enamel"),
+    ("25251", "enchanted"),
+    ("25252", "enchilada"),
+    ("25253", "encircle"),
+    ("25254", "enclose"),
+    ("25255", "enclosure"),
+    ("25256", "encode"),
+    ("25261", "encore"),
+    ("25262", "encounter"),
+    ("25263", "encourage"),
+    ("25264", "encroach"),
+    ("25265", "encrust"),
+    ("25266", "encrypt"),
+    ("25311", "endanger"),
+    ("25312", "endeared"),
+    ("25313", "endearing"),
+    ("25314", "ended"),
+    ("25315", "ending"),
+    ("25316", "endless"),
+    ("25321", "endnote"),
+    ("25322", "endocrine"),
+    ("25323", "endorphin"),
+    ("25324", "endorse"),
+    ("25325", "endowment"),
+    ("25326", "endpoint"),
+    ("25331", "endurable"),
+    ("25332", "endurance"),
+    ("25333", "enduring"),
+    ("25334", "energetic"),
+    ("25335", "energize"),
+    ("25336", "energy"),
+    ("25341", "enforced"),
+    ("25342", "enforcer"),
+    ("25343", "engaged"),
+    ("25344", "engaging"),
+    ("25345", "engine"),
+    ("25346", "engorge"),
+    ("25351", "engraved"),
+    ("25352", "engraver"),
+    ("25353", "engraving"),
+    ("25354", "engross"),
+    ("25355", "engulf"),
+    ("25356", "enhance"),
+    ("25361", "enigmatic"),
+    ("25362", "enjoyable"),
+    ("25363", "enjoyably"),
+    ("25364", "enjoyer"),
+    ("25365", "enjoying"),
+    ("25366", "enjoyment"),
+    ("25411", "enlarged"),
+    ("25412", "enlarging"),
+    ("25413", "enlighten"),
+    ("25414", "enlisted"),
+    ("25415", "enquirer"),
+    ("25416", "enrage"),
+    ("25421", "enrich"),
+    ("25422", "enroll"),
+    ("25423", "enslave"),
+    ("25424", "ensnare"),
+    ("25425", "ensure"),
+    ("25426", "entail"),
+    ("25431", "entangled"),
+    ("25432", "entering"),
+    ("25433", "entertain"),
+    ("25434", "enticing"),
+    ("25435", "entire"),
+    ("25436", "entitle"),
+    ("25441", "entity"),
+    ("25442", "entomb"),
+    ("25443", "entourage"),
+    ("25444", "entrap"),
+    ("25445", "entree"),
+    ("25446", "entrench"),
+    ("25451", "entrust"),
+    ("25452", "entryway"),
+    ("25453", "entwine"),
+    ("25454", "enunciate"),
+    ("25455", "envelope"),
+    ("25456", "enviable"),
+    ("25461", "enviably"),
+    ("25462", "envious"),
+    ("25463", "envision"),
+    ("25464", "envoy"),
+    ("25465", "envy"),
+    ("25466", "enzyme"),
+    ("25511", "epic"),
+    ("25512", "epidemic"),
+    ("25513", "epidermal"),
+    ("25514", "epidermis"),
+    ("25515", "epidural"),
+    ("25516", "epilepsy"),
+    ("25521", "epileptic"),
+    ("25522", "epilogue"),
+    ("25523", "epiphany"),
+    ("25524", "episode"),
+    ("25525", "equal"),
+    ("25526", "equate"),
+    ("25531", "equation"),
+    ("25532", "equator"),
+    ("25533", "equinox"),
+    ("25534", "equipment"),
+    ("25535", "equity"),
+    ("25536", "equivocal"),
+    ("25541", "eradicate"),
+    ("25542", "erasable"),
+    ("25543", "erased"),
+    ("25544", "eraser"),
+    ("25545", "erasure"),
+    ("25546", "ergonomic"),
+    ("25551", "errand"),
+    ("25552", "errant"),
+    ("25553", "erratic"),
+    ("25554", "error"),
+    ("25555", "erupt"),
+    ("25556", "escalate"),
+    ("25561", "escalator"),
+    ("25562", "escapable"),
+    ("25563", "escapade"),
+    ("25564", "escapist"),
+    ("25565", "escargot"),
+    ("25566", "eskimo"),
+    ("25611", "esophagus"),
+    ("25612", "espionage"),
+    ("25613", "espresso"),
+    ("25614", "esquire"),
+    ("25615", "essay"),
+    ("25616", "essence"),
+    ("25621", "essential"),
+    ("25622", "establish"),
+    ("25623", "estate"),
+    ("25624", "esteemed"),
+    ("25625", "estimate"),
+    ("25626", "estimator"),
+    ("25631", "estranged"),
+    ("25632", "estrogen"),
+    ("25633", "etching"),
+    ("25634", "eternal"),
+    ("25635", "eternity"),
+    ("25636", "ethanol"),
+    ("25641", "ether"),
+    ("25642", "ethically"),
+    ("25643", "ethics"),
+    ("25644", "euphemism"),
+    ("25645", "evacuate"),
+    ("25646", "evacuee"),
+    ("25651", "evade"),
+    ("25652", "evaluate"),
+    ("25653", "evaluator"),
+    ("25654", "evaporate"),
+    ("25655", "evasion"),
+    ("25656", "evasive"),
+    ("25661", "even"),
+    ("25662", "everglade"),
+    ("25663", "evergreen"),
+    ("25664", "everybody"),
+    ("25665", "everyday"),
+    ("25666", "everyone"),
+    ("26111", "evict"),
+    ("26112", "evidence"),
+    ("26113", "evident"),
+    ("26114", "evil"),
+    ("26115", "evoke"),
+    ("26116", "evolution"),
+    ("26121", "evolve"),
+    ("26122", "exact"),
+    ("26123", "exalted"),
+    ("26124", "example"),
+    ("26125", "excavate"),
+    ("26126", "excavator"),
+    ("26131", "exceeding"),
+    ("26132", "exception"),
+    ("26133", "excess"),
+    ("26134", "exchange"),
+    ("26135", "excitable"),
+    ("26136", "exciting"),
+    ("26141", "exclaim"),
+    ("26142", "exclude"),
+    ("26143", "excluding"),
+    ("26144", "exclusion"),
+    ("26145", "exclusive"),
+    ("26146", "excretion"),
+    ("26151", "excretory"),
+    ("26152", "excursion"),
+    ("26153", "excusable"),
+    ("26154", "excusably"),
+    ("26155", "excuse"),
+    ("26156", "exemplary"),
+    ("26161", "exemplify"),
+    ("26162", "exemption"),
+    ("26163", "exerciser"),
+    ("26164", "exert"),
+    ("26165", "exes"),
+    ("26166", "exfoliate"),
+    ("26211", "exhale"),
+    ("26212", "exhaust"),
+    ("26213", "exhume"),
+    ("26214", "exile"),
+    ("26215", "existing"),
+    ("26216", "exit"),
+    ("26221", "exodus"),
+    ("26222", "exonerate"),
+    ("26223", "exorcism"),
+    ("26224", "exorcist"),
+    ("26225", "expand"),
+    ("26226", "expanse"),
+    ("26231", "expansion"),
+    ("26232", "expansive"),
+    ("26233", "expectant"),
+    ("26234", "expedited"),
+    ("26235", "expediter"),
+    ("26236", "expel"),
+    ("26241", "expend"),
+    ("26242", "expenses"),
+    ("26243", "expensive"),
+    ("26244", "expert"),
+    ("26245", "expire"),
+    ("26246", "expiring"),
+    ("26251", "explain"),
+    ("26252", "expletive"),
+    ("26253", "explicit"),
+    ("26254", "explode"),
+    ("26255", "exploit"),
+    ("26256", "explore"),
+    ("26261", "exploring"),
+    ("26262", "exponent"),
+    ("26263", "exporter"),
+    ("26264", "exposable"),
+    ("26265", "expose"),
+    ("26266", "exposure"),
+    ("26311", "express"),
+    ("26312", "expulsion"),
+    ("26313", "exquisite"),
+    ("26314", "extended"),
+    ("26315", "extending"),
+    ("26316", "extent"),
+    ("26321", "extenuate"),
+    ("26322", "exterior"),
+    ("26323", "external"),
+    ("26324", "extinct"),
+    ("26325", "extortion"),
+    ("26326", "extradite"),
+    ("26331", "extras"),
+    ("26332", "extrovert"),
+    ("26333", "extrude"),
+    ("26334", "extruding"),
+    ("26335", "exuberant"),
+    ("26336", "fable"),
+    ("26341", "fabric"),
+    ("26342", "fabulous"),
+    ("26343", "facebook"),
+    ("26344", "facecloth"),
+    ("26345", "facedown"),
+    ("26346", "faceless"),
+    ("26351", "facelift"),
+    ("26352", "faceplate"),
+    ("26353", "faceted"),
+    ("26354", "facial"),
+    ("26355", "facility"),
+    ("26356", "facing"),
+    ("26361", "facsimile"),
+    ("26362", "faction"),
+    ("26363", "factoid"),
+    ("26364", "factor"),
+    ("26365", "factsheet"),
+    ("26366", "factual"),
+    ("26411", "faculty"),
+    ("26412", "fade"),
+    ("26413", "fading"),
+    ("26414", "failing"),
+    ("26415", "falcon"),
+    ("26416", "fall"),
+    ("26421", "false"),
+    ("26422", "falsify"),
+    ("26423", "fame"),
+    ("26424", "familiar"),
+    ("26425", "family"),
+    ("26426", "famine"),
+    ("26431", "famished"),
+    ("26432", "fanatic"),
+    ("26433", "fancied"),
+    ("26434", "fanciness"),
+    ("26435", "fancy"),
+    ("26436", "fanfare"),
+    ("26441", "fang"),
+    ("26442", "fanning"),
+    ("26443", "fantasize"),
+    ("26444", "fantastic"),
+    ("26445", "fantasy"),
+    ("26446", "fascism"),
+    ("26451", "fastball"),
+    ("26452", "faster"),
+    ("26453", "fasting"),
+    ("26454", "fastness"),
+    ("26455", "faucet"),
+    ("26456", "favorable"),
+    ("26461", "favorably"),
+    ("26462", "favored"),
+    ("26463", "favoring"),
+    ("26464", "favorite"),
+    ("26465", "fax"),
+    ("26466", "feast"),
+    ("26511", "federal"),
+    ("26512", "fedora"),
+    ("26513", "feeble"),
+    ("26514", "feed"),
+    ("26515", "feel"),
+    ("26516", "feisty"),
+    ("26521", "feline"),
+    ("26522", "felt-tip"),
+    ("26523", "feminine"),
+    ("26524", "feminism"),
+    ("26525", "feminist"),
+    ("26526", "feminize"),
+    ("26531", "femur"),
+    ("26532", "fence"),
+    ("26533", "fencing"),
+    ("26534", "fender"),
+    ("26535", "ferment"),
+    ("26536", "fernlike"),
+    ("26541", "ferocious"),
+    ("26542", "ferocity"),
+    ("26543", "ferret"),
+    ("26544", "ferris"),
+    ("26545", "ferry"),
+    ("26546", "fervor"),
+    ("26551", "fester"),
+    ("26552", "festival"),
+    ("26553", "festive"),
+    ("26554", "festivity"),
+    ("26555", "fetal"),
+    ("26556", "fetch"),
+    ("26561", "fever"),
+    ("26562", "fiber"),
+    ("26563", "fiction"),
+    ("26564", "fiddle"),
+    ("26565", "fiddling"),
+    ("26566", "fidelity"),
+    ("26611", "fidgeting"),
+    ("26612", "fidgety"),
+    ("26613", "fifteen"),
+    ("26614", "fifth"),
+    ("26615", "fiftieth"),
+    ("26616", "fifty"),
+    ("26621", "figment"),
+    ("26622", "figure"),
+    ("26623", "figurine"),
+    ("26624", "filing"),
+    ("26625", "filled"),
+    ("26626", "filler"),
+    ("26631", "filling"),
+    ("26632", "film"),
+    ("26633", "filter"),
+    ("26634", "filth"),
+    ("26635", "filtrate"),
+    ("26636", "finale"),
+    ("26641", "finalist"),
+    ("26642", "finalize"),
+    ("26643", "finally"),
+    ("26644", "finance"),
+    ("26645", "financial"),
+    ("26646", "finch"),
+    ("26651", "fineness"),
+    ("26652", "finer"),
+    ("26653", "finicky"),
+    ("26654", "finished"),
+    ("26655", "finisher"),
+    ("26656", "finishing"),
+    ("26661", "finite"),
+    ("26662", "finless"),
+    ("26663", "finlike"),
+    ("26664", "fiscally"),
+    ("26665", "fit"),
+    ("26666", "five"),
+    ("31111", "flaccid"),
+    ("31112", "flagman"),
+    ("31113", "flagpole"),
+    ("31114", "flagship"),
+    ("31115", "flagstick"),
+    ("31116", "flagstone"),
+    ("31121", "flail"),
+    ("31122", "flakily"),
+    ("31123", "flaky"),
+    ("31124", "flame"),
+    ("31125", "flammable"),
+    ("31126", "flanked"),
+    ("31131", "flanking"),
+    ("31132", "flannels"),
+    ("31133", "flap"),
+    ("31134", "flaring"),
+    ("31135", "flashback"),
+    ("31136", "flashbulb"),
+    ("31141", "flashcard"),
+    ("31142", "flashily"),
+    ("31143", "flashing"),
+    ("31144", "flashy"),
+    ("31145", "flask"),
+    ("31146", "flatbed"),
+    ("31151", "flatfoot"),
+    ("31152", "flatly"),
+    ("31153", "flatness"),
+    ("31154", "flatten"),
+    ("31155", "flattered"),
+    ("31156", "flatterer"),
+    ("31161", "flattery"),
+    ("31162", "flattop"),
+    ("31163", "flatware"),
+    ("31164", "flatworm"),
+    ("31165", "flavored"),
+    ("31166", "flavorful"),
+    ("31211", "flavoring"),
+    ("31212", "flaxseed"),
+    ("31213", "fled"),
+    ("31214", "fleshed"),
+    ("31215", "fleshy"),
+    ("31216", "flick"),
+    ("31221", "flier"),
+    ("31222", "flight"),
+    ("31223", "flinch"),
+    ("31224", "fling"),
+    ("31225", "flint"),
+    ("31226", "flip"),
+    ("31231", "flirt"),
+    ("31232", "float"),
+    ("31233", "flock"),
+    ("31234", "flogging"),
+    ("31235", "flop"),
+    ("31236", "floral"),
+    ("31241", "florist"),
+    ("31242", "floss"),
+    ("31243", "flounder"),
+    ("31244", "flyable"),
+    ("31245", "flyaway"),
+    ("31246", "flyer"),
+    ("31251", "flying"),
+    ("31252", "flyover"),
+    ("31253", "flypaper"),
+    ("31254", "foam"),
+    ("31255", "foe"),
+    ("31256", "fog"),
+    ("31261", "foil"),
+    ("31262", "folic"),
+    ("31263", "folk"),
+    ("31264", "follicle"),
+    ("31265", "follow"),
+    ("31266", "fondling"),
+    ("31311", "fondly"),
+    ("31312", "fondness"),
+    ("31313", "fondue"),
+    ("31314", "font"),
+    ("31315", "food"),
+    ("31316", "fool"),
+    ("31321", "footage"),
+    ("31322", "football"),
+    ("31323", "footbath"),
+    ("31324", "footboard"),
+    ("31325", "footer"),
+    ("31326", "footgear"),
+    ("31331", "foothill"),
+    ("31332", "foothold"),
+    ("31333", "footing"),
+    ("31334", "footless"),
+    ("31335", "footman"),
+    ("31336", "footnote"),
+    ("31341", "footpad"),
+    ("31342", "footpath"),
+    ("31343", "footprint"),
+    ("31344", "footrest"),
+    ("31345", "footsie"),
+    ("31346", "footsore"),
+    ("31351", "footwear"),
+    ("31352", "footwork"),
+    ("31353", "fossil"),
+    ("31354", "foster"),
+    ("31355", "founder"),
+    ("31356", "founding"),
+    ("31361", "fountain"),
+    ("31362", "fox"),
+    ("31363", "foyer"),
+    ("31364", "fraction"),
+    ("31365", "fracture"),
+    ("31366", "fragile"),
+    ("31411", "fragility"),
+    ("31412", "fragment"),
+    ("31413", "fragrance"),
+    ("31414", "fragrant"),
+    ("31415", "frail"),
+    ("31416", "frame"),
+    ("31421", "framing"),
+    ("31422", "frantic"),
+    ("31423", "fraternal"),
+    ("31424", "frayed"),
+    ("31425", "fraying"),
+    ("31426", "frays"),
+    ("31431", "freckled"),
+    ("31432", "freckles"),
+    ("31433", "freebase"),
+    ("31434", "freebee"),
+    ("31435", "freebie"),
+    ("31436", "freedom"),
+    ("31441", "freefall"),
+    ("31442", "freehand"),
+    ("31443", "freeing"),
+    ("31444", "freeload"),
+    ("31445", "freely"),
+    ("31446", "freemason"),
+    ("31451", "freeness"),
+    ("31452", "freestyle"),
+    ("31453", "freeware"),
+    ("31454", "freeway"),
+    ("31455", "freewill"),
+    ("31456", "freezable"),
+    ("31461", "freezing"),
+    ("31462", "freight"),
+    ("31463", "french"),
+    ("31464", "frenzied"),
+    ("31465", "frenzy"),
+    ("31466", "frequency"),
+    ("31511", "frequent"),
+    ("31512", "fresh"),
+    ("31513", "fretful"),
+    ("31514", "fretted"),
+    ("31515", "friction"),
+    ("31516", "friday"),
+    ("31521", "fridge"),
+    ("31522", "fried"),
+    ("31523", "friend"),
+    ("31524", "frighten"),
+    ("31525", "frightful"),
+    ("31526", "frigidity"),
+    ("31531", "frigidly"),
+    ("31532", "frill"),
+    ("31533", "fringe"),
+    ("31534", "frisbee"),
+    ("31535", "frisk"),
+    ("31536", "fritter"),
+    ("31541", "frivolous"),
+    ("31542", "frolic"),
+    ("31543", "from"),
+    ("31544", "front"),
+    ("31545", "frostbite"),
+    ("31546", "frosted"),
+    ("31551", "frostily"),
+    ("31552", "frosting"),
+    ("31553", "frostlike"),
+    ("31554", "frosty"),
+    ("31555", "froth"),
+    ("31556", "frown"),
+    ("31561", "frozen"),
+    ("31562", "fructose"),
+    ("31563", "frugality"),
+    ("31564", "frugally"),
+    ("31565", "fruit"),
+    ("31566", "frustrate"),
+    ("31611", "frying"),
+    ("31612", "gab"),
+    ("31613", "gaffe"),
+    ("31614", "gag"),
+    ("31615", "gainfully"),
+    ("31616", "gaining"),
+    ("31621", "gains"),
+    ("31622", "gala"),
+    ("31623", "gallantly"),
+    ("31624", "galleria"),
+    ("31625", "gallery"),
+    ("31626", "galley"),
+    ("31631", "gallon"),
+    ("31632", "gallows"),
+    ("31633", "gallstone"),
+    ("31634", "galore"),
+    ("31635", "galvanize"),
+    ("31636", "gambling"),
+    ("31641", "game"),
+    ("31642", "gaming"),
+    ("31643", "gamma"),
+    ("31644", "gander"),
+    ("31645", "gangly"),
+    ("31646", "gangrene"),
+    ("31651", "gangway"),
+    ("31652", "gap"),
+    ("31653", "garage"),
+    ("31654", "garbage"),
+    ("31655", "garden"),
+    ("31656", "gargle"),
+    ("31661", "garland"),
+    ("31662", "garlic"),
+    ("31663", "garment"),
+    ("31664", "garnet"),
+    ("31665", "garnish"),
+    ("31666", "garter"),
+    ("32111", "gas"),
+    ("32112", "gatherer"),
+    ("32113", "gathering"),
+    ("32114", "gating"),
+    ("32115", "gauging"),
+    ("32116", "gauntlet"),
+    ("32121", "gauze"),
+    ("32122", "gave"),
+    ("32123", "gawk"),
+    ("32124", "gazing"),
+    ("32125", "gear"),
+    ("32126", "gecko"),
+    ("32131", "geek"),
+    ("32132", "geiger"),
+    ("32133", "gem"),
+    ("32134", "gender"),
+    ("32135", "generic"),
+    ("32136", "generous"),
+    ("32141", "genetics"),
+    ("32142", "genre"),
+    ("32143", "gentile"),
+    ("32144", "gentleman"),
+    ("32145", "gently"),
+    ("32146", "gents"),
+    ("32151", "geography"),
+    ("32152", "geologic"),
+    ("32153", "geologist"),
+    ("32154", "geology"),
+    ("32155", "geometric"),
+    ("32156", "geometry"),
+    ("32161", "geranium"),
+    ("32162", "gerbil"),
+    ("32163", "geriatric"),
+    ("32164", "germicide"),
+    ("32165", "germinate"),
+    ("32166", "germless"),
+    ("32211", "germproof"),
+    ("32212", "gestate"),
+    ("32213", "gestation"),
+    ("32214", "gesture"),
+    ("32215", "getaway"),
+    ("32216", "getting"),
+    ("32221", "getup"),
+    ("32222", "giant"),
+    ("32223", "gibberish"),
+    ("32224", "giblet"),
+    ("32225", "giddily"),
+    ("32226", "giddiness"),
+    ("32231", "giddy"),
+    ("32232", "gift"),
+    ("32233", "gigabyte"),
+    ("32234", "gigahertz"),
+    ("32235", "gigantic"),
+    ("32236", "giggle"),
+    ("32241", "giggling"),
+    ("32242", "giggly"),
+    ("32243", "gigolo"),
+    ("32244", "gilled"),
+    ("32245", "gills"),
+    ("32246", "gimmick"),
+    ("32251", "girdle"),
+    ("32252", "giveaway"),
+    ("32253", "given"),
+    ("32254", "giver"),
+    ("32255", "giving"),
+    ("32256", "gizmo"),
+    ("32261", "gizzard"),
+    ("32262", "glacial"),
+    ("32263", "glacier"),
+    ("32264", "glade"),
+    ("32265", "gladiator"),
+    ("32266", "gladly"),
+    ("32311", "glamorous"),
+    ("32312", "glamour"),
+    ("32313", "glance"),
+    ("32314", "glancing"),
+    ("32315", "glandular"),
+    ("32316", "glare"),
+    ("32321", "glaring"),
+    ("32322", "glass"),
+    ("32323", "glaucoma"),
+    ("32324", "glazing"),
+    ("32325", "gleaming"),
+    ("32326", "gleeful"),
+    ("32331", "glider"),
+    ("32332", "gliding"),
+    ("32333", "glimmer"),
+    ("32334", "glimpse"),
+    ("32335", "glisten"),
+    ("32336", "glitch"),
+    ("32341", "glitter"),
+    ("32342", "glitzy"),
+    ("32343", "gloater"),
+    ("32344", "gloating"),
+    ("32345", "gloomily"),
+    ("32346", "gloomy"),
+    ("32351", "glorified"),
+    ("32352", "glorifier"),
+    ("32353", "glorify"),
+    ("32354", "glorious"),
+    ("32355", "glory"),
+    ("32356", "gloss"),
+    ("32361", "glove"),
+    ("32362", "glowing"),
+    ("32363", "glowworm"),
+    ("32364", "glucose"),
+    ("32365", "glue"),
+    ("32366", "gluten"),
+    ("32411", "glutinous"),
+    ("32412", "glutton"),
+    ("32413", "gnarly"),
+    ("32414", "gnat"),
+    ("32415", "goal"),
+    ("32416", "goatskin"),
+    ("32421", "goes"),
+    ("32422", "goggles"),
+    ("32423", "going"),
+    ("32424", "goldfish"),
+    ("32425", "goldmine"),
+    ("32426", "goldsmith"),
+    ("32431", "golf"),
+    ("32432", "goliath"),
+    ("32433", "gonad"),
+    ("32434", "gondola"),
+    ("32435", "gone"),
+    ("32436", "gong"),
+    ("32441", "good"),
+    ("32442", "gooey"),
+    ("32443", "goofball"),
+    ("32444", "goofiness"),
+    ("32445", "goofy"),
+    ("32446", "google"),
+    ("32451", "goon"),
+    ("32452", "gopher"),
+    ("32453", "gore"),
+    ("32454", "gorged"),
+    ("32455", "gorgeous"),
+    ("32456", "gory"),
+    ("32461", "gosling"),
+    ("32462", "gossip"),
+    ("32463", "gothic"),
+    ("32464", "gotten"),
+    ("32465", "gout"),
+    ("32466", "gown"),
+    ("32511", "grab"),
+    ("32512", "graceful"),
+    ("32513", "graceless"),
+    ("32514", "gracious"),
+    ("32515", "gradation"),
+    ("32516", "graded"),
+    ("32521", "grader"),
+    ("32522", "gradient"),
+    ("32523", "grading"),
+    ("32524", "gradually"),
+    ("32525", "graduate"),
+    ("32526", "graffiti"),
+    ("32531", "grafted"),
+    ("32532", "grafting"),
+    ("32533", "grain"),
+    ("32534", "granddad"),
+    ("32535", "grandkid"),
+    ("32536", "grandly"),
+    ("32541", "grandma"),
+    ("32542", "grandpa"),
+    ("32543", "grandson"),
+    ("32544", "granite"),
+    ("32545", "granny"),
+    ("32546", "granola"),
+    ("32551", "grant"),
+    ("32552", "granular"),
+    ("32553", "grape"),
+    ("32554", "graph"),
+    ("32555", "grapple"),
+    ("32556", "grappling"),
+    ("32561", "grasp"),
+    ("32562", "grass"),
+    ("32563", "gratified"),
+    ("32564", "gratify"),
+    ("32565", "grating"),
+    ("32566", "gratitude"),
+    ("32611", "gratuity"),
+    ("32612", "gravel"),
+    ("32613", "graveness"),
+    ("32614", "graves"),
+    ("32615", "graveyard"),
+    ("32616", "gravitate"),
+    ("32621", "gravity"),
+    ("32622", "gravy"),
+    ("32623", "gray"),
+    ("32624", "grazing"),
+    ("32625", "greasily"),
+    ("32626", "greedily"),
+    ("32631", "greedless"),
+    ("32632", "greedy"),
+    ("32633", "green"),
+    ("32634", "greeter"),
+    ("32635", "greeting"),
+    ("32636", "grew"),
+    ("32641", "greyhound"),
+    ("32642", "grid"),
+    ("32643", "grief"),
+    ("32644", "grievance"),
+    ("32645", "grieving"),
+    ("32646", "grievous"),
+    ("32651", "grill"),
+    ("32652", "grimace"),
+    ("32653", "grimacing"),
+    ("32654", "grime"),
+    ("32655", "griminess"),
+    ("32656", "grimy"),
+    ("32661", "grinch"),
+    ("32662", "grinning"),
+    ("32663", "grip"),
+    ("32664", "gristle"),
+    ("32665", "grit"),
+    ("32666", "groggily"),
+    ("33111", "groggy"),
+    ("33112", "groin"),
+    ("33113", "groom"),
+    ("33114", "groove"),
+    ("33115", "grooving"),
+    ("33116", "groovy"),
+    ("33121", "grope"),
+    ("33122", "ground"),
+    ("33123", "grouped"),
+    ("33124", "grout"),
+    ("33125", "grove"),
+    ("33126", "grower"),
+    ("33131", "growing"),
+    ("33132", "growl"),
+    ("33133", "grub"),
+    ("33134", "grudge"),
+    ("33135", "grudging"),
+    ("33136", "grueling"),
+    ("33141", "gruffly"),
+    ("33142", "grumble"),
+    ("33143", "grumbling"),
+    ("33144", "grumbly"),
+    ("33145", "grumpily"),
+    ("33146", "grunge"),
+    ("33151", "grunt"),
+    ("33152", "guacamole"),
+    ("33153", "guidable"),
+    ("33154", "guidance"),
+    ("33155", "guide"),
+    ("33156", "guiding"),
+    ("33161", "guileless"),
+    ("33162", "guise"),
+    ("33163", "gulf"),
+    ("33164", "gullible"),
+    ("33165", "gully"),
+    ("33166", "gulp"),
+    ("33211", "gumball"),
+    ("33212", "gumdrop"),
+    ("33213", "gumminess"),
+    ("33214", "gumming"),
+    ("33215", "gummy"),
+    ("33216", "gurgle"),
+    ("33221", "gurgling"),
+    ("33222", "guru"),
+    ("33223", "gush"),
+    ("33224", "gusto"),
+    ("33225", "gusty"),
+    ("33226", "gutless"),
+    ("33231", "guts"),
+    ("33232", "gutter"),
+    ("33233", "guy"),
+    ("33234", "guzzler"),
+    ("33235", "gyration"),
+    ("33236", "habitable"),
+    ("33241", "habitant"),
+    ("33242", "habitat"),
+    ("33243", "habitual"),
+    ("33244", "hacked"),
+    ("33245", "hacker"),
+    ("33246", "hacking"),
+    ("33251", "hacksaw"),
+    ("33252", "had"),
+    ("33253", "haggler"),
+    ("33254", "haiku"),
+    ("33255", "half"),
+    ("33256", "halogen"),
+    ("33261", "halt"),
+    ("33262", "halved"),
+    ("33263", "halves"),
+    ("33264", "hamburger"),
+    ("33265", "hamlet"),
+    ("33266", "hammock"),
+    ("33311", "hamper"),
+    ("33312", "hamster"),
+    ("33313", "hamstring"),
+    ("33314", "handbag"),
+    ("33315", "handball"),
+    ("33316", "handbook"),
+    ("33321", "handbrake"),
+    ("33322", "handcart"),
+    ("33323", "handclap"),
+    ("33324", "handclasp"),
+    ("33325", "handcraft"),
+    ("33326", "handcuff"),
+    ("33331", "handed"),
+    ("33332", "handful"),
+    ("33333", "handgrip"),
+    ("33334", "handgun"),
+    ("33335", "handheld"),
+    ("33336", "handiness"),
+    ("33341", "handiwork"),
+    ("33342", "handlebar"),
+    ("33343", "handled"),
+    ("33344", "handler"),
+    ("33345", "handling"),
+    ("33346", "handmade"),
+    ("33351", "handoff"),
+    ("33352", "handpick"),
+    ("33353", "handprint"),
+    ("33354", "handrail"),
+    ("33355", "handsaw"),
+    ("33356", "handset"),
+    ("33361", "handsfree"),
+    ("33362", "handshake"),
+    ("33363", "handstand"),
+    ("33364", "handwash"),
+    ("33365", "handwork"),
+    ("33366", "handwoven"),
+    ("33411", "handwrite"),
+    ("33412", "handyman"),
+    ("33413", "hangnail"),
+    ("33414", "hangout"),
+    ("33415", "hangover"),
+    ("33416", "hangup"),
+    ("33421", "hankering"),
+    ("33422", "hankie"),
+    ("33423", "hanky"),
+    ("33424", "haphazard"),
+    ("33425", "happening"),
+    ("33426", "happier"),
+    ("33431", "happiest"),
+    ("33432", "happily"),
+    ("33433", "happiness"),
+    ("33434", "happy"),
+    ("33435", "harbor"),
+    ("33436", "hardcopy"),
+    ("33441", "hardcore"),
+    ("33442", "hardcover"),
+    ("33443", "harddisk"),
+    ("33444", "hardened"),
+    ("33445", "hardener"),
+    ("33446", "hardening"),
+    ("33451", "hardhat"),
+    ("33452", "hardhead"),
+    ("33453", "hardiness"),
+    ("33454", "hardly"),
+    ("33455", "hardness"),
+    ("33456", "hardship"),
+    ("33461", "hardware"),
+    ("33462", "hardwired"),
+    ("33463", "hardwood"),
+    ("33464", "hardy"),
+    ("33465", "harmful"),
+    ("33466", "harmless"),
+    ("33511", "harmonica"),
+    ("33512", "harmonics"),
+    ("33513", "harmonize"),
+    ("33514", "harmony"),
+    ("33515", "harness"),
+    ("33516", "harpist"),
+    ("33521", "harsh"),
+    ("33522", "harvest"),
+    ("33523", "hash"),
+    ("33524", "hassle"),
+    ("33525", "haste"),
+    ("33526", "hastily"),
+    ("33531", "hastiness"),
+    ("33532", "hasty"),
+    ("33533", "hatbox"),
+    ("33534", "hatchback"),
+    ("33535", "hatchery"),
+    ("33536", "hatchet"),
+    ("33541", "hatching"),
+    ("33542", "hatchling"),
+    ("33543", "hate"),
+    ("33544", "hatless"),
+    ("33545", "hatred"),
+    ("33546", "haunt"),
+    ("33551", "haven"),
+    ("33552", "hazard"),
+    ("33553", "hazelnut"),
+    ("33554", "hazily"),
+    ("33555", "haziness"),
+    ("33556", "hazing"),
+    ("33561", "hazy"),
+    ("33562", "headache"),
+    ("33563", "headband"),
+    ("33564", "headboard"),
+    ("33565", "headcount"),
+    ("33566", "headdress"),
+    ("33611", "headed"),
+    ("33612", "header"),
+    ("33613", "headfirst"),
+    ("33614", "headgear"),
+    ("33615", "heading"),
+    ("33616", "headlamp"),
+    ("33621", "headless"),
+    ("33622", "headlock"),
+    ("33623", "headphone"),
+    ("33624", "headpiece"),
+    ("33625", "headrest"),
+    ("33626", "headroom"),
+    ("33631", "headscarf"),
+    ("33632", "headset"),
+    ("33633", "headsman"),
+    ("33634", "headstand"),
+    ("33635", "headstone"),
+    ("33636", "headway"),
+    ("33641", "headwear"),
+    ("33642", "heap"),
+    ("33643", "heat"),
+    ("33644", "heave"),
+    ("33645", "heavily"),
+    ("33646", "heaviness"),
+    ("33651", "heaving"),
+    ("33652", "hedge"),
+    ("33653", "hedging"),
+    ("33654", "heftiness"),
+    ("33655", "hefty"),
+    ("33656", "helium"),
+    ("33661", "helmet"),
+    ("33662", "helper"),
+    ("33663", "helpful"),
+    ("33664", "helping"),
+    ("33665", "helpless"),
+    ("33666", "helpline"),
+    ("34111", "hemlock"),
+    ("34112", "hemstitch"),
+    ("34113", "hence"),
+    ("34114", "henchman"),
+    ("34115", "henna"),
+    ("34116", "herald"),
+    ("34121", "herbal"),
+    ("34122", "herbicide"),
+    ("34123", "herbs"),
+    ("34124", "heritage"),
+    ("34125", "hermit"),
+    ("34126", "heroics"),
+    ("34131", "heroism"),
+    ("34132", "herring"),
+    ("34133", "herself"),
+    ("34134", "hertz"),
+    ("34135", "hesitancy"),
+    ("34136", "hesitant"),
+    ("34141", "hesitate"),
+    ("34142", "hexagon"),
+    ("34143", "hexagram"),
+    ("34144", "hubcap"),
+    ("34145", "huddle"),
+    ("34146", "huddling"),
+    ("34151", "huff"),
+    ("34152", "hug"),
+    ("34153", "hula"),
+    ("34154", "hulk"),
+    ("34155", "hull"),
+    ("34156", "human"),
+    ("34161", "humble"),
+    ("34162", "humbling"),
+    ("34163", "humbly"),
+    ("34164", "humid"),
+    ("34165", "humiliate"),
+    ("34166", "humility"),
+    ("34211", "humming"),
+    ("34212", "hummus"),
+    ("34213", "humongous"),
+    ("34214", "humorist"),
+    ("34215", "humorless"),
+    ("34216", "humorous"),
+    ("34221", "humpback"),
+    ("34222", "humped"),
+    ("34223", "humvee"),
+    ("34224", "hunchback"),
+    ("34225", "hundredth"),
+    ("34226", "hunger"),
+    ("34231", "hungrily"),
+    ("34232", "hungry"),
+    ("34233", "hunk"),
+    ("34234", "hunter"),
+    ("34235", "hunting"),
+    ("34236", "huntress"),
+    ("34241", "huntsman"),
+    ("34242", "hurdle"),
+    ("34243", "hurled"),
+    ("34244", "hurler"),
+    ("34245", "hurling"),
+    ("34246", "hurray"),
+    ("34251", "hurricane"),
+    ("34252", "hurried"),
+    ("34253", "hurry"),
+    ("34254", "hurt"),
+    ("34255", "husband"),
+    ("34256", "hush"),
+    ("34261", "husked"),
+    ("34262", "huskiness"),
+    ("34263", "hut"),
+    ("34264", "hybrid"),
+    ("34265", "hydrant"),
+    ("34266", "hydrated"),
+    ("34311", "hydration"),
+    ("34312", "hydrogen"),
+    ("34313", "hydroxide"),
+    ("34314", "hyperlink"),
+    ("34315", "hypertext"),
+    ("34316", "hyphen"),
+    ("34321", "hypnoses"),
+    ("34322", "hypnosis"),
+    ("34323", "hypnotic"),
+    ("34324", "hypnotism"),
+    ("34325", "hypnotist"),
+    ("34326", "hypnotize"),
+    ("34331", "hypocrisy"),
+    ("34332", "hypocrite"),
+    ("34333", "ibuprofen"),
+    ("34334", "ice"),
+    ("34335", "iciness"),
+    ("34336", "icing"),
+    ("34341", "icky"),
+    ("34342", "icon"),
+    ("34343", "icy"),
+    ("34344", "idealism"),
+    ("34345", "idealist"),
+    ("34346", "idealize"),
+    ("34351", "ideally"),
+    ("34352", "idealness"),
+    ("34353", "identical"),
+    ("34354", "identify"),
+    ("34355", "identity"),
+    ("34356", "ideology"),
+    ("34361", "idiocy"),
+    ("34362", "idiom"),
+    ("34363", "idly"),
+    ("34364", "igloo"),
+    ("34365", "ignition"),
+    ("34366", "ignore"),
+    ("34411", "iguana"),
+    ("34412", "illicitly"),
+    ("34413", "illusion"),
+    ("34414", "illusive"),
+    ("34415", "image"),
+    ("34416", "imaginary"),
+    ("34421", "imagines"),
+    ("34422", "imaging"),
+    ("34423", "imbecile"),
+    ("34424", "imitate"),
+    ("34425", "imitation"),
+    ("34426", "immature"),
+    ("34431", "immerse"),
+    ("34432", "immersion"),
+    ("34433", "imminent"),
+    ("34434", "immobile"),
+    ("34435", "immodest"),
+    ("34436", "immorally"),
+    ("34441", "immortal"),
+    ("34442", "immovable"),
+    ("34443", "immovably"),
+    ("34444", "immunity"),
+    ("34445", "immunize"),
+    ("34446", "impaired"),
+    ("34451", "impale"),
+    ("34452", "impart"),
+    ("34453", "impatient"),
+    ("34454", "impeach"),
+    ("34455", "impeding"),
+    ("34456", "impending"),
+    ("34461", "imperfect"),
+    ("34462", "imperial"),
+    ("34463", "impish"),
+    ("34464", "implant"),
+    ("34465", "implement"),
+    ("34466", "implicate"),
+    ("34511", "implicit"),
+    ("34512", "implode"),
+    ("34513", "implosion"),
+    ("34514", "implosive"),
+    ("34515", "imply"),
+    ("34516", "impolite"),
+    ("34521", "important"),
+    ("34522", "importer"),
+    ("34523", "impose"),
+    ("34524", "imposing"),
+    ("34525", "impotence"),
+    ("34526", "impotency"),
+    ("34531", "impotent"),
+    ("34532", "impound"),
+    ("34533", "imprecise"),
+    ("34534", "imprint"),
+    ("34535", "imprison"),
+    ("34536", "impromptu"),
+    ("34541", "improper"),
+    ("34542", "improve"),
+    ("34543", "improving"),
+    ("34544", "improvise"),
+    ("34545", "imprudent"),
+    ("34546", "impulse"),
+    ("34551", "impulsive"),
+    ("34552", "impure"),
+    ("34553", "impurity"),
+    ("34554", "iodine"),
+    ("34555", "iodize"),
+    ("34556", "ion"),
+    ("34561", "ipad"),
+    ("34562", "iphone"),
+    ("34563", "ipod"),
+    ("34564", "irate"),
+    ("34565", "irk"),
+    ("34566", "iron"),
+    ("34611", "irregular"),
+    ("34612", "irrigate"),
+    ("34613", "irritable"),
+    ("34614", "irritably"),
+    ("34615", "irritant"),
+    ("34616", "irritate"),
+    ("34621", "islamic"),
+    ("34622", "islamist"),
+    ("34623", "isolated"),
+    ("34624", "isolating"),
+    ("34625", "isolation"),
+    ("34626", "isotope"),
+    ("34631", "issue"),
+    ("34632", "issuing"),
+    ("34633", "italicize"),
+    ("34634", "italics"),
+    ("34635", "item"),
+    ("34636", "itinerary"),
+    ("34641", "itunes"),
+    ("34642", "ivory"),
+    ("34643", "ivy"),
+    ("34644", "jab"),
+    ("34645", "jackal"),
+    ("34646", "jacket"),
+    ("34651", "jackknife"),
+    ("34652", "jackpot"),
+    ("34653", "jailbird"),
+    ("34654", "jailbreak"),
+    ("34655", "jailer"),
+    ("34656", "jailhouse"),
+    ("34661", "jalapeno"),
+    ("34662", "jam"),
+    ("34663", "janitor"),
+    ("34664", "january"),
+    ("34665", "jargon"),
+    ("34666", "jarring"),
+    ("35111", "jasmine"),
+    ("35112", "jaundice"),
+    ("35113", "jaunt"),
+    ("35114", "java"),
+    ("35115", "jawed"),
+    ("35116", "jawless"),
+    ("35121", "jawline"),
+    ("35122", "jaws"),
+    ("35123", "jaybird"),
+    ("35124", "jaywalker"),
+    ("35125", "jazz"),
+    ("35126", "jeep"),
+    ("35131", "jeeringly"),
+    ("35132", "jellied"),
+    ("35133", "jelly"),
+    ("35134", "jersey"),
+    ("35135", "jester"),
+    ("35136", "jet"),
+    ("35141", "jiffy"),
+    ("35142", "jigsaw"),
+    ("35143", "jimmy"),
+    ("35144", "jingle"),
+    ("35145", "jingling"),
+    ("35146", "jinx"),
+    ("35151", "jitters"),
+    ("35152", "jittery"),
+    ("35153", "job"),
+    ("35154", "jockey"),
+    ("35155", "jockstrap"),
+    ("35156", "jogger"),
+    ("35161", "jogging"),
+    ("35162", "john"),
+    ("35163", "joining"),
+    ("35164", "jokester"),
+    ("35165", "jokingly"),
+    ("35166", "jolliness"),
+    ("35211", "jolly"),
+    ("35212", "jolt"),
+    ("35213", "jot"),
+    ("35214", "jovial"),
+    ("35215", "joyfully"),
+    ("35216", "joylessly"),
+    ("35221", "joyous"),
+    ("35222", "joyride"),
+    ("35223", "joystick"),
+    ("35224", "jubilance"),
+    ("35225", "jubilant"),
+    ("35226", "judge"),
+    ("35231", "judgingly"),
+    ("35232", "judicial"),
+    ("35233", "judiciary"),
+    ("35234", "judo"),
+    ("35235", "juggle"),
+    ("35236", "juggling"),
+    ("35241", "jugular"),
+    ("35242", "juice"),
+    ("35243", "juiciness"),
+    ("35244", "juicy"),
+    ("35245", "jujitsu"),
+    ("35246", "jukebox"),
+    ("35251", "july"),
+    ("35252", "jumble"),
+    ("35253", "jumbo"),
+    ("35254", "jump"),
+    ("35255", "junction"),
+    ("35256", "juncture"),
+    ("35261", "june"),
+    ("35262", "junior"),
+    ("35263", "juniper"),
+    ("35264", "junkie"),
+    ("35265", "junkman"),
+    ("35266", "junkyard"),
+    ("35311", "jurist"),
+    ("35312", "juror"),
+    ("35313", "jury"),
+    ("35314", "justice"),
+    ("35315", "justifier"),
+    ("35316", "justify"),
+    ("35321", "justly"),
+    ("35322", "justness"),
+    ("35323", "juvenile"),
+    ("35324", "kabob"),
+    ("35325", "kangaroo"),
+    ("35326", "karaoke"),
+    ("35331", "karate"),
+    ("35332", "karma"),
+    ("35333", "kebab"),
+    ("35334", "keenly"),
+    ("35335", "keenness"),
+    ("35336", "keep"),
+    ("35341", "keg"),
+    ("35342", "kelp"),
+    ("35343", "kennel"),
+    ("35344", "kept"),
+    ("35345", "kerchief"),
+    ("35346", "kerosene"),
+    ("35351", "kettle"),
+    ("35352", "kick"),
+    ("35353", "kiln"),
+    ("35354", "kilobyte"),
+    ("35355", "kilogram"),
+    ("35356", "kilometer"),
+    ("35361", "kilowatt"),
+    ("35362", "kilt"),
+    ("35363", "kimono"),
+    ("35364", "kindle"),
+    ("35365", "kindling"),
+    ("35366", "kindly"),
+    ("35411", "kindness"),
+    ("35412", "kindred"),
+    ("35413", "kinetic"),
+    ("35414", "kinfolk"),
+    ("35415", "king"),
+    ("35416", "kinship"),
+    ("35421", "kinsman"),
+    ("35422", "kinswoman"),
+    ("35423", "kissable"),
+    ("35424", "kisser"),
+    ("35425", "kissing"),
+    ("35426", "kitchen"),
+    ("35431", "kite"),
+    ("35432", "kitten"),
+    ("35433", "kitty"),
+    ("35434", "kiwi"),
+    ("35435", "kleenex"),
+    ("35436", "knapsack"),
+    ("35441", "knee"),
+    ("35442", "knelt"),
+    ("35443", "knickers"),
+    ("35444", "knoll"),
+    ("35445", "koala"),
+    ("35446", "kooky"),
+    ("35451", "kosher"),
+    ("35452", "krypton"),
+    ("35453", "kudos"),
+    ("35454", "kung"),
+    ("35455", "labored"),
+    ("35456", "laborer"),
+    ("35461", "laboring"),
+    ("35462", "laborious"),
+    ("35463", "labrador"),
+    ("35464", "ladder"),
+    ("35465", "ladies"),
+    ("35466", "ladle"),
+    ("35511", "ladybug"),
+    ("35512", "ladylike"),
+    ("35513", "lagged"),
+    ("35514", "lagging"),
+    ("35515", "lagoon"),
+    ("35516", "lair"),
+    ("35521", "lake"),
+    ("35522", "lance"),
+    ("35523", "landed"),
+    ("35524", "landfall"),
+    ("35525", "landfill"),
+    ("35526", "landing"),
+    ("35531", "landlady"),
+    ("35532", "landless"),
+    ("35533", "landline"),
+    ("35534", "landlord"),
+    ("35535", "landmark"),
+    ("35536", "landmass"),
+    ("35541", "landmine"),
+    ("35542", "landowner"),
+    ("35543", "landscape"),
+    ("35544", "landside"),
+    ("35545", "landslide"),
+    ("35546", "language"),
+    ("35551", "lankiness"),
+    ("35552", "lanky"),
+    ("35553", "lantern"),
+    ("35554", "lapdog"),
+    ("35555", "lapel"),
+    ("35556", "lapped"),
+    ("35561", "lapping"),
+    ("35562", "laptop"),
+    ("35563", "lard"),
+    ("35564", "large"),
+    ("35565", "lark"),
+    ("35566", "lash"),
+    ("35611", "lasso"),
+    ("35612", "last"),
+    ("35613", "latch"),
+    ("35614", "late"),
+    ("35615", "lather"),
+    ("35616", "latitude"),
+    ("35621", "latrine"),
+    ("35622", "latter"),
+    ("35623", "latticed"),
+    ("35624", "launch"),
+    ("35625", "launder"),
+    ("35626", "laundry"),
+    ("35631", "laurel"),
+    ("35632", "lavender"),
+    ("35633", "lavish"),
+    ("35634", "laxative"),
+    ("35635", "lazily"),
+    ("35636", "laziness"),
+    ("35641", "lazy"),
+    ("35642", "lecturer"),
+    ("35643", "left"),
+    ("35644", "legacy"),
+    ("35645", "legal"),
+    ("35646", "legend"),
+    ("35651", "legged"),
+    ("35652", "leggings"),
+    ("35653", "legible"),
+    ("35654", "legibly"),
+    ("35655", "legislate"),
+    ("35656", "lego"),
+    ("35661", "legroom"),
+    ("35662", "legume"),
+    ("35663", "legwarmer"),
+    ("35664", "legwork"),
+    ("35665", "lemon"),
+    ("35666", "lend"),
+    ("36111", "length"),
+    ("36112", "lens"),
+    ("36113", "lent"),
+    ("36114", "leotard"),
+    ("36115", "lesser"),
+    ("36116", "letdown"),
+    ("36121", "lethargic"),
+    ("36122", "lethargy"),
+    ("36123", "letter"),
+    ("36124", "lettuce"),
+    ("36125", "level"),
+    ("36126", "leverage"),
+    ("36131", "levers"),
+    ("36132", "levitate"),
+    ("36133", "levitator"),
+    ("36134", "liability"),
+    ("36135", "liable"),
+    ("36136", "liberty"),
+    ("36141", "librarian"),
+    ("36142", "library"),
+    ("36143", "licking"),
+    ("36144", "licorice"),
+    ("36145", "lid"),
+    ("36146", "life"),
+    ("36151", "lifter"),
+    ("36152", "lifting"),
+    ("36153", "liftoff"),
+    ("36154", "ligament"),
+    ("36155", "likely"),
+    ("36156", "likeness"),
+    ("36161", "likewise"),
+    ("36162", "liking"),
+    ("36163", "lilac"),
+    ("36164", "lilly"),
+    ("36165", "lily"),
+    ("36166", "limb"),
+    ("36211", "limeade"),
+    ("36212", "limelight"),
+    ("36213", "limes"),
+    ("36214", "limit"),
+    ("36215", "limping"),
+    ("36216", "limpness"),
+    ("36221", "line"),
+    ("36222", "lingo"),
+    ("36223", "linguini"),
+    ("36224", "linguist"),
+    ("36225", "lining"),
+    ("36226", "linked"),
+    ("36231", "linoleum"),
+    ("36232", "linseed"),
+    ("36233", "lint"),
+    ("36234", "lion"),
+    ("36235", "lip"),
+    ("36236", "liquefy"),
+    ("36241", "liqueur"),
+    ("36242", "liquid"),
+    ("36243", "lisp"),
+    ("36244", "list"),
+    ("36245", "litigate"),
+    ("36246", "litigator"),
+    ("36251", "litmus"),
+    ("36252", "litter"),
+    ("36253", "little"),
+    ("36254", "livable"),
+    ("36255", "lived"),
+    ("36256", "lively"),
+    ("36261", "liver"),
+    ("36262", "livestock"),
+    ("36263", "lividly"),
+    ("36264", "living"),
+    ("36265", "lizard"),
+    ("36266", "lubricant"),
+    ("36311", "lubricate"),
+    ("36312", "lucid"),
+    ("36313", "luckily"),
+    ("36314", "luckiness"),
+    ("36315", "luckless"),
+    ("36316", "lucrative"),
+    ("36321", "ludicrous"),
+    ("36322", "lugged"),
+    ("36323", "lukewarm"),
+    ("36324", "lullaby"),
+    ("36325", "lumber"),
+    ("36326", "luminance"),
+    ("36331", "luminous"),
+    ("36332", "lumpiness"),
+    ("36333", "lumping"),
+    ("36334", "lumpish"),
+    ("36335", "lunacy"),
+    ("36336", "lunar"),
+    ("36341", "lunchbox"),
+    ("36342", "luncheon"),
+    ("36343", "lunchroom"),
+    ("36344", "lunchtime"),
+    ("36345", "lung"),
+    ("36346", "lurch"),
+    ("36351", "lure"),
+    ("36352", "luridness"),
+    ("36353", "lurk"),
+    ("36354", "lushly"),
+    ("36355", "lushness"),
+    ("36356", "luster"),
+    ("36361", "lustfully"),
+    ("36362", "lustily"),
+    ("36363", "lustiness"),
+    ("36364", "lustrous"),
+    ("36365", "lusty"),
+    ("36366", "luxurious"),
+    ("36411", "luxury"),
+    ("36412", "lying"),
+    ("36413", "lyrically"),
+    ("36414", "lyricism"),
+    ("36415", "lyricist"),
+    ("36416", "lyrics"),
+    ("36421", "macarena"),
+    ("36422", "macaroni"),
+    ("36423", "macaw"),
+    ("36424", "mace"),
+    ("36425", "machine"),
+    ("36426", "machinist"),
+    ("36431", "magazine"),
+    ("36432", "magenta"),
+    ("36433", "maggot"),
+    ("36434", "magical"),
+    ("36435", "magician"),
+    ("36436", "magma"),
+    ("36441", "magnesium"),
+    ("36442", "magnetic"),
+    ("36443", "magnetism"),
+    ("36444", "magnetize"),
+    ("36445", "magnifier"),
+    ("36446", "magnify"),
+    ("36451", "magnitude"),
+    ("36452", "magnolia"),
+    ("36453", "mahogany"),
+    ("36454", "maimed"),
+    ("36455", "majestic"),
+    ("36456", "majesty"),
+    ("36461", "majorette"),
+    ("36462", "majority"),
+    ("36463", "makeover"),
+    ("36464", "maker"),
+    ("36465", "makeshift"),
+    ("36466", "making"),
+    ("36511", "malformed"),
+    ("36512", "malt"),
+    ("36513", "mama"),
+    ("36514", "mammal"),
+    ("36515", "mammary"),
+    ("36516", "mammogram"),
+    ("36521", "manager"),
+    ("36522", "managing"),
+    ("36523", "manatee"),
+    ("36524", "mandarin"),
+    ("36525", "mandate"),
+    ("36526", "mandatory"),
+    ("36531", "mandolin"),
+    ("36532", "manger"),
+    ("36533", "mangle"),
+    ("36534", "mango"),
+    ("36535", "mangy"),
+    ("36536", "manhandle"),
+    ("36541", "manhole"),
+    ("36542", "manhood"),
+    ("36543", "manhunt"),
+    ("36544", "manicotti"),
+    ("36545", "manicure"),
+    ("36546", "manifesto"),
+    ("36551", "manila"),
+    ("36552", "mankind"),
+    ("36553", "manlike"),
+    ("36554", "manliness"),
+    ("36555", "manly"),
+    ("36556", "manmade"),
+    ("36561", "manned"),
+    ("36562", "mannish"),
+    ("36563", "manor"),
+    ("36564", "manpower"),
+    ("36565", "mantis"),
+    ("36566", "mantra"),
+    ("36611", "manual"),
+    ("36612", "many"),
+    ("36613", "map"),
+    ("36614", "marathon"),
+    ("36615", "marauding"),
+    ("36616", "marbled"),
+    ("36621", "marbles"),
+    ("36622", "marbling"),
+    ("36623", "march"),
+    ("36624", "mardi"),
+    ("36625", "margarine"),
+    ("36626", "margarita"),
+    ("36631", "margin"),
+    ("36632", "marigold"),
+    ("36633", "marina"),
+    ("36634", "marine"),
+    ("36635", "marital"),
+    ("36636", "maritime"),
+    ("36641", "marlin"),
+    ("36642", "marmalade"),
+    ("36643", "maroon"),
+    ("36644", "married"),
+    ("36645", "marrow"),
+    ("36646", "marry"),
+    ("36651", "marshland"),
+    ("36652", "marshy"),
+    ("36653", "marsupial"),
+    ("36654", "marvelous"),
+    ("36655", "marxism"),
+    ("36656", "mascot"),
+    ("36661", "masculine"),
+    ("36662", "mashed"),
+    ("36663", "mashing"),
+    ("36664", "massager"),
+    ("36665", "masses"),
+    ("36666", "massive"),
+    ("41111", "mastiff"),
+    ("41112", "matador"),
+    ("41113", "matchbook"),
+    ("41114", "matchbox"),
+    ("41115", "matcher"),
+    ("41116", "matching"),
+    ("41121", "matchless"),
+    ("41122", "material"),
+    ("41123", "maternal"),
+    ("41124", "maternity"),
+    ("41125", "math"),
+    ("41126", "mating"),
+    ("41131", "matriarch"),
+    ("41132", "matrimony"),
+    ("41133", "matrix"),
+    ("41134", "matron"),
+    ("41135", "matted"),
+    ("41136", "matter"),
+    ("41141", "maturely"),
+    ("41142", "maturing"),
+    ("41143", "maturity"),
+    ("41144", "mauve"),
+    ("41145", "maverick"),
+    ("41146", "maximize"),
+    ("41151", "maximum"),
+    ("41152", "maybe"),
+    ("41153", "mayday"),
+    ("41154", "mayflower"),
+    ("41155", "moaner"),
+    ("41156", "moaning"),
+    ("41161", "mobile"),
+    ("41162", "mobility"),
+    ("41163", "mobilize"),
+    ("41164", "mobster"),
+    ("41165", "mocha"),
+    ("41166", "mocker"),
+    ("41211", "mockup"),
+    ("41212", "modified"),
+    ("41213", "modify"),
+    ("41214", "modular"),
+    ("41215", "modulator"),
+    ("41216", "module"),
+    ("41221", "moisten"),
+    ("41222", "moistness"),
+    ("41223", "moisture"),
+    ("41224", "molar"),
+    ("41225", "molasses"),
+    ("41226", "mold"),
+    ("41231", "molecular"),
+    ("41232", "molecule"),
+    ("41233", "molehill"),
+    ("41234", "mollusk"),
+    ("41235", "mom"),
+    ("41236", "monastery"),
+    ("41241", "monday"),
+    ("41242", "monetary"),
+    ("41243", "monetize"),
+    ("41244", "moneybags"),
+    ("41245", "moneyless"),
+    ("41246", "moneywise"),
+    ("41251", "mongoose"),
+    ("41252", "mongrel"),
+    ("41253", "monitor"),
+    ("41254", "monkhood"),
+    ("41255", "monogamy"),
+    ("41256", "monogram"),
+    ("41261", "monologue"),
+    ("41262", "monopoly"),
+    ("41263", "monorail"),
+    ("41264", "monotone"),
+    ("41265", "monotype"),
+    ("41266", "monoxide"),
+    ("41311", "monsieur"),
+    ("41312", "monsoon"),
+    ("41313", "monstrous"),
+    ("41314", "monthly"),
+    ("41315", "monument"),
+    ("41316", "moocher"),
+    ("41321", "moodiness"),
+    ("41322", "moody"),
+    ("41323", "mooing"),
+    ("41324", "moonbeam"),
+    ("41325", "mooned"),
+    ("41326", "moonlight"),
+    ("41331", "moonlike"),
+    ("41332", "moonlit"),
+    ("41333", "moonrise"),
+    ("41334", "moonscape"),
+    ("41335", "moonshine"),
+    ("41336", "moonstone"),
+    ("41341", "moonwalk"),
+    ("41342", "mop"),
+    ("41343", "morale"),
+    ("41344", "morality"),
+    ("41345", "morally"),
+    ("41346", "morbidity"),
+    ("41351", "morbidly"),
+    ("41352", "morphine"),
+    ("41353", "morphing"),
+    ("41354", "morse"),
+    ("41355", "mortality"),
+    ("41356", "mortally"),
+    ("41361", "mortician"),
+    ("41362", "mortified"),
+    ("41363", "mortify"),
+    ("41364", "mortuary"),
+    ("41365", "mosaic"),
+    ("41366", "mossy"),
+    ("41411", "most"),
+    ("41412", "mothball"),
+    ("41413", "mothproof"),
+    ("41414", "motion"),
+    ("41415", "motivate"),
+    ("41416", "motivator"),
+    ("41421", "motive"),
+    ("41422", "motocross"),
+    ("41423", "motor"),
+    ("41424", "motto"),
+    ("41425", "mountable"),
+    ("41426", "mountain"),
+    ("41431", "mounted"),
+    ("41432", "mounting"),
+    ("41433", "mourner"),
+    ("41434", "mournful"),
+    ("41435", "mouse"),
+    ("41436", "mousiness"),
+    ("41441", "moustache"),
+    ("41442", "mousy"),
+    ("41443", "mouth"),
+    ("41444", "movable"),
+    ("41445", "move"),
+    ("41446", "movie"),
+    ("41451", "moving"),
+    ("41452", "mower"),
+    ("41453", "mowing"),
+    ("41454", "much"),
+    ("41455", "muck"),
+    ("41456", "mud"),
+    ("41461", "mug"),
+    ("41462", "mulberry"),
+    ("41463", "mulch"),
+    ("41464", "mule"),
+    ("41465", "mulled"),
+    ("41466", "mullets"),
+    ("41511", "multiple"),
+    ("41512", "multiply"),
+    ("41513", "multitask"),
+    ("41514", "multitude"),
+    ("41515", "mumble"),
+    ("41516", "mumbling"),
+    ("41521", "mumbo"),
+    ("41522", "mummified"),
+    ("41523", "mummify"),
+    ("41524", "mummy"),
+    ("41525", "mumps"),
+    ("41526", "munchkin"),
+    ("41531", "mundane"),
+    ("41532", "municipal"),
+    ("41533", "muppet"),
+    ("41534", "mural"),
+    ("41535", "murkiness"),
+    ("41536", "murky"),
+    ("41541", "murmuring"),
+    ("41542", "muscular"),
+    ("41543", "museum"),
+    ("41544", "mushily"),
+    ("41545", "mushiness"),
+    ("41546", "mushroom"),
+    ("41551", "mushy"),
+    ("41552", "music"),
+    ("41553", "musket"),
+    ("41554", "muskiness"),
+    ("41555", "musky"),
+    ("41556", "mustang"),
+    ("41561", "mustard"),
+    ("41562", "muster"),
+    ("41563", "mustiness"),
+    ("41564", "musty"),
+    ("41565", "mutable"),
+    ("41566", "mutate"),
+    ("41611", "mutation"),
+    ("41612", "mute"),
+    ("41613", "mutilated"),
+    ("41614", "mutilator"),
+    ("41615", "mutiny"),
+    ("41616", "mutt"),
+    ("41621", "mutual"),
+    ("41622", "muzzle"),
+    ("41623", "myself"),
+    ("41624", "myspace"),
+    ("41625", "mystified"),
+    ("41626", "mystify"),
+    ("41631", "myth"),
+    ("41632", "nacho"),
+    ("41633", "nag"),
+    ("41634", "nail"),
+    ("41635", "name"),
+    ("41636", "naming"),
+    ("41641", "nanny"),
+    ("41642", "nanometer"),
+    ("41643", "nape"),
+    ("41644", "napkin"),
+    ("41645", "napped"),
+    ("41646", "napping"),
+    ("41651", "nappy"),
+    ("41652", "narrow"),
+    ("41653", "nastily"),
+    ("41654", "nastiness"),
+    ("41655", "national"),
+    ("41656", "native"),
+    ("41661", "nativity"),
+    ("41662", "natural"),
+    ("41663", "nature"),
+    ("41664", "naturist"),
+    ("41665", "nautical"),
+    ("41666", "navigate"),
+    ("42111", "navigator"),
+    ("42112", "navy"),
+    ("42113", "nearby"),
+    ("42114", "nearest"),
+    ("42115", "nearly"),
+    ("42116", "nearness"),
+    ("42121", "neatly"),
+    ("42122", "neatness"),
+    ("42123", "nebula"),
+    ("42124", "nebulizer"),
+    ("42125", "nectar"),
+    ("42126", "negate"),
+    ("42131", "negation"),
+    ("42132", "negative"),
+    ("42133", "neglector"),
+    ("42134", "negligee"),
+    ("42135", "negligent"),
+    ("42136", "negotiate"),
+    ("42141", "nemeses"),
+    ("42142", "nemesis"),
+    ("42143", "neon"),
+    ("42144", "nephew"),
+    ("42145", "nerd"),
+    ("42146", "nervous"),
+    ("42151", "nervy"),
+    ("42152", "nest"),
+    ("42153", "net"),
+    ("42154", "neurology"),
+    ("42155", "neuron"),
+    ("42156", "neurosis"),
+    ("42161", "neurotic"),
+    ("42162", "neuter"),
+    ("42163", "neutron"),
+    ("42164", "never"),
+    ("42165", "next"),
+    ("42166", "nibble"),
+    ("42211", "nickname"),
+    ("42212", "nicotine"),
+    ("42213", "niece"),
+    ("42214", "nifty"),
+    ("42215", "nimble"),
+    ("42216", "nimbly"),
+    ("42221", "nineteen"),
+    ("42222", "ninetieth"),
+    ("42223", "ninja"),
+    ("42224", "nintendo"),
+    ("42225", "ninth"),
+    ("42226", "nuclear"),
+    ("42231", "nuclei"),
+    ("42232", "nucleus"),
+    ("42233", "nugget"),
+    ("42234", "nullify"),
+    ("42235", "number"),
+    ("42236", "numbing"),
+    ("42241", "numbly"),
+    ("42242", "numbness"),
+    ("42243", "numeral"),
+    ("42244", "numerate"),
+    ("42245", "numerator"),
+    ("42246", "numeric"),
+    ("42251", "numerous"),
+    ("42252", "nuptials"),
+    ("42253", "nursery"),
+    ("42254", "nursing"),
+    ("42255", "nurture"),
+    ("42256", "nutcase"),
+    ("42261", "nutlike"),
+    ("42262", "nutmeg"),
+    ("42263", "nutrient"),
+    ("42264", "nutshell"),
+    ("42265", "nuttiness"),
+    ("42266", "nutty"),
+    ("42311", "nuzzle"),
+    ("42312", "nylon"),
+    ("42313", "oaf"),
+    ("42314", "oak"),
+    ("42315", "oasis"),
+    ("42316", "oat"),
+    ("42321", "obedience"),
+    ("42322", "obedient"),
+    ("42323", "obituary"),
+    ("42324", "object"),
+    ("42325", "obligate"),
+    ("42326", "obliged"),
+    ("42331", "oblivion"),
+    ("42332", "oblivious"),
+    ("42333", "oblong"),
+    ("42334", "obnoxious"),
+    ("42335", "oboe"),
+    ("42336", "obscure"),
+    ("42341", "obscurity"),
+    ("42342", "observant"),
+    ("42343", "observer"),
+    ("42344", "observing"),
+    ("42345", "obsessed"),
+    ("42346", "obsession"),
+    ("42351", "obsessive"),
+    ("42352", "obsolete"),
+    ("42353", "obstacle"),
+    ("42354", "obstinate"),
+    ("42355", "obstruct"),
+    ("42356", "obtain"),
+    ("42361", "obtrusive"),
+    ("42362", "obtuse"),
+    ("42363", "obvious"),
+    ("42364", "occultist"),
+    ("42365", "occupancy"),
+    ("42366", "occupant"),
+    ("42411", "occupier"),
+    ("42412", "occupy"),
+    ("42413", "ocean"),
+    ("42414", "ocelot"),
+    ("42415", "octagon"),
+    ("42416", "octane"),
+    ("42421", "october"),
+    ("42422", "octopus"),
+    ("42423", "ogle"),
+    ("42424", "oil"),
+    ("42425", "oink"),
+    ("42426", "ointment"),
+    ("42431", "okay"),
+    ("42432", "old"),
+    ("42433", "olive"),
+    ("42434", "olympics"),
+    ("42435", "omega"),
+    ("42436", "omen"),
+    ("42441", "ominous"),
+    ("42442", "omission"),
+    ("42443", "omit"),
+    ("42444", "omnivore"),
+    ("42445", "onboard"),
+    ("42446", "oncoming"),
+    ("42451", "ongoing"),
+    ("42452", "onion"),
+    ("42453", "online"),
+    ("42454", "onlooker"),
+    ("42455", "only"),
+    ("42456", "onscreen"),
+    ("42461", "onset"),
+    ("42462", "onshore"),
+    ("42463", "onslaught"),
+    ("42464", "onstage"),
+    ("42465", "onto"),
+    ("42466", "onward"),
+    ("42511", "onyx"),
+    ("42512", "oops"),
+    ("42513", "ooze"),
+    ("42514", "oozy"),
+    ("42515", "opacity"),
+    ("42516", "opal"),
+    ("42521", "open"),
+    ("42522", "operable"),
+    ("42523", "operate"),
+    ("42524", "operating"),
+    ("42525", "operation"),
+    ("42526", "operative"),
+    ("42531", "operator"),
+    ("42532", "opium"),
+    ("42533", "opossum"),
+    ("42534", "opponent"),
+    ("42535", "oppose"),
+    ("42536", "opposing"),
+    ("42541", "opposite"),
+    ("42542", "oppressed"),
+    ("42543", "oppressor"),
+    ("42544", "opt"),
+    ("42545", "opulently"),
+    ("42546", "osmosis"),
+    ("42551", "other"),
+    ("42552", "otter"),
+    ("42553", "ouch"),
+    ("42554", "ought"),
+    ("42555", "ounce"),
+    ("42556", "outage"),
+    ("42561", "outback"),
+    ("42562", "outbid"),
+    ("42563", "outboard"),
+    ("42564", "outbound"),
+    ("42565", "outbreak"),
+    ("42566", "outburst"),
+    ("42611", "outcast"),
+    ("42612", "outclass"),
+    ("42613", "outcome"),
+    ("42614", "outdated"),
+    ("42615", "outdoors"),
+    ("42616", "outer"),
+    ("42621", "outfield"),
+    ("42622", "outfit"),
+    ("42623", "outflank"),
+    ("42624", "outgoing"),
+    ("42625", "outgrow"),
+    ("42626", "outhouse"),
+    ("42631", "outing"),
+    ("42632", "outlast"),
+    ("42633", "outlet"),
+    ("42634", "outline"),
+    ("42635", "outlook"),
+    ("42636", "outlying"),
+    ("42641", "outmatch"),
+    ("42642", "outmost"),
+    ("42643", "outnumber"),
+    ("42644", "outplayed"),
+    ("42645", "outpost"),
+    ("42646", "outpour"),
+    ("42651", "output"),
+    ("42652", "outrage"),
+    ("42653", "outrank"),
+    ("42654", "outreach"),
+    ("42655", "outright"),
+    ("42656", "outscore"),
+    ("42661", "outsell"),
+    ("42662", "outshine"),
+    ("42663", "outshoot"),
+    ("42664", "outsider"),
+    ("42665", "outskirts"),
+    ("42666", "outsmart"),
+    ("43111", "outsource"),
+    ("43112", "outspoken"),
+    ("43113", "outtakes"),
+    ("43114", "outthink"),
+    ("43115", "outward"),
+    ("43116", "outweigh"),
+    ("43121", "outwit"),
+    ("43122", "oval"),
+    ("43123", "ovary"),
+    ("43124", "oven"),
+    ("43125", "overact"),
+    ("43126", "overall"),
+    ("43131", "overarch"),
+    ("43132", "overbid"),
+    ("43133", "overbill"),
+    ("43134", "overbite"),
+    ("43135", "overblown"),
+    ("43136", "overboard"),
+    ("43141", "overbook"),
+    ("43142", "overbuilt"),
+    ("43143", "overcast"),
+    ("43144", "overcoat"),
+    ("43145", "overcome"),
+    ("43146", "overcook"),
+    ("43151", "overcrowd"),
+    ("43152", "overdraft"),
+    ("43153", "overdrawn"),
+    ("43154", "overdress"),
+    ("43155", "overdrive"),
+    ("43156", "overdue"),
+    ("43161", "overeager"),
+    ("43162", "overeater"),
+    ("43163", "overexert"),
+    ("43164", "overfed"),
+    ("43165", "overfeed"),
+    ("43166", "overfill"),
+    ("43211", "overflow"),
+    ("43212", "overfull"),
+    ("43213", "overgrown"),
+    ("43214", "overhand"),
+    ("43215", "overhang"),
+    ("43216", "overhaul"),
+    ("43221", "overhead"),
+    ("43222", "overhear"),
+    ("43223", "overheat"),
+    ("43224", "overhung"),
+    ("43225", "overjoyed"),
+    ("43226", "overkill"),
+    ("43231", "overlabor"),
+    ("43232", "overlaid"),
+    ("43233", "overlap"),
+    ("43234", "overlay"),
+    ("43235", "overload"),
+    ("43236", "overlook"),
+    ("43241", "overlord"),
+    ("43242", "overlying"),
+    ("43243", "overnight"),
+    ("43244", "overpass"),
+    ("43245", "overpay"),
+    ("43246", "overplant"),
+    ("43251", "overplay"),
+    ("43252", "overpower"),
+    ("43253", "overprice"),
+    ("43254", "overrate"),
+    ("43255", "overreach"),
+    ("43256", "overreact"),
+    ("43261", "override"),
+    ("43262", "overripe"),
+    ("43263", "overrule"),
+    ("43264", "overrun"),
+    ("43265", "overshoot"),
+    ("43266", "overshot"),
+    ("43311", "oversight"),
+    ("43312", "oversized"),
+    ("43313", "oversleep"),
+    ("43314", "oversold"),
+    ("43315", "overspend"),
+    ("43316", "overstate"),
+    ("43321", "overstay"),
+    ("43322", "overstep"),
+    ("43323", "overstock"),
+    ("43324", "overstuff"),
+    ("43325", "oversweet"),
+    ("43326", "overtake"),
+    ("43331", "overthrow"),
+    ("43332", "overtime"),
+    ("43333", "overtly"),
+    ("43334", "overtone"),
+    ("43335", "overture"),
+    ("43336", "overturn"),
+    ("43341", "overuse"),
+    ("43342", "overvalue"),
+    ("43343", "overview"),
+    ("43344", "overwrite"),
+    ("43345", "owl"),
+    ("43346", "oxford"),
+    ("43351", "oxidant"),
+    ("43352", "oxidation"),
+    ("43353", "oxidize"),
+    ("43354", "oxidizing"),
+    ("43355", "oxygen"),
+    ("43356", "oxymoron"),
+    ("43361", "oyster"),
+    ("43362", "ozone"),
+    ("43363", "paced"),
+    ("43364", "pacemaker"),
+    ("43365", "pacific"),
+    ("43366", "pacifier"),
+    ("43411", "pacifism"),
+    ("43412", "pacifist"),
+    ("43413", "pacify"),
+    ("43414", "padded"),
+    ("43415", "padding"),
+    ("43416", "paddle"),
+    ("43421", "paddling"),
+    ("43422", "padlock"),
+    ("43423", "pagan"),
+    ("43424", "pager"),
+    ("43425", "paging"),
+    ("43426", "pajamas"),
+    ("43431", "palace"),
+    ("43432", "palatable"),
+    ("43433", "palm"),
+    ("43434", "palpable"),
+    ("43435", "palpitate"),
+    ("43436", "paltry"),
+    ("43441", "pampered"),
+    ("43442", "pamperer"),
+    ("43443", "pampers"),
+    ("43444", "pamphlet"),
+    ("43445", "panama"),
+    ("43446", "pancake"),
+    ("43451", "pancreas"),
+    ("43452", "panda"),
+    ("43453", "pandemic"),
+    ("43454", "pang"),
+    ("43455", "panhandle"),
+    ("43456", "panic"),
+    ("43461", "panning"),
+    ("43462", "panorama"),
+    ("43463", "panoramic"),
+    ("43464", "panther"),
+    ("43465", "pantomime"),
+    ("43466", "pantry"),
+    ("43511", "pants"),
+    ("43512", "pantyhose"),
+    ("43513", "paparazzi"),
+    ("43514", "papaya"),
+    ("43515", "paper"),
+    ("43516", "paprika"),
+    ("43521", "papyrus"),
+    ("43522", "parabola"),
+    ("43523", "parachute"),
+    ("43524", "parade"),
+    ("43525", "paradox"),
+    ("43526", "paragraph"),
+    ("43531", "parakeet"),
+    ("43532", "paralegal"),
+    ("43533", "paralyses"),
+    ("43534", "paralysis"),
+    ("43535", "paralyze"),
+    ("43536", "paramedic"),
+    ("43541", "parameter"),
+    ("43542", "paramount"),
+    ("43543", "parasail"),
+    ("43544", "parasite"),
+    ("43545", "parasitic"),
+    ("43546", "parcel"),
+    ("43551", "parched"),
+    ("43552", "parchment"),
+    ("43553", "pardon"),
+    ("43554", "parish"),
+    ("43555", "parka"),
+    ("43556", "parking"),
+    ("43561", "parkway"),
+    ("43562", "parlor"),
+    ("43563", "parmesan"),
+    ("43564", "parole"),
+    ("43565", "parrot"),
+    ("43566", "parsley"),
+    ("43611", "parsnip"),
+    ("43612", "partake"),
+    ("43613", "parted"),
+    ("43614", "parting"),
+    ("43615", "partition"),
+    ("43616", "partly"),
+    ("43621", "partner"),
+    ("43622", "partridge"),
+    ("43623", "party"),
+    ("43624", "passable"),
+    ("43625", "passably"),
+    ("43626", "passage"),
+    ("43631", "passcode"),
+    ("43632", "passenger"),
+    ("43633", "passerby"),
+    ("43634", "passing"),
+    ("43635", "passion"),
+    ("43636", "passive"),
+    ("43641", "passivism"),
+    ("43642", "passover"),
+    ("43643", "passport"),
+    ("43644", "password"),
+    ("43645", "pasta"),
+    ("43646", "pasted"),
+    ("43651", "pastel"),
+    ("43652", "pastime"),
+    ("43653", "pastor"),
+    ("43654", "pastrami"),
+    ("43655", "pasture"),
+    ("43656", "pasty"),
+    ("43661", "patchwork"),
+    ("43662", "patchy"),
+    ("43663", "paternal"),
+    ("43664", "paternity"),
+    ("43665", "path"),
+    ("43666", "patience"),
+    ("44111", "patient"),
+    ("44112", "patio"),
+    ("44113", "patriarch"),
+    ("44114", "patriot"),
+    ("44115", "patrol"),
+    ("44116", "patronage"),
+    ("44121", "patronize"),
+    ("44122", "pauper"),
+    ("44123", "pavement"),
+    ("44124", "paver"),
+    ("44125", "pavestone"),
+    ("44126", "pavilion"),
+    ("44131", "paving"),
+    ("44132", "pawing"),
+    ("44133", "payable"),
+    ("44134", "payback"),
+    ("44135", "paycheck"),
+    ("44136", "payday"),
+    ("44141", "payee"),
+    ("44142", "payer"),
+    ("44143", "paying"),
+    ("44144", "payment"),
+    ("44145", "payphone"),
+    ("44146", "payroll"),
+    ("44151", "pebble"),
+    ("44152", "pebbly"),
+    ("44153", "pecan"),
+    ("44154", "pectin"),
+    ("44155", "peculiar"),
+    ("44156", "peddling"),
+    ("44161", "pediatric"),
+    ("44162", "pedicure"),
+    ("44163", "pedigree"),
+    ("44164", "pedometer"),
+    ("44165", "pegboard"),
+    ("44166", "pelican"),
+    ("44211", "pellet"),
+    ("44212", "pelt"),
+    ("44213", "pelvis"),
+    ("44214", "penalize"),
+    ("44215", "penalty"),
+    ("44216", "pencil"),
+    ("44221", "pendant"),
+    ("44222", "pending"),
+    ("44223", "penholder"),
+    ("44224", "penknife"),
+    ("44225", "pennant"),
+    ("44226", "penniless"),
+    ("44231", "penny"),
+    ("44232", "penpal"),
+    ("44233", "pension"),
+    ("44234", "pentagon"),
+    ("44235", "pentagram"),
+    ("44236", "pep"),
+    ("44241", "perceive"),
+    ("44242", "percent"),
+    ("44243", "perch"),
+    ("44244", "percolate"),
+    ("44245", "perennial"),
+    ("44246", "perfected"),
+    ("44251", "perfectly"),
+    ("44252", "perfume"),
+    ("44253", "periscope"),
+    ("44254", "perish"),
+    ("44255", "perjurer"),
+    ("44256", "perjury"),
+    ("44261", "perkiness"),
+    ("44262", "perky"),
+    ("44263", "perm"),
+    ("44264", "peroxide"),
+    ("44265", "perpetual"),
+    ("44266", "perplexed"),
+    ("44311", "persecute"),
+    ("44312", "persevere"),
+    ("44313", "persuaded"),
+    ("44314", "persuader"),
+    ("44315", "pesky"),
+    ("44316", "peso"),
+    ("44321", "pessimism"),
+    ("44322", "pessimist"),
+    ("44323", "pester"),
+    ("44324", "pesticide"),
+    ("44325", "petal"),
+    ("44326", "petite"),
+    ("44331", "petition"),
+    ("44332", "petri"),
+    ("44333", "petroleum"),
+    ("44334", "petted"),
+    ("44335", "petticoat"),
+    ("44336", "pettiness"),
+    ("44341", "petty"),
+    ("44342", "petunia"),
+    ("44343", "phantom"),
+    ("44344", "phobia"),
+    ("44345", "phoenix"),
+    ("44346", "phonebook"),
+    ("44351", "phoney"),
+    ("44352", "phonics"),
+    ("44353", "phoniness"),
+    ("44354", "phony"),
+    ("44355", "phosphate"),
+    ("44356", "photo"),
+    ("44361", "phrase"),
+    ("44362", "phrasing"),
+    ("44363", "placard"),
+    ("44364", "placate"),
+    ("44365", "placidly"),
+    ("44366", "plank"),
+    ("44411", "planner"),
+    ("44412", "plant"),
+    ("44413", "plasma"),
+    ("44414", "plaster"),
+    ("44415", "plastic"),
+    ("44416", "plated"),
+    ("44421", "platform"),
+    ("44422", "plating"),
+    ("44423", "platinum"),
+    ("44424", "platonic"),
+    ("44425", "platter"),
+    ("44426", "platypus"),
+    ("44431", "plausible"),
+    ("44432", "plausibly"),
+    ("44433", "playable"),
+    ("44434", "playback"),
+    ("44435", "player"),
+    ("44436", "playful"),
+    ("44441", "playgroup"),
+    ("44442", "playhouse"),
+    ("44443", "playing"),
+    ("44444", "playlist"),
+    ("44445", "playmaker"),
+    ("44446", "playmate"),
+    ("44451", "playoff"),
+    ("44452", "playpen"),
+    ("44453", "playroom"),
+    ("44454", "playset"),
+    ("44455", "plaything"),
+    ("44456", "playtime"),
+    ("44461", "plaza"),
+    ("44462", "pleading"),
+    ("44463", "pleat"),
+    ("44464", "pledge"),
+    ("44465", "plentiful"),
+    ("44466", "plenty"),
+    ("44511", "plethora"),
+    ("44512", "plexiglas"),
+    ("44513", "pliable"),
+    ("44514", "plod"),
+    ("44515", "plop"),
+    ("44516", "plot"),
+    ("44521", "plow"),
+    ("44522", "ploy"),
+    ("44523", "pluck"),
+    ("44524", "plug"),
+    ("44525", "plunder"),
+    ("44526", "plunging"),
+    ("44531", "plural"),
+    ("44532", "plus"),
+    ("44533", "plutonium"),
+    ("44534", "plywood"),
+    ("44535", "poach"),
+    ("44536", "pod"),
+    ("44541", "poem"),
+    ("44542", "poet"),
+    ("44543", "pogo"),
+    ("44544", "pointed"),
+    ("44545", "pointer"),
+    ("44546", "pointing"),
+    ("44551", "pointless"),
+    ("44552", "pointy"),
+    ("44553", "poise"),
+    ("44554", "poison"),
+    ("44555", "poker"),
+    ("44556", "poking"),
+    ("44561", "polar"),
+    ("44562", "police"),
+    ("44563", "policy"),
+    ("44564", "polio"),
+    ("44565", "polish"),
+    ("44566", "politely"),
+    ("44611", "polka"),
+    ("44612", "polo"),
+    ("44613", "polyester"),
+    ("44614", "polygon"),
+    ("44615", "polygraph"),
+    ("44616", "polymer"),
+    ("44621", "poncho"),
+    ("44622", "pond"),
+    ("44623", "pony"),
+    ("44624", "popcorn"),
+    ("44625", "pope"),
+    ("44626", "poplar"),
+    ("44631", "popper"),
+    ("44632", "poppy"),
+    ("44633", "popsicle"),
+    ("44634", "populace"),
+    ("44635", "popular"),
+    ("44636", "populate"),
+    ("44641", "porcupine"),
+    ("44642", "pork"),
+    ("44643", "porous"),
+    ("44644", "porridge"),
+    ("44645", "portable"),
+    ("44646", "portal"),
+    ("44651", "portfolio"),
+    ("44652", "porthole"),
+    ("44653", "portion"),
+    ("44654", "portly"),
+    ("44655", "portside"),
+    ("44656", "poser"),
+    ("44661", "posh"),
+    ("44662", "posing"),
+    ("44663", "possible"),
+    ("44664", "possibly"),
+    ("44665", "possum"),
+    ("44666", "postage"),
+    ("45111", "postal"),
+    ("45112", "postbox"),
+    ("45113", "postcard"),
+    ("45114", "posted"),
+    ("45115", "poster"),
+    ("45116", "posting"),
+    ("45121", "postnasal"),
+    ("45122", "posture"),
+    ("45123", "postwar"),
+    ("45124", "pouch"),
+    ("45125", "pounce"),
+    ("45126", "pouncing"),
+    ("45131", "pound"),
+    ("45132", "pouring"),
+    ("45133", "pout"),
+    ("45134", "powdered"),
+    ("45135", "powdering"),
+    ("45136", "powdery"),
+    ("45141", "power"),
+    ("45142", "powwow"),
+    ("45143", "pox"),
+    ("45144", "praising"),
+    ("45145", "prance"),
+    ("45146", "prancing"),
+    ("45151", "pranker"),
+    ("45152", "prankish"),
+    ("45153", "prankster"),
+    ("45154", "prayer"),
+    ("45155", "praying"),
+    ("45156", "preacher"),
+    ("45161", "preaching"),
+    ("45162", "preachy"),
+    ("45163", "preamble"),
+    ("45164", "precinct"),
+    ("45165", "precise"),
+    ("45166", "precision"),
+    ("45211", "precook"),
+    ("45212", "precut"),
+    ("45213", "predator"),
+    ("45214", "predefine"),
+    ("45215", "predict"),
+    ("45216", "preface"),
+    ("45221", "prefix"),
+    ("45222", "preflight"),
+    ("45223", "preformed"),
+    ("45224", "pregame"),
+    ("45225", "pregnancy"),
+    ("45226", "pregnant"),
+    ("45231", "preheated"),
+    ("45232", "prelaunch"),
+    ("45233", "prelaw"),
+    ("45234", "prelude"),
+    ("45235", "premiere"),
+    ("45236", "premises"),
+    ("45241", "premium"),
+    ("45242", "prenatal"),
+    ("45243", "preoccupy"),
+    ("45244", "preorder"),
+    ("45245", "prepaid"),
+    ("45246", "prepay"),
+    ("45251", "preplan"),
+    ("45252", "preppy"),
+    ("45253", "preschool"),
+    ("45254", "prescribe"),
+    ("45255", "preseason"),
+    ("45256", "preset"),
+    ("45261", "preshow"),
+    ("45262", "president"),
+    ("45263", "presoak"),
+    ("45264", "press"),
+    ("45265", "presume"),
+    ("45266", "presuming"),
+    ("45311", "preteen"),
+    ("45312", "pretended"),
+    ("45313", "pretender"),
+    ("45314", "pretense"),
+    ("45315", "pretext"),
+    ("45316", "pretty"),
+    ("45321", "pretzel"),
+    ("45322", "prevail"),
+    ("45323", "prevalent"),
+    ("45324", "prevent"),
+    ("45325", "preview"),
+    ("45326", "previous"),
+    ("45331", "prewar"),
+    ("45332", "prewashed"),
+    ("45333", "prideful"),
+    ("45334", "pried"),
+    ("45335", "primal"),
+    ("45336", "primarily"),
+    ("45341", "primary"),
+    ("45342", "primate"),
+    ("45343", "primer"),
+    ("45344", "primp"),
+    ("45345", "princess"),
+    ("45346", "print"),
+    ("45351", "prior"),
+    ("45352", "prism"),
+    ("45353", "prison"),
+    ("45354", "prissy"),
+    ("45355", "pristine"),
+    ("45356", "privacy"),
+    ("45361", "private"),
+    ("45362", "privatize"),
+    ("45363", "prize"),
+    ("45364", "proactive"),
+    ("45365", "probable"),
+    ("45366", "probably"),
+    ("45411", "probation"),
+    ("45412", "probe"),
+    ("45413", "probing"),
+    ("45414", "probiotic"),
+    ("45415", "problem"),
+    ("45416", "procedure"),
+    ("45421", "process"),
+    ("45422", "proclaim"),
+    ("45423", "procreate"),
+    ("45424", "procurer"),
+    ("45425", "prodigal"),
+    ("45426", "prodigy"),
+    ("45431", "produce"),
+    ("45432", "product"),
+    ("45433", "profane"),
+    ("45434", "profanity"),
+    ("45435", "professed"),
+    ("45436", "professor"),
+    ("45441", "profile"),
+    ("45442", "profound"),
+    ("45443", "profusely"),
+    ("45444", "progeny"),
+    ("45445", "prognosis"),
+    ("45446", "program"),
+    ("45451", "progress"),
+    ("45452", "projector"),
+    ("45453", "prologue"),
+    ("45454", "prolonged"),
+    ("45455", "promenade"),
+    ("45456", "prominent"),
+    ("45461", "promoter"),
+    ("45462", "promotion"),
+    ("45463", "prompter"),
+    ("45464", "promptly"),
+    ("45465", "prone"),
+    ("45466", "prong"),
+    ("45511", "pronounce"),
+    ("45512", "pronto"),
+    ("45513", "proofing"),
+    ("45514", "proofread"),
+    ("45515", "proofs"),
+    ("45516", "propeller"),
+    ("45521", "properly"),
+    ("45522", "property"),
+    ("45523", "proponent"),
+    ("45524", "proposal"),
+    ("45525", "propose"),
+    ("45526", "props"),
+    ("45531", "prorate"),
+    ("45532", "protector"),
+    ("45533", "protegee"),
+    ("45534", "proton"),
+    ("45535", "prototype"),
+    ("45536", "protozoan"),
+    ("45541", "protract"),
+    ("45542", "protrude"),
+    ("45543", "proud"),
+    ("45544", "provable"),
+    ("45545", "proved"),
+    ("45546", "proven"),
+    ("45551", "provided"),
+    ("45552", "provider"),
+    ("45553", "providing"),
+    ("45554", "province"),
+    ("45555", "proving"),
+    ("45556", "provoke"),
+    ("45561", "provoking"),
+    ("45562", "provolone"),
+    ("45563", "prowess"),
+    ("45564", "prowler"),
+    ("45565", "prowling"),
+    ("45566", "proximity"),
+    ("45611", "proxy"),
+    ("45612", "prozac"),
+    ("45613", "prude"),
+    ("45614", "prudishly"),
+    ("45615", "prune"),
+    ("45616", "pruning"),
+    ("45621", "pry"),
+    ("45622", "psychic"),
+    ("45623", "public"),
+    ("45624", "publisher"),
+    ("45625", "pucker"),
+    ("45626", "pueblo"),
+    ("45631", "pug"),
+    ("45632", "pull"),
+    ("45633", "pulmonary"),
+    ("45634", "pulp"),
+    ("45635", "pulsate"),
+    ("45636", "pulse"),
+    ("45641", "pulverize"),
+    ("45642", "puma"),
+    ("45643", "pumice"),
+    ("45644", "pummel"),
+    ("45645", "punch"),
+    ("45646", "punctual"),
+    ("45651", "punctuate"),
+    ("45652", "punctured"),
+    ("45653", "pungent"),
+    ("45654", "punisher"),
+    ("45655", "punk"),
+    ("45656", "pupil"),
+    ("45661", "puppet"),
+    ("45662", "puppy"),
+    ("45663", "purchase"),
+    ("45664", "pureblood"),
+    ("45665", "purebred"),
+    ("45666", "purely"),
+    ("46111", "pureness"),
+    ("46112", "purgatory"),
+    ("46113", "purge"),
+    ("46114", "purging"),
+    ("46115", "purifier"),
+    ("46116", "purify"),
+    ("46121", "purist"),
+    ("46122", "puritan"),
+    ("46123", "purity"),
+    ("46124", "purple"),
+    ("46125", "purplish"),
+    ("46126", "purposely"),
+    ("46131", "purr"),
+    ("46132", "purse"),
+    ("46133", "pursuable"),
+    ("46134", "pursuant"),
+    ("46135", "pursuit"),
+    ("46136", "purveyor"),
+    ("46141", "pushcart"),
+    ("46142", "pushchair"),
+    ("46143", "pusher"),
+    ("46144", "pushiness"),
+    ("46145", "pushing"),
+    ("46146", "pushover"),
+    ("46151", "pushpin"),
+    ("46152", "pushup"),
+    ("46153", "pushy"),
+    ("46154", "putdown"),
+    ("46155", "putt"),
+    ("46156", "puzzle"),
+    ("46161", "puzzling"),
+    ("46162", "pyramid"),
+    ("46163", "pyromania"),
+    ("46164", "python"),
+    ("46165", "quack"),
+    ("46166", "quadrant"),
+    ("46211", "quail"),
+    ("46212", "quaintly"),
+    ("46213", "quake"),
+    ("46214", "quaking"),
+    ("46215", "qualified"),
+    ("46216", "qualifier"),
+    ("46221", "qualify"),
+    ("46222", "quality"),
+    ("46223", "qualm"),
+    ("46224", "quantum"),
+    ("46225", "quarrel"),
+    ("46226", "quarry"),
+    ("46231", "quartered"),
+    ("46232", "quarterly"),
+    ("46233", "quarters"),
+    ("46234", "quartet"),
+    ("46235", "quench"),
+    ("46236", "query"),
+    ("46241", "quicken"),
+    ("46242", "quickly"),
+    ("46243", "quickness"),
+    ("46244", "quicksand"),
+    ("46245", "quickstep"),
+    ("46246", "quiet"),
+    ("46251", "quill"),
+    ("46252", "quilt"),
+    ("46253", "quintet"),
+    ("46254", "quintuple"),
+    ("46255", "quirk"),
+    ("46256", "quit"),
+    ("46261", "quiver"),
+    ("46262", "quizzical"),
+    ("46263", "quotable"),
+    ("46264", "quotation"),
+    ("46265", "quote"),
+    ("46266", "rabid"),
+    ("46311", "race"),
+    ("46312", "racing"),
+    ("46313", "racism"),
+    ("46314", "rack"),
+    ("46315", "racoon"),
+    ("46316", "radar"),
+    ("46321", "radial"),
+    ("46322", "radiance"),
+    ("46323", "radiantly"),
+    ("46324", "radiated"),
+    ("46325", "radiation"),
+    ("46326", "radiator"),
+    ("46331", "radio"),
+    ("46332", "radish"),
+    ("46333", "raffle"),
+    ("46334", "raft"),
+    ("46335", "rage"),
+    ("46336", "ragged"),
+    ("46341", "raging"),
+    ("46342", "ragweed"),
+    ("46343", "raider"),
+    ("46344", "railcar"),
+    ("46345", "railing"),
+    ("46346", "railroad"),
+    ("46351", "railway"),
+    ("46352", "raisin"),
+    ("46353", "rake"),
+    ("46354", "raking"),
+    ("46355", "rally"),
+    ("46356", "ramble"),
+    ("46361", "rambling"),
+    ("46362", "ramp"),
+    ("46363", "ramrod"),
+    ("46364", "ranch"),
+    ("46365", "rancidity"),
+    ("46366", "random"),
+    ("46411", "ranged"),
+    ("46412", "ranger"),
+    ("46413", "ranging"),
+    ("46414", "ranked"),
+    ("46415", "ranking"),
+    ("46416", "ransack"),
+    ("46421", "ranting"),
+    ("46422", "rants"),
+    ("46423", "rare"),
+    ("46424", "rarity"),
+    ("46425", "rascal"),
+    ("46426", "rash"),
+    ("46431", "rasping"),
+    ("46432", "ravage"),
+    ("46433", "raven"),
+    ("46434", "ravine"),
+    ("46435", "raving"),
+    ("46436", "ravioli"),
+    ("46441", "ravishing"),
+    ("46442", "reabsorb"),
+    ("46443", "reach"),
+    ("46444", "reacquire"),
+    ("46445", "reaction"),
+    ("46446", "reactive"),
+    ("46451", "reactor"),
+    ("46452", "reaffirm"),
+    ("46453", "ream"),
+    ("46454", "reanalyze"),
+    ("46455", "reappear"),
+    ("46456", "reapply"),
+    ("46461", "reappoint"),
+    ("46462", "reapprove"),
+    ("46463", "rearrange"),
+    ("46464", "rearview"),
+    ("46465", "reason"),
+    ("46466", "reassign"),
+    ("46511", "reassure"),
+    ("46512", "reattach"),
+    ("46513", "reawake"),
+    ("46514", "rebalance"),
+    ("46515", "rebate"),
+    ("46516", "rebel"),
+    ("46521", "rebirth"),
+    ("46522", "reboot"),
+    ("46523", "reborn"),
+    ("46524", "rebound"),
+    ("46525", "rebuff"),
+    ("46526", "rebuild"),
+    ("46531", "rebuilt"),
+    ("46532", "reburial"),
+    ("46533", "rebuttal"),
+    ("46534", "recall"),
+    ("46535", "recant"),
+    ("46536", "recapture"),
+    ("46541", "recast"),
+    ("46542", "recede"),
+    ("46543", "recent"),
+    ("46544", "recess"),
+    ("46545", "recharger"),
+    ("46546", "recipient"),
+    ("46551", "recital"),
+    ("46552", "recite"),
+    ("46553", "reckless"),
+    ("46554", "reclaim"),
+    ("46555", "recliner"),
+    ("46556", "reclining"),
+    ("46561", "recluse"),
+    ("46562", "reclusive"),
+    ("46563", "recognize"),
+    ("46564", "recoil"),
+    ("46565", "recollect"),
+    ("46566", "recolor"),
+    ("46611", "reconcile"),
+    ("46612", "reconfirm"),
+    ("46613", "reconvene"),
+    ("46614", "recopy"),
+    ("46615", "record"),
+    ("46616", "recount"),
+    ("46621", "recoup"),
+    ("46622", "recovery"),
+    ("46623", "recreate"),
+    ("46624", "rectal"),
+    ("46625", "rectangle"),
+    ("46626", "rectified"),
+    ("46631", "rectify"),
+    ("46632", "recycled"),
+    ("46633", "recycler"),
+    ("46634", "recycling"),
+    ("46635", "reemerge"),
+    ("46636", "reenact"),
+    ("46641", "reenter"),
+    ("46642", "reentry"),
+    ("46643", "reexamine"),
+    ("46644", "referable"),
+    ("46645", "referee"),
+    ("46646", "reference"),
+    ("46651", "refill"),
+    ("46652", "refinance"),
+    ("46653", "refined"),
+    ("46654", "refinery"),
+    ("46655", "refining"),
+    ("46656", "refinish"),
+    ("46661", "reflected"),
+    ("46662", "reflector"),
+    ("46663", "reflex"),
+    ("46664", "reflux"),
+    ("46665", "refocus"),
+    ("46666", "refold"),
+    ("51111", "reforest"),
+    ("51112", "reformat"),
+    ("51113", "reformed"),
+    ("51114", "reformer"),
+    ("51115", "reformist"),
+    ("51116", "refract"),
+    ("51121", "refrain"),
+    ("51122", "refreeze"),
+    ("51123", "refresh"),
+    ("51124", "refried"),
+    ("51125", "refueling"),
+    ("51126", "refund"),
+    ("51131", "refurbish"),
+    ("51132", "refurnish"),
+    ("51133", "refusal"),
+    ("51134", "refuse"),
+    ("51135", "refusing"),
+    ("51136", "refutable"),
+    ("51141", "refute"),
+    ("51142", "regain"),
+    ("51143", "regalia"),
+    ("51144", "regally"),
+    ("51145", "reggae"),
+    ("51146", "regime"),
+    ("51151", "region"),
+    ("51152", "register"),
+    ("51153", "registrar"),
+    ("51154", "registry"),
+    ("51155", "regress"),
+    ("51156", "regretful"),
+    ("51161", "regroup"),
+    ("51162", "regular"),
+    ("51163", "regulate"),
+    ("51164", "regulator"),
+    ("51165", "rehab"),
+    ("51166", "reheat"),
+    ("51211", "rehire"),
+    ("51212", "rehydrate"),
+    ("51213", "reimburse"),
+    ("51214", "reissue"),
+    ("51215", "reiterate"),
+    ("51216", "rejoice"),
+    ("51221", "rejoicing"),
+    ("51222", "rejoin"),
+    ("51223", "rekindle"),
+    ("51224", "relapse"),
+    ("51225", "relapsing"),
+    ("51226", "relatable"),
+    ("51231", "related"),
+    ("51232", "relation"),
+    ("51233", "relative"),
+    ("51234", "relax"),
+    ("51235", "relay"),
+    ("51236", "relearn"),
+    ("51241", "release"),
+    ("51242", "relenting"),
+    ("51243", "reliable"),
+    ("51244", "reliably"),
+    ("51245", "reliance"),
+    ("51246", "reliant"),
+    ("51251", "relic"),
+    ("51252", "relieve"),
+    ("51253", "relieving"),
+    ("51254", "relight"),
+    ("51255", "relish"),
+    ("51256", "relive"),
+    ("51261", "reload"),
+    ("51262", "relocate"),
+    ("51263", "relock"),
+    ("51264", "reluctant"),
+    ("51265", "rely"),
+    ("51266", "remake"),
+    ("51311", "remark"),
+    ("51312", "remarry"),
+    ("51313", "rematch"),
+    ("51314", "remedial"),
+    ("51315", "remedy"),
+    ("51316", "remember"),
+    ("51321", "reminder"),
+    ("51322", "remindful"),
+    ("51323", "remission"),
+    ("51324", "remix"),
+    ("51325", "remnant"),
+    ("51326", "remodeler"),
+    ("51331", "remold"),
+    ("51332", "remorse"),
+    ("51333", "remote"),
+    ("51334", "removable"),
+    ("51335", "removal"),
+    ("51336", "removed"),
+    ("51341", "remover"),
+    ("51342", "removing"),
+    ("51343", "rename"),
+    ("51344", "renderer"),
+    ("51345", "rendering"),
+    ("51346", "rendition"),
+    ("51351", "renegade"),
+    ("51352", "renewable"),
+    ("51353", "renewably"),
+    ("51354", "renewal"),
+    ("51355", "renewed"),
+    ("51356", "renounce"),
+    ("51361", "renovate"),
+    ("51362", "renovator"),
+    ("51363", "rentable"),
+    ("51364", "rental"),
+    ("51365", "rented"),
+    ("51366", "renter"),
+    ("51411", "reoccupy"),
+    ("51412", "reoccur"),
+    ("51413", "reopen"),
+    ("51414", "reorder"),
+    ("51415", "repackage"),
+    ("51416", "repacking"),
+    ("51421", "repaint"),
+    ("51422", "repair"),
+    ("51423", "repave"),
+    ("51424", "repaying"),
+    ("51425", "repayment"),
+    ("51426", "repeal"),
+    ("51431", "repeated"),
+    ("51432", "repeater"),
+    ("51433", "repent"),
+    ("51434", "rephrase"),
+    ("51435", "replace"),
+    ("51436", "replay"),
+    ("51441", "replica"),
+    ("51442", "reply"),
+    ("51443", "reporter"),
+    ("51444", "repose"),
+    ("51445", "repossess"),
+    ("51446", "repost"),
+    ("51451", "repressed"),
+    ("51452", "reprimand"),
+    ("51453", "reprint"),
+    ("51454", "reprise"),
+    ("51455", "reproach"),
+    ("51456", "reprocess"),
+    ("51461", "reproduce"),
+    ("51462", "reprogram"),
+    ("51463", "reps"),
+    ("51464", "reptile"),
+    ("51465", "reptilian"),
+    ("51466", "repugnant"),
+    ("51511", "repulsion"),
+    ("51512", "repulsive"),
+    ("51513", "repurpose"),
+    ("51514", "reputable"),
+    ("51515", "reputably"),
+    ("51516", "request"),
+    ("51521", "require"),
+    ("51522", "requisite"),
+    ("51523", "reroute"),
+    ("51524", "rerun"),
+    ("51525", "resale"),
+    ("51526", "resample"),
+    ("51531", "rescuer"),
+    ("51532", "reseal"),
+    ("51533", "research"),
+    ("51534", "reselect"),
+    ("51535", "reseller"),
+    ("51536", "resemble"),
+    ("51541", "resend"),
+    ("51542", "resent"),
+    ("51543", "reset"),
+    ("51544", "reshape"),
+    ("51545", "reshoot"),
+    ("51546", "reshuffle"),
+    ("51551", "residence"),
+    ("51552", "residency"),
+    ("51553", "resident"),
+    ("51554", "residual"),
+    ("51555", "residue"),
+    ("51556", "resigned"),
+    ("51561", "resilient"),
+    ("51562", "resistant"),
+    ("51563", "resisting"),
+    ("51564", "resize"),
+    ("51565", "resolute"),
+    ("51566", "resolved"),
+    ("51611", "resonant"),
+    ("51612", "resonate"),
+    ("51613", "resort"),
+    ("51614", "resource"),
+    ("51615", "respect"),
+    ("51616", "resubmit"),
+    ("51621", "result"),
+    ("51622", "resume"),
+    ("51623", "resupply"),
+    ("51624", "resurface"),
+    ("51625", "resurrect"),
+    ("51626", "retail"),
+    ("51631", "retainer"),
+    ("51632", "retaining"),
+    ("51633", "retake"),
+    ("51634", "retaliate"),
+    ("51635", "retention"),
+    ("51636", "rethink"),
+    ("51641", "retinal"),
+    ("51642", "retired"),
+    ("51643", "retiree"),
+    ("51644", "retiring"),
+    ("51645", "retold"),
+    ("51646", "retool"),
+    ("51651", "retorted"),
+    ("51652", "retouch"),
+    ("51653", "retrace"),
+    ("51654", "retract"),
+    ("51655", "retrain"),
+    ("51656", "retread"),
+    ("51661", "retreat"),
+    ("51662", "retrial"),
+    ("51663", "retrieval"),
+    ("51664", "retriever"),
+    ("51665", "retry"),
+    ("51666", "return"),
+    ("52111", "retying"),
+    ("52112", "retype"),
+    ("52113", "reunion"),
+    ("52114", "reunite"),
+    ("52115", "reusable"),
+    ("52116", "reuse"),
+    ("52121", "reveal"),
+    ("52122", "reveler"),
+    ("52123", "revenge"),
+    ("52124", "revenue"),
+    ("52125", "reverb"),
+    ("52126", "revered"),
+    ("52131", "reverence"),
+    ("52132", "reverend"),
+    ("52133", "reversal"),
+    ("52134", "reverse"),
+    ("52135", "reversing"),
+    ("52136", "reversion"),
+    ("52141", "revert"),
+    ("52142", "revisable"),
+    ("52143", "revise"),
+    ("52144", "revision"),
+    ("52145", "revisit"),
+    ("52146", "revivable"),
+    ("52151", "revival"),
+    ("52152", "reviver"),
+    ("52153", "reviving"),
+    ("52154", "revocable"),
+    ("52155", "revoke"),
+    ("52156", "revolt"),
+    ("52161", "revolver"),
+    ("52162", "revolving"),
+    ("52163", "reward"),
+    ("52164", "rewash"),
+    ("52165", "rewind"),
+    ("52166", "rewire"),
+    ("52211", "reword"),
+    ("52212", "rework"),
+    ("52213", "rewrap"),
+    ("52214", "rewrite"),
+    ("52215", "rhyme"),
+    ("52216", "ribbon"),
+    ("52221", "ribcage"),
+    ("52222", "rice"),
+    ("52223", "riches"),
+    ("52224", "richly"),
+    ("52225", "richness"),
+    ("52226", "rickety"),
+    ("52231", "ricotta"),
+    ("52232", "riddance"),
+    ("52233", "ridden"),
+    ("52234", "ride"),
+    ("52235", "riding"),
+    ("52236", "rifling"),
+    ("52241", "rift"),
+    ("52242", "rigging"),
+    ("52243", "rigid"),
+    ("52244", "rigor"),
+    ("52245", "rimless"),
+    ("52246", "rimmed"),
+    ("52251", "rind"),
+    ("52252", "rink"),
+    ("52253", "rinse"),
+    ("52254", "rinsing"),
+    ("52255", "riot"),
+    ("52256", "ripcord"),
+    ("52261", "ripeness"),
+    ("52262", "ripening"),
+    ("52263", "ripping"),
+    ("52264", "ripple"),
+    ("52265", "rippling"),
+    ("52266", "riptide"),
+    ("52311", "rise"),
+    ("52312", "rising"),
+    ("52313", "risk"),
+    ("52314", "risotto"),
+    ("52315", "ritalin"),
+    ("52316", "ritzy"),
+    ("52321", "rival"),
+    ("52322", "riverbank"),
+    ("52323", "riverbed"),
+    ("52324", "riverboat"),
+    ("52325", "riverside"),
+    ("52326", "riveter"),
+    ("52331", "riveting"),
+    ("52332", "roamer"),
+    ("52333", "roaming"),
+    ("52334", "roast"),
+    ("52335", "robbing"),
+    ("52336", "robe"),
+    ("52341", "robin"),
+    ("52342", "robotics"),
+    ("52343", "robust"),
+    ("52344", "rockband"),
+    ("52345", "rocker"),
+    ("52346", "rocket"),
+    ("52351", "rockfish"),
+    ("52352", "rockiness"),
+    ("52353", "rocking"),
+    ("52354", "rocklike"),
+    ("52355", "rockslide"),
+    ("52356", "rockstar"),
+    ("52361", "rocky"),
+    ("52362", "rogue"),
+    ("52363", "roman"),
+    ("52364", "romp"),
+    ("52365", "rope"),
+    ("52366", "roping"),
+    ("52411", "roster"),
+    ("52412", "rosy"),
+    ("52413", "rotten"),
+    ("52414", "rotting"),
+    ("52415", "rotunda"),
+    ("52416", "roulette"),
+    ("52421", "rounding"),
+    ("52422", "roundish"),
+    ("52423", "roundness"),
+    ("52424", "roundup"),
+    ("52425", "roundworm"),
+    ("52426", "routine"),
+    ("52431", "routing"),
+    ("52432", "rover"),
+    ("52433", "roving"),
+    ("52434", "royal"),
+    ("52435", "rubbed"),
+    ("52436", "rubber"),
+    ("52441", "rubbing"),
+    ("52442", "rubble"),
+    ("52443", "rubdown"),
+    ("52444", "ruby"),
+    ("52445", "ruckus"),
+    ("52446", "rudder"),
+    ("52451", "rug"),
+    ("52452", "ruined"),
+    ("52453", "rule"),
+    ("52454", "rumble"),
+    ("52455", "rumbling"),
+    ("52456", "rummage"),
+    ("52461", "rumor"),
+    ("52462", "runaround"),
+    ("52463", "rundown"),
+    ("52464", "runner"),
+    ("52465", "running"),
+    ("52466", "runny"),
+    ("52511", "runt"),
+    ("52512", "runway"),
+    ("52513", "rupture"),
+    ("52514", "rural"),
+    ("52515", "ruse"),
+    ("52516", "rush"),
+    ("52521", "rust"),
+    ("52522", "rut"),
+    ("52523", "sabbath"),
+    ("52524", "sabotage"),
+    ("52525", "sacrament"),
+    ("52526", "sacred"),
+    ("52531", "sacrifice"),
+    ("52532", "sadden"),
+    ("52533", "saddlebag"),
+    ("52534", "saddled"),
+    ("52535", "saddling"),
+    ("52536", "sadly"),
+    ("52541", "sadness"),
+    ("52542", "safari"),
+    ("52543", "safeguard"),
+    ("52544", "safehouse"),
+    ("52545", "safely"),
+    ("52546", "safeness"),
+    ("52551", "saffron"),
+    ("52552", "saga"),
+    ("52553", "sage"),
+    ("52554", "sagging"),
+    ("52555", "saggy"),
+    ("52556", "said"),
+    ("52561", "saint"),
+    ("52562", "sake"),
+    ("52563", "salad"),
+    ("52564", "salami"),
+    ("52565", "salaried"),
+    ("52566", "salary"),
+    ("52611", "saline"),
+    ("52612", "salon"),
+    ("52613", "saloon"),
+    ("52614", "salsa"),
+    ("52615", "salt"),
+    ("52616", "salutary"),
+    ("52621", "salute"),
+    ("52622", "salvage"),
+    ("52623", "salvaging"),
+    ("52624", "salvation"),
+    ("52625", "same"),
+    ("52626", "sample"),
+    ("52631", "sampling"),
+    ("52632", "sanction"),
+    ("52633", "sanctity"),
+    ("52634", "sanctuary"),
+    ("52635", "sandal"),
+    ("52636", "sandbag"),
+    ("52641", "sandbank"),
+    ("52642", "sandbar"),
+    ("52643", "sandblast"),
+    ("52644", "sandbox"),
+    ("52645", "sanded"),
+    ("52646", "sandfish"),
+    ("52651", "sanding"),
+    ("52652", "sandlot"),
+    ("52653", "sandpaper"),
+    ("52654", "sandpit"),
+    ("52655", "sandstone"),
+    ("52656", "sandstorm"),
+    ("52661", "sandworm"),
+    ("52662", "sandy"),
+    ("52663", "sanitary"),
+    ("52664", "sanitizer"),
+    ("52665", "sank"),
+    ("52666", "santa"),
+    ("53111", "sapling"),
+    ("53112", "sappiness"),
+    ("53113", "sappy"),
+    ("53114", "sarcasm"),
+    ("53115", "sarcastic"),
+    ("53116", "sardine"),
+    ("53121", "sash"),
+    ("53122", "sasquatch"),
+    ("53123", "sassy"),
+    ("53124", "satchel"),
+    ("53125", "satiable"),
+    ("53126", "satin"),
+    ("53131", "satirical"),
+    ("53132", "satisfied"),
+    ("53133", "satisfy"),
+    ("53134", "saturate"),
+    ("53135", "saturday"),
+    ("53136", "sauciness"),
+    ("53141", "saucy"),
+    ("53142", "sauna"),
+    ("53143", "savage"),
+    ("53144", "savanna"),
+    ("53145", "saved"),
+    ("53146", "savings"),
+    ("53151", "savior"),
+    ("53152", "savor"),
+    ("53153", "saxophone"),
+    ("53154", "say"),
+    ("53155", "scabbed"),
+    ("53156", "scabby"),
+    ("53161", "scalded"),
+    ("53162", "scalding"),
+    ("53163", "scale"),
+    ("53164", "scaling"),
+    ("53165", "scallion"),
+    ("53166", "scallop"),
+    ("53211", "scalping"),
+    ("53212", "scam"),
+    ("53213", "scandal"),
+    ("53214", "scanner"),
+    ("53215", "scanning"),
+    ("53216", "scant"),
+    ("53221", "scapegoat"),
+    ("53222", "scarce"),
+    ("53223", "scarcity"),
+    ("53224", "scarecrow"),
+    ("53225", "scared"),
+    ("53226", "scarf"),
+    ("53231", "scarily"),
+    ("53232", "scariness"),
+    ("53233", "scarring"),
+    ("53234", "scary"),
+    ("53235", "scavenger"),
+    ("53236", "scenic"),
+    ("53241", "schedule"),
+    ("53242", "schematic"),
+    ("53243", "scheme"),
+    ("53244", "scheming"),
+    ("53245", "schilling"),
+    ("53246", "schnapps"),
+    ("53251", "scholar"),
+    ("53252", "science"),
+    ("53253", "scientist"),
+    ("53254", "scion"),
+    ("53255", "scoff"),
+    ("53256", "scolding"),
+    ("53261", "scone"),
+    ("53262", "scoop"),
+    ("53263", "scooter"),
+    ("53264", "scope"),
+    ("53265", "scorch"),
+    ("53266", "scorebook"),
+    ("53311", "scorecard"),
+    ("53312", "scored"),
+    ("53313", "scoreless"),
+    ("53314", "scorer"),
+    ("53315", "scoring"),
+    ("53316", "scorn"),
+    ("53321", "scorpion"),
+    ("53322", "scotch"),
+    ("53323", "scoundrel"),
+    ("53324", "scoured"),
+    ("53325", "scouring"),
+    ("53326", "scouting"),
+    ("53331", "scouts"),
+    ("53332", "scowling"),
+    ("53333", "scrabble"),
+    ("53334", "scraggly"),
+    ("53335", "scrambled"),
+    ("53336", "scrambler"),
+    ("53341", "scrap"),
+    ("53342", "scratch"),
+    ("53343", "scrawny"),
+    ("53344", "screen"),
+    ("53345", "scribble"),
+    ("53346", "scribe"),
+    ("53351", "scribing"),
+    ("53352", "scrimmage"),
+    ("53353", "script"),
+    ("53354", "scroll"),
+    ("53355", "scrooge"),
+    ("53356", "scrounger"),
+    ("53361", "scrubbed"),
+    ("53362", "scrubber"),
+    ("53363", "scruffy"),
+    ("53364", "scrunch"),
+    ("53365", "scrutiny"),
+    ("53366", "scuba"),
+    ("53411", "scuff"),
+    ("53412", "sculptor"),
+    ("53413", "sculpture"),
+    ("53414", "scurvy"),
+    ("53415", "scuttle"),
+    ("53416", "secluded"),
+    ("53421", "secluding"),
+    ("53422", "seclusion"),
+    ("53423", "second"),
+    ("53424", "secrecy"),
+    ("53425", "secret"),
+    ("53426", "sectional"),
+    ("53431", "sector"),
+    ("53432", "secular"),
+    ("53433", "securely"),
+    ("53434", "security"),
+    ("53435", "sedan"),
+    ("53436", "sedate"),
+    ("53441", "sedation"),
+    ("53442", "sedative"),
+    ("53443", "sediment"),
+    ("53444", "seduce"),
+    ("53445", "seducing"),
+    ("53446", "segment"),
+    ("53451", "seismic"),
+    ("53452", "seizing"),
+    ("53453", "seldom"),
+    ("53454", "selected"),
+    ("53455", "selection"),
+    ("53456", "selective"),
+    ("53461", "selector"),
+    ("53462", "self"),
+    ("53463", "seltzer"),
+    ("53464", "semantic"),
+    ("53465", "semester"),
+    ("53466", "semicolon"),
+    ("53511", "semifinal"),
+    ("53512", "seminar"),
+    ("53513", "semisoft"),
+    ("53514", "semisweet"),
+    ("53515", "senate"),
+    ("53516", "senator"),
+    ("53521", "send"),
+    ("53522", "senior"),
+    ("53523", "senorita"),
+    ("53524", "sensation"),
+    ("53525", "sensitive"),
+    ("53526", "sensitize"),
+    ("53531", "sensually"),
+    ("53532", "sensuous"),
+    ("53533", "sepia"),
+    ("53534", "september"),
+    ("53535", "septic"),
+    ("53536", "septum"),
+    ("53541", "sequel"),
+    ("53542", "sequence"),
+    ("53543", "sequester"),
+    ("53544", "series"),
+    ("53545", "sermon"),
+    ("53546", "serotonin"),
+    ("53551", "serpent"),
+    ("53552", "serrated"),
+    ("53553", "serve"),
+    ("53554", "service"),
+    ("53555", "serving"),
+    ("53556", "sesame"),
+    ("53561", "sessions"),
+    ("53562", "setback"),
+    ("53563", "setting"),
+    ("53564", "settle"),
+    ("53565", "settling"),
+    ("53566", "setup"),
+    ("53611", "sevenfold"),
+    ("53612", "seventeen"),
+    ("53613", "seventh"),
+    ("53614", "seventy"),
+    ("53615", "severity"),
+    ("53616", "shabby"),
+    ("53621", "shack"),
+    ("53622", "shaded"),
+    ("53623", "shadily"),
+    ("53624", "shadiness"),
+    ("53625", "shading"),
+    ("53626", "shadow"),
+    ("53631", "shady"),
+    ("53632", "shaft"),
+    ("53633", "shakable"),
+    ("53634", "shakily"),
+    ("53635", "shakiness"),
+    ("53636", "shaking"),
+    ("53641", "shaky"),
+    ("53642", "shale"),
+    ("53643", "shallot"),
+    ("53644", "shallow"),
+    ("53645", "shame"),
+    ("53646", "shampoo"),
+    ("53651", "shamrock"),
+    ("53652", "shank"),
+    ("53653", "shanty"),
+    ("53654", "shape"),
+    ("53655", "shaping"),
+    ("53656", "share"),
+    ("53661", "sharpener"),
+    ("53662", "sharper"),
+    ("53663", "sharpie"),
+    ("53664", "sharply"),
+    ("53665", "sharpness"),
+    ("53666", "shawl"),
+    ("54111", "sheath"),
+    ("54112", "shed"),
+    ("54113", "sheep"),
+    ("54114", "sheet"),
+    ("54115", "shelf"),
+    ("54116", "shell"),
+    ("54121", "shelter"),
+    ("54122", "shelve"),
+    ("54123", "shelving"),
+    ("54124", "sherry"),
+    ("54125", "shield"),
+    ("54126", "shifter"),
+    ("54131", "shifting"),
+    ("54132", "shiftless"),
+    ("54133", "shifty"),
+    ("54134", "shimmer"),
+    ("54135", "shimmy"),
+    ("54136", "shindig"),
+    ("54141", "shine"),
+    ("54142", "shingle"),
+    ("54143", "shininess"),
+    ("54144", "shining"),
+    ("54145", "shiny"),
+    ("54146", "ship"),
+    ("54151", "shirt"),
+    ("54152", "shivering"),
+    ("54153", "shock"),
+    ("54154", "shone"),
+    ("54155", "shoplift"),
+    ("54156", "shopper"),
+    ("54161", "shopping"),
+    ("54162", "shoptalk"),
+    ("54163", "shore"),
+    ("54164", "shortage"),
+    ("54165", "shortcake"),
+    ("54166", "shortcut"),
+    ("54211", "shorten"),
+    ("54212", "shorter"),
+    ("54213", "shorthand"),
+    ("54214", "shortlist"),
+    ("54215", "shortly"),
+    ("54216", "shortness"),
+    ("54221", "shorts"),
+    ("54222", "shortwave"),
+    ("54223", "shorty"),
+    ("54224", "shout"),
+    ("54225", "shove"),
+    ("54226", "showbiz"),
+    ("54231", "showcase"),
+    ("54232", "showdown"),
+    ("54233", "shower"),
+    ("54234", "showgirl"),
+    ("54235", "showing"),
+    ("54236", "showman"),
+    ("54241", "shown"),
+    ("54242", "showoff"),
+    ("54243", "showpiece"),
+    ("54244", "showplace"),
+    ("54245", "showroom"),
+    ("54246", "showy"),
+    ("54251", "shrank"),
+    ("54252", "shrapnel"),
+    ("54253", "shredder"),
+    ("54254", "shredding"),
+    ("54255", "shrewdly"),
+    ("54256", "shriek"),
+    ("54261", "shrill"),
+    ("54262", "shrimp"),
+    ("54263", "shrine"),
+    ("54264", "shrink"),
+    ("54265", "shrivel"),
+    ("54266", "shrouded"),
+    ("54311", "shrubbery"),
+    ("54312", "shrubs"),
+    ("54313", "shrug"),
+    ("54314", "shrunk"),
+    ("54315", "shucking"),
+    ("54316", "shudder"),
+    ("54321", "shuffle"),
+    ("54322", "shuffling"),
+    ("54323", "shun"),
+    ("54324", "shush"),
+    ("54325", "shut"),
+    ("54326", "shy"),
+    ("54331", "siamese"),
+    ("54332", "siberian"),
+    ("54333", "sibling"),
+    ("54334", "siding"),
+    ("54335", "sierra"),
+    ("54336", "siesta"),
+    ("54341", "sift"),
+    ("54342", "sighing"),
+    ("54343", "silenced"),
+    ("54344", "silencer"),
+    ("54345", "silent"),
+    ("54346", "silica"),
+    ("54351", "silicon"),
+    ("54352", "silk"),
+    ("54353", "silliness"),
+    ("54354", "silly"),
+    ("54355", "silo"),
+    ("54356", "silt"),
+    ("54361", "silver"),
+    ("54362", "similarly"),
+    ("54363", "simile"),
+    ("54364", "simmering"),
+    ("54365", "simple"),
+    ("54366", "simplify"),
+    ("54411", "simply"),
+    ("54412", "sincere"),
+    ("54413", "sincerity"),
+    ("54414", "singer"),
+    ("54415", "singing"),
+    ("54416", "single"),
+    ("54421", "singular"),
+    ("54422", "sinister"),
+    ("54423", "sinless"),
+    ("54424", "sinner"),
+    ("54425", "sinuous"),
+    ("54426", "sip"),
+    ("54431", "siren"),
+    ("54432", "sister"),
+    ("54433", "sitcom"),
+    ("54434", "sitter"),
+    ("54435", "sitting"),
+    ("54436", "situated"),
+    ("54441", "situation"),
+    ("54442", "sixfold"),
+    ("54443", "sixteen"),
+    ("54444", "sixth"),
+    ("54445", "sixties"),
+    ("54446", "sixtieth"),
+    ("54451", "sixtyfold"),
+    ("54452", "sizable"),
+    ("54453", "sizably"),
+    ("54454", "size"),
+    ("54455", "sizing"),
+    ("54456", "sizzle"),
+    ("54461", "sizzling"),
+    ("54462", "skater"),
+    ("54463", "skating"),
+    ("54464", "skedaddle"),
+    ("54465", "skeletal"),
+    ("54466", "skeleton"),
+    ("54511", "skeptic"),
+    ("54512", "sketch"),
+    ("54513", "skewed"),
+    ("54514", "skewer"),
+    ("54515", "skid"),
+    ("54516", "skied"),
+    ("54521", "skier"),
+    ("54522", "skies"),
+    ("54523", "skiing"),
+    ("54524", "skilled"),
+    ("54525", "skillet"),
+    ("54526", "skillful"),
+    ("54531", "skimmed"),
+    ("54532", "skimmer"),
+    ("54533", "skimming"),
+    ("54534", "skimpily"),
+    ("54535", "skincare"),
+    ("54536", "skinhead"),
+    ("54541", "skinless"),
+    ("54542", "skinning"),
+    ("54543", "skinny"),
+    ("54544", "skintight"),
+    ("54545", "skipper"),
+    ("54546", "skipping"),
+    ("54551", "skirmish"),
+    ("54552", "skirt"),
+    ("54553", "skittle"),
+    ("54554", "skydiver"),
+    ("54555", "skylight"),
+    ("54556", "skyline"),
+    ("54561", "skype"),
+    ("54562", "skyrocket"),
+    ("54563", "skyward"),
+    ("54564", "slab"),
+    ("54565", "slacked"),
+    ("54566", "slacker"),
+    ("54611", "slacking"),
+    ("54612", "slackness"),
+    ("54613", "slacks"),
+    ("54614", "slain"),
+    ("54615", "slam"),
+    ("54616", "slander"),
+    ("54621", "slang"),
+    ("54622", "slapping"),
+    ("54623", "slapstick"),
+    ("54624", "slashed"),
+    ("54625", "slashing"),
+    ("54626", "slate"),
+    ("54631", "slather"),
+    ("54632", "slaw"),
+    ("54633", "sled"),
+    ("54634", "sleek"),
+    ("54635", "sleep"),
+    ("54636", "sleet"),
+    ("54641", "sleeve"),
+    ("54642", "slept"),
+    ("54643", "sliceable"),
+    ("54644", "sliced"),
+    ("54645", "slicer"),
+    ("54646", "slicing"),
+    ("54651", "slick"),
+    ("54652", "slider"),
+    ("54653", "slideshow"),
+    ("54654", "sliding"),
+    ("54655", "slighted"),
+    ("54656", "slighting"),
+    ("54661", "slightly"),
+    ("54662", "slimness"),
+    ("54663", "slimy"),
+    ("54664", "slinging"),
+    ("54665", "slingshot"),
+    ("54666", "slinky"),
+    ("55111", "slip"),
+    ("55112", "slit"),
+    ("55113", "sliver"),
+    ("55114", "slobbery"),
+    ("55115", "slogan"),
+    ("55116", "sloped"),
+    ("55121", "sloping"),
+    ("55122", "sloppily"),
+    ("55123", "sloppy"),
+    ("55124", "slot"),
+    ("55125", "slouching"),
+    ("55126", "slouchy"),
+    ("55131", "sludge"),
+    ("55132", "slug"),
+    ("55133", "slum"),
+    ("55134", "slurp"),
+    ("55135", "slush"),
+    ("55136", "sly"),
+    ("55141", "small"),
+    ("55142", "smartly"),
+    ("55143", "smartness"),
+    ("55144", "smasher"),
+    ("55145", "smashing"),
+    ("55146", "smashup"),
+    ("55151", "smell"),
+    ("55152", "smelting"),
+    ("55153", "smile"),
+    ("55154", "smilingly"),
+    ("55155", "smirk"),
+    ("55156", "smite"),
+    ("55161", "smith"),
+    ("55162", "smitten"),
+    ("55163", "smock"),
+    ("55164", "smog"),
+    ("55165", "smoked"),
+    ("55166", "smokeless"),
+    ("55211", "smokiness"),
+    ("55212", "smoking"),
+    ("55213", "smoky"),
+    ("55214", "smolder"),
+    ("55215", "smooth"),
+    ("55216", "smother"),
+    ("55221", "smudge"),
+    ("55222", "smudgy"),
+    ("55223", "smuggler"),
+    ("55224", "smuggling"),
+    ("55225", "smugly"),
+    ("55226", "smugness"),
+    ("55231", "snack"),
+    ("55232", "snagged"),
+    ("55233", "snaking"),
+    ("55234", "snap"),
+    ("55235", "snare"),
+    ("55236", "snarl"),
+    ("55241", "snazzy"),
+    ("55242", "sneak"),
+    ("55243", "sneer"),
+    ("55244", "sneeze"),
+    ("55245", "sneezing"),
+    ("55246", "snide"),
+    ("55251", "sniff"),
+    ("55252", "snippet"),
+    ("55253", "snipping"),
+    ("55254", "snitch"),
+    ("55255", "snooper"),
+    ("55256", "snooze"),
+    ("55261", "snore"),
+    ("55262", "snoring"),
+    ("55263", "snorkel"),
+    ("55264", "snort"),
+    ("55265", "snout"),
+    ("55266", "snowbird"),
+    ("55311", "snowboard"),
+    ("55312", "snowbound"),
+    ("55313", "snowcap"),
+    ("55314", "snowdrift"),
+    ("55315", "snowdrop"),
+    ("55316", "snowfall"),
+    ("55321", "snowfield"),
+    ("55322", "snowflake"),
+    ("55323", "snowiness"),
+    ("55324", "snowless"),
+    ("55325", "snowman"),
+    ("55326", "snowplow"),
+    ("55331", "snowshoe"),
+    ("55332", "snowstorm"),
+    ("55333", "snowsuit"),
+    ("55334", "snowy"),
+    ("55335", "snub"),
+    ("55336", "snuff"),
+    ("55341", "snuggle"),
+    ("55342", "snugly"),
+    ("55343", "snugness"),
+    ("55344", "speak"),
+    ("55345", "spearfish"),
+    ("55346", "spearhead"),
+    ("55351", "spearman"),
+    ("55352", "spearmint"),
+    ("55353", "species"),
+    ("55354", "specimen"),
+    ("55355", "specked"),
+    ("55356", "speckled"),
+    ("55361", "specks"),
+    ("55362", "spectacle"),
+    ("55363", "spectator"),
+    ("55364", "spectrum"),
+    ("55365", "speculate"),
+    ("55366", "speech"),
+    ("55411", "speed"),
+    ("55412", "spellbind"),
+    ("55413", "speller"),
+    ("55414", "spelling"),
+    ("55415", "spendable"),
+    ("55416", "spender"),
+    ("55421", "spending"),
+    ("55422", "spent"),
+    ("55423", "spew"),
+    ("55424", "sphere"),
+    ("55425", "spherical"),
+    ("55426", "sphinx"),
+    ("55431", "spider"),
+    ("55432", "spied"),
+    ("55433", "spiffy"),
+    ("55434", "spill"),
+    ("55435", "spilt"),
+    ("55436", "spinach"),
+    ("55441", "spinal"),
+    ("55442", "spindle"),
+    ("55443", "spinner"),
+    ("55444", "spinning"),
+    ("55445", "spinout"),
+    ("55446", "spinster"),
+    ("55451", "spiny"),
+    ("55452", "spiral"),
+    ("55453", "spirited"),
+    ("55454", "spiritism"),
+    ("55455", "spirits"),
+    ("55456", "spiritual"),
+    ("55461", "splashed"),
+    ("55462", "splashing"),
+    ("55463", "splashy"),
+    ("55464", "splatter"),
+    ("55465", "spleen"),
+    ("55466", "splendid"),
+    ("55511", "splendor"),
+    ("55512", "splice"),
+    ("55513", "splicing"),
+    ("55514", "splinter"),
+    ("55515", "splotchy"),
+    ("55516", "splurge"),
+    ("55521", "spoilage"),
+    ("55522", "spoiled"),
+    ("55523", "spoiler"),
+    ("55524", "spoiling"),
+    ("55525", "spoils"),
+    ("55526", "spoken"),
+    ("55531", "spokesman"),
+    ("55532", "sponge"),
+    ("55533", "spongy"),
+    ("55534", "sponsor"),
+    ("55535", "spoof"),
+    ("55536", "spookily"),
+    ("55541", "spooky"),
+    ("55542", "spool"),
+    ("55543", "spoon"),
+    ("55544", "spore"),
+    ("55545", "sporting"),
+    ("55546", "sports"),
+    ("55551", "sporty"),
+    ("55552", "spotless"),
+    ("55553", "spotlight"),
+    ("55554", "spotted"),
+    ("55555", "spotter"),
+    ("55556", "spotting"),
+    ("55561", "spotty"),
+    ("55562", "spousal"),
+    ("55563", "spouse"),
+    ("55564", "spout"),
+    ("55565", "sprain"),
+    ("55566", "sprang"),
+    ("55611", "sprawl"),
+    ("55612", "spray"),
+    ("55613", "spree"),
+    ("55614", "sprig"),
+    ("55615", "spring"),
+    ("55616", "sprinkled"),
+    ("55621", "sprinkler"),
+    ("55622", "sprint"),
+    ("55623", "sprite"),
+    ("55624", "sprout"),
+    ("55625", "spruce"),
+    ("55626", "sprung"),
+    ("55631", "spry"),
+    ("55632", "spud"),
+    ("55633", "spur"),
+    ("55634", "sputter"),
+    ("55635", "spyglass"),
+    ("55636", "squabble"),
+    ("55641", "squad"),
+    ("55642", "squall"),
+    ("55643", "squander"),
+    ("55644", "squash"),
+    ("55645", "squatted"),
+    ("55646", "squatter"),
+    ("55651", "squatting"),
+    ("55652", "squeak"),
+    ("55653", "squealer"),
+    ("55654", "squealing"),
+    ("55655", "squeamish"),
+    ("55656", "squeegee"),
+    ("55661", "squeeze"),
+    ("55662", "squeezing"),
+    ("55663", "squid"),
+    ("55664", "squiggle"),
+    ("55665", "squiggly"),
+    ("55666", "squint"),
+    ("56111", "squire"),
+    ("56112", "squirt"),
+    ("56113", "squishier"),
+    ("56114", "squishy"),
+    ("56115", "stability"),
+    ("56116", "stabilize"),
+    ("56121", "stable"),
+    ("56122", "stack"),
+    ("56123", "stadium"),
+    ("56124", "staff"),
+    ("56125", "stage"),
+    ("56126", "staging"),
+    ("56131", "stagnant"),
+    ("56132", "stagnate"),
+    ("56133", "stainable"),
+    ("56134", "stained"),
+    ("56135", "staining"),
+    ("56136", "stainless"),
+    ("56141", "stalemate"),
+    ("56142", "staleness"),
+    ("56143", "stalling"),
+    ("56144", "stallion"),
+    ("56145", "stamina"),
+    ("56146", "stammer"),
+    ("56151", "stamp"),
+    ("56152", "stand"),
+    ("56153", "stank"),
+    ("56154", "staple"),
+    ("56155", "stapling"),
+    ("56156", "starboard"),
+    ("56161", "starch"),
+    ("56162", "stardom"),
+    ("56163", "stardust"),
+    ("56164", "starfish"),
+    ("56165", "stargazer"),
+    ("56166", "staring"),
+    ("56211", "stark"),
+    ("56212", "starless"),
+    ("56213", "starlet"),
+    ("56214", "starlight"),
+    ("56215", "starlit"),
+    ("56216", "starring"),
+    ("56221", "starry"),
+    ("56222", "starship"),
+    ("56223", "starter"),
+    ("56224", "starting"),
+    ("56225", "startle"),
+    ("56226", "startling"),
+    ("56231", "startup"),
+    ("56232", "starved"),
+    ("56233", "starving"),
+    ("56234", "stash"),
+    ("56235", "state"),
+    ("56236", "static"),
+    ("56241", "statistic"),
+    ("56242", "statue"),
+    ("56243", "stature"),
+    ("56244", "status"),
+    ("56245", "statute"),
+    ("56246", "statutory"),
+    ("56251", "staunch"),
+    ("56252", "stays"),
+    ("56253", "steadfast"),
+    ("56254", "steadier"),
+    ("56255", "steadily"),
+    ("56256", "steadying"),
+    ("56261", "steam"),
+    ("56262", "steed"),
+    ("56263", "steep"),
+    ("56264", "steerable"),
+    ("56265", "steering"),
+    ("56266", "steersman"),
+    ("56311", "stegosaur"),
+    ("56312", "stellar"),
+    ("56313", "stem"),
+    ("56314", "stench"),
+    ("56315", "stencil"),
+    ("56316", "step"),
+    ("56321", "stereo"),
+    ("56322", "sterile"),
+    ("56323", "sterility"),
+    ("56324", "sterilize"),
+    ("56325", "sterling"),
+    ("56326", "sternness"),
+    ("56331", "sternum"),
+    ("56332", "stew"),
+    ("56333", "stick"),
+    ("56334", "stiffen"),
+    ("56335", "stiffly"),
+    ("56336", "stiffness"),
+    ("56341", "stifle"),
+    ("56342", "stifling"),
+    ("56343", "stillness"),
+    ("56344", "stilt"),
+    ("56345", "stimulant"),
+    ("56346", "stimulate"),
+    ("56351", "stimuli"),
+    ("56352", "stimulus"),
+    ("56353", "stinger"),
+    ("56354", "stingily"),
+    ("56355", "stinging"),
+    ("56356", "stingray"),
+    ("56361", "stingy"),
+    ("56362", "stinking"),
+    ("56363", "stinky"),
+    ("56364", "stipend"),
+    ("56365", "stipulate"),
+    ("56366", "stir"),
+    ("56411", "stitch"),
+    ("56412", "stock"),
+    ("56413", "stoic"),
+    ("56414", "stoke"),
+    ("56415", "stole"),
+    ("56416", "stomp"),
+    ("56421", "stonewall"),
+    ("56422", "stoneware"),
+    ("56423", "stonework"),
+    ("56424", "stoning"),
+    ("56425", "stony"),
+    ("56426", "stood"),
+    ("56431", "stooge"),
+    ("56432", "stool"),
+    ("56433", "stoop"),
+    ("56434", "stoplight"),
+    ("56435", "stoppable"),
+    ("56436", "stoppage"),
+    ("56441", "stopped"),
+    ("56442", "stopper"),
+    ("56443", "stopping"),
+    ("56444", "stopwatch"),
+    ("56445", "storable"),
+    ("56446", "storage"),
+    ("56451", "storeroom"),
+    ("56452", "storewide"),
+    ("56453", "storm"),
+    ("56454", "stout"),
+    ("56455", "stove"),
+    ("56456", "stowaway"),
+    ("56461", "stowing"),
+    ("56462", "straddle"),
+    ("56463", "straggler"),
+    ("56464", "strained"),
+    ("56465", "strainer"),
+    ("56466", "straining"),
+    ("56511", "strangely"),
+    ("56512", "stranger"),
+    ("56513", "strangle"),
+    ("56514", "strategic"),
+    ("56515", "strategy"),
+    ("56516", "stratus"),
+    ("56521", "straw"),
+    ("56522", "stray"),
+    ("56523", "streak"),
+    ("56524", "stream"),
+    ("56525", "street"),
+    ("56526", "strength"),
+    ("56531", "strenuous"),
+    ("56532", "strep"),
+    ("56533", "stress"),
+    ("56534", "stretch"),
+    ("56535", "strewn"),
+    ("56536", "stricken"),
+    ("56541", "strict"),
+    ("56542", "stride"),
+    ("56543", "strife"),
+    ("56544", "strike"),
+    ("56545", "striking"),
+    ("56546", "strive"),
+    ("56551", "striving"),
+    ("56552", "strobe"),
+    ("56553", "strode"),
+    ("56554", "stroller"),
+    ("56555", "strongbox"),
+    ("56556", "strongly"),
+    ("56561", "strongman"),
+    ("56562", "struck"),
+    ("56563", "structure"),
+    ("56564", "strudel"),
+    ("56565", "struggle"),
+    ("56566", "strum"),
+    ("56611", "strung"),
+    ("56612", "strut"),
+    ("56613", "stubbed"),
+    ("56614", "stubble"),
+    ("56615", "stubbly"),
+    ("56616", "stubborn"),
+    ("56621", "stucco"),
+    ("56622", "stuck"),
+    ("56623", "student"),
+    ("56624", "studied"),
+    ("56625", "studio"),
+    ("56626", "study"),
+    ("56631", "stuffed"),
+    ("56632", "stuffing"),
+    ("56633", "stuffy"),
+    ("56634", "stumble"),
+    ("56635", "stumbling"),
+    ("56636", "stump"),
+    ("56641", "stung"),
+    ("56642", "stunned"),
+    ("56643", "stunner"),
+    ("56644", "stunning"),
+    ("56645", "stunt"),
+    ("56646", "stupor"),
+    ("56651", "sturdily"),
+    ("56652", "sturdy"),
+    ("56653", "styling"),
+    ("56654", "stylishly"),
+    ("56655", "stylist"),
+    ("56656", "stylized"),
+    ("56661", "stylus"),
+    ("56662", "suave"),
+    ("56663", "subarctic"),
+    ("56664", "subatomic"),
+    ("56665", "subdivide"),
+    ("56666", "subdued"),
+    ("61111", "subduing"),
+    ("61112", "subfloor"),
+    ("61113", "subgroup"),
+    ("61114", "subheader"),
+    ("61115", "subject"),
+    ("61116", "sublease"),
+    ("61121", "sublet"),
+    ("61122", "sublevel"),
+    ("61123", "sublime"),
+    ("61124", "submarine"),
+    ("61125", "submerge"),
+    ("61126", "submersed"),
+    ("61131", "submitter"),
+    ("61132", "subpanel"),
+    ("61133", "subpar"),
+    ("61134", "subplot"),
+    ("61135", "subprime"),
+    ("61136", "subscribe"),
+    ("61141", "subscript"),
+    ("61142", "subsector"),
+    ("61143", "subside"),
+    ("61144", "subsiding"),
+    ("61145", "subsidize"),
+    ("61146", "subsidy"),
+    ("61151", "subsoil"),
+    ("61152", "subsonic"),
+    ("61153", "substance"),
+    ("61154", "subsystem"),
+    ("61155", "subtext"),
+    ("61156", "subtitle"),
+    ("61161", "subtly"),
+    ("61162", "subtotal"),
+    ("61163", "subtract"),
+    ("61164", "subtype"),
+    ("61165", "suburb"),
+    ("61166", "subway"),
+    ("61211", "subwoofer"),
+    ("61212", "subzero"),
+    ("61213", "succulent"),
+    ("61214", "such"),
+    ("61215", "suction"),
+    ("61216", "sudden"),
+    ("61221", "sudoku"),
+    ("61222", "suds"),
+    ("61223", "sufferer"),
+    ("61224", "suffering"),
+    ("61225", "suffice"),
+    ("61226", "suffix"),
+    ("61231", "suffocate"),
+    ("61232", "suffrage"),
+    ("61233", "sugar"),
+    ("61234", "suggest"),
+    ("61235", "suing"),
+    ("61236", "suitable"),
+    ("61241", "suitably"),
+    ("61242", "suitcase"),
+    ("61243", "suitor"),
+    ("61244", "sulfate"),
+    ("61245", "sulfide"),
+    ("61246", "sulfite"),
+    ("61251", "sulfur"),
+    ("61252", "sulk"),
+    ("61253", "sullen"),
+    ("61254", "sulphate"),
+    ("61255", "sulphuric"),
+    ("61256", "sultry"),
+    ("61261", "superbowl"),
+    ("61262", "superglue"),
+    ("61263", "superhero"),
+    ("61264", "superior"),
+    ("61265", "superjet"),
+    ("61266", "superman"),
+    ("61311", "supermom"),
+    ("61312", "supernova"),
+    ("61313", "supervise"),
+    ("61314", "supper"),
+    ("61315", "supplier"),
+    ("61316", "supply"),
+    ("61321", "support"),
+    ("61322", "supremacy"),
+    ("61323", "supreme"),
+    ("61324", "surcharge"),
+    ("61325", "surely"),
+    ("61326", "sureness"),
+    ("61331", "surface"),
+    ("61332", "surfacing"),
+    ("61333", "surfboard"),
+    ("61334", "surfer"),
+    ("61335", "surgery"),
+    ("61336", "surgical"),
+    ("61341", "surging"),
+    ("61342", "surname"),
+    ("61343", "surpass"),
+    ("61344", "surplus"),
+    ("61345", "surprise"),
+    ("61346", "surreal"),
+    ("61351", "surrender"),
+    ("61352", "surrogate"),
+    ("61353", "surround"),
+    ("61354", "survey"),
+    ("61355", "survival"),
+    ("61356", "survive"),
+    ("61361", "surviving"),
+    ("61362", "survivor"),
+    ("61363", "sushi"),
+    ("61364", "suspect"),
+    ("61365", "suspend"),
+    ("61366", "suspense"),
+    ("61411", "sustained"),
+    ("61412", "sustainer"),
+    ("61413", "swab"),
+    ("61414", "swaddling"),
+    ("61415", "swagger"),
+    ("61416", "swampland"),
+    ("61421", "swan"),
+    ("61422", "swapping"),
+    ("61423", "swarm"),
+    ("61424", "sway"),
+    ("61425", "swear"),
+    ("61426", "sweat"),
+    ("61431", "sweep"),
+    ("61432", "swell"),
+    ("61433", "swept"),
+    ("61434", "swerve"),
+    ("61435", "swifter"),
+    ("61436", "swiftly"),
+    ("61441", "swiftness"),
+    ("61442", "swimmable"),
+    ("61443", "swimmer"),
+    ("61444", "swimming"),
+    ("61445", "swimsuit"),
+    ("61446", "swimwear"),
+    ("61451", "swinger"),
+    ("61452", "swinging"),
+    ("61453", "swipe"),
+    ("61454", "swirl"),
+    ("61455", "switch"),
+    ("61456", "swivel"),
+    ("61461", "swizzle"),
+    ("61462", "swooned"),
+    ("61463", "swoop"),
+    ("61464", "swoosh"),
+    ("61465", "swore"),
+    ("61466", "sworn"),
+    ("61511", "swung"),
+    ("61512", "sycamore"),
+    ("61513", "sympathy"),
+    ("61514", "symphonic"),
+    ("61515", "symphony"),
+    ("61516", "symptom"),
+    ("61521", "synapse"),
+    ("61522", "syndrome"),
+    ("61523", "synergy"),
+    ("61524", "synopses"),
+    ("61525", "synopsis"),
+    ("61526", "synthesis"),
+    ("61531", "synthetic"),
+    ("61532", "syrup"),
+    ("61533", "system"),
+    ("61534", "t-shirt"),
+    ("61535", "tabasco"),
+    ("61536", "tabby"),
+    ("61541", "tableful"),
+    ("61542", "tables"),
+    ("61543", "tablet"),
+    ("61544", "tableware"),
+    ("61545", "tabloid"),
+    ("61546", "tackiness"),
+    ("61551", "tacking"),
+    ("61552", "tackle"),
+    ("61553", "tackling"),
+    ("61554", "tacky"),
+    ("61555", "taco"),
+    ("61556", "tactful"),
+    ("61561", "tactical"),
+    ("61562", "tactics"),
+    ("61563", "tactile"),
+    ("61564", "tactless"),
+    ("61565", "tadpole"),
+    ("61566", "taekwondo"),
+    ("61611", "tag"),
+    ("61612", "tainted"),
+    ("61613", "take"),
+    ("61614", "taking"),
+    ("61615", "talcum"),
+    ("61616", "talisman"),
+    ("61621", "tall"),
+    ("61622", "talon"),
+    ("61623", "tamale"),
+    ("61624", "tameness"),
+    ("61625", "tamer"),
+    ("61626", "tamper"),
+    ("61631", "tank"),
+    ("61632", "tanned"),
+    ("61633", "tannery"),
+    ("61634", "tanning"),
+    ("61635", "tantrum"),
+    ("61636", "tapeless"),
+    ("61641", "tapered"),
+    ("61642", "tapering"),
+    ("61643", "tapestry"),
+    ("61644", "tapioca"),
+    ("61645", "tapping"),
+    ("61646", "taps"),
+    ("61651", "tarantula"),
+    ("61652", "target"),
+    ("61653", "tarmac"),
+    ("61654", "tarnish"),
+    ("61655", "tarot"),
+    ("61656", "tartar"),
+    ("61661", "tartly"),
+    ("61662", "tartness"),
+    ("61663", "task"),
+    ("61664", "tassel"),
+    ("61665", "taste"),
+    ("61666", "tastiness"),
+    ("62111", "tasting"),
+    ("62112", "tasty"),
+    ("62113", "tattered"),
+    ("62114", "tattle"),
+    ("62115", "tattling"),
+    ("62116", "tattoo"),
+    ("62121", "taunt"),
+    ("62122", "tavern"),
+    ("62123", "thank"),
+    ("62124", "that"),
+    ("62125", "thaw"),
+    ("62126", "theater"),
+    ("62131", "theatrics"),
+    ("62132", "thee"),
+    ("62133", "theft"),
+    ("62134", "theme"),
+    ("62135", "theology"),
+    ("62136", "theorize"),
+    ("62141", "thermal"),
+    ("62142", "thermos"),
+    ("62143", "thesaurus"),
+    ("62144", "these"),
+    ("62145", "thesis"),
+    ("62146", "thespian"),
+    ("62151", "thicken"),
+    ("62152", "thicket"),
+    ("62153", "thickness"),
+    ("62154", "thieving"),
+    ("62155", "thievish"),
+    ("62156", "thigh"),
+    ("62161", "thimble"),
+    ("62162", "thing"),
+    ("62163", "think"),
+    ("62164", "thinly"),
+    ("62165", "thinner"),
+    ("62166", "thinness"),
+    ("62211", "thinning"),
+    ("62212", "thirstily"),
+    ("62213", "thirsting"),
+    ("62214", "thirsty"),
+    ("62215", "thirteen"),
+    ("62216", "thirty"),
+    ("62221", "thong"),
+    ("62222", "thorn"),
+    ("62223", "those"),
+    ("62224", "thousand"),
+    ("62225", "thrash"),
+    ("62226", "thread"),
+    ("62231", "threaten"),
+    ("62232", "threefold"),
+    ("62233", "thrift"),
+    ("62234", "thrill"),
+    ("62235", "thrive"),
+    ("62236", "thriving"),
+    ("62241", "throat"),
+    ("62242", "throbbing"),
+    ("62243", "throng"),
+    ("62244", "throttle"),
+    ("62245", "throwaway"),
+    ("62246", "throwback"),
+    ("62251", "thrower"),
+    ("62252", "throwing"),
+    ("62253", "thud"),
+    ("62254", "thumb"),
+    ("62255", "thumping"),
+    ("62256", "thursday"),
+    ("62261", "thus"),
+    ("62262", "thwarting"),
+    ("62263", "thyself"),
+    ("62264", "tiara"),
+    ("62265", "tibia"),
+    ("62266", "tidal"),
+    ("62311", "tidbit"),
+    ("62312", "tidiness"),
+    ("62313", "tidings"),
+    ("62314", "tidy"),
+    ("62315", "tiger"),
+    ("62316", "tighten"),
+    ("62321", "tightly"),
+    ("62322", "tightness"),
+    ("62323", "tightrope"),
+    ("62324", "tightwad"),
+    ("62325", "tigress"),
+    ("62326", "tile"),
+    ("62331", "tiling"),
+    ("62332", "till"),
+    ("62333", "tilt"),
+    ("62334", "timid"),
+    ("62335", "timing"),
+    ("62336", "timothy"),
+    ("62341", "tinderbox"),
+    ("62342", "tinfoil"),
+    ("62343", "tingle"),
+    ("62344", "tingling"),
+    ("62345", "tingly"),
+    ("62346", "tinker"),
+    ("62351", "tinkling"),
+    ("62352", "tinsel"),
+    ("62353", "tinsmith"),
+    ("62354", "tint"),
+    ("62355", "tinwork"),
+    ("62356", "tiny"),
+    ("62361", "tipoff"),
+    ("62362", "tipped"),
+    ("62363", "tipper"),
+    ("62364", "tipping"),
+    ("62365", "tiptoeing"),
+    ("62366", "tiptop"),
+    ("62411", "tiring"),
+    ("62412", "tissue"),
+    ("62413", "trace"),
+    ("62414", "tracing"),
+    ("62415", "track"),
+    ("62416", "traction"),
+    ("62421", "tractor"),
+    ("62422", "trade"),
+    ("62423", "trading"),
+    ("62424", "tradition"),
+    ("62425", "traffic"),
+    ("62426", "tragedy"),
+    ("62431", "trailing"),
+    ("62432", "trailside"),
+    ("62433", "train"),
+    ("62434", "traitor"),
+    ("62435", "trance"),
+    ("62436", "tranquil"),
+    ("62441", "transfer"),
+    ("62442", "transform"),
+    ("62443", "translate"),
+    ("62444", "transpire"),
+    ("62445", "transport"),
+    ("62446", "transpose"),
+    ("62451", "trapdoor"),
+    ("62452", "trapeze"),
+    ("62453", "trapezoid"),
+    ("62454", "trapped"),
+    ("62455", "trapper"),
+    ("62456", "trapping"),
+    ("62461", "traps"),
+    ("62462", "trash"),
+    ("62463", "travel"),
+    ("62464", "traverse"),
+    ("62465", "travesty"),
+    ("62466", "tray"),
+    ("62511", "treachery"),
+    ("62512", "treading"),
+    ("62513", "treadmill"),
+    ("62514", "treason"),
+    ("62515", "treat"),
+    ("62516", "treble"),
+    ("62521", "tree"),
+    ("62522", "trekker"),
+    ("62523", "tremble"),
+    ("62524", "trembling"),
+    ("62525", "tremor"),
+    ("62526", "trench"),
+    ("62531", "trend"),
+    ("62532", "trespass"),
+    ("62533", "triage"),
+    ("62534", "trial"),
+    ("62535", "triangle"),
+    ("62536", "tribesman"),
+    ("62541", "tribunal"),
+    ("62542", "tribune"),
+    ("62543", "tributary"),
+    ("62544", "tribute"),
+    ("62545", "triceps"),
+    ("62546", "trickery"),
+    ("62551", "trickily"),
+    ("62552", "tricking"),
+    ("62553", "trickle"),
+    ("62554", "trickster"),
+    ("62555", "tricky"),
+    ("62556", "tricolor"),
+    ("62561", "tricycle"),
+    ("62562", "trident"),
+    ("62563", "tried"),
+    ("62564", "trifle"),
+    ("62565", "trifocals"),
+    ("62566", "trillion"),
+    ("62611", "trilogy"),
+    ("62612", "trimester"),
+    ("62613", "trimmer"),
+    ("62614", "trimming"),
+    ("62615", "trimness"),
+    ("62616", "trinity"),
+    ("62621", "trio"),
+    ("62622", "tripod"),
+    ("62623", "tripping"),
+    ("62624", "triumph"),
+    ("62625", "trivial"),
+    ("62626", "trodden"),
+    ("62631", "trolling"),
+    ("62632", "trombone"),
+    ("62633", "trophy"),
+    ("62634", "tropical"),
+    ("62635", "tropics"),
+    ("62636", "trouble"),
+    ("62641", "troubling"),
+    ("62642", "trough"),
+    ("62643", "trousers"),
+    ("62644", "trout"),
+    ("62645", "trowel"),
+    ("62646", "truce"),
+    ("62651", "truck"),
+    ("62652", "truffle"),
+    ("62653", "trump"),
+    ("62654", "trunks"),
+    ("62655", "trustable"),
+    ("62656", "trustee"),
+    ("62661", "trustful"),
+    ("62662", "trusting"),
+    ("62663", "trustless"),
+    ("62664", "truth"),
+    ("62665", "try"),
+    ("62666", "tubby"),
+    ("63111", "tubeless"),
+    ("63112", "tubular"),
+    ("63113", "tucking"),
+    ("63114", "tuesday"),
+    ("63115", "tug"),
+    ("63116", "tuition"),
+    ("63121", "tulip"),
+    ("63122", "tumble"),
+    ("63123", "tumbling"),
+    ("63124", "tummy"),
+    ("63125", "turban"),
+    ("63126", "turbine"),
+    ("63131", "turbofan"),
+    ("63132", "turbojet"),
+    ("63133", "turbulent"),
+    ("63134", "turf"),
+    ("63135", "turkey"),
+    ("63136", "turmoil"),
+    ("63141", "turret"),
+    ("63142", "turtle"),
+    ("63143", "tusk"),
+    ("63144", "tutor"),
+    ("63145", "tutu"),
+    ("63146", "tux"),
+    ("63151", "tweak"),
+    ("63152", "tweed"),
+    ("63153", "tweet"),
+    ("63154", "tweezers"),
+    ("63155", "twelve"),
+    ("63156", "twentieth"),
+    ("63161", "twenty"),
+    ("63162", "twerp"),
+    ("63163", "twice"),
+    ("63164", "twiddle"),
+    ("63165", "twiddling"),
+    ("63166", "twig"),
+    ("63211", "twilight"),
+    ("63212", "twine"),
+    ("63213", "twins"),
+    ("63214", "twirl"),
+    ("63215", "twistable"),
+    ("63216", "twisted"),
+    ("63221", "twister"),
+    ("63222", "twisting"),
+    ("63223", "twisty"),
+    ("63224", "twitch"),
+    ("63225", "twitter"),
+    ("63226", "tycoon"),
+    ("63231", "tying"),
+    ("63232", "tyke"),
+    ("63233", "udder"),
+    ("63234", "ultimate"),
+    ("63235", "ultimatum"),
+    ("63236", "ultra"),
+    ("63241", "umbilical"),
+    ("63242", "umbrella"),
+    ("63243", "umpire"),
+    ("63244", "unabashed"),
+    ("63245", "unable"),
+    ("63246", "unadorned"),
+    ("63251", "unadvised"),
+    ("63252", "unafraid"),
+    ("63253", "unaired"),
+    ("63254", "unaligned"),
+    ("63255", "unaltered"),
+    ("63256", "unarmored"),
+    ("63261", "unashamed"),
+    ("63262", "unaudited"),
+    ("63263", "unawake"),
+    ("63264", "unaware"),
+    ("63265", "unbaked"),
+    ("63266", "unbalance"),
+    ("63311", "unbeaten"),
+    ("63312", "unbend"),
+    ("63313", "unbent"),
+    ("63314", "unbiased"),
+    ("63315", "unbitten"),
+    ("63316", "unblended"),
+    ("63321", "unblessed"),
+    ("63322", "unblock"),
+    ("63323", "unbolted"),
+    ("63324", "unbounded"),
+    ("63325", "unboxed"),
+    ("63326", "unbraided"),
+    ("63331", "unbridle"),
+    ("63332", "unbroken"),
+    ("63333", "unbuckled"),
+    ("63334", "unbundle"),
+    ("63335", "unburned"),
+    ("63336", "unbutton"),
+    ("63341", "uncanny"),
+    ("63342", "uncapped"),
+    ("63343", "uncaring"),
+    ("63344", "uncertain"),
+    ("63345", "unchain"),
+    ("63346", "unchanged"),
+    ("63351", "uncharted"),
+    ("63352", "uncheck"),
+    ("63353", "uncivil"),
+    ("63354", "unclad"),
+    ("63355", "unclaimed"),
+    ("63356", "unclamped"),
+    ("63361", "unclasp"),
+    ("63362", "uncle"),
+    ("63363", "unclip"),
+    ("63364", "uncloak"),
+    ("63365", "unclog"),
+    ("63366", "unclothed"),
+    ("63411", "uncoated"),
+    ("63412", "uncoiled"),
+    ("63413", "uncolored"),
+    ("63414", "uncombed"),
+    ("63415", "uncommon"),
+    ("63416", "uncooked"),
+    ("63421", "uncork"),
+    ("63422", "uncorrupt"),
+    ("63423", "uncounted"),
+    ("63424", "uncouple"),
+    ("63425", "uncouth"),
+    ("63426", "uncover"),
+    ("63431", "uncross"),
+    ("63432", "uncrown"),
+    ("63433", "uncrushed"),
+    ("63434", "uncured"),
+    ("63435", "uncurious"),
+    ("63436", "uncurled"),
+    ("63441", "uncut"),
+    ("63442", "undamaged"),
+    ("63443", "undated"),
+    ("63444", "undaunted"),
+    ("63445", "undead"),
+    ("63446", "undecided"),
+    ("63451", "undefined"),
+    ("63452", "underage"),
+    ("63453", "underarm"),
+    ("63454", "undercoat"),
+    ("63455", "undercook"),
+    ("63456", "undercut"),
+    ("63461", "underdog"),
+    ("63462", "underdone"),
+    ("63463", "underfed"),
+    ("63464", "underfeed"),
+    ("63465", "underfoot"),
+    ("63466", "undergo"),
+    ("63511", "undergrad"),
+    ("63512", "underhand"),
+    ("63513", "underline"),
+    ("63514", "underling"),
+    ("63515", "undermine"),
+    ("63516", "undermost"),
+    ("63521", "underpaid"),
+    ("63522", "underpass"),
+    ("63523", "underpay"),
+    ("63524", "underrate"),
+    ("63525", "undertake"),
+    ("63526", "undertone"),
+    ("63531", "undertook"),
+    ("63532", "undertow"),
+    ("63533", "underuse"),
+    ("63534", "underwear"),
+    ("63535", "underwent"),
+    ("63536", "underwire"),
+    ("63541", "undesired"),
+    ("63542", "undiluted"),
+    ("63543", "undivided"),
+    ("63544", "undocked"),
+    ("63545", "undoing"),
+    ("63546", "undone"),
+    ("63551", "undrafted"),
+    ("63552", "undress"),
+    ("63553", "undrilled"),
+    ("63554", "undusted"),
+    ("63555", "undying"),
+    ("63556", "unearned"),
+    ("63561", "unearth"),
+    ("63562", "unease"),
+    ("63563", "uneasily"),
+    ("63564", "uneasy"),
+    ("63565", "uneatable"),
+    ("63566", "uneaten"),
+    ("63611", "unedited"),
+    ("63612", "unelected"),
+    ("63613", "unending"),
+    ("63614", "unengaged"),
+    ("63615", "unenvied"),
+    ("63616", "unequal"),
+    ("63621", "unethical"),
+    ("63622", "uneven"),
+    ("63623", "unexpired"),
+    ("63624", "unexposed"),
+    ("63625", "unfailing"),
+    ("63626", "unfair"),
+    ("63631", "unfasten"),
+    ("63632", "unfazed"),
+    ("63633", "unfeeling"),
+    ("63634", "unfiled"),
+    ("63635", "unfilled"),
+    ("63636", "unfitted"),
+    ("63641", "unfitting"),
+    ("63642", "unfixable"),
+    ("63643", "unfixed"),
+    ("63644", "unflawed"),
+    ("63645", "unfocused"),
+    ("63646", "unfold"),
+    ("63651", "unfounded"),
+    ("63652", "unframed"),
+    ("63653", "unfreeze"),
+    ("63654", "unfrosted"),
+    ("63655", "unfrozen"),
+    ("63656", "unfunded"),
+    ("63661", "unglazed"),
+    ("63662", "ungloved"),
+    ("63663", "unglue"),
+    ("63664", "ungodly"),
+    ("63665", "ungraded"),
+    ("63666", "ungreased"),
+    ("64111", "unguarded"),
+    ("64112", "unguided"),
+    ("64113", "unhappily"),
+    ("64114", "unhappy"),
+    ("64115", "unharmed"),
+    ("64116", "unhealthy"),
+    ("64121", "unheard"),
+    ("64122", "unhearing"),
+    ("64123", "unheated"),
+    ("64124", "unhelpful"),
+    ("64125", "unhidden"),
+    ("64126", "unhinge"),
+    ("64131", "unhitched"),
+    ("64132", "unholy"),
+    ("64133", "unhook"),
+    ("64134", "unicorn"),
+    ("64135", "unicycle"),
+    ("64136", "unified"),
+    ("64141", "unifier"),
+    ("64142", "uniformed"),
+    ("64143", "uniformly"),
+    ("64144", "unify"),
+    ("64145", "unimpeded"),
+    ("64146", "uninjured"),
+    ("64151", "uninstall"),
+    ("64152", "uninsured"),
+    ("64153", "uninvited"),
+    ("64154", "union"),
+    ("64155", "uniquely"),
+    ("64156", "unisexual"),
+    ("64161", "unison"),
+    ("64162", "unissued"),
+    ("64163", "unit"),
+    ("64164", "universal"),
+    ("64165", "universe"),
+    ("64166", "unjustly"),
+    ("64211", "unkempt"),
+    ("64212", "unkind"),
+    ("64213", "unknotted"),
+    ("64214", "unknowing"),
+    ("64215", "unknown"),
+    ("64216", "unlaced"),
+    ("64221", "unlatch"),
+    ("64222", "unlawful"),
+    ("64223", "unleaded"),
+    ("64224", "unlearned"),
+    ("64225", "unleash"),
+    ("64226", "unless"),
+    ("64231", "unleveled"),
+    ("64232", "unlighted"),
+    ("64233", "unlikable"),
+    ("64234", "unlimited"),
+    ("64235", "unlined"),
+    ("64236", "unlinked"),
+    ("64241", "unlisted"),
+    ("64242", "unlit"),
+    ("64243", "unlivable"),
+    ("64244", "unloaded"),
+    ("64245", "unloader"),
+    ("64246", "unlocked"),
+    ("64251", "unlocking"),
+    ("64252", "unlovable"),
+    ("64253", "unloved"),
+    ("64254", "unlovely"),
+    ("64255", "unloving"),
+    ("64256", "unluckily"),
+    ("64261", "unlucky"),
+    ("64262", "unmade"),
+    ("64263", "unmanaged"),
+    ("64264", "unmanned"),
+    ("64265", "unmapped"),
+    ("64266", "unmarked"),
+    ("64311", "unmasked"),
+    ("64312", "unmasking"),
+    ("64313", "unmatched"),
+    ("64314", "unmindful"),
+    ("64315", "unmixable"),
+    ("64316", "unmixed"),
+    ("64321", "unmolded"),
+    ("64322", "unmoral"),
+    ("64323", "unmovable"),
+    ("64324", "unmoved"),
+    ("64325", "unmoving"),
+    ("64326", "unnamable"),
+    ("64331", "unnamed"),
+    ("64332", "unnatural"),
+    ("64333", "unneeded"),
+    ("64334", "unnerve"),
+    ("64335", "unnerving"),
+    ("64336", "unnoticed"),
+    ("64341", "unopened"),
+    ("64342", "unopposed"),
+    ("64343", "unpack"),
+    ("64344", "unpadded"),
+    ("64345", "unpaid"),
+    ("64346", "unpainted"),
+    ("64351", "unpaired"),
+    ("64352", "unpaved"),
+    ("64353", "unpeeled"),
+    ("64354", "unpicked"),
+    ("64355", "unpiloted"),
+    ("64356", "unpinned"),
+    ("64361", "unplanned"),
+    ("64362", "unplanted"),
+    ("64363", "unpleased"),
+    ("64364", "unpledged"),
+    ("64365", "unplowed"),
+    ("64366", "unplug"),
+    ("64411", "unpopular"),
+    ("64412", "unproven"),
+    ("64413", "unquote"),
+    ("64414", "unranked"),
+    ("64415", "unrated"),
+    ("64416", "unraveled"),
+    ("64421", "unreached"),
+    ("64422", "unread"),
+    ("64423", "unreal"),
+    ("64424", "unreeling"),
+    ("64425", "unrefined"),
+    ("64426", "unrelated"),
+    ("64431", "unrented"),
+    ("64432", "unrest"),
+    ("64433", "unretired"),
+    ("64434", "unrevised"),
+    ("64435", "unrigged"),
+    ("64436", "unripe"),
+    ("64441", "unrivaled"),
+    ("64442", "unroasted"),
+    ("64443", "unrobed"),
+    ("64444", "unroll"),
+    ("64445", "unruffled"),
+    ("64446", "unruly"),
+    ("64451", "unrushed"),
+    ("64452", "unsaddle"),
+    ("64453", "unsafe"),
+    ("64454", "unsaid"),
+    ("64455", "unsalted"),
+    ("64456", "unsaved"),
+    ("64461", "unsavory"),
+    ("64462", "unscathed"),
+    ("64463", "unscented"),
+    ("64464", "unscrew"),
+    ("64465", "unsealed"),
+    ("64466", "unseated"),
+    ("64511", "unsecured"),
+    ("64512", "unseeing"),
+    ("64513", "unseemly"),
+    ("64514", "unseen"),
+    ("64515", "unselect"),
+    ("64516", "unselfish"),
+    ("64521", "unsent"),
+    ("64522", "unsettled"),
+    ("64523", "unshackle"),
+    ("64524", "unshaken"),
+    ("64525", "unshaved"),
+    ("64526", "unshaven"),
+    ("64531", "unsheathe"),
+    ("64532", "unshipped"),
+    ("64533", "unsightly"),
+    ("64534", "unsigned"),
+    ("64535", "unskilled"),
+    ("64536", "unsliced"),
+    ("64541", "unsmooth"),
+    ("64542", "unsnap"),
+    ("64543", "unsocial"),
+    ("64544", "unsoiled"),
+    ("64545", "unsold"),
+    ("64546", "unsolved"),
+    ("64551", "unsorted"),
+    ("64552", "unspoiled"),
+    ("64553", "unspoken"),
+    ("64554", "unstable"),
+    ("64555", "unstaffed"),
+    ("64556", "unstamped"),
+    ("64561", "unsteady"),
+    ("64562", "unsterile"),
+    ("64563", "unstirred"),
+    ("64564", "unstitch"),
+    ("64565", "unstopped"),
+    ("64566", "unstuck"),
+    ("64611", "unstuffed"),
+    ("64612", "unstylish"),
+    ("64613", "unsubtle"),
+    ("64614", "unsubtly"),
+    ("64615", "unsuited"),
+    ("64616", "unsure"),
+    ("64621", "unsworn"),
+    ("64622", "untagged"),
+    ("64623", "untainted"),
+    ("64624", "untaken"),
+    ("64625", "untamed"),
+    ("64626", "untangled"),
+    ("64631", "untapped"),
+    ("64632", "untaxed"),
+    ("64633", "unthawed"),
+    ("64634", "unthread"),
+    ("64635", "untidy"),
+    ("64636", "untie"),
+    ("64641", "until"),
+    ("64642", "untimed"),
+    ("64643", "untimely"),
+    ("64644", "untitled"),
+    ("64645", "untoasted"),
+    ("64646", "untold"),
+    ("64651", "untouched"),
+    ("64652", "untracked"),
+    ("64653", "untrained"),
+    ("64654", "untreated"),
+    ("64655", "untried"),
+    ("64656", "untrimmed"),
+    ("64661", "untrue"),
+    ("64662", "untruth"),
+    ("64663", "unturned"),
+    ("64664", "untwist"),
+    ("64665", "untying"),
+    ("64666", "unusable"),
+    ("65111", "unused"),
+    ("65112", "unusual"),
+    ("65113", "unvalued"),
+    ("65114", "unvaried"),
+    ("65115", "unvarying"),
+    ("65116", "unveiled"),
+    ("65121", "unveiling"),
+    ("65122", "unvented"),
+    ("65123", "unviable"),
+    ("65124", "unvisited"),
+    ("65125", "unvocal"),
+    ("65126", "unwanted"),
+    ("65131", "unwarlike"),
+    ("65132", "unwary"),
+    ("65133", "unwashed"),
+    ("65134", "unwatched"),
+    ("65135", "unweave"),
+    ("65136", "unwed"),
+    ("65141", "unwelcome"),
+    ("65142", "unwell"),
+    ("65143", "unwieldy"),
+    ("65144", "unwilling"),
+    ("65145", "unwind"),
+    ("65146", "unwired"),
+    ("65151", "unwitting"),
+    ("65152", "unwomanly"),
+    ("65153", "unworldly"),
+    ("65154", "unworn"),
+    ("65155", "unworried"),
+    ("65156", "unworthy"),
+    ("65161", "unwound"),
+    ("65162", "unwoven"),
+    ("65163", "unwrapped"),
+    ("65164", "unwritten"),
+    ("65165", "unzip"),
+    ("65166", "upbeat"),
+    ("65211", "upchuck"),
+    ("65212", "upcoming"),
+    ("65213", "upcountry"),
+    ("65214", "update"),
+    ("65215", "upfront"),
+    ("65216", "upgrade"),
+    ("65221", "upheaval"),
+    ("65222", "upheld"),
+    ("65223", "uphill"),
+    ("65224", "uphold"),
+    ("65225", "uplifted"),
+    ("65226", "uplifting"),
+    ("65231", "upload"),
+    ("65232", "upon"),
+    ("65233", "upper"),
+    ("65234", "upright"),
+    ("65235", "uprising"),
+    ("65236", "upriver"),
+    ("65241", "uproar"),
+    ("65242", "uproot"),
+    ("65243", "upscale"),
+    ("65244", "upside"),
+    ("65245", "upstage"),
+    ("65246", "upstairs"),
+    ("65251", "upstart"),
+    ("65252", "upstate"),
+    ("65253", "upstream"),
+    ("65254", "upstroke"),
+    ("65255", "upswing"),
+    ("65256", "uptake"),
+    ("65261", "uptight"),
+    ("65262", "uptown"),
+    ("65263", "upturned"),
+    ("65264", "upward"),
+    ("65265", "upwind"),
+    ("65266", "uranium"),
+    ("65311", "urban"),
+    ("65312", "urchin"),
+    ("65313", "urethane"),
+    ("65314", "urgency"),
+    ("65315", "urgent"),
+    ("65316", "urging"),
+    ("65321", "urologist"),
+    ("65322", "urology"),
+    ("65323", "usable"),
+    ("65324", "usage"),
+    ("65325", "useable"),
+    ("65326", "used"),
+    ("65331", "uselessly"),
+    ("65332", "user"),
+    ("65333", "usher"),
+    ("65334", "usual"),
+    ("65335", "utensil"),
+    ("65336", "utility"),
+    ("65341", "utilize"),
+    ("65342", "utmost"),
+    ("65343", "utopia"),
+    ("65344", "utter"),
+    ("65345", "vacancy"),
+    ("65346", "vacant"),
+    ("65351", "vacate"),
+    ("65352", "vacation"),
+    ("65353", "vagabond"),
+    ("65354", "vagrancy"),
+    ("65355", "vagrantly"),
+    ("65356", "vaguely"),
+    ("65361", "vagueness"),
+    ("65362", "valiant"),
+    ("65363", "valid"),
+    ("65364", "valium"),
+    ("65365", "valley"),
+    ("65366", "valuables"),
+    ("65411", "value"),
+    ("65412", "vanilla"),
+    ("65413", "vanish"),
+    ("65414", "vanity"),
+    ("65415", "vanquish"),
+    ("65416", "vantage"),
+    ("65421", "vaporizer"),
+    ("65422", "variable"),
+    ("65423", "variably"),
+    ("65424", "varied"),
+    ("65425", "variety"),
+    ("65426", "various"),
+    ("65431", "varmint"),
+    ("65432", "varnish"),
+    ("65433", "varsity"),
+    ("65434", "varying"),
+    ("65435", "vascular"),
+    ("65436", "vaseline"),
+    ("65441", "vastly"),
+    ("65442", "vastness"),
+    ("65443", "veal"),
+    ("65444", "vegan"),
+    ("65445", "veggie"),
+    ("65446", "vehicular"),
+    ("65451", "velcro"),
+    ("65452", "velocity"),
+    ("65453", "velvet"),
+    ("65454", "vendetta"),
+    ("65455", "vending"),
+    ("65456", "vendor"),
+    ("65461", "veneering"),
+    ("65462", "vengeful"),
+    ("65463", "venomous"),
+    ("65464", "ventricle"),
+    ("65465", "venture"),
+    ("65466", "venue"),
+    ("65511", "venus"),
+    ("65512", "verbalize"),
+    ("65513", "verbally"),
+    ("65514", "verbose"),
+    ("65515", "verdict"),
+    ("65516", "verify"),
+    ("65521", "verse"),
+    ("65522", "version"),
+    ("65523", "versus"),
+    ("65524", "vertebrae"),
+    ("65525", "vertical"),
+    ("65526", "vertigo"),
+    ("65531", "very"),
+    ("65532", "vessel"),
+    ("65533", "vest"),
+    ("65534", "veteran"),
+    ("65535", "veto"),
+    ("65536", "vexingly"),
+    ("65541", "viability"),
+    ("65542", "viable"),
+    ("65543", "vibes"),
+    ("65544", "vice"),
+    ("65545", "vicinity"),
+    ("65546", "victory"),
+    ("65551", "video"),
+    ("65552", "viewable"),
+    ("65553", "viewer"),
+    ("65554", "viewing"),
+    ("65555", "viewless"),
+    ("65556", "viewpoint"),
+    ("65561", "vigorous"),
+    ("65562", "village"),
+    ("65563", "villain"),
+    ("65564", "vindicate"),
+    ("65565", "vineyard"),
+    ("65566", "vintage"),
+    ("65611", "violate"),
+    ("65612", "violation"),
+    ("65613", "violator"),
+    ("65614", "violet"),
+    ("65615", "violin"),
+    ("65616", "viper"),
+    ("65621", "viral"),
+    ("65622", "virtual"),
+    ("65623", "virtuous"),
+    ("65624", "virus"),
+    ("65625", "visa"),
+    ("65626", "viscosity"),
+    ("65631", "viscous"),
+    ("65632", "viselike"),
+    ("65633", "visible"),
+    ("65634", "visibly"),
+    ("65635", "vision"),
+    ("65636", "visiting"),
+    ("65641", "visitor"),
+    ("65642", "visor"),
+    ("65643", "vista"),
+    ("65644", "vitality"),
+    ("65645", "vitalize"),
+    ("65646", "vitally"),
+    ("65651", "vitamins"),
+    ("65652", "vivacious"),
+    ("65653", "vividly"),
+    ("65654", "vividness"),
+    ("65655", "vixen"),
+    ("65656", "vocalist"),
+    ("65661", "vocalize"),
+    ("65662", "vocally"),
+    ("65663", "vocation"),
+    ("65664", "voice"),
+    ("65665", "voicing"),
+    ("65666", "void"),
+    ("66111", "volatile"),
+    ("66112", "volley"),
+    ("66113", "voltage"),
+    ("66114", "volumes"),
+    ("66115", "voter"),
+    ("66116", "voting"),
+    ("66121", "voucher"),
+    ("66122", "vowed"),
+    ("66123", "vowel"),
+    ("66124", "voyage"),
+    ("66125", "wackiness"),
+    ("66126", "wad"),
+    ("66131", "wafer"),
+    ("66132", "waffle"),
+    ("66133", "waged"),
+    ("66134", "wager"),
+    ("66135", "wages"),
+    ("66136", "waggle"),
+    ("66141", "wagon"),
+    ("66142", "wake"),
+    ("66143", "waking"),
+    ("66144", "walk"),
+    ("66145", "walmart"),
+    ("66146", "walnut"),
+    ("66151", "walrus"),
+    ("66152", "waltz"),
+    ("66153", "wand"),
+    ("66154", "wannabe"),
+    ("66155", "wanted"),
+    ("66156", "wanting"),
+    ("66161", "wasabi"),
+    ("66162", "washable"),
+    ("66163", "washbasin"),
+    ("66164", "washboard"),
+    ("66165", "washbowl"),
+    ("66166", "washcloth"),
+    ("66211", "washday"),
+    ("66212", "washed"),
+    ("66213", "washer"),
+    ("66214", "washhouse"),
+    ("66215", "washing"),
+    ("66216", "washout"),
+    ("66221", "washroom"),
+    ("66222", "washstand"),
+    ("66223", "washtub"),
+    ("66224", "wasp"),
+    ("66225", "wasting"),
+    ("66226", "watch"),
+    ("66231", "water"),
+    ("66232", "waviness"),
+    ("66233", "waving"),
+    ("66234", "wavy"),
+    ("66235", "whacking"),
+    ("66236", "whacky"),
+    ("66241", "wham"),
+    ("66242", "wharf"),
+    ("66243", "wheat"),
+    ("66244", "whenever"),
+    ("66245", "whiff"),
+    ("66246", "whimsical"),
+    ("66251", "whinny"),
+    ("66252", "whiny"),
+    ("66253", "whisking"),
+    ("66254", "whoever"),
+    ("66255", "whole"),
+    ("66256", "whomever"),
+    ("66261", "whoopee"),
+    ("66262", "whooping"),
+    ("66263", "whoops"),
+    ("66264", "why"),
+    ("66265", "wick"),
+    ("66266", "widely"),
+    ("66311", "widen"),
+    ("66312", "widget"),
+    ("66313", "widow"),
+    ("66314", "width"),
+    ("66315", "wieldable"),
+    ("66316", "wielder"),
+    ("66321", "wife"),
+    ("66322", "wifi"),
+    ("66323", "wikipedia"),
+    ("66324", "wildcard"),
+    ("66325", "wildcat"),
+    ("66326", "wilder"),
+    ("66331", "wildfire"),
+    ("66332", "wildfowl"),
+    ("66333", "wildland"),
+    ("66334", "wildlife"),
+    ("66335", "wildly"),
+    ("66336", "wildness"),
+    ("66341", "willed"),
+    ("66342", "willfully"),
+    ("66343", "willing"),
+    ("66344", "willow"),
+    ("66345", "willpower"),
+    ("66346", "wilt"),
+    ("66351", "wimp"),
+    ("66352", "wince"),
+    ("66353", "wincing"),
+    ("66354", "wind"),
+    ("66355", "wing"),
+    ("66356", "winking"),
+    ("66361", "winner"),
+    ("66362", "winnings"),
+    ("66363", "winter"),
+    ("66364", "wipe"),
+    ("66365", "wired"),
+    ("66366", "wireless"),
+    ("66411", "wiring"),
+    ("66412", "wiry"),
+    ("66413", "wisdom"),
+    ("66414", "wise"),
+    ("66415", "wish"),
+    ("66416", "wisplike"),
+    ("66421", "wispy"),
+    ("66422", "wistful"),
+    ("66423", "wizard"),
+    ("66424", "wobble"),
+    ("66425", "wobbling"),
+    ("66426", "wobbly"),
+    ("66431", "wok"),
+    ("66432", "wolf"),
+    ("66433", "wolverine"),
+    ("66434", "womanhood"),
+    ("66435", "womankind"),
+    ("66436", "womanless"),
+    ("66441", "womanlike"),
+    ("66442", "womanly"),
+    ("66443", "womb"),
+    ("66444", "woof"),
+    ("66445", "wooing"),
+    ("66446", "wool"),
+    ("66451", "woozy"),
+    ("66452", "word"),
+    ("66453", "work"),
+    ("66454", "worried"),
+    ("66455", "worrier"),
+    ("66456", "worrisome"),
+    ("66461", "worry"),
+    ("66462", "worsening"),
+    ("66463", "worshiper"),
+    ("66464", "worst"),
+    ("66465", "wound"),
+    ("66466", "woven"),
+    ("66511", "wow"),
+    ("66512", "wrangle"),
+    ("66513", "wrath"),
+    ("66514", "wreath"),
+    ("66515", "wreckage"),
+    ("66516", "wrecker"),
+    ("66521", "wrecking"),
+    ("66522", "wrench"),
+    ("66523", "wriggle"),
+    ("66524", "wriggly"),
+    ("66525", "wrinkle"),
+    ("66526", "wrinkly"),
+    ("66531", "wrist"),
+    ("66532", "writing"),
+    ("66533", "written"),
+    ("66534", "wrongdoer"),
+    ("66535", "wronged"),
+    ("66536", "wrongful"),
+    ("66541", "wrongly"),
+    ("66542", "wrongness"),
+    ("66543", "wrought"),
+    ("66544", "xbox"),
+    ("66545", "xerox"),
+    ("66546", "yahoo"),
+    ("66551", "yam"),
+    ("66552", "yanking"),
+    ("66553", "yapping"),
+    ("66554", "yard"),
+    ("66555", "yarn"),
+    ("66556", "yeah"),
+    ("66561", "yearbook"),
+    ("66562", "yearling"),
+    ("66563", "yearly"),
+    ("66564", "yearning"),
+    ("66565", "yeast"),
+    ("66566", "yelling"),
+    ("66611", "yelp"),
+    ("66612", "yen"),
+    ("66613", "yesterday"),
+    ("66614", "yiddish"),
+    ("66615", "yield"),
+    ("66616", "yin"),
+    ("66621", "yippee"),
+    ("66622", "yo-yo"),
+    ("66623", "yodel"),
+    ("66624", "yoga"),
+    ("66625", "yogurt"),
+    ("66626", "yonder"),
+    ("66631", "yoyo"),
+    ("66632", "yummy"),
+    ("66633", "zap"),
+    ("66634", "zealous"),
+    ("66635", "zebra"),
+    ("66636", "zen"),
+    ("66641", "zeppelin"),
+    ("66642", "zero"),
+    ("66643", "zestfully"),
+    ("66644", "zesty"),
+    ("66645", "zigzagged"),
+    ("66646", "zipfile"),
+    ("66651", "zipping"),
+    ("66652", "zippy"),
+    ("66653", "zips"),
+    ("66654", "zit"),
+    ("66655", "zodiac"),
+    ("66656", "zombie"),
+    ("66661", "zone"),
+    ("66662", "zoning"),
+    ("66663", "zookeeper"),
+    ("66664", "zoologist"),
+    ("66665", "zoology"),
+    ("66666", "zoom"),
+];
+
+#[cfg(feature = "std")]
 lazy_static! {
     ///
     /// The `EffLong` word list represented as a HashMap.
     ///
-    pub static ref WORD_LIST: HashMap<&'static str, &'static str> = {
-        let mut m = HashMap::with_capacity(7776);
-        m.insert("11111", "abacus");
-        m.insert("11112", "abdomen");
-        m.insert("11113", "abdominal");
-        m.insert("11114", "abide");
-        m.insert("11115", "abiding");
-        m.insert("11116", "ability");
-        m.insert("11121", "ablaze");
-        m.insert("11122", "able");
-        m.insert("11123", "abnormal");
-        m.insert("11124", "abrasion");
-        m.insert("11125", "abrasive");
-        m.insert("11126", "abreast");
-        m.insert("11131", "abridge");
-        m.insert("11132", "abroad");
-        m.insert("11133", "abruptly");
-        m.insert("11134", "absence");
-        m.insert("11135", "absentee");
-        m.insert("11136", "absently");
-        m.insert("11141", "absinthe");
-        m.insert("11142", "absolute");
-        m.insert("11143", "absolve");
-        m.insert("11144", "abstain");
-        m.insert("11145", "abstract");
-        m.insert("11146", "absurd");
-        m.insert("11151", "accent");
-        m.insert("11152", "acclaim");
-        m.insert("11153", "acclimate");
-        m.insert("11154", "accompany");
-        m.insert("11155", "account");
-        m.insert("11156", "accuracy");
-        m.insert("11161", "accurate");
-        m.insert("11162", "accustom");
-        m.insert("11163", "acetone");
-        m.insert("11164", "achiness");
-        m.insert("11165", "aching");
-        m.insert("11166", "acid");
-        m.insert("11211", "acorn");
-        m.insert("11212", "acquaint");
-        m.insert("11213", "acquire");
-        m.insert("11214", "acre");
-        m.insert("11215", "acrobat");
-        m.insert("11216", "acronym");
-        m.insert("11221", "acting");
-        m.insert("11222", "action");
-        m.insert("11223", "activate");
-        m.insert("11224", "activator");
-        m.insert("11225", "active");
-        m.insert("11226", "activism");
-        m.insert("11231", "activist");
-        m.insert("11232", "activity");
-        m.insert("11233", "actress");
-        m.insert("11234", "acts");
-        m.insert("11235", "acutely");
-        m.insert("11236", "acuteness");
-        m.insert("11241", "aeration");
-        m.insert("11242", "aerobics");
-        m.insert("11243", "aerosol");
-        m.insert("11244", "aerospace");
-        m.insert("11245", "afar");
-        m.insert("11246", "affair");
-        m.insert("11251", "affected");
-        m.insert("11252", "affecting");
-        m.insert("11253", "affection");
-        m.insert("11254", "affidavit");
-        m.insert("11255", "affiliate");
-        m.insert("11256", "affirm");
-        m.insert("11261", "affix");
-        m.insert("11262", "afflicted");
-        m.insert("11263", "affluent");
-        m.insert("11264", "afford");
-        m.insert("11265", "affront");
-        m.insert("11266", "aflame");
-        m.insert("11311", "afloat");
-        m.insert("11312", "aflutter");
-        m.insert("11313", "afoot");
-        m.insert("11314", "afraid");
-        m.insert("11315", "afterglow");
-        m.insert("11316", "afterlife");
-        m.insert("11321", "aftermath");
-        m.insert("11322", "aftermost");
-        m.insert("11323", "afternoon");
-        m.insert("11324", "aged");
-        m.insert("11325", "ageless");
-        m.insert("11326", "agency");
-        m.insert("11331", "agenda");
-        m.insert("11332", "agent");
-        m.insert("11333", "aggregate");
-        m.insert("11334", "aghast");
-        m.insert("11335", "agile");
-        m.insert("11336", "agility");
-        m.insert("11341", "aging");
-        m.insert("11342", "agnostic");
-        m.insert("11343", "agonize");
-        m.insert("11344", "agonizing");
-        m.insert("11345", "agony");
-        m.insert("11346", "agreeable");
-        m.insert("11351", "agreeably");
-        m.insert("11352", "agreed");
-        m.insert("11353", "agreeing");
-        m.insert("11354", "agreement");
-        m.insert("11355", "aground");
-        m.insert("11356", "ahead");
-        m.insert("11361", "ahoy");
-        m.insert("11362", "aide");
-        m.insert("11363", "aids");
-        m.insert("11364", "aim");
-        m.insert("11365", "ajar");
-        m.insert("11366", "alabaster");
-        m.insert("11411", "alarm");
-        m.insert("11412", "albatross");
-        m.insert("11413", "album");
-        m.insert("11414", "alfalfa");
-        m.insert("11415", "algebra");
-        m.insert("11416", "algorithm");
-        m.insert("11421", "alias");
-        m.insert("11422", "alibi");
-        m.insert("11423", "alienable");
-        m.insert("11424", "alienate");
-        m.insert("11425", "aliens");
-        m.insert("11426", "alike");
-        m.insert("11431", "alive");
-        m.insert("11432", "alkaline");
-        m.insert("11433", "alkalize");
-        m.insert("11434", "almanac");
-        m.insert("11435", "almighty");
-        m.insert("11436", "almost");
-        m.insert("11441", "aloe");
-        m.insert("11442", "aloft");
-        m.insert("11443", "aloha");
-        m.insert("11444", "alone");
-        m.insert("11445", "alongside");
-        m.insert("11446", "aloof");
-        m.insert("11451", "alphabet");
-        m.insert("11452", "alright");
-        m.insert("11453", "although");
-        m.insert("11454", "altitude");
-        m.insert("11455", "alto");
-        m.insert("11456", "aluminum");
-        m.insert("11461", "alumni");
-        m.insert("11462", "always");
-        m.insert("11463", "amaretto");
-        m.insert("11464", "amaze");
-        m.insert("11465", "amazingly");
-        m.insert("11466", "amber");
-        m.insert("11511", "ambiance");
-        m.insert("11512", "ambiguity");
-        m.insert("11513", "ambiguous");
-        m.insert("11514", "ambition");
-        m.insert("11515", "ambitious");
-        m.insert("11516", "ambulance");
-        m.insert("11521", "ambush");
-        m.insert("11522", "amendable");
-        m.insert("11523", "amendment");
-        m.insert("11524", "amends");
-        m.insert("11525", "amenity");
-        m.insert("11526", "amiable");
-        m.insert("11531", "amicably");
-        m.insert("11532", "amid");
-        m.insert("11533", "amigo");
-        m.insert("11534", "amino");
-        m.insert("11535", "amiss");
-        m.insert("11536", "ammonia");
-        m.insert("11541", "ammonium");
-        m.insert("11542", "amnesty");
-        m.insert("11543", "amniotic");
-        m.insert("11544", "among");
-        m.insert("11545", "amount");
-        m.insert("11546", "amperage");
-        m.insert("11551", "ample");
-        m.insert("11552", "amplifier");
-        m.insert("11553", "amplify");
-        m.insert("11554", "amply");
-        m.insert("11555", "amuck");
-        m.insert("11556", "amulet");
-        m.insert("11561", "amusable");
-        m.insert("11562", "amused");
-        m.insert("11563", "amusement");
-        m.insert("11564", "amuser");
-        m.insert("11565", "amusing");
-        m.insert("11566", "anaconda");
-        m.insert("11611", "anaerobic");
-        m.insert("11612", "anagram");
-        m.insert("11613", "anatomist");
-        m.insert("11614", "anatomy");
-        m.insert("11615", "anchor");
-        m.insert("11616", "anchovy");
-        m.insert("11621", "ancient");
-        m.insert("11622", "android");
-        m.insert("11623", "anemia");
-        m.insert("11624", "anemic");
-        m.insert("11625", "aneurism");
-        m.insert("11626", "anew");
-        m.insert("11631", "angelfish");
-        m.insert("11632", "angelic");
-        m.insert("11633", "anger");
-        m.insert("11634", "angled");
-        m.insert("11635", "angler");
-        m.insert("11636", "angles");
-        m.insert("11641", "angling");
-        m.insert("11642", "angrily");
-        m.insert("11643", "angriness");
-        m.insert("11644", "anguished");
-        m.insert("11645", "angular");
-        m.insert("11646", "animal");
-        m.insert("11651", "animate");
-        m.insert("11652", "animating");
-        m.insert("11653", "animation");
-        m.insert("11654", "animator");
-        m.insert("11655", "anime");
-        m.insert("11656", "animosity");
-        m.insert("11661", "ankle");
-        m.insert("11662", "annex");
-        m.insert("11663", "annotate");
-        m.insert("11664", "announcer");
-        m.insert("11665", "annoying");
-        m.insert("11666", "annually");
-        m.insert("12111", "annuity");
-        m.insert("12112", "anointer");
-        m.insert("12113", "another");
-        m.insert("12114", "answering");
-        m.insert("12115", "antacid");
-        m.insert("12116", "antarctic");
-        m.insert("12121", "anteater");
-        m.insert("12122", "antelope");
-        m.insert("12123", "antennae");
-        m.insert("12124", "anthem");
-        m.insert("12125", "anthill");
-        m.insert("12126", "anthology");
-        m.insert("12131", "antibody");
-        m.insert("12132", "antics");
-        m.insert("12133", "antidote");
-        m.insert("12134", "antihero");
-        m.insert("12135", "antiquely");
-        m.insert("12136", "antiques");
-        m.insert("12141", "antiquity");
-        m.insert("12142", "antirust");
-        m.insert("12143", "antitoxic");
-        m.insert("12144", "antitrust");
-        m.insert("12145", "antiviral");
-        m.insert("12146", "antivirus");
-        m.insert("12151", "antler");
-        m.insert("12152", "antonym");
-        m.insert("12153", "antsy");
-        m.insert("12154", "anvil");
-        m.insert("12155", "anybody");
-        m.insert("12156", "anyhow");
-        m.insert("12161", "anymore");
-        m.insert("12162", "anyone");
-        m.insert("12163", "anyplace");
-        m.insert("12164", "anything");
-        m.insert("12165", "anytime");
-        m.insert("12166", "anyway");
-        m.insert("12211", "anywhere");
-        m.insert("12212", "aorta");
-        m.insert("12213", "apache");
-        m.insert("12214", "apostle");
-        m.insert("12215", "appealing");
-        m.insert("12216", "appear");
-        m.insert("12221", "appease");
-        m.insert("12222", "appeasing");
-        m.insert("12223", "appendage");
-        m.insert("12224", "appendix");
-        m.insert("12225", "appetite");
-        m.insert("12226", "appetizer");
-        m.insert("12231", "applaud");
-        m.insert("12232", "applause");
-        m.insert("12233", "apple");
-        m.insert("12234", "appliance");
-        m.insert("12235", "applicant");
-        m.insert("12236", "applied");
-        m.insert("12241", "apply");
-        m.insert("12242", "appointee");
-        m.insert("12243", "appraisal");
-        m.insert("12244", "appraiser");
-        m.insert("12245", "apprehend");
-        m.insert("12246", "approach");
-        m.insert("12251", "approval");
-        m.insert("12252", "approve");
-        m.insert("12253", "apricot");
-        m.insert("12254", "april");
-        m.insert("12255", "apron");
-        m.insert("12256", "aptitude");
-        m.insert("12261", "aptly");
-        m.insert("12262", "aqua");
-        m.insert("12263", "aqueduct");
-        m.insert("12264", "arbitrary");
-        m.insert("12265", "arbitrate");
-        m.insert("12266", "ardently");
-        m.insert("12311", "area");
-        m.insert("12312", "arena");
-        m.insert("12313", "arguable");
-        m.insert("12314", "arguably");
-        m.insert("12315", "argue");
-        m.insert("12316", "arise");
-        m.insert("12321", "armadillo");
-        m.insert("12322", "armband");
-        m.insert("12323", "armchair");
-        m.insert("12324", "armed");
-        m.insert("12325", "armful");
-        m.insert("12326", "armhole");
-        m.insert("12331", "arming");
-        m.insert("12332", "armless");
-        m.insert("12333", "armoire");
-        m.insert("12334", "armored");
-        m.insert("12335", "armory");
-        m.insert("12336", "armrest");
-        m.insert("12341", "army");
-        m.insert("12342", "aroma");
-        m.insert("12343", "arose");
-        m.insert("12344", "around");
-        m.insert("12345", "arousal");
-        m.insert("12346", "arrange");
-        m.insert("12351", "array");
-        m.insert("12352", "arrest");
-        m.insert("12353", "arrival");
-        m.insert("12354", "arrive");
-        m.insert("12355", "arrogance");
-        m.insert("12356", "arrogant");
-        m.insert("12361", "arson");
-        m.insert("12362", "art");
-        m.insert("12363", "ascend");
-        m.insert("12364", "ascension");
-        m.insert("12365", "ascent");
-        m.insert("12366", "ascertain");
-        m.insert("12411", "ashamed");
-        m.insert("12412", "ashen");
-        m.insert("12413", "ashes");
-        m.insert("12414", "ashy");
-        m.insert("12415", "aside");
-        m.insert("12416", "askew");
-        m.insert("12421", "asleep");
-        m.insert("12422", "asparagus");
-        m.insert("12423", "aspect");
-        m.insert("12424", "aspirate");
-        m.insert("12425", "aspire");
-        m.insert("12426", "aspirin");
-        m.insert("12431", "astonish");
-        m.insert("12432", "astound");
-        m.insert("12433", "astride");
-        m.insert("12434", "astrology");
-        m.insert("12435", "astronaut");
-        m.insert("12436", "astronomy");
-        m.insert("12441", "astute");
-        m.insert("12442", "atlantic");
-        m.insert("12443", "atlas");
-        m.insert("12444", "atom");
-        m.insert("12445", "atonable");
-        m.insert("12446", "atop");
-        m.insert("12451", "atrium");
-        m.insert("12452", "atrocious");
-        m.insert("12453", "atrophy");
-        m.insert("12454", "attach");
-        m.insert("12455", "attain");
-        m.insert("12456", "attempt");
-        m.insert("12461", "attendant");
-        m.insert("12462", "attendee");
-        m.insert("12463", "attention");
-        m.insert("12464", "attentive");
-        m.insert("12465", "attest");
-        m.insert("12466", "attic");
-        m.insert("12511", "attire");
-        m.insert("12512", "attitude");
-        m.insert("12513", "attractor");
-        m.insert("12514", "attribute");
-        m.insert("12515", "atypical");
-        m.insert("12516", "auction");
-        m.insert("12521", "audacious");
-        m.insert("12522", "audacity");
-        m.insert("12523", "audible");
-        m.insert("12524", "audibly");
-        m.insert("12525", "audience");
-        m.insert("12526", "audio");
-        m.insert("12531", "audition");
-        m.insert("12532", "augmented");
-        m.insert("12533", "august");
-        m.insert("12534", "authentic");
-        m.insert("12535", "author");
-        m.insert("12536", "autism");
-        m.insert("12541", "autistic");
-        m.insert("12542", "autograph");
-        m.insert("12543", "automaker");
-        m.insert("12544", "automated");
-        m.insert("12545", "automatic");
-        m.insert("12546", "autopilot");
-        m.insert("12551", "available");
-        m.insert("12552", "avalanche");
-        m.insert("12553", "avatar");
-        m.insert("12554", "avenge");
-        m.insert("12555", "avenging");
-        m.insert("12556", "avenue");
-        m.insert("12561", "average");
-        m.insert("12562", "aversion");
-        m.insert("12563", "avert");
-        m.insert("12564", "aviation");
-        m.insert("12565", "aviator");
-        m.insert("12566", "avid");
-        m.insert("12611", "avoid");
-        m.insert("12612", "await");
-        m.insert("12613", "awaken");
-        m.insert("12614", "award");
-        m.insert("12615", "aware");
-        m.insert("12616", "awhile");
-        m.insert("12621", "awkward");
-        m.insert("12622", "awning");
-        m.insert("12623", "awoke");
-        m.insert("12624", "awry");
-        m.insert("12625", "axis");
-        m.insert("12626", "babble");
-        m.insert("12631", "babbling");
-        m.insert("12632", "babied");
-        m.insert("12633", "baboon");
-        m.insert("12634", "backache");
-        m.insert("12635", "backboard");
-        m.insert("12636", "backboned");
-        m.insert("12641", "backdrop");
-        m.insert("12642", "backed");
-        m.insert("12643", "backer");
-        m.insert("12644", "backfield");
-        m.insert("12645", "backfire");
-        m.insert("12646", "backhand");
-        m.insert("12651", "backing");
-        m.insert("12652", "backlands");
-        m.insert("12653", "backlash");
-        m.insert("12654", "backless");
-        m.insert("12655", "backlight");
-        m.insert("12656", "backlit");
-        m.insert("12661", "backlog");
-        m.insert("12662", "backpack");
-        m.insert("12663", "backpedal");
-        m.insert("12664", "backrest");
-        m.insert("12665", "backroom");
-        m.insert("12666", "backshift");
-        m.insert("13111", "backside");
-        m.insert("13112", "backslid");
-        m.insert("13113", "backspace");
-        m.insert("13114", "backspin");
-        m.insert("13115", "backstab");
-        m.insert("13116", "backstage");
-        m.insert("13121", "backtalk");
-        m.insert("13122", "backtrack");
-        m.insert("13123", "backup");
-        m.insert("13124", "backward");
-        m.insert("13125", "backwash");
-        m.insert("13126", "backwater");
-        m.insert("13131", "backyard");
-        m.insert("13132", "bacon");
-        m.insert("13133", "bacteria");
-        m.insert("13134", "bacterium");
-        m.insert("13135", "badass");
-        m.insert("13136", "badge");
-        m.insert("13141", "badland");
-        m.insert("13142", "badly");
-        m.insert("13143", "badness");
-        m.insert("13144", "baffle");
-        m.insert("13145", "baffling");
-        m.insert("13146", "bagel");
-        m.insert("13151", "bagful");
-        m.insert("13152", "baggage");
-        m.insert("13153", "bagged");
-        m.insert("13154", "baggie");
-        m.insert("13155", "bagginess");
-        m.insert("13156", "bagging");
-        m.insert("13161", "baggy");
-        m.insert("13162", "bagpipe");
-        m.insert("13163", "baguette");
-        m.insert("13164", "baked");
-        m.insert("13165", "bakery");
-        m.insert("13166", "bakeshop");
-        m.insert("13211", "baking");
-        m.insert("13212", "balance");
-        m.insert("13213", "balancing");
-        m.insert("13214", "balcony");
-        m.insert("13215", "balmy");
-        m.insert("13216", "balsamic");
-        m.insert("13221", "bamboo");
-        m.insert("13222", "banana");
-        m.insert("13223", "banish");
-        m.insert("13224", "banister");
-        m.insert("13225", "banjo");
-        m.insert("13226", "bankable");
-        m.insert("13231", "bankbook");
-        m.insert("13232", "banked");
-        m.insert("13233", "banker");
-        m.insert("13234", "banking");
-        m.insert("13235", "banknote");
-        m.insert("13236", "bankroll");
-        m.insert("13241", "banner");
-        m.insert("13242", "bannister");
-        m.insert("13243", "banshee");
-        m.insert("13244", "banter");
-        m.insert("13245", "barbecue");
-        m.insert("13246", "barbed");
-        m.insert("13251", "barbell");
-        m.insert("13252", "barber");
-        m.insert("13253", "barcode");
-        m.insert("13254", "barge");
-        m.insert("13255", "bargraph");
-        m.insert("13256", "barista");
-        m.insert("13261", "baritone");
-        m.insert("13262", "barley");
-        m.insert("13263", "barmaid");
-        m.insert("13264", "barman");
-        m.insert("13265", "barn");
-        m.insert("13266", "barometer");
-        m.insert("13311", "barrack");
-        m.insert("13312", "barracuda");
-        m.insert("13313", "barrel");
-        m.insert("13314", "barrette");
-        m.insert("13315", "barricade");
-        m.insert("13316", "barrier");
-        m.insert("13321", "barstool");
-        m.insert("13322", "bartender");
-        m.insert("13323", "barterer");
-        m.insert("13324", "bash");
-        m.insert("13325", "basically");
-        m.insert("13326", "basics");
-        m.insert("13331", "basil");
-        m.insert("13332", "basin");
-        m.insert("13333", "basis");
-        m.insert("13334", "basket");
-        m.insert("13335", "batboy");
-        m.insert("13336", "batch");
-        m.insert("13341", "bath");
-        m.insert("13342", "baton");
-        m.insert("13343", "bats");
-        m.insert("13344", "battalion");
-        m.insert("13345", "battered");
-        m.insert("13346", "battering");
-        m.insert("13351", "battery");
-        m.insert("13352", "batting");
-        m.insert("13353", "battle");
-        m.insert("13354", "bauble");
-        m.insert("13355", "bazooka");
-        m.insert("13356", "blabber");
-        m.insert("13361", "bladder");
-        m.insert("13362", "blade");
-        m.insert("13363", "blah");
-        m.insert("13364", "blame");
-        m.insert("13365", "blaming");
-        m.insert("13366", "blanching");
-        m.insert("13411", "blandness");
-        m.insert("13412", "blank");
-        m.insert("13413", "blaspheme");
-        m.insert("13414", "blasphemy");
-        m.insert("13415", "blast");
-        m.insert("13416", "blatancy");
-        m.insert("13421", "blatantly");
-        m.insert("13422", "blazer");
-        m.insert("13423", "blazing");
-        m.insert("13424", "bleach");
-        m.insert("13425", "bleak");
-        m.insert("13426", "bleep");
-        m.insert("13431", "blemish");
-        m.insert("13432", "blend");
-        m.insert("13433", "bless");
-        m.insert("13434", "blighted");
-        m.insert("13435", "blimp");
-        m.insert("13436", "bling");
-        m.insert("13441", "blinked");
-        m.insert("13442", "blinker");
-        m.insert("13443", "blinking");
-        m.insert("13444", "blinks");
-        m.insert("13445", "blip");
-        m.insert("13446", "blissful");
-        m.insert("13451", "blitz");
-        m.insert("13452", "blizzard");
-        m.insert("13453", "bloated");
-        m.insert("13454", "bloating");
-        m.insert("13455", "blob");
-        m.insert("13456", "blog");
-        m.insert("13461", "bloomers");
-        m.insert("13462", "blooming");
-        m.insert("13463", "blooper");
-        m.insert("13464", "blot");
-        m.insert("13465", "blouse");
-        m.insert("13466", "blubber");
-        m.insert("13511", "bluff");
-        m.insert("13512", "bluish");
-        m.insert("13513", "blunderer");
-        m.insert("13514", "blunt");
-        m.insert("13515", "blurb");
-        m.insert("13516", "blurred");
-        m.insert("13521", "blurry");
-        m.insert("13522", "blurt");
-        m.insert("13523", "blush");
-        m.insert("13524", "blustery");
-        m.insert("13525", "boaster");
-        m.insert("13526", "boastful");
-        m.insert("13531", "boasting");
-        m.insert("13532", "boat");
-        m.insert("13533", "bobbed");
-        m.insert("13534", "bobbing");
-        m.insert("13535", "bobble");
-        m.insert("13536", "bobcat");
-        m.insert("13541", "bobsled");
-        m.insert("13542", "bobtail");
-        m.insert("13543", "bodacious");
-        m.insert("13544", "body");
-        m.insert("13545", "bogged");
-        m.insert("13546", "boggle");
-        m.insert("13551", "bogus");
-        m.insert("13552", "boil");
-        m.insert("13553", "bok");
-        m.insert("13554", "bolster");
-        m.insert("13555", "bolt");
-        m.insert("13556", "bonanza");
-        m.insert("13561", "bonded");
-        m.insert("13562", "bonding");
-        m.insert("13563", "bondless");
-        m.insert("13564", "boned");
-        m.insert("13565", "bonehead");
-        m.insert("13566", "boneless");
-        m.insert("13611", "bonelike");
-        m.insert("13612", "boney");
-        m.insert("13613", "bonfire");
-        m.insert("13614", "bonnet");
-        m.insert("13615", "bonsai");
-        m.insert("13616", "bonus");
-        m.insert("13621", "bony");
-        m.insert("13622", "boogeyman");
-        m.insert("13623", "boogieman");
-        m.insert("13624", "book");
-        m.insert("13625", "boondocks");
-        m.insert("13626", "booted");
-        m.insert("13631", "booth");
-        m.insert("13632", "bootie");
-        m.insert("13633", "booting");
-        m.insert("13634", "bootlace");
-        m.insert("13635", "bootleg");
-        m.insert("13636", "boots");
-        m.insert("13641", "boozy");
-        m.insert("13642", "borax");
-        m.insert("13643", "boring");
-        m.insert("13644", "borough");
-        m.insert("13645", "borrower");
-        m.insert("13646", "borrowing");
-        m.insert("13651", "boss");
-        m.insert("13652", "botanical");
-        m.insert("13653", "botanist");
-        m.insert("13654", "botany");
-        m.insert("13655", "botch");
-        m.insert("13656", "both");
-        m.insert("13661", "bottle");
-        m.insert("13662", "bottling");
-        m.insert("13663", "bottom");
-        m.insert("13664", "bounce");
-        m.insert("13665", "bouncing");
-        m.insert("13666", "bouncy");
-        m.insert("14111", "bounding");
-        m.insert("14112", "boundless");
-        m.insert("14113", "bountiful");
-        m.insert("14114", "bovine");
-        m.insert("14115", "boxcar");
-        m.insert("14116", "boxer");
-        m.insert("14121", "boxing");
-        m.insert("14122", "boxlike");
-        m.insert("14123", "boxy");
-        m.insert("14124", "breach");
-        m.insert("14125", "breath");
-        m.insert("14126", "breeches");
-        m.insert("14131", "breeching");
-        m.insert("14132", "breeder");
-        m.insert("14133", "breeding");
-        m.insert("14134", "breeze");
-        m.insert("14135", "breezy");
-        m.insert("14136", "brethren");
-        m.insert("14141", "brewery");
-        m.insert("14142", "brewing");
-        m.insert("14143", "briar");
-        m.insert("14144", "bribe");
-        m.insert("14145", "brick");
-        m.insert("14146", "bride");
-        m.insert("14151", "bridged");
-        m.insert("14152", "brigade");
-        m.insert("14153", "bright");
-        m.insert("14154", "brilliant");
-        m.insert("14155", "brim");
-        m.insert("14156", "bring");
-        m.insert("14161", "brink");
-        m.insert("14162", "brisket");
-        m.insert("14163", "briskly");
-        m.insert("14164", "briskness");
-        m.insert("14165", "bristle");
-        m.insert("14166", "brittle");
-        m.insert("14211", "broadband");
-        m.insert("14212", "broadcast");
-        m.insert("14213", "broaden");
-        m.insert("14214", "broadly");
-        m.insert("14215", "broadness");
-        m.insert("14216", "broadside");
-        m.insert("14221", "broadways");
-        m.insert("14222", "broiler");
-        m.insert("14223", "broiling");
-        m.insert("14224", "broken");
-        m.insert("14225", "broker");
-        m.insert("14226", "bronchial");
-        m.insert("14231", "bronco");
-        m.insert("14232", "bronze");
-        m.insert("14233", "bronzing");
-        m.insert("14234", "brook");
-        m.insert("14235", "broom");
-        m.insert("14236", "brought");
-        m.insert("14241", "browbeat");
-        m.insert("14242", "brownnose");
-        m.insert("14243", "browse");
-        m.insert("14244", "browsing");
-        m.insert("14245", "bruising");
-        m.insert("14246", "brunch");
-        m.insert("14251", "brunette");
-        m.insert("14252", "brunt");
-        m.insert("14253", "brush");
-        m.insert("14254", "brussels");
-        m.insert("14255", "brute");
-        m.insert("14256", "brutishly");
-        m.insert("14261", "bubble");
-        m.insert("14262", "bubbling");
-        m.insert("14263", "bubbly");
-        m.insert("14264", "buccaneer");
-        m.insert("14265", "bucked");
-        m.insert("14266", "bucket");
-        m.insert("14311", "buckle");
-        m.insert("14312", "buckshot");
-        m.insert("14313", "buckskin");
-        m.insert("14314", "bucktooth");
-        m.insert("14315", "buckwheat");
-        m.insert("14316", "buddhism");
-        m.insert("14321", "buddhist");
-        m.insert("14322", "budding");
-        m.insert("14323", "buddy");
-        m.insert("14324", "budget");
-        m.insert("14325", "buffalo");
-        m.insert("14326", "buffed");
-        m.insert("14331", "buffer");
-        m.insert("14332", "buffing");
-        m.insert("14333", "buffoon");
-        m.insert("14334", "buggy");
-        m.insert("14335", "bulb");
-        m.insert("14336", "bulge");
-        m.insert("14341", "bulginess");
-        m.insert("14342", "bulgur");
-        m.insert("14343", "bulk");
-        m.insert("14344", "bulldog");
-        m.insert("14345", "bulldozer");
-        m.insert("14346", "bullfight");
-        m.insert("14351", "bullfrog");
-        m.insert("14352", "bullhorn");
-        m.insert("14353", "bullion");
-        m.insert("14354", "bullish");
-        m.insert("14355", "bullpen");
-        m.insert("14356", "bullring");
-        m.insert("14361", "bullseye");
-        m.insert("14362", "bullwhip");
-        m.insert("14363", "bully");
-        m.insert("14364", "bunch");
-        m.insert("14365", "bundle");
-        m.insert("14366", "bungee");
-        m.insert("14411", "bunion");
-        m.insert("14412", "bunkbed");
-        m.insert("14413", "bunkhouse");
-        m.insert("14414", "bunkmate");
-        m.insert("14415", "bunny");
-        m.insert("14416", "bunt");
-        m.insert("14421", "busboy");
-        m.insert("14422", "bush");
-        m.insert("14423", "busily");
-        m.insert("14424", "busload");
-        m.insert("14425", "bust");
-        m.insert("14426", "busybody");
-        m.insert("14431", "buzz");
-        m.insert("14432", "cabana");
-        m.insert("14433", "cabbage");
-        m.insert("14434", "cabbie");
-        m.insert("14435", "cabdriver");
-        m.insert("14436", "cable");
-        m.insert("14441", "caboose");
-        m.insert("14442", "cache");
-        m.insert("14443", "cackle");
-        m.insert("14444", "cacti");
-        m.insert("14445", "cactus");
-        m.insert("14446", "caddie");
-        m.insert("14451", "caddy");
-        m.insert("14452", "cadet");
-        m.insert("14453", "cadillac");
-        m.insert("14454", "cadmium");
-        m.insert("14455", "cage");
-        m.insert("14456", "cahoots");
-        m.insert("14461", "cake");
-        m.insert("14462", "calamari");
-        m.insert("14463", "calamity");
-        m.insert("14464", "calcium");
-        m.insert("14465", "calculate");
-        m.insert("14466", "calculus");
-        m.insert("14511", "caliber");
-        m.insert("14512", "calibrate");
-        m.insert("14513", "calm");
-        m.insert("14514", "caloric");
-        m.insert("14515", "calorie");
-        m.insert("14516", "calzone");
-        m.insert("14521", "camcorder");
-        m.insert("14522", "cameo");
-        m.insert("14523", "camera");
-        m.insert("14524", "camisole");
-        m.insert("14525", "camper");
-        m.insert("14526", "campfire");
-        m.insert("14531", "camping");
-        m.insert("14532", "campsite");
-        m.insert("14533", "campus");
-        m.insert("14534", "canal");
-        m.insert("14535", "canary");
-        m.insert("14536", "cancel");
-        m.insert("14541", "candied");
-        m.insert("14542", "candle");
-        m.insert("14543", "candy");
-        m.insert("14544", "cane");
-        m.insert("14545", "canine");
-        m.insert("14546", "canister");
-        m.insert("14551", "cannabis");
-        m.insert("14552", "canned");
-        m.insert("14553", "canning");
-        m.insert("14554", "cannon");
-        m.insert("14555", "cannot");
-        m.insert("14556", "canola");
-        m.insert("14561", "canon");
-        m.insert("14562", "canopener");
-        m.insert("14563", "canopy");
-        m.insert("14564", "canteen");
-        m.insert("14565", "canyon");
-        m.insert("14566", "capable");
-        m.insert("14611", "capably");
-        m.insert("14612", "capacity");
-        m.insert("14613", "cape");
-        m.insert("14614", "capillary");
-        m.insert("14615", "capital");
-        m.insert("14616", "capitol");
-        m.insert("14621", "capped");
-        m.insert("14622", "capricorn");
-        m.insert("14623", "capsize");
-        m.insert("14624", "capsule");
-        m.insert("14625", "caption");
-        m.insert("14626", "captivate");
-        m.insert("14631", "captive");
-        m.insert("14632", "captivity");
-        m.insert("14633", "capture");
-        m.insert("14634", "caramel");
-        m.insert("14635", "carat");
-        m.insert("14636", "caravan");
-        m.insert("14641", "carbon");
-        m.insert("14642", "cardboard");
-        m.insert("14643", "carded");
-        m.insert("14644", "cardiac");
-        m.insert("14645", "cardigan");
-        m.insert("14646", "cardinal");
-        m.insert("14651", "cardstock");
-        m.insert("14652", "carefully");
-        m.insert("14653", "caregiver");
-        m.insert("14654", "careless");
-        m.insert("14655", "caress");
-        m.insert("14656", "caretaker");
-        m.insert("14661", "cargo");
-        m.insert("14662", "caring");
-        m.insert("14663", "carless");
-        m.insert("14664", "carload");
-        m.insert("14665", "carmaker");
-        m.insert("14666", "carnage");
-        m.insert("15111", "carnation");
-        m.insert("15112", "carnival");
-        m.insert("15113", "carnivore");
-        m.insert("15114", "carol");
-        m.insert("15115", "carpenter");
-        m.insert("15116", "carpentry");
-        m.insert("15121", "carpool");
-        m.insert("15122", "carport");
-        m.insert("15123", "carried");
-        m.insert("15124", "carrot");
-        m.insert("15125", "carrousel");
-        m.insert("15126", "carry");
-        m.insert("15131", "cartel");
-        m.insert("15132", "cartload");
-        m.insert("15133", "carton");
-        m.insert("15134", "cartoon");
-        m.insert("15135", "cartridge");
-        m.insert("15136", "cartwheel");
-        m.insert("15141", "carve");
-        m.insert("15142", "carving");
-        m.insert("15143", "carwash");
-        m.insert("15144", "cascade");
-        m.insert("15145", "case");
-        m.insert("15146", "cash");
-        m.insert("15151", "casing");
-        m.insert("15152", "casino");
-        m.insert("15153", "casket");
-        m.insert("15154", "cassette");
-        m.insert("15155", "casually");
-        m.insert("15156", "casualty");
-        m.insert("15161", "catacomb");
-        m.insert("15162", "catalog");
-        m.insert("15163", "catalyst");
-        m.insert("15164", "catalyze");
-        m.insert("15165", "catapult");
-        m.insert("15166", "cataract");
-        m.insert("15211", "catatonic");
-        m.insert("15212", "catcall");
-        m.insert("15213", "catchable");
-        m.insert("15214", "catcher");
-        m.insert("15215", "catching");
-        m.insert("15216", "catchy");
-        m.insert("15221", "caterer");
-        m.insert("15222", "catering");
-        m.insert("15223", "catfight");
-        m.insert("15224", "catfish");
-        m.insert("15225", "cathedral");
-        m.insert("15226", "cathouse");
-        m.insert("15231", "catlike");
-        m.insert("15232", "catnap");
-        m.insert("15233", "catnip");
-        m.insert("15234", "catsup");
-        m.insert("15235", "cattail");
-        m.insert("15236", "cattishly");
-        m.insert("15241", "cattle");
-        m.insert("15242", "catty");
-        m.insert("15243", "catwalk");
-        m.insert("15244", "caucasian");
-        m.insert("15245", "caucus");
-        m.insert("15246", "causal");
-        m.insert("15251", "causation");
-        m.insert("15252", "cause");
-        m.insert("15253", "causing");
-        m.insert("15254", "cauterize");
-        m.insert("15255", "caution");
-        m.insert("15256", "cautious");
-        m.insert("15261", "cavalier");
-        m.insert("15262", "cavalry");
-        m.insert("15263", "caviar");
-        m.insert("15264", "cavity");
-        m.insert("15265", "cedar");
-        m.insert("15266", "celery");
-        m.insert("15311", "celestial");
-        m.insert("15312", "celibacy");
-        m.insert("15313", "celibate");
-        m.insert("15314", "celtic");
-        m.insert("15315", "cement");
-        m.insert("15316", "census");
-        m.insert("15321", "ceramics");
-        m.insert("15322", "ceremony");
-        m.insert("15323", "certainly");
-        m.insert("15324", "certainty");
-        m.insert("15325", "certified");
-        m.insert("15326", "certify");
-        m.insert("15331", "cesarean");
-        m.insert("15332", "cesspool");
-        m.insert("15333", "chafe");
-        m.insert("15334", "chaffing");
-        m.insert("15335", "chain");
-        m.insert("15336", "chair");
-        m.insert("15341", "chalice");
-        m.insert("15342", "challenge");
-        m.insert("15343", "chamber");
-        m.insert("15344", "chamomile");
-        m.insert("15345", "champion");
-        m.insert("15346", "chance");
-        m.insert("15351", "change");
-        m.insert("15352", "channel");
-        m.insert("15353", "chant");
-        m.insert("15354", "chaos");
-        m.insert("15355", "chaperone");
-        m.insert("15356", "chaplain");
-        m.insert("15361", "chapped");
-        m.insert("15362", "chaps");
-        m.insert("15363", "chapter");
-        m.insert("15364", "character");
-        m.insert("15365", "charbroil");
-        m.insert("15366", "charcoal");
-        m.insert("15411", "charger");
-        m.insert("15412", "charging");
-        m.insert("15413", "chariot");
-        m.insert("15414", "charity");
-        m.insert("15415", "charm");
-        m.insert("15416", "charred");
-        m.insert("15421", "charter");
-        m.insert("15422", "charting");
-        m.insert("15423", "chase");
-        m.insert("15424", "chasing");
-        m.insert("15425", "chaste");
-        m.insert("15426", "chastise");
-        m.insert("15431", "chastity");
-        m.insert("15432", "chatroom");
-        m.insert("15433", "chatter");
-        m.insert("15434", "chatting");
-        m.insert("15435", "chatty");
-        m.insert("15436", "cheating");
-        m.insert("15441", "cheddar");
-        m.insert("15442", "cheek");
-        m.insert("15443", "cheer");
-        m.insert("15444", "cheese");
-        m.insert("15445", "cheesy");
-        m.insert("15446", "chef");
-        m.insert("15451", "chemicals");
-        m.insert("15452", "chemist");
-        m.insert("15453", "chemo");
-        m.insert("15454", "cherisher");
-        m.insert("15455", "cherub");
-        m.insert("15456", "chess");
-        m.insert("15461", "chest");
-        m.insert("15462", "chevron");
-        m.insert("15463", "chevy");
-        m.insert("15464", "chewable");
-        m.insert("15465", "chewer");
-        m.insert("15466", "chewing");
-        m.insert("15511", "chewy");
-        m.insert("15512", "chief");
-        m.insert("15513", "chihuahua");
-        m.insert("15514", "childcare");
-        m.insert("15515", "childhood");
-        m.insert("15516", "childish");
-        m.insert("15521", "childless");
-        m.insert("15522", "childlike");
-        m.insert("15523", "chili");
-        m.insert("15524", "chill");
-        m.insert("15525", "chimp");
-        m.insert("15526", "chip");
-        m.insert("15531", "chirping");
-        m.insert("15532", "chirpy");
-        m.insert("15533", "chitchat");
-        m.insert("15534", "chivalry");
-        m.insert("15535", "chive");
-        m.insert("15536", "chloride");
-        m.insert("15541", "chlorine");
-        m.insert("15542", "choice");
-        m.insert("15543", "chokehold");
-        m.insert("15544", "choking");
-        m.insert("15545", "chomp");
-        m.insert("15546", "chooser");
-        m.insert("15551", "choosing");
-        m.insert("15552", "choosy");
-        m.insert("15553", "chop");
-        m.insert("15554", "chosen");
-        m.insert("15555", "chowder");
-        m.insert("15556", "chowtime");
-        m.insert("15561", "chrome");
-        m.insert("15562", "chubby");
-        m.insert("15563", "chuck");
-        m.insert("15564", "chug");
-        m.insert("15565", "chummy");
-        m.insert("15566", "chump");
-        m.insert("15611", "chunk");
-        m.insert("15612", "churn");
-        m.insert("15613", "chute");
-        m.insert("15614", "cider");
-        m.insert("15615", "cilantro");
-        m.insert("15616", "cinch");
-        m.insert("15621", "cinema");
-        m.insert("15622", "cinnamon");
-        m.insert("15623", "circle");
-        m.insert("15624", "circling");
-        m.insert("15625", "circular");
-        m.insert("15626", "circulate");
-        m.insert("15631", "circus");
-        m.insert("15632", "citable");
-        m.insert("15633", "citadel");
-        m.insert("15634", "citation");
-        m.insert("15635", "citizen");
-        m.insert("15636", "citric");
-        m.insert("15641", "citrus");
-        m.insert("15642", "city");
-        m.insert("15643", "civic");
-        m.insert("15644", "civil");
-        m.insert("15645", "clad");
-        m.insert("15646", "claim");
-        m.insert("15651", "clambake");
-        m.insert("15652", "clammy");
-        m.insert("15653", "clamor");
-        m.insert("15654", "clamp");
-        m.insert("15655", "clamshell");
-        m.insert("15656", "clang");
-        m.insert("15661", "clanking");
-        m.insert("15662", "clapped");
-        m.insert("15663", "clapper");
-        m.insert("15664", "clapping");
-        m.insert("15665", "clarify");
-        m.insert("15666", "clarinet");
-        m.insert("16111", "clarity");
-        m.insert("16112", "clash");
-        m.insert("16113", "clasp");
-        m.insert("16114", "class");
-        m.insert("16115", "clatter");
-        m.insert("16116", "clause");
-        m.insert("16121", "clavicle");
-        m.insert("16122", "claw");
-        m.insert("16123", "clay");
-        m.insert("16124", "clean");
-        m.insert("16125", "clear");
-        m.insert("16126", "cleat");
-        m.insert("16131", "cleaver");
-        m.insert("16132", "cleft");
-        m.insert("16133", "clench");
-        m.insert("16134", "clergyman");
-        m.insert("16135", "clerical");
-        m.insert("16136", "clerk");
-        m.insert("16141", "clever");
-        m.insert("16142", "clicker");
-        m.insert("16143", "client");
-        m.insert("16144", "climate");
-        m.insert("16145", "climatic");
-        m.insert("16146", "cling");
-        m.insert("16151", "clinic");
-        m.insert("16152", "clinking");
-        m.insert("16153", "clip");
-        m.insert("16154", "clique");
-        m.insert("16155", "cloak");
-        m.insert("16156", "clobber");
-        m.insert("16161", "clock");
-        m.insert("16162", "clone");
-        m.insert("16163", "cloning");
-        m.insert("16164", "closable");
-        m.insert("16165", "closure");
-        m.insert("16166", "clothes");
-        m.insert("16211", "clothing");
-        m.insert("16212", "cloud");
-        m.insert("16213", "clover");
-        m.insert("16214", "clubbed");
-        m.insert("16215", "clubbing");
-        m.insert("16216", "clubhouse");
-        m.insert("16221", "clump");
-        m.insert("16222", "clumsily");
-        m.insert("16223", "clumsy");
-        m.insert("16224", "clunky");
-        m.insert("16225", "clustered");
-        m.insert("16226", "clutch");
-        m.insert("16231", "clutter");
-        m.insert("16232", "coach");
-        m.insert("16233", "coagulant");
-        m.insert("16234", "coastal");
-        m.insert("16235", "coaster");
-        m.insert("16236", "coasting");
-        m.insert("16241", "coastland");
-        m.insert("16242", "coastline");
-        m.insert("16243", "coat");
-        m.insert("16244", "coauthor");
-        m.insert("16245", "cobalt");
-        m.insert("16246", "cobbler");
-        m.insert("16251", "cobweb");
-        m.insert("16252", "cocoa");
-        m.insert("16253", "coconut");
-        m.insert("16254", "cod");
-        m.insert("16255", "coeditor");
-        m.insert("16256", "coerce");
-        m.insert("16261", "coexist");
-        m.insert("16262", "coffee");
-        m.insert("16263", "cofounder");
-        m.insert("16264", "cognition");
-        m.insert("16265", "cognitive");
-        m.insert("16266", "cogwheel");
-        m.insert("16311", "coherence");
-        m.insert("16312", "coherent");
-        m.insert("16313", "cohesive");
-        m.insert("16314", "coil");
-        m.insert("16315", "coke");
-        m.insert("16316", "cola");
-        m.insert("16321", "cold");
-        m.insert("16322", "coleslaw");
-        m.insert("16323", "coliseum");
-        m.insert("16324", "collage");
-        m.insert("16325", "collapse");
-        m.insert("16326", "collar");
-        m.insert("16331", "collected");
-        m.insert("16332", "collector");
-        m.insert("16333", "collide");
-        m.insert("16334", "collie");
-        m.insert("16335", "collision");
-        m.insert("16336", "colonial");
-        m.insert("16341", "colonist");
-        m.insert("16342", "colonize");
-        m.insert("16343", "colony");
-        m.insert("16344", "colossal");
-        m.insert("16345", "colt");
-        m.insert("16346", "coma");
-        m.insert("16351", "come");
-        m.insert("16352", "comfort");
-        m.insert("16353", "comfy");
-        m.insert("16354", "comic");
-        m.insert("16355", "coming");
-        m.insert("16356", "comma");
-        m.insert("16361", "commence");
-        m.insert("16362", "commend");
-        m.insert("16363", "comment");
-        m.insert("16364", "commerce");
-        m.insert("16365", "commode");
-        m.insert("16366", "commodity");
-        m.insert("16411", "commodore");
-        m.insert("16412", "common");
-        m.insert("16413", "commotion");
-        m.insert("16414", "commute");
-        m.insert("16415", "commuting");
-        m.insert("16416", "compacted");
-        m.insert("16421", "compacter");
-        m.insert("16422", "compactly");
-        m.insert("16423", "compactor");
-        m.insert("16424", "companion");
-        m.insert("16425", "company");
-        m.insert("16426", "compare");
-        m.insert("16431", "compel");
-        m.insert("16432", "compile");
-        m.insert("16433", "comply");
-        m.insert("16434", "component");
-        m.insert("16435", "composed");
-        m.insert("16436", "composer");
-        m.insert("16441", "composite");
-        m.insert("16442", "compost");
-        m.insert("16443", "composure");
-        m.insert("16444", "compound");
-        m.insert("16445", "compress");
-        m.insert("16446", "comprised");
-        m.insert("16451", "computer");
-        m.insert("16452", "computing");
-        m.insert("16453", "comrade");
-        m.insert("16454", "concave");
-        m.insert("16455", "conceal");
-        m.insert("16456", "conceded");
-        m.insert("16461", "concept");
-        m.insert("16462", "concerned");
-        m.insert("16463", "concert");
-        m.insert("16464", "conch");
-        m.insert("16465", "concierge");
-        m.insert("16466", "concise");
-        m.insert("16511", "conclude");
-        m.insert("16512", "concrete");
-        m.insert("16513", "concur");
-        m.insert("16514", "condense");
-        m.insert("16515", "condiment");
-        m.insert("16516", "condition");
-        m.insert("16521", "condone");
-        m.insert("16522", "conducive");
-        m.insert("16523", "conductor");
-        m.insert("16524", "conduit");
-        m.insert("16525", "cone");
-        m.insert("16526", "confess");
-        m.insert("16531", "confetti");
-        m.insert("16532", "confidant");
-        m.insert("16533", "confident");
-        m.insert("16534", "confider");
-        m.insert("16535", "confiding");
-        m.insert("16536", "configure");
-        m.insert("16541", "confined");
-        m.insert("16542", "confining");
-        m.insert("16543", "confirm");
-        m.insert("16544", "conflict");
-        m.insert("16545", "conform");
-        m.insert("16546", "confound");
-        m.insert("16551", "confront");
-        m.insert("16552", "confused");
-        m.insert("16553", "confusing");
-        m.insert("16554", "confusion");
-        m.insert("16555", "congenial");
-        m.insert("16556", "congested");
-        m.insert("16561", "congrats");
-        m.insert("16562", "congress");
-        m.insert("16563", "conical");
-        m.insert("16564", "conjoined");
-        m.insert("16565", "conjure");
-        m.insert("16566", "conjuror");
-        m.insert("16611", "connected");
-        m.insert("16612", "connector");
-        m.insert("16613", "consensus");
-        m.insert("16614", "consent");
-        m.insert("16615", "console");
-        m.insert("16616", "consoling");
-        m.insert("16621", "consonant");
-        m.insert("16622", "constable");
-        m.insert("16623", "constant");
-        m.insert("16624", "constrain");
-        m.insert("16625", "constrict");
-        m.insert("16626", "construct");
-        m.insert("16631", "consult");
-        m.insert("16632", "consumer");
-        m.insert("16633", "consuming");
-        m.insert("16634", "contact");
-        m.insert("16635", "container");
-        m.insert("16636", "contempt");
-        m.insert("16641", "contend");
-        m.insert("16642", "contented");
-        m.insert("16643", "contently");
-        m.insert("16644", "contents");
-        m.insert("16645", "contest");
-        m.insert("16646", "context");
-        m.insert("16651", "contort");
-        m.insert("16652", "contour");
-        m.insert("16653", "contrite");
-        m.insert("16654", "control");
-        m.insert("16655", "contusion");
-        m.insert("16656", "convene");
-        m.insert("16661", "convent");
-        m.insert("16662", "copartner");
-        m.insert("16663", "cope");
-        m.insert("16664", "copied");
-        m.insert("16665", "copier");
-        m.insert("16666", "copilot");
-        m.insert("21111", "coping");
-        m.insert("21112", "copious");
-        m.insert("21113", "copper");
-        m.insert("21114", "copy");
-        m.insert("21115", "coral");
-        m.insert("21116", "cork");
-        m.insert("21121", "cornball");
-        m.insert("21122", "cornbread");
-        m.insert("21123", "corncob");
-        m.insert("21124", "cornea");
-        m.insert("21125", "corned");
-        m.insert("21126", "corner");
-        m.insert("21131", "cornfield");
-        m.insert("21132", "cornflake");
-        m.insert("21133", "cornhusk");
-        m.insert("21134", "cornmeal");
-        m.insert("21135", "cornstalk");
-        m.insert("21136", "corny");
-        m.insert("21141", "coronary");
-        m.insert("21142", "coroner");
-        m.insert("21143", "corporal");
-        m.insert("21144", "corporate");
-        m.insert("21145", "corral");
-        m.insert("21146", "correct");
-        m.insert("21151", "corridor");
-        m.insert("21152", "corrode");
-        m.insert("21153", "corroding");
-        m.insert("21154", "corrosive");
-        m.insert("21155", "corsage");
-        m.insert("21156", "corset");
-        m.insert("21161", "cortex");
-        m.insert("21162", "cosigner");
-        m.insert("21163", "cosmetics");
-        m.insert("21164", "cosmic");
-        m.insert("21165", "cosmos");
-        m.insert("21166", "cosponsor");
-        m.insert("21211", "cost");
-        m.insert("21212", "cottage");
-        m.insert("21213", "cotton");
-        m.insert("21214", "couch");
-        m.insert("21215", "cough");
-        m.insert("21216", "could");
-        m.insert("21221", "countable");
-        m.insert("21222", "countdown");
-        m.insert("21223", "counting");
-        m.insert("21224", "countless");
-        m.insert("21225", "country");
-        m.insert("21226", "county");
-        m.insert("21231", "courier");
-        m.insert("21232", "covenant");
-        m.insert("21233", "cover");
-        m.insert("21234", "coveted");
-        m.insert("21235", "coveting");
-        m.insert("21236", "coyness");
-        m.insert("21241", "cozily");
-        m.insert("21242", "coziness");
-        m.insert("21243", "cozy");
-        m.insert("21244", "crabbing");
-        m.insert("21245", "crabgrass");
-        m.insert("21246", "crablike");
-        m.insert("21251", "crabmeat");
-        m.insert("21252", "cradle");
-        m.insert("21253", "cradling");
-        m.insert("21254", "crafter");
-        m.insert("21255", "craftily");
-        m.insert("21256", "craftsman");
-        m.insert("21261", "craftwork");
-        m.insert("21262", "crafty");
-        m.insert("21263", "cramp");
-        m.insert("21264", "cranberry");
-        m.insert("21265", "crane");
-        m.insert("21266", "cranial");
-        m.insert("21311", "cranium");
-        m.insert("21312", "crank");
-        m.insert("21313", "crate");
-        m.insert("21314", "crave");
-        m.insert("21315", "craving");
-        m.insert("21316", "crawfish");
-        m.insert("21321", "crawlers");
-        m.insert("21322", "crawling");
-        m.insert("21323", "crayfish");
-        m.insert("21324", "crayon");
-        m.insert("21325", "crazed");
-        m.insert("21326", "crazily");
-        m.insert("21331", "craziness");
-        m.insert("21332", "crazy");
-        m.insert("21333", "creamed");
-        m.insert("21334", "creamer");
-        m.insert("21335", "creamlike");
-        m.insert("21336", "crease");
-        m.insert("21341", "creasing");
-        m.insert("21342", "creatable");
-        m.insert("21343", "create");
-        m.insert("21344", "creation");
-        m.insert("21345", "creative");
-        m.insert("21346", "creature");
-        m.insert("21351", "credible");
-        m.insert("21352", "credibly");
-        m.insert("21353", "credit");
-        m.insert("21354", "creed");
-        m.insert("21355", "creme");
-        m.insert("21356", "creole");
-        m.insert("21361", "crepe");
-        m.insert("21362", "crept");
-        m.insert("21363", "crescent");
-        m.insert("21364", "crested");
-        m.insert("21365", "cresting");
-        m.insert("21366", "crestless");
-        m.insert("21411", "crevice");
-        m.insert("21412", "crewless");
-        m.insert("21413", "crewman");
-        m.insert("21414", "crewmate");
-        m.insert("21415", "crib");
-        m.insert("21416", "cricket");
-        m.insert("21421", "cried");
-        m.insert("21422", "crier");
-        m.insert("21423", "crimp");
-        m.insert("21424", "crimson");
-        m.insert("21425", "cringe");
-        m.insert("21426", "cringing");
-        m.insert("21431", "crinkle");
-        m.insert("21432", "crinkly");
-        m.insert("21433", "crisped");
-        m.insert("21434", "crisping");
-        m.insert("21435", "crisply");
-        m.insert("21436", "crispness");
-        m.insert("21441", "crispy");
-        m.insert("21442", "criteria");
-        m.insert("21443", "critter");
-        m.insert("21444", "croak");
-        m.insert("21445", "crock");
-        m.insert("21446", "crook");
-        m.insert("21451", "croon");
-        m.insert("21452", "crop");
-        m.insert("21453", "cross");
-        m.insert("21454", "crouch");
-        m.insert("21455", "crouton");
-        m.insert("21456", "crowbar");
-        m.insert("21461", "crowd");
-        m.insert("21462", "crown");
-        m.insert("21463", "crucial");
-        m.insert("21464", "crudely");
-        m.insert("21465", "crudeness");
-        m.insert("21466", "cruelly");
-        m.insert("21511", "cruelness");
-        m.insert("21512", "cruelty");
-        m.insert("21513", "crumb");
-        m.insert("21514", "crummiest");
-        m.insert("21515", "crummy");
-        m.insert("21516", "crumpet");
-        m.insert("21521", "crumpled");
-        m.insert("21522", "cruncher");
-        m.insert("21523", "crunching");
-        m.insert("21524", "crunchy");
-        m.insert("21525", "crusader");
-        m.insert("21526", "crushable");
-        m.insert("21531", "crushed");
-        m.insert("21532", "crusher");
-        m.insert("21533", "crushing");
-        m.insert("21534", "crust");
-        m.insert("21535", "crux");
-        m.insert("21536", "crying");
-        m.insert("21541", "cryptic");
-        m.insert("21542", "crystal");
-        m.insert("21543", "cubbyhole");
-        m.insert("21544", "cube");
-        m.insert("21545", "cubical");
-        m.insert("21546", "cubicle");
-        m.insert("21551", "cucumber");
-        m.insert("21552", "cuddle");
-        m.insert("21553", "cuddly");
-        m.insert("21554", "cufflink");
-        m.insert("21555", "culinary");
-        m.insert("21556", "culminate");
-        m.insert("21561", "culpable");
-        m.insert("21562", "culprit");
-        m.insert("21563", "cultivate");
-        m.insert("21564", "cultural");
-        m.insert("21565", "culture");
-        m.insert("21566", "cupbearer");
-        m.insert("21611", "cupcake");
-        m.insert("21612", "cupid");
-        m.insert("21613", "cupped");
-        m.insert("21614", "cupping");
-        m.insert("21615", "curable");
-        m.insert("21616", "curator");
-        m.insert("21621", "curdle");
-        m.insert("21622", "cure");
-        m.insert("21623", "curfew");
-        m.insert("21624", "curing");
-        m.insert("21625", "curled");
-        m.insert("21626", "curler");
-        m.insert("21631", "curliness");
-        m.insert("21632", "curling");
-        m.insert("21633", "curly");
-        m.insert("21634", "curry");
-        m.insert("21635", "curse");
-        m.insert("21636", "cursive");
-        m.insert("21641", "cursor");
-        m.insert("21642", "curtain");
-        m.insert("21643", "curtly");
-        m.insert("21644", "curtsy");
-        m.insert("21645", "curvature");
-        m.insert("21646", "curve");
-        m.insert("21651", "curvy");
-        m.insert("21652", "cushy");
-        m.insert("21653", "cusp");
-        m.insert("21654", "cussed");
-        m.insert("21655", "custard");
-        m.insert("21656", "custodian");
-        m.insert("21661", "custody");
-        m.insert("21662", "customary");
-        m.insert("21663", "customer");
-        m.insert("21664", "customize");
-        m.insert("21665", "customs");
-        m.insert("21666", "cut");
-        m.insert("22111", "cycle");
-        m.insert("22112", "cyclic");
-        m.insert("22113", "cycling");
-        m.insert("22114", "cyclist");
-        m.insert("22115", "cylinder");
-        m.insert("22116", "cymbal");
-        m.insert("22121", "cytoplasm");
-        m.insert("22122", "cytoplast");
-        m.insert("22123", "dab");
-        m.insert("22124", "dad");
-        m.insert("22125", "daffodil");
-        m.insert("22126", "dagger");
-        m.insert("22131", "daily");
-        m.insert("22132", "daintily");
-        m.insert("22133", "dainty");
-        m.insert("22134", "dairy");
-        m.insert("22135", "daisy");
-        m.insert("22136", "dallying");
-        m.insert("22141", "dance");
-        m.insert("22142", "dancing");
-        m.insert("22143", "dandelion");
-        m.insert("22144", "dander");
-        m.insert("22145", "dandruff");
-        m.insert("22146", "dandy");
-        m.insert("22151", "danger");
-        m.insert("22152", "dangle");
-        m.insert("22153", "dangling");
-        m.insert("22154", "daredevil");
-        m.insert("22155", "dares");
-        m.insert("22156", "daringly");
-        m.insert("22161", "darkened");
-        m.insert("22162", "darkening");
-        m.insert("22163", "darkish");
-        m.insert("22164", "darkness");
-        m.insert("22165", "darkroom");
-        m.insert("22166", "darling");
-        m.insert("22211", "darn");
-        m.insert("22212", "dart");
-        m.insert("22213", "darwinism");
-        m.insert("22214", "dash");
-        m.insert("22215", "dastardly");
-        m.insert("22216", "data");
-        m.insert("22221", "datebook");
-        m.insert("22222", "dating");
-        m.insert("22223", "daughter");
-        m.insert("22224", "daunting");
-        m.insert("22225", "dawdler");
-        m.insert("22226", "dawn");
-        m.insert("22231", "daybed");
-        m.insert("22232", "daybreak");
-        m.insert("22233", "daycare");
-        m.insert("22234", "daydream");
-        m.insert("22235", "daylight");
-        m.insert("22236", "daylong");
-        m.insert("22241", "dayroom");
-        m.insert("22242", "daytime");
-        m.insert("22243", "dazzler");
-        m.insert("22244", "dazzling");
-        m.insert("22245", "deacon");
-        m.insert("22246", "deafening");
-        m.insert("22251", "deafness");
-        m.insert("22252", "dealer");
-        m.insert("22253", "dealing");
-        m.insert("22254", "dealmaker");
-        m.insert("22255", "dealt");
-        m.insert("22256", "dean");
-        m.insert("22261", "debatable");
-        m.insert("22262", "debate");
-        m.insert("22263", "debating");
-        m.insert("22264", "debit");
-        m.insert("22265", "debrief");
-        m.insert("22266", "debtless");
-        m.insert("22311", "debtor");
-        m.insert("22312", "debug");
-        m.insert("22313", "debunk");
-        m.insert("22314", "decade");
-        m.insert("22315", "decaf");
-        m.insert("22316", "decal");
-        m.insert("22321", "decathlon");
-        m.insert("22322", "decay");
-        m.insert("22323", "deceased");
-        m.insert("22324", "deceit");
-        m.insert("22325", "deceiver");
-        m.insert("22326", "deceiving");
-        m.insert("22331", "december");
-        m.insert("22332", "decency");
-        m.insert("22333", "decent");
-        m.insert("22334", "deception");
-        m.insert("22335", "deceptive");
-        m.insert("22336", "decibel");
-        m.insert("22341", "decidable");
-        m.insert("22342", "decimal");
-        m.insert("22343", "decimeter");
-        m.insert("22344", "decipher");
-        m.insert("22345", "deck");
-        m.insert("22346", "declared");
-        m.insert("22351", "decline");
-        m.insert("22352", "decode");
-        m.insert("22353", "decompose");
-        m.insert("22354", "decorated");
-        m.insert("22355", "decorator");
-        m.insert("22356", "decoy");
-        m.insert("22361", "decrease");
-        m.insert("22362", "decree");
-        m.insert("22363", "dedicate");
-        m.insert("22364", "dedicator");
-        m.insert("22365", "deduce");
-        m.insert("22366", "deduct");
-        m.insert("22411", "deed");
-        m.insert("22412", "deem");
-        m.insert("22413", "deepen");
-        m.insert("22414", "deeply");
-        m.insert("22415", "deepness");
-        m.insert("22416", "deface");
-        m.insert("22421", "defacing");
-        m.insert("22422", "defame");
-        m.insert("22423", "default");
-        m.insert("22424", "defeat");
-        m.insert("22425", "defection");
-        m.insert("22426", "defective");
-        m.insert("22431", "defendant");
-        m.insert("22432", "defender");
-        m.insert("22433", "defense");
-        m.insert("22434", "defensive");
-        m.insert("22435", "deferral");
-        m.insert("22436", "deferred");
-        m.insert("22441", "defiance");
-        m.insert("22442", "defiant");
-        m.insert("22443", "defile");
-        m.insert("22444", "defiling");
-        m.insert("22445", "define");
-        m.insert("22446", "definite");
-        m.insert("22451", "deflate");
-        m.insert("22452", "deflation");
-        m.insert("22453", "deflator");
-        m.insert("22454", "deflected");
-        m.insert("22455", "deflector");
-        m.insert("22456", "defog");
-        m.insert("22461", "deforest");
-        m.insert("22462", "defraud");
-        m.insert("22463", "defrost");
-        m.insert("22464", "deftly");
-        m.insert("22465", "defuse");
-        m.insert("22466", "defy");
-        m.insert("22511", "degraded");
-        m.insert("22512", "degrading");
-        m.insert("22513", "degrease");
-        m.insert("22514", "degree");
-        m.insert("22515", "dehydrate");
-        m.insert("22516", "deity");
-        m.insert("22521", "dejected");
-        m.insert("22522", "delay");
-        m.insert("22523", "delegate");
-        m.insert("22524", "delegator");
-        m.insert("22525", "delete");
-        m.insert("22526", "deletion");
-        m.insert("22531", "delicacy");
-        m.insert("22532", "delicate");
-        m.insert("22533", "delicious");
-        m.insert("22534", "delighted");
-        m.insert("22535", "delirious");
-        m.insert("22536", "delirium");
-        m.insert("22541", "deliverer");
-        m.insert("22542", "delivery");
-        m.insert("22543", "delouse");
-        m.insert("22544", "delta");
-        m.insert("22545", "deluge");
-        m.insert("22546", "delusion");
-        m.insert("22551", "deluxe");
-        m.insert("22552", "demanding");
-        m.insert("22553", "demeaning");
-        m.insert("22554", "demeanor");
-        m.insert("22555", "demise");
-        m.insert("22556", "democracy");
-        m.insert("22561", "democrat");
-        m.insert("22562", "demote");
-        m.insert("22563", "demotion");
-        m.insert("22564", "demystify");
-        m.insert("22565", "denatured");
-        m.insert("22566", "deniable");
-        m.insert("22611", "denial");
-        m.insert("22612", "denim");
-        m.insert("22613", "denote");
-        m.insert("22614", "dense");
-        m.insert("22615", "density");
-        m.insert("22616", "dental");
-        m.insert("22621", "dentist");
-        m.insert("22622", "denture");
-        m.insert("22623", "deny");
-        m.insert("22624", "deodorant");
-        m.insert("22625", "deodorize");
-        m.insert("22626", "departed");
-        m.insert("22631", "departure");
-        m.insert("22632", "depict");
-        m.insert("22633", "deplete");
-        m.insert("22634", "depletion");
-        m.insert("22635", "deplored");
-        m.insert("22636", "deploy");
-        m.insert("22641", "deport");
-        m.insert("22642", "depose");
-        m.insert("22643", "depraved");
-        m.insert("22644", "depravity");
-        m.insert("22645", "deprecate");
-        m.insert("22646", "depress");
-        m.insert("22651", "deprive");
-        m.insert("22652", "depth");
-        m.insert("22653", "deputize");
-        m.insert("22654", "deputy");
-        m.insert("22655", "derail");
-        m.insert("22656", "deranged");
-        m.insert("22661", "derby");
-        m.insert("22662", "derived");
-        m.insert("22663", "desecrate");
-        m.insert("22664", "deserve");
-        m.insert("22665", "deserving");
-        m.insert("22666", "designate");
-        m.insert("23111", "designed");
-        m.insert("23112", "designer");
-        m.insert("23113", "designing");
-        m.insert("23114", "deskbound");
-        m.insert("23115", "desktop");
-        m.insert("23116", "deskwork");
-        m.insert("23121", "desolate");
-        m.insert("23122", "despair");
-        m.insert("23123", "despise");
-        m.insert("23124", "despite");
-        m.insert("23125", "destiny");
-        m.insert("23126", "destitute");
-        m.insert("23131", "destruct");
-        m.insert("23132", "detached");
-        m.insert("23133", "detail");
-        m.insert("23134", "detection");
-        m.insert("23135", "detective");
-        m.insert("23136", "detector");
-        m.insert("23141", "detention");
-        m.insert("23142", "detergent");
-        m.insert("23143", "detest");
-        m.insert("23144", "detonate");
-        m.insert("23145", "detonator");
-        m.insert("23146", "detoxify");
-        m.insert("23151", "detract");
-        m.insert("23152", "deuce");
-        m.insert("23153", "devalue");
-        m.insert("23154", "deviancy");
-        m.insert("23155", "deviant");
-        m.insert("23156", "deviate");
-        m.insert("23161", "deviation");
-        m.insert("23162", "deviator");
-        m.insert("23163", "device");
-        m.insert("23164", "devious");
-        m.insert("23165", "devotedly");
-        m.insert("23166", "devotee");
-        m.insert("23211", "devotion");
-        m.insert("23212", "devourer");
-        m.insert("23213", "devouring");
-        m.insert("23214", "devoutly");
-        m.insert("23215", "dexterity");
-        m.insert("23216", "dexterous");
-        m.insert("23221", "diabetes");
-        m.insert("23222", "diabetic");
-        m.insert("23223", "diabolic");
-        m.insert("23224", "diagnoses");
-        m.insert("23225", "diagnosis");
-        m.insert("23226", "diagram");
-        m.insert("23231", "dial");
-        m.insert("23232", "diameter");
-        m.insert("23233", "diaper");
-        m.insert("23234", "diaphragm");
-        m.insert("23235", "diary");
-        m.insert("23236", "dice");
-        m.insert("23241", "dicing");
-        m.insert("23242", "dictate");
-        m.insert("23243", "dictation");
-        m.insert("23244", "dictator");
-        m.insert("23245", "difficult");
-        m.insert("23246", "diffused");
-        m.insert("23251", "diffuser");
-        m.insert("23252", "diffusion");
-        m.insert("23253", "diffusive");
-        m.insert("23254", "dig");
-        m.insert("23255", "dilation");
-        m.insert("23256", "diligence");
-        m.insert("23261", "diligent");
-        m.insert("23262", "dill");
-        m.insert("23263", "dilute");
-        m.insert("23264", "dime");
-        m.insert("23265", "diminish");
-        m.insert("23266", "dimly");
-        m.insert("23311", "dimmed");
-        m.insert("23312", "dimmer");
-        m.insert("23313", "dimness");
-        m.insert("23314", "dimple");
-        m.insert("23315", "diner");
-        m.insert("23316", "dingbat");
-        m.insert("23321", "dinghy");
-        m.insert("23322", "dinginess");
-        m.insert("23323", "dingo");
-        m.insert("23324", "dingy");
-        m.insert("23325", "dining");
-        m.insert("23326", "dinner");
-        m.insert("23331", "diocese");
-        m.insert("23332", "dioxide");
-        m.insert("23333", "diploma");
-        m.insert("23334", "dipped");
-        m.insert("23335", "dipper");
-        m.insert("23336", "dipping");
-        m.insert("23341", "directed");
-        m.insert("23342", "direction");
-        m.insert("23343", "directive");
-        m.insert("23344", "directly");
-        m.insert("23345", "directory");
-        m.insert("23346", "direness");
-        m.insert("23351", "dirtiness");
-        m.insert("23352", "disabled");
-        m.insert("23353", "disagree");
-        m.insert("23354", "disallow");
-        m.insert("23355", "disarm");
-        m.insert("23356", "disarray");
-        m.insert("23361", "disaster");
-        m.insert("23362", "disband");
-        m.insert("23363", "disbelief");
-        m.insert("23364", "disburse");
-        m.insert("23365", "discard");
-        m.insert("23366", "discern");
-        m.insert("23411", "discharge");
-        m.insert("23412", "disclose");
-        m.insert("23413", "discolor");
-        m.insert("23414", "discount");
-        m.insert("23415", "discourse");
-        m.insert("23416", "discover");
-        m.insert("23421", "discuss");
-        m.insert("23422", "disdain");
-        m.insert("23423", "disengage");
-        m.insert("23424", "disfigure");
-        m.insert("23425", "disgrace");
-        m.insert("23426", "dish");
-        m.insert("23431", "disinfect");
-        m.insert("23432", "disjoin");
-        m.insert("23433", "disk");
-        m.insert("23434", "dislike");
-        m.insert("23435", "disliking");
-        m.insert("23436", "dislocate");
-        m.insert("23441", "dislodge");
-        m.insert("23442", "disloyal");
-        m.insert("23443", "dismantle");
-        m.insert("23444", "dismay");
-        m.insert("23445", "dismiss");
-        m.insert("23446", "dismount");
-        m.insert("23451", "disobey");
-        m.insert("23452", "disorder");
-        m.insert("23453", "disown");
-        m.insert("23454", "disparate");
-        m.insert("23455", "disparity");
-        m.insert("23456", "dispatch");
-        m.insert("23461", "dispense");
-        m.insert("23462", "dispersal");
-        m.insert("23463", "dispersed");
-        m.insert("23464", "disperser");
-        m.insert("23465", "displace");
-        m.insert("23466", "display");
-        m.insert("23511", "displease");
-        m.insert("23512", "disposal");
-        m.insert("23513", "dispose");
-        m.insert("23514", "disprove");
-        m.insert("23515", "dispute");
-        m.insert("23516", "disregard");
-        m.insert("23521", "disrupt");
-        m.insert("23522", "dissuade");
-        m.insert("23523", "distance");
-        m.insert("23524", "distant");
-        m.insert("23525", "distaste");
-        m.insert("23526", "distill");
-        m.insert("23531", "distinct");
-        m.insert("23532", "distort");
-        m.insert("23533", "distract");
-        m.insert("23534", "distress");
-        m.insert("23535", "district");
-        m.insert("23536", "distrust");
-        m.insert("23541", "ditch");
-        m.insert("23542", "ditto");
-        m.insert("23543", "ditzy");
-        m.insert("23544", "dividable");
-        m.insert("23545", "divided");
-        m.insert("23546", "dividend");
-        m.insert("23551", "dividers");
-        m.insert("23552", "dividing");
-        m.insert("23553", "divinely");
-        m.insert("23554", "diving");
-        m.insert("23555", "divinity");
-        m.insert("23556", "divisible");
-        m.insert("23561", "divisibly");
-        m.insert("23562", "division");
-        m.insert("23563", "divisive");
-        m.insert("23564", "divorcee");
-        m.insert("23565", "dizziness");
-        m.insert("23566", "dizzy");
-        m.insert("23611", "doable");
-        m.insert("23612", "docile");
-        m.insert("23613", "dock");
-        m.insert("23614", "doctrine");
-        m.insert("23615", "document");
-        m.insert("23616", "dodge");
-        m.insert("23621", "dodgy");
-        m.insert("23622", "doily");
-        m.insert("23623", "doing");
-        m.insert("23624", "dole");
-        m.insert("23625", "dollar");
-        m.insert("23626", "dollhouse");
-        m.insert("23631", "dollop");
-        m.insert("23632", "dolly");
-        m.insert("23633", "dolphin");
-        m.insert("23634", "domain");
-        m.insert("23635", "domelike");
-        m.insert("23636", "domestic");
-        m.insert("23641", "dominion");
-        m.insert("23642", "dominoes");
-        m.insert("23643", "donated");
-        m.insert("23644", "donation");
-        m.insert("23645", "donator");
-        m.insert("23646", "donor");
-        m.insert("23651", "donut");
-        m.insert("23652", "doodle");
-        m.insert("23653", "doorbell");
-        m.insert("23654", "doorframe");
-        m.insert("23655", "doorknob");
-        m.insert("23656", "doorman");
-        m.insert("23661", "doormat");
-        m.insert("23662", "doornail");
-        m.insert("23663", "doorpost");
-        m.insert("23664", "doorstep");
-        m.insert("23665", "doorstop");
-        m.insert("23666", "doorway");
-        m.insert("24111", "doozy");
-        m.insert("24112", "dork");
-        m.insert("24113", "dormitory");
-        m.insert("24114", "dorsal");
-        m.insert("24115", "dosage");
-        m.insert("24116", "dose");
-        m.insert("24121", "dotted");
-        m.insert("24122", "doubling");
-        m.insert("24123", "douche");
-        m.insert("24124", "dove");
-        m.insert("24125", "down");
-        m.insert("24126", "dowry");
-        m.insert("24131", "doze");
-        m.insert("24132", "drab");
-        m.insert("24133", "dragging");
-        m.insert("24134", "dragonfly");
-        m.insert("24135", "dragonish");
-        m.insert("24136", "dragster");
-        m.insert("24141", "drainable");
-        m.insert("24142", "drainage");
-        m.insert("24143", "drained");
-        m.insert("24144", "drainer");
-        m.insert("24145", "drainpipe");
-        m.insert("24146", "dramatic");
-        m.insert("24151", "dramatize");
-        m.insert("24152", "drank");
-        m.insert("24153", "drapery");
-        m.insert("24154", "drastic");
-        m.insert("24155", "draw");
-        m.insert("24156", "dreaded");
-        m.insert("24161", "dreadful");
-        m.insert("24162", "dreadlock");
-        m.insert("24163", "dreamboat");
-        m.insert("24164", "dreamily");
-        m.insert("24165", "dreamland");
-        m.insert("24166", "dreamless");
-        m.insert("24211", "dreamlike");
-        m.insert("24212", "dreamt");
-        m.insert("24213", "dreamy");
-        m.insert("24214", "drearily");
-        m.insert("24215", "dreary");
-        m.insert("24216", "drench");
-        m.insert("24221", "dress");
-        m.insert("24222", "drew");
-        m.insert("24223", "dribble");
-        m.insert("24224", "dried");
-        m.insert("24225", "drier");
-        m.insert("24226", "drift");
-        m.insert("24231", "driller");
-        m.insert("24232", "drilling");
-        m.insert("24233", "drinkable");
-        m.insert("24234", "drinking");
-        m.insert("24235", "dripping");
-        m.insert("24236", "drippy");
-        m.insert("24241", "drivable");
-        m.insert("24242", "driven");
-        m.insert("24243", "driver");
-        m.insert("24244", "driveway");
-        m.insert("24245", "driving");
-        m.insert("24246", "drizzle");
-        m.insert("24251", "drizzly");
-        m.insert("24252", "drone");
-        m.insert("24253", "drool");
-        m.insert("24254", "droop");
-        m.insert("24255", "drop-down");
-        m.insert("24256", "dropbox");
-        m.insert("24261", "dropkick");
-        m.insert("24262", "droplet");
-        m.insert("24263", "dropout");
-        m.insert("24264", "dropper");
-        m.insert("24265", "drove");
-        m.insert("24266", "drown");
-        m.insert("24311", "drowsily");
-        m.insert("24312", "drudge");
-        m.insert("24313", "drum");
-        m.insert("24314", "dry");
-        m.insert("24315", "dubbed");
-        m.insert("24316", "dubiously");
-        m.insert("24321", "duchess");
-        m.insert("24322", "duckbill");
-        m.insert("24323", "ducking");
-        m.insert("24324", "duckling");
-        m.insert("24325", "ducktail");
-        m.insert("24326", "ducky");
-        m.insert("24331", "duct");
-        m.insert("24332", "dude");
-        m.insert("24333", "duffel");
-        m.insert("24334", "dugout");
-        m.insert("24335", "duh");
-        m.insert("24336", "duke");
-        m.insert("24341", "duller");
-        m.insert("24342", "dullness");
-        m.insert("24343", "duly");
-        m.insert("24344", "dumping");
-        m.insert("24345", "dumpling");
-        m.insert("24346", "dumpster");
-        m.insert("24351", "duo");
-        m.insert("24352", "dupe");
-        m.insert("24353", "duplex");
-        m.insert("24354", "duplicate");
-        m.insert("24355", "duplicity");
-        m.insert("24356", "durable");
-        m.insert("24361", "durably");
-        m.insert("24362", "duration");
-        m.insert("24363", "duress");
-        m.insert("24364", "during");
-        m.insert("24365", "dusk");
-        m.insert("24366", "dust");
-        m.insert("24411", "dutiful");
-        m.insert("24412", "duty");
-        m.insert("24413", "duvet");
-        m.insert("24414", "dwarf");
-        m.insert("24415", "dweeb");
-        m.insert("24416", "dwelled");
-        m.insert("24421", "dweller");
-        m.insert("24422", "dwelling");
-        m.insert("24423", "dwindle");
-        m.insert("24424", "dwindling");
-        m.insert("24425", "dynamic");
-        m.insert("24426", "dynamite");
-        m.insert("24431", "dynasty");
-        m.insert("24432", "dyslexia");
-        m.insert("24433", "dyslexic");
-        m.insert("24434", "each");
-        m.insert("24435", "eagle");
-        m.insert("24436", "earache");
-        m.insert("24441", "eardrum");
-        m.insert("24442", "earflap");
-        m.insert("24443", "earful");
-        m.insert("24444", "earlobe");
-        m.insert("24445", "early");
-        m.insert("24446", "earmark");
-        m.insert("24451", "earmuff");
-        m.insert("24452", "earphone");
-        m.insert("24453", "earpiece");
-        m.insert("24454", "earplugs");
-        m.insert("24455", "earring");
-        m.insert("24456", "earshot");
-        m.insert("24461", "earthen");
-        m.insert("24462", "earthlike");
-        m.insert("24463", "earthling");
-        m.insert("24464", "earthly");
-        m.insert("24465", "earthworm");
-        m.insert("24466", "earthy");
-        m.insert("24511", "earwig");
-        m.insert("24512", "easeful");
-        m.insert("24513", "easel");
-        m.insert("24514", "easiest");
-        m.insert("24515", "easily");
-        m.insert("24516", "easiness");
-        m.insert("24521", "easing");
-        m.insert("24522", "eastbound");
-        m.insert("24523", "eastcoast");
-        m.insert("24524", "easter");
-        m.insert("24525", "eastward");
-        m.insert("24526", "eatable");
-        m.insert("24531", "eaten");
-        m.insert("24532", "eatery");
-        m.insert("24533", "eating");
-        m.insert("24534", "eats");
-        m.insert("24535", "ebay");
-        m.insert("24536", "ebony");
-        m.insert("24541", "ebook");
-        m.insert("24542", "ecard");
-        m.insert("24543", "eccentric");
-        m.insert("24544", "echo");
-        m.insert("24545", "eclair");
-        m.insert("24546", "eclipse");
-        m.insert("24551", "ecologist");
-        m.insert("24552", "ecology");
-        m.insert("24553", "economic");
-        m.insert("24554", "economist");
-        m.insert("24555", "economy");
-        m.insert("24556", "ecosphere");
-        m.insert("24561", "ecosystem");
-        m.insert("24562", "edge");
-        m.insert("24563", "edginess");
-        m.insert("24564", "edging");
-        m.insert("24565", "edgy");
-        m.insert("24566", "edition");
-        m.insert("24611", "editor");
-        m.insert("24612", "educated");
-        m.insert("24613", "education");
-        m.insert("24614", "educator");
-        m.insert("24615", "eel");
-        m.insert("24616", "effective");
-        m.insert("24621", "effects");
-        m.insert("24622", "efficient");
-        m.insert("24623", "effort");
-        m.insert("24624", "eggbeater");
-        m.insert("24625", "egging");
-        m.insert("24626", "eggnog");
-        m.insert("24631", "eggplant");
-        m.insert("24632", "eggshell");
-        m.insert("24633", "egomaniac");
-        m.insert("24634", "egotism");
-        m.insert("24635", "egotistic");
-        m.insert("24636", "either");
-        m.insert("24641", "eject");
-        m.insert("24642", "elaborate");
-        m.insert("24643", "elastic");
-        m.insert("24644", "elated");
-        m.insert("24645", "elbow");
-        m.insert("24646", "eldercare");
-        m.insert("24651", "elderly");
-        m.insert("24652", "eldest");
-        m.insert("24653", "electable");
-        m.insert("24654", "election");
-        m.insert("24655", "elective");
-        m.insert("24656", "elephant");
-        m.insert("24661", "elevate");
-        m.insert("24662", "elevating");
-        m.insert("24663", "elevation");
-        m.insert("24664", "elevator");
-        m.insert("24665", "eleven");
-        m.insert("24666", "elf");
-        m.insert("25111", "eligible");
-        m.insert("25112", "eligibly");
-        m.insert("25113", "eliminate");
-        m.insert("25114", "elite");
-        m.insert("25115", "elitism");
-        m.insert("25116", "elixir");
-        m.insert("25121", "elk");
-        m.insert("25122", "ellipse");
-        m.insert("25123", "elliptic");
-        m.insert("25124", "elm");
-        m.insert("25125", "elongated");
-        m.insert("25126", "elope");
-        m.insert("25131", "eloquence");
-        m.insert("25132", "eloquent");
-        m.insert("25133", "elsewhere");
-        m.insert("25134", "elude");
-        m.insert("25135", "elusive");
-        m.insert("25136", "elves");
-        m.insert("25141", "email");
-        m.insert("25142", "embargo");
-        m.insert("25143", "embark");
-        m.insert("25144", "embassy");
-        m.insert("25145", "embattled");
-        m.insert("25146", "embellish");
-        m.insert("25151", "ember");
-        m.insert("25152", "embezzle");
-        m.insert("25153", "emblaze");
-        m.insert("25154", "emblem");
-        m.insert("25155", "embody");
-        m.insert("25156", "embolism");
-        m.insert("25161", "emboss");
-        m.insert("25162", "embroider");
-        m.insert("25163", "emcee");
-        m.insert("25164", "emerald");
-        m.insert("25165", "emergency");
-        m.insert("25166", "emission");
-        m.insert("25211", "emit");
-        m.insert("25212", "emote");
-        m.insert("25213", "emoticon");
-        m.insert("25214", "emotion");
-        m.insert("25215", "empathic");
-        m.insert("25216", "empathy");
-        m.insert("25221", "emperor");
-        m.insert("25222", "emphases");
-        m.insert("25223", "emphasis");
-        m.insert("25224", "emphasize");
-        m.insert("25225", "emphatic");
-        m.insert("25226", "empirical");
-        m.insert("25231", "employed");
-        m.insert("25232", "employee");
-        m.insert("25233", "employer");
-        m.insert("25234", "emporium");
-        m.insert("25235", "empower");
-        m.insert("25236", "emptier");
-        m.insert("25241", "emptiness");
-        m.insert("25242", "empty");
-        m.insert("25243", "emu");
-        m.insert("25244", "enable");
-        m.insert("25245", "enactment");
-        m.insert("25246", "enamel");
-        m.insert("25251", "enchanted");
-        m.insert("25252", "enchilada");
-        m.insert("25253", "encircle");
-        m.insert("25254", "enclose");
-        m.insert("25255", "enclosure");
-        m.insert("25256", "encode");
-        m.insert("25261", "encore");
-        m.insert("25262", "encounter");
-        m.insert("25263", "encourage");
-        m.insert("25264", "encroach");
-        m.insert("25265", "encrust");
-        m.insert("25266", "encrypt");
-        m.insert("25311", "endanger");
-        m.insert("25312", "endeared");
-        m.insert("25313", "endearing");
-        m.insert("25314", "ended");
-        m.insert("25315", "ending");
-        m.insert("25316", "endless");
-        m.insert("25321", "endnote");
-        m.insert("25322", "endocrine");
-        m.insert("25323", "endorphin");
-        m.insert("25324", "endorse");
-        m.insert("25325", "endowment");
-        m.insert("25326", "endpoint");
-        m.insert("25331", "endurable");
-        m.insert("25332", "endurance");
-        m.insert("25333", "enduring");
-        m.insert("25334", "energetic");
-        m.insert("25335", "energize");
-        m.insert("25336", "energy");
-        m.insert("25341", "enforced");
-        m.insert("25342", "enforcer");
-        m.insert("25343", "engaged");
-        m.insert("25344", "engaging");
-        m.insert("25345", "engine");
-        m.insert("25346", "engorge");
-        m.insert("25351", "engraved");
-        m.insert("25352", "engraver");
-        m.insert("25353", "engraving");
-        m.insert("25354", "engross");
-        m.insert("25355", "engulf");
-        m.insert("25356", "enhance");
-        m.insert("25361", "enigmatic");
-        m.insert("25362", "enjoyable");
-        m.insert("25363", "enjoyably");
-        m.insert("25364", "enjoyer");
-        m.insert("25365", "enjoying");
-        m.insert("25366", "enjoyment");
-        m.insert("25411", "enlarged");
-        m.insert("25412", "enlarging");
-        m.insert("25413", "enlighten");
-        m.insert("25414", "enlisted");
-        m.insert("25415", "enquirer");
-        m.insert("25416", "enrage");
-        m.insert("25421", "enrich");
-        m.insert("25422", "enroll");
-        m.insert("25423", "enslave");
-        m.insert("25424", "ensnare");
-        m.insert("25425", "ensure");
-        m.insert("25426", "entail");
-        m.insert("25431", "entangled");
-        m.insert("25432", "entering");
-        m.insert("25433", "entertain");
-        m.insert("25434", "enticing");
-        m.insert("25435", "entire");
-        m.insert("25436", "entitle");
-        m.insert("25441", "entity");
-        m.insert("25442", "entomb");
-        m.insert("25443", "entourage");
-        m.insert("25444", "entrap");
-        m.insert("25445", "entree");
-        m.insert("25446", "entrench");
-        m.insert("25451", "entrust");
-        m.insert("25452", "entryway");
-        m.insert("25453", "entwine");
-        m.insert("25454", "enunciate");
-        m.insert("25455", "envelope");
-        m.insert("25456", "enviable");
-        m.insert("25461", "enviably");
-        m.insert("25462", "envious");
-        m.insert("25463", "envision");
-        m.insert("25464", "envoy");
-        m.insert("25465", "envy");
-        m.insert("25466", "enzyme");
-        m.insert("25511", "epic");
-        m.insert("25512", "epidemic");
-        m.insert("25513", "epidermal");
-        m.insert("25514", "epidermis");
-        m.insert("25515", "epidural");
-        m.insert("25516", "epilepsy");
-        m.insert("25521", "epileptic");
-        m.insert("25522", "epilogue");
-        m.insert("25523", "epiphany");
-        m.insert("25524", "episode");
-        m.insert("25525", "equal");
-        m.insert("25526", "equate");
-        m.insert("25531", "equation");
-        m.insert("25532", "equator");
-        m.insert("25533", "equinox");
-        m.insert("25534", "equipment");
-        m.insert("25535", "equity");
-        m.insert("25536", "equivocal");
-        m.insert("25541", "eradicate");
-        m.insert("25542", "erasable");
-        m.insert("25543", "erased");
-        m.insert("25544", "eraser");
-        m.insert("25545", "erasure");
-        m.insert("25546", "ergonomic");
-        m.insert("25551", "errand");
-        m.insert("25552", "errant");
-        m.insert("25553", "erratic");
-        m.insert("25554", "error");
-        m.insert("25555", "erupt");
-        m.insert("25556", "escalate");
-        m.insert("25561", "escalator");
-        m.insert("25562", "escapable");
-        m.insert("25563", "escapade");
-        m.insert("25564", "escapist");
-        m.insert("25565", "escargot");
-        m.insert("25566", "eskimo");
-        m.insert("25611", "esophagus");
-        m.insert("25612", "espionage");
-        m.insert("25613", "espresso");
-        m.insert("25614", "esquire");
-        m.insert("25615", "essay");
-        m.insert("25616", "essence");
-        m.insert("25621", "essential");
-        m.insert("25622", "establish");
-        m.insert("25623", "estate");
-        m.insert("25624", "esteemed");
-        m.insert("25625", "estimate");
-        m.insert("25626", "estimator");
-        m.insert("25631", "estranged");
-        m.insert("25632", "estrogen");
-        m.insert("25633", "etching");
-        m.insert("25634", "eternal");
-        m.insert("25635", "eternity");
-        m.insert("25636", "ethanol");
-        m.insert("25641", "ether");
-        m.insert("25642", "ethically");
-        m.insert("25643", "ethics");
-        m.insert("25644", "euphemism");
-        m.insert("25645", "evacuate");
-        m.insert("25646", "evacuee");
-        m.insert("25651", "evade");
-        m.insert("25652", "evaluate");
-        m.insert("25653", "evaluator");
-        m.insert("25654", "evaporate");
-        m.insert("25655", "evasion");
-        m.insert("25656", "evasive");
-        m.insert("25661", "even");
-        m.insert("25662", "everglade");
-        m.insert("25663", "evergreen");
-        m.insert("25664", "everybody");
-        m.insert("25665", "everyday");
-        m.insert("25666", "everyone");
-        m.insert("26111", "evict");
-        m.insert("26112", "evidence");
-        m.insert("26113", "evident");
-        m.insert("26114", "evil");
-        m.insert("26115", "evoke");
-        m.insert("26116", "evolution");
-        m.insert("26121", "evolve");
-        m.insert("26122", "exact");
-        m.insert("26123", "exalted");
-        m.insert("26124", "example");
-        m.insert("26125", "excavate");
-        m.insert("26126", "excavator");
-        m.insert("26131", "exceeding");
-        m.insert("26132", "exception");
-        m.insert("26133", "excess");
-        m.insert("26134", "exchange");
-        m.insert("26135", "excitable");
-        m.insert("26136", "exciting");
-        m.insert("26141", "exclaim");
-        m.insert("26142", "exclude");
-        m.insert("26143", "excluding");
-        m.insert("26144", "exclusion");
-        m.insert("26145", "exclusive");
-        m.insert("26146", "excretion");
-        m.insert("26151", "excretory");
-        m.insert("26152", "excursion");
-        m.insert("26153", "excusable");
-        m.insert("26154", "excusably");
-        m.insert("26155", "excuse");
-        m.insert("26156", "exemplary");
-        m.insert("26161", "exemplify");
-        m.insert("26162", "exemption");
-        m.insert("26163", "exerciser");
-        m.insert("26164", "exert");
-        m.insert("26165", "exes");
-        m.insert("26166", "exfoliate");
-        m.insert("26211", "exhale");
-        m.insert("26212", "exhaust");
-        m.insert("26213", "exhume");
-        m.insert("26214", "exile");
-        m.insert("26215", "existing");
-        m.insert("26216", "exit");
-        m.insert("26221", "exodus");
-        m.insert("26222", "exonerate");
-        m.insert("26223", "exorcism");
-        m.insert("26224", "exorcist");
-        m.insert("26225", "expand");
-        m.insert("26226", "expanse");
-        m.insert("26231", "expansion");
-        m.insert("26232", "expansive");
-        m.insert("26233", "expectant");
-        m.insert("26234", "expedited");
-        m.insert("26235", "expediter");
-        m.insert("26236", "expel");
-        m.insert("26241", "expend");
-        m.insert("26242", "expenses");
-        m.insert("26243", "expensive");
-        m.insert("26244", "expert");
-        m.insert("26245", "expire");
-        m.insert("26246", "expiring");
-        m.insert("26251", "explain");
-        m.insert("26252", "expletive");
-        m.insert("26253", "explicit");
-        m.insert("26254", "explode");
-        m.insert("26255", "exploit");
-        m.insert("26256", "explore");
-        m.insert("26261", "exploring");
-        m.insert("26262", "exponent");
-        m.insert("26263", "exporter");
-        m.insert("26264", "exposable");
-        m.insert("26265", "expose");
-        m.insert("26266", "exposure");
-        m.insert("26311", "express");
-        m.insert("26312", "expulsion");
-        m.insert("26313", "exquisite");
-        m.insert("26314", "extended");
-        m.insert("26315", "extending");
-        m.insert("26316", "extent");
-        m.insert("26321", "extenuate");
-        m.insert("26322", "exterior");
-        m.insert("26323", "external");
-        m.insert("26324", "extinct");
-        m.insert("26325", "extortion");
-        m.insert("26326", "extradite");
-        m.insert("26331", "extras");
-        m.insert("26332", "extrovert");
-        m.insert("26333", "extrude");
-        m.insert("26334", "extruding");
-        m.insert("26335", "exuberant");
-        m.insert("26336", "fable");
-        m.insert("26341", "fabric");
-        m.insert("26342", "fabulous");
-        m.insert("26343", "facebook");
-        m.insert("26344", "facecloth");
-        m.insert("26345", "facedown");
-        m.insert("26346", "faceless");
-        m.insert("26351", "facelift");
-        m.insert("26352", "faceplate");
-        m.insert("26353", "faceted");
-        m.insert("26354", "facial");
-        m.insert("26355", "facility");
-        m.insert("26356", "facing");
-        m.insert("26361", "facsimile");
-        m.insert("26362", "faction");
-        m.insert("26363", "factoid");
-        m.insert("26364", "factor");
-        m.insert("26365", "factsheet");
-        m.insert("26366", "factual");
-        m.insert("26411", "faculty");
-        m.insert("26412", "fade");
-        m.insert("26413", "fading");
-        m.insert("26414", "failing");
-        m.insert("26415", "falcon");
-        m.insert("26416", "fall");
-        m.insert("26421", "false");
-        m.insert("26422", "falsify");
-        m.insert("26423", "fame");
-        m.insert("26424", "familiar");
-        m.insert("26425", "family");
-        m.insert("26426", "famine");
-        m.insert("26431", "famished");
-        m.insert("26432", "fanatic");
-        m.insert("26433", "fancied");
-        m.insert("26434", "fanciness");
-        m.insert("26435", "fancy");
-        m.insert("26436", "fanfare");
-        m.insert("26441", "fang");
-        m.insert("26442", "fanning");
-        m.insert("26443", "fantasize");
-        m.insert("26444", "fantastic");
-        m.insert("26445", "fantasy");
-        m.insert("26446", "fascism");
-        m.insert("26451", "fastball");
-        m.insert("26452", "faster");
-        m.insert("26453", "fasting");
-        m.insert("26454", "fastness");
-        m.insert("26455", "faucet");
-        m.insert("26456", "favorable");
-        m.insert("26461", "favorably");
-        m.insert("26462", "favored");
-        m.insert("26463", "favoring");
-        m.insert("26464", "favorite");
-        m.insert("26465", "fax");
-        m.insert("26466", "feast");
-        m.insert("26511", "federal");
-        m.insert("26512", "fedora");
-        m.insert("26513", "feeble");
-        m.insert("26514", "feed");
-        m.insert("26515", "feel");
-        m.insert("26516", "feisty");
-        m.insert("26521", "feline");
-        m.insert("26522", "felt-tip");
-        m.insert("26523", "feminine");
-        m.insert("26524", "feminism");
-        m.insert("26525", "feminist");
-        m.insert("26526", "feminize");
-        m.insert("26531", "femur");
-        m.insert("26532", "fence");
-        m.insert("26533", "fencing");
-        m.insert("26534", "fender");
-        m.insert("26535", "ferment");
-        m.insert("26536", "fernlike");
-        m.insert("26541", "ferocious");
-        m.insert("26542", "ferocity");
-        m.insert("26543", "ferret");
-        m.insert("26544", "ferris");
-        m.insert("26545", "ferry");
-        m.insert("26546", "fervor");
-        m.insert("26551", "fester");
-        m.insert("26552", "festival");
-        m.insert("26553", "festive");
-        m.insert("26554", "festivity");
-        m.insert("26555", "fetal");
-        m.insert("26556", "fetch");
-        m.insert("26561", "fever");
-        m.insert("26562", "fiber");
-        m.insert("26563", "fiction");
-        m.insert("26564", "fiddle");
-        m.insert("26565", "fiddling");
-        m.insert("26566", "fidelity");
-        m.insert("26611", "fidgeting");
-        m.insert("26612", "fidgety");
-        m.insert("26613", "fifteen");
-        m.insert("26614", "fifth");
-        m.insert("26615", "fiftieth");
-        m.insert("26616", "fifty");
-        m.insert("26621", "figment");
-        m.insert("26622", "figure");
-        m.insert("26623", "figurine");
-        m.insert("26624", "filing");
-        m.insert("26625", "filled");
-        m.insert("26626", "filler");
-        m.insert("26631", "filling");
-        m.insert("26632", "film");
-        m.insert("26633", "filter");
-        m.insert("26634", "filth");
-        m.insert("26635", "filtrate");
-        m.insert("26636", "finale");
-        m.insert("26641", "finalist");
-        m.insert("26642", "finalize");
-        m.insert("26643", "finally");
-        m.insert("26644", "finance");
-        m.insert("26645", "financial");
-        m.insert("26646", "finch");
-        m.insert("26651", "fineness");
-        m.insert("26652", "finer");
-        m.insert("26653", "finicky");
-        m.insert("26654", "finished");
-        m.insert("26655", "finisher");
-        m.insert("26656", "finishing");
-        m.insert("26661", "finite");
-        m.insert("26662", "finless");
-        m.insert("26663", "finlike");
-        m.insert("26664", "fiscally");
-        m.insert("26665", "fit");
-        m.insert("26666", "five");
-        m.insert("31111", "flaccid");
-        m.insert("31112", "flagman");
-        m.insert("31113", "flagpole");
-        m.insert("31114", "flagship");
-        m.insert("31115", "flagstick");
-        m.insert("31116", "flagstone");
-        m.insert("31121", "flail");
-        m.insert("31122", "flakily");
-        m.insert("31123", "flaky");
-        m.insert("31124", "flame");
-        m.insert("31125", "flammable");
-        m.insert("31126", "flanked");
-        m.insert("31131", "flanking");
-        m.insert("31132", "flannels");
-        m.insert("31133", "flap");
-        m.insert("31134", "flaring");
-        m.insert("31135", "flashback");
-        m.insert("31136", "flashbulb");
-        m.insert("31141", "flashcard");
-        m.insert("31142", "flashily");
-        m.insert("31143", "flashing");
-        m.insert("31144", "flashy");
-        m.insert("31145", "flask");
-        m.insert("31146", "flatbed");
-        m.insert("31151", "flatfoot");
-        m.insert("31152", "flatly");
-        m.insert("31153", "flatness");
-        m.insert("31154", "flatten");
-        m.insert("31155", "flattered");
-        m.insert("31156", "flatterer");
-        m.insert("31161", "flattery");
-        m.insert("31162", "flattop");
-        m.insert("31163", "flatware");
-        m.insert("31164", "flatworm");
-        m.insert("31165", "flavored");
-        m.insert("31166", "flavorful");
-        m.insert("31211", "flavoring");
-        m.insert("31212", "flaxseed");
-        m.insert("31213", "fled");
-        m.insert("31214", "fleshed");
-        m.insert("31215", "fleshy");
-        m.insert("31216", "flick");
-        m.insert("31221", "flier");
-        m.insert("31222", "flight");
-        m.insert("31223", "flinch");
-        m.insert("31224", "fling");
-        m.insert("31225", "flint");
-        m.insert("31226", "flip");
-        m.insert("31231", "flirt");
-        m.insert("31232", "float");
-        m.insert("31233", "flock");
-        m.insert("31234", "flogging");
-        m.insert("31235", "flop");
-        m.insert("31236", "floral");
-        m.insert("31241", "florist");
-        m.insert("31242", "floss");
-        m.insert("31243", "flounder");
-        m.insert("31244", "flyable");
-        m.insert("31245", "flyaway");
-        m.insert("31246", "flyer");
-        m.insert("31251", "flying");
-        m.insert("31252", "flyover");
-        m.insert("31253", "flypaper");
-        m.insert("31254", "foam");
-        m.insert("31255", "foe");
-        m.insert("31256", "fog");
-        m.insert("31261", "foil");
-        m.insert("31262", "folic");
-        m.insert("31263", "folk");
-        m.insert("31264", "follicle");
-        m.insert("31265", "follow");
-        m.insert("31266", "fondling");
-        m.insert("31311", "fondly");
-        m.insert("31312", "fondness");
-        m.insert("31313", "fondue");
-        m.insert("31314", "font");
-        m.insert("31315", "food");
-        m.insert("31316", "fool");
-        m.insert("31321", "footage");
-        m.insert("31322", "football");
-        m.insert("31323", "footbath");
-        m.insert("31324", "footboard");
-        m.insert("31325", "footer");
-        m.insert("31326", "footgear");
-        m.insert("31331", "foothill");
-        m.insert("31332", "foothold");
-        m.insert("31333", "footing");
-        m.insert("31334", "footless");
-        m.insert("31335", "footman");
-        m.insert("31336", "footnote");
-        m.insert("31341", "footpad");
-        m.insert("31342", "footpath");
-        m.insert("31343", "footprint");
-        m.insert("31344", "footrest");
-        m.insert("31345", "footsie");
-        m.insert("31346", "footsore");
-        m.insert("31351", "footwear");
-        m.insert("31352", "footwork");
-        m.insert("31353", "fossil");
-        m.insert("31354", "foster");
-        m.insert("31355", "founder");
-        m.insert("31356", "founding");
-        m.insert("31361", "fountain");
-        m.insert("31362", "fox");
-        m.insert("31363", "foyer");
-        m.insert("31364", "fraction");
-        m.insert("31365", "fracture");
-        m.insert("31366", "fragile");
-        m.insert("31411", "fragility");
-        m.insert("31412", "fragment");
-        m.insert("31413", "fragrance");
-        m.insert("31414", "fragrant");
-        m.insert("31415", "frail");
-        m.insert("31416", "frame");
-        m.insert("31421", "framing");
-        m.insert("31422", "frantic");
-        m.insert("31423", "fraternal");
-        m.insert("31424", "frayed");
-        m.insert("31425", "fraying");
-        m.insert("31426", "frays");
-        m.insert("31431", "freckled");
-        m.insert("31432", "freckles");
-        m.insert("31433", "freebase");
-        m.insert("31434", "freebee");
-        m.insert("31435", "freebie");
-        m.insert("31436", "freedom");
-        m.insert("31441", "freefall");
-        m.insert("31442", "freehand");
-        m.insert("31443", "freeing");
-        m.insert("31444", "freeload");
-        m.insert("31445", "freely");
-        m.insert("31446", "freemason");
-        m.insert("31451", "freeness");
-        m.insert("31452", "freestyle");
-        m.insert("31453", "freeware");
-        m.insert("31454", "freeway");
-        m.insert("31455", "freewill");
-        m.insert("31456", "freezable");
-        m.insert("31461", "freezing");
-        m.insert("31462", "freight");
-        m.insert("31463", "french");
-        m.insert("31464", "frenzied");
-        m.insert("31465", "frenzy");
-        m.insert("31466", "frequency");
-        m.insert("31511", "frequent");
-        m.insert("31512", "fresh");
-        m.insert("31513", "fretful");
-        m.insert("31514", "fretted");
-        m.insert("31515", "friction");
-        m.insert("31516", "friday");
-        m.insert("31521", "fridge");
-        m.insert("31522", "fried");
-        m.insert("31523", "friend");
-        m.insert("31524", "frighten");
-        m.insert("31525", "frightful");
-        m.insert("31526", "frigidity");
-        m.insert("31531", "frigidly");
-        m.insert("31532", "frill");
-        m.insert("31533", "fringe");
-        m.insert("31534", "frisbee");
-        m.insert("31535", "frisk");
-        m.insert("31536", "fritter");
-        m.insert("31541", "frivolous");
-        m.insert("31542", "frolic");
-        m.insert("31543", "from");
-        m.insert("31544", "front");
-        m.insert("31545", "frostbite");
-        m.insert("31546", "frosted");
-        m.insert("31551", "frostily");
-        m.insert("31552", "frosting");
-        m.insert("31553", "frostlike");
-        m.insert("31554", "frosty");
-        m.insert("31555", "froth");
-        m.insert("31556", "frown");
-        m.insert("31561", "frozen");
-        m.insert("31562", "fructose");
-        m.insert("31563", "frugality");
-        m.insert("31564", "frugally");
-        m.insert("31565", "fruit");
-        m.insert("31566", "frustrate");
-        m.insert("31611", "frying");
-        m.insert("31612", "gab");
-        m.insert("31613", "gaffe");
-        m.insert("31614", "gag");
-        m.insert("31615", "gainfully");
-        m.insert("31616", "gaining");
-        m.insert("31621", "gains");
-        m.insert("31622", "gala");
-        m.insert("31623", "gallantly");
-        m.insert("31624", "galleria");
-        m.insert("31625", "gallery");
-        m.insert("31626", "galley");
-        m.insert("31631", "gallon");
-        m.insert("31632", "gallows");
-        m.insert("31633", "gallstone");
-        m.insert("31634", "galore");
-        m.insert("31635", "galvanize");
-        m.insert("31636", "gambling");
-        m.insert("31641", "game");
-        m.insert("31642", "gaming");
-        m.insert("31643", "gamma");
-        m.insert("31644", "gander");
-        m.insert("31645", "gangly");
-        m.insert("31646", "gangrene");
-        m.insert("31651", "gangway");
-        m.insert("31652", "gap");
-        m.insert("31653", "garage");
-        m.insert("31654", "garbage");
-        m.insert("31655", "garden");
-        m.insert("31656", "gargle");
-        m.insert("31661", "garland");
-        m.insert("31662", "garlic");
-        m.insert("31663", "garment");
-        m.insert("31664", "garnet");
-        m.insert("31665", "garnish");
-        m.insert("31666", "garter");
-        m.insert("32111", "gas");
-        m.insert("32112", "gatherer");
-        m.insert("32113", "gathering");
-        m.insert("32114", "gating");
-        m.insert("32115", "gauging");
-        m.insert("32116", "gauntlet");
-        m.insert("32121", "gauze");
-        m.insert("32122", "gave");
-        m.insert("32123", "gawk");
-        m.insert("32124", "gazing");
-        m.insert("32125", "gear");
-        m.insert("32126", "gecko");
-        m.insert("32131", "geek");
-        m.insert("32132", "geiger");
-        m.insert("32133", "gem");
-        m.insert("32134", "gender");
-        m.insert("32135", "generic");
-        m.insert("32136", "generous");
-        m.insert("32141", "genetics");
-        m.insert("32142", "genre");
-        m.insert("32143", "gentile");
-        m.insert("32144", "gentleman");
-        m.insert("32145", "gently");
-        m.insert("32146", "gents");
-        m.insert("32151", "geography");
-        m.insert("32152", "geologic");
-        m.insert("32153", "geologist");
-        m.insert("32154", "geology");
-        m.insert("32155", "geometric");
-        m.insert("32156", "geometry");
-        m.insert("32161", "geranium");
-        m.insert("32162", "gerbil");
-        m.insert("32163", "geriatric");
-        m.insert("32164", "germicide");
-        m.insert("32165", "germinate");
-        m.insert("32166", "germless");
-        m.insert("32211", "germproof");
-        m.insert("32212", "gestate");
-        m.insert("32213", "gestation");
-        m.insert("32214", "gesture");
-        m.insert("32215", "getaway");
-        m.insert("32216", "getting");
-        m.insert("32221", "getup");
-        m.insert("32222", "giant");
-        m.insert("32223", "gibberish");
-        m.insert("32224", "giblet");
-        m.insert("32225", "giddily");
-        m.insert("32226", "giddiness");
-        m.insert("32231", "giddy");
-        m.insert("32232", "gift");
-        m.insert("32233", "gigabyte");
-        m.insert("32234", "gigahertz");
-        m.insert("32235", "gigantic");
-        m.insert("32236", "giggle");
-        m.insert("32241", "giggling");
-        m.insert("32242", "giggly");
-        m.insert("32243", "gigolo");
-        m.insert("32244", "gilled");
-        m.insert("32245", "gills");
-        m.insert("32246", "gimmick");
-        m.insert("32251", "girdle");
-        m.insert("32252", "giveaway");
-        m.insert("32253", "given");
-        m.insert("32254", "giver");
-        m.insert("32255", "giving");
-        m.insert("32256", "gizmo");
-        m.insert("32261", "gizzard");
-        m.insert("32262", "glacial");
-        m.insert("32263", "glacier");
-        m.insert("32264", "glade");
-        m.insert("32265", "gladiator");
-        m.insert("32266", "gladly");
-        m.insert("32311", "glamorous");
-        m.insert("32312", "glamour");
-        m.insert("32313", "glance");
-        m.insert("32314", "glancing");
-        m.insert("32315", "glandular");
-        m.insert("32316", "glare");
-        m.insert("32321", "glaring");
-        m.insert("32322", "glass");
-        m.insert("32323", "glaucoma");
-        m.insert("32324", "glazing");
-        m.insert("32325", "gleaming");
-        m.insert("32326", "gleeful");
-        m.insert("32331", "glider");
-        m.insert("32332", "gliding");
-        m.insert("32333", "glimmer");
-        m.insert("32334", "glimpse");
-        m.insert("32335", "glisten");
-        m.insert("32336", "glitch");
-        m.insert("32341", "glitter");
-        m.insert("32342", "glitzy");
-        m.insert("32343", "gloater");
-        m.insert("32344", "gloating");
-        m.insert("32345", "gloomily");
-        m.insert("32346", "gloomy");
-        m.insert("32351", "glorified");
-        m.insert("32352", "glorifier");
-        m.insert("32353", "glorify");
-        m.insert("32354", "glorious");
-        m.insert("32355", "glory");
-        m.insert("32356", "gloss");
-        m.insert("32361", "glove");
-        m.insert("32362", "glowing");
-        m.insert("32363", "glowworm");
-        m.insert("32364", "glucose");
-        m.insert("32365", "glue");
-        m.insert("32366", "gluten");
-        m.insert("32411", "glutinous");
-        m.insert("32412", "glutton");
-        m.insert("32413", "gnarly");
-        m.insert("32414", "gnat");
-        m.insert("32415", "goal");
-        m.insert("32416", "goatskin");
-        m.insert("32421", "goes");
-        m.insert("32422", "goggles");
-        m.insert("32423", "going");
-        m.insert("32424", "goldfish");
-        m.insert("32425", "goldmine");
-        m.insert("32426", "goldsmith");
-        m.insert("32431", "golf");
-        m.insert("32432", "goliath");
-        m.insert("32433", "gonad");
-        m.insert("32434", "gondola");
-        m.insert("32435", "gone");
-        m.insert("32436", "gong");
-        m.insert("32441", "good");
-        m.insert("32442", "gooey");
-        m.insert("32443", "goofball");
-        m.insert("32444", "goofiness");
-        m.insert("32445", "goofy");
-        m.insert("32446", "google");
-        m.insert("32451", "goon");
-        m.insert("32452", "gopher");
-        m.insert("32453", "gore");
-        m.insert("32454", "gorged");
-        m.insert("32455", "gorgeous");
-        m.insert("32456", "gory");
-        m.insert("32461", "gosling");
-        m.insert("32462", "gossip");
-        m.insert("32463", "gothic");
-        m.insert("32464", "gotten");
-        m.insert("32465", "gout");
-        m.insert("32466", "gown");
-        m.insert("32511", "grab");
-        m.insert("32512", "graceful");
-        m.insert("32513", "graceless");
-        m.insert("32514", "gracious");
-        m.insert("32515", "gradation");
-        m.insert("32516", "graded");
-        m.insert("32521", "grader");
-        m.insert("32522", "gradient");
-        m.insert("32523", "grading");
-        m.insert("32524", "gradually");
-        m.insert("32525", "graduate");
-        m.insert("32526", "graffiti");
-        m.insert("32531", "grafted");
-        m.insert("32532", "grafting");
-        m.insert("32533", "grain");
-        m.insert("32534", "granddad");
-        m.insert("32535", "grandkid");
-        m.insert("32536", "grandly");
-        m.insert("32541", "grandma");
-        m.insert("32542", "grandpa");
-        m.insert("32543", "grandson");
-        m.insert("32544", "granite");
-        m.insert("32545", "granny");
-        m.insert("32546", "granola");
-        m.insert("32551", "grant");
-        m.insert("32552", "granular");
-        m.insert("32553", "grape");
-        m.insert("32554", "graph");
-        m.insert("32555", "grapple");
-        m.insert("32556", "grappling");
-        m.insert("32561", "grasp");
-        m.insert("32562", "grass");
-        m.insert("32563", "gratified");
-        m.insert("32564", "gratify");
-        m.insert("32565", "grating");
-        m.insert("32566", "gratitude");
-        m.insert("32611", "gratuity");
-        m.insert("32612", "gravel");
-        m.insert("32613", "graveness");
-        m.insert("32614", "graves");
-        m.insert("32615", "graveyard");
-        m.insert("32616", "gravitate");
-        m.insert("32621", "gravity");
-        m.insert("32622", "gravy");
-        m.insert("32623", "gray");
-        m.insert("32624", "grazing");
-        m.insert("32625", "greasily");
-        m.insert("32626", "greedily");
-        m.insert("32631", "greedless");
-        m.insert("32632", "greedy");
-        m.insert("32633", "green");
-        m.insert("32634", "greeter");
-        m.insert("32635", "greeting");
-        m.insert("32636", "grew");
-        m.insert("32641", "greyhound");
-        m.insert("32642", "grid");
-        m.insert("32643", "grief");
-        m.insert("32644", "grievance");
-        m.insert("32645", "grieving");
-        m.insert("32646", "grievous");
-        m.insert("32651", "grill");
-        m.insert("32652", "grimace");
-        m.insert("32653", "grimacing");
-        m.insert("32654", "grime");
-        m.insert("32655", "griminess");
-        m.insert("32656", "grimy");
-        m.insert("32661", "grinch");
-        m.insert("32662", "grinning");
-        m.insert("32663", "grip");
-        m.insert("32664", "gristle");
-        m.insert("32665", "grit");
-        m.insert("32666", "groggily");
-        m.insert("33111", "groggy");
-        m.insert("33112", "groin");
-        m.insert("33113", "groom");
-        m.insert("33114", "groove");
-        m.insert("33115", "grooving");
-        m.insert("33116", "groovy");
-        m.insert("33121", "grope");
-        m.insert("33122", "ground");
-        m.insert("33123", "grouped");
-        m.insert("33124", "grout");
-        m.insert("33125", "grove");
-        m.insert("33126", "grower");
-        m.insert("33131", "growing");
-        m.insert("33132", "growl");
-        m.insert("33133", "grub");
-        m.insert("33134", "grudge");
-        m.insert("33135", "grudging");
-        m.insert("33136", "grueling");
-        m.insert("33141", "gruffly");
-        m.insert("33142", "grumble");
-        m.insert("33143", "grumbling");
-        m.insert("33144", "grumbly");
-        m.insert("33145", "grumpily");
-        m.insert("33146", "grunge");
-        m.insert("33151", "grunt");
-        m.insert("33152", "guacamole");
-        m.insert("33153", "guidable");
-        m.insert("33154", "guidance");
-        m.insert("33155", "guide");
-        m.insert("33156", "guiding");
-        m.insert("33161", "guileless");
-        m.insert("33162", "guise");
-        m.insert("33163", "gulf");
-        m.insert("33164", "gullible");
-        m.insert("33165", "gully");
-        m.insert("33166", "gulp");
-        m.insert("33211", "gumball");
-        m.insert("33212", "gumdrop");
-        m.insert("33213", "gumminess");
-        m.insert("33214", "gumming");
-        m.insert("33215", "gummy");
-        m.insert("33216", "gurgle");
-        m.insert("33221", "gurgling");
-        m.insert("33222", "guru");
-        m.insert("33223", "gush");
-        m.insert("33224", "gusto");
-        m.insert("33225", "gusty");
-        m.insert("33226", "gutless");
-        m.insert("33231", "guts");
-        m.insert("33232", "gutter");
-        m.insert("33233", "guy");
-        m.insert("33234", "guzzler");
-        m.insert("33235", "gyration");
-        m.insert("33236", "habitable");
-        m.insert("33241", "habitant");
-        m.insert("33242", "habitat");
-        m.insert("33243", "habitual");
-        m.insert("33244", "hacked");
-        m.insert("33245", "hacker");
-        m.insert("33246", "hacking");
-        m.insert("33251", "hacksaw");
-        m.insert("33252", "had");
-        m.insert("33253", "haggler");
-        m.insert("33254", "haiku");
-        m.insert("33255", "half");
-        m.insert("33256", "halogen");
-        m.insert("33261", "halt");
-        m.insert("33262", "halved");
-        m.insert("33263", "halves");
-        m.insert("33264", "hamburger");
-        m.insert("33265", "hamlet");
-        m.insert("33266", "hammock");
-        m.insert("33311", "hamper");
-        m.insert("33312", "hamster");
-        m.insert("33313", "hamstring");
-        m.insert("33314", "handbag");
-        m.insert("33315", "handball");
-        m.insert("33316", "handbook");
-        m.insert("33321", "handbrake");
-        m.insert("33322", "handcart");
-        m.insert("33323", "handclap");
-        m.insert("33324", "handclasp");
-        m.insert("33325", "handcraft");
-        m.insert("33326", "handcuff");
-        m.insert("33331", "handed");
-        m.insert("33332", "handful");
-        m.insert("33333", "handgrip");
-        m.insert("33334", "handgun");
-        m.insert("33335", "handheld");
-        m.insert("33336", "handiness");
-        m.insert("33341", "handiwork");
-        m.insert("33342", "handlebar");
-        m.insert("33343", "handled");
-        m.insert("33344", "handler");
-        m.insert("33345", "handling");
-        m.insert("33346", "handmade");
-        m.insert("33351", "handoff");
-        m.insert("33352", "handpick");
-        m.insert("33353", "handprint");
-        m.insert("33354", "handrail");
-        m.insert("33355", "handsaw");
-        m.insert("33356", "handset");
-        m.insert("33361", "handsfree");
-        m.insert("33362", "handshake");
-        m.insert("33363", "handstand");
-        m.insert("33364", "handwash");
-        m.insert("33365", "handwork");
-        m.insert("33366", "handwoven");
-        m.insert("33411", "handwrite");
-        m.insert("33412", "handyman");
-        m.insert("33413", "hangnail");
-        m.insert("33414", "hangout");
-        m.insert("33415", "hangover");
-        m.insert("33416", "hangup");
-        m.insert("33421", "hankering");
-        m.insert("33422", "hankie");
-        m.insert("33423", "hanky");
-        m.insert("33424", "haphazard");
-        m.insert("33425", "happening");
-        m.insert("33426", "happier");
-        m.insert("33431", "happiest");
-        m.insert("33432", "happily");
-        m.insert("33433", "happiness");
-        m.insert("33434", "happy");
-        m.insert("33435", "harbor");
-        m.insert("33436", "hardcopy");
-        m.insert("33441", "hardcore");
-        m.insert("33442", "hardcover");
-        m.insert("33443", "harddisk");
-        m.insert("33444", "hardened");
-        m.insert("33445", "hardener");
-        m.insert("33446", "hardening");
-        m.insert("33451", "hardhat");
-        m.insert("33452", "hardhead");
-        m.insert("33453", "hardiness");
-        m.insert("33454", "hardly");
-        m.insert("33455", "hardness");
-        m.insert("33456", "hardship");
-        m.insert("33461", "hardware");
-        m.insert("33462", "hardwired");
-        m.insert("33463", "hardwood");
-        m.insert("33464", "hardy");
-        m.insert("33465", "harmful");
-        m.insert("33466", "harmless");
-        m.insert("33511", "harmonica");
-        m.insert("33512", "harmonics");
-        m.insert("33513", "harmonize");
-        m.insert("33514", "harmony");
-        m.insert("33515", "harness");
-        m.insert("33516", "harpist");
-        m.insert("33521", "harsh");
-        m.insert("33522", "harvest");
-        m.insert("33523", "hash");
-        m.insert("33524", "hassle");
-        m.insert("33525", "haste");
-        m.insert("33526", "hastily");
-        m.insert("33531", "hastiness");
-        m.insert("33532", "hasty");
-        m.insert("33533", "hatbox");
-        m.insert("33534", "hatchback");
-        m.insert("33535", "hatchery");
-        m.insert("33536", "hatchet");
-        m.insert("33541", "hatching");
-        m.insert("33542", "hatchling");
-        m.insert("33543", "hate");
-        m.insert("33544", "hatless");
-        m.insert("33545", "hatred");
-        m.insert("33546", "haunt");
-        m.insert("33551", "haven");
-        m.insert("33552", "hazard");
-        m.insert("33553", "hazelnut");
-        m.insert("33554", "hazily");
-        m.insert("33555", "haziness");
-        m.insert("33556", "hazing");
-        m.insert("33561", "hazy");
-        m.insert("33562", "headache");
-        m.insert("33563", "headband");
-        m.insert("33564", "headboard");
-        m.insert("33565", "headcount");
-        m.insert("33566", "headdress");
-        m.insert("33611", "headed");
-        m.insert("33612", "header");
-        m.insert("33613", "headfirst");
-        m.insert("33614", "headgear");
-        m.insert("33615", "heading");
-        m.insert("33616", "headlamp");
-        m.insert("33621", "headless");
-        m.insert("33622", "headlock");
-        m.insert("33623", "headphone");
-        m.insert("33624", "headpiece");
-        m.insert("33625", "headrest");
-        m.insert("33626", "headroom");
-        m.insert("33631", "headscarf");
-        m.insert("33632", "headset");
-        m.insert("33633", "headsman");
-        m.insert("33634", "headstand");
-        m.insert("33635", "headstone");
-        m.insert("33636", "headway");
-        m.insert("33641", "headwear");
-        m.insert("33642", "heap");
-        m.insert("33643", "heat");
-        m.insert("33644", "heave");
-        m.insert("33645", "heavily");
-        m.insert("33646", "heaviness");
-        m.insert("33651", "heaving");
-        m.insert("33652", "hedge");
-        m.insert("33653", "hedging");
-        m.insert("33654", "heftiness");
-        m.insert("33655", "hefty");
-        m.insert("33656", "helium");
-        m.insert("33661", "helmet");
-        m.insert("33662", "helper");
-        m.insert("33663", "helpful");
-        m.insert("33664", "helping");
-        m.insert("33665", "helpless");
-        m.insert("33666", "helpline");
-        m.insert("34111", "hemlock");
-        m.insert("34112", "hemstitch");
-        m.insert("34113", "hence");
-        m.insert("34114", "henchman");
-        m.insert("34115", "henna");
-        m.insert("34116", "herald");
-        m.insert("34121", "herbal");
-        m.insert("34122", "herbicide");
-        m.insert("34123", "herbs");
-        m.insert("34124", "heritage");
-        m.insert("34125", "hermit");
-        m.insert("34126", "heroics");
-        m.insert("34131", "heroism");
-        m.insert("34132", "herring");
-        m.insert("34133", "herself");
-        m.insert("34134", "hertz");
-        m.insert("34135", "hesitancy");
-        m.insert("34136", "hesitant");
-        m.insert("34141", "hesitate");
-        m.insert("34142", "hexagon");
-        m.insert("34143", "hexagram");
-        m.insert("34144", "hubcap");
-        m.insert("34145", "huddle");
-        m.insert("34146", "huddling");
-        m.insert("34151", "huff");
-        m.insert("34152", "hug");
-        m.insert("34153", "hula");
-        m.insert("34154", "hulk");
-        m.insert("34155", "hull");
-        m.insert("34156", "human");
-        m.insert("34161", "humble");
-        m.insert("34162", "humbling");
-        m.insert("34163", "humbly");
-        m.insert("34164", "humid");
-        m.insert("34165", "humiliate");
-        m.insert("34166", "humility");
-        m.insert("34211", "humming");
-        m.insert("34212", "hummus");
-        m.insert("34213", "humongous");
-        m.insert("34214", "humorist");
-        m.insert("34215", "humorless");
-        m.insert("34216", "humorous");
-        m.insert("34221", "humpback");
-        m.insert("34222", "humped");
-        m.insert("34223", "humvee");
-        m.insert("34224", "hunchback");
-        m.insert("34225", "hundredth");
-        m.insert("34226", "hunger");
-        m.insert("34231", "hungrily");
-        m.insert("34232", "hungry");
-        m.insert("34233", "hunk");
-        m.insert("34234", "hunter");
-        m.insert("34235", "hunting");
-        m.insert("34236", "huntress");
-        m.insert("34241", "huntsman");
-        m.insert("34242", "hurdle");
-        m.insert("34243", "hurled");
-        m.insert("34244", "hurler");
-        m.insert("34245", "hurling");
-        m.insert("34246", "hurray");
-        m.insert("34251", "hurricane");
-        m.insert("34252", "hurried");
-        m.insert("34253", "hurry");
-        m.insert("34254", "hurt");
-        m.insert("34255", "husband");
-        m.insert("34256", "hush");
-        m.insert("34261", "husked");
-        m.insert("34262", "huskiness");
-        m.insert("34263", "hut");
-        m.insert("34264", "hybrid");
-        m.insert("34265", "hydrant");
-        m.insert("34266", "hydrated");
-        m.insert("34311", "hydration");
-        m.insert("34312", "hydrogen");
-        m.insert("34313", "hydroxide");
-        m.insert("34314", "hyperlink");
-        m.insert("34315", "hypertext");
-        m.insert("34316", "hyphen");
-        m.insert("34321", "hypnoses");
-        m.insert("34322", "hypnosis");
-        m.insert("34323", "hypnotic");
-        m.insert("34324", "hypnotism");
-        m.insert("34325", "hypnotist");
-        m.insert("34326", "hypnotize");
-        m.insert("34331", "hypocrisy");
-        m.insert("34332", "hypocrite");
-        m.insert("34333", "ibuprofen");
-        m.insert("34334", "ice");
-        m.insert("34335", "iciness");
-        m.insert("34336", "icing");
-        m.insert("34341", "icky");
-        m.insert("34342", "icon");
-        m.insert("34343", "icy");
-        m.insert("34344", "idealism");
-        m.insert("34345", "idealist");
-        m.insert("34346", "idealize");
-        m.insert("34351", "ideally");
-        m.insert("34352", "idealness");
-        m.insert("34353", "identical");
-        m.insert("34354", "identify");
-        m.insert("34355", "identity");
-        m.insert("34356", "ideology");
-        m.insert("34361", "idiocy");
-        m.insert("34362", "idiom");
-        m.insert("34363", "idly");
-        m.insert("34364", "igloo");
-        m.insert("34365", "ignition");
-        m.insert("34366", "ignore");
-        m.insert("34411", "iguana");
-        m.insert("34412", "illicitly");
-        m.insert("34413", "illusion");
-        m.insert("34414", "illusive");
-        m.insert("34415", "image");
-        m.insert("34416", "imaginary");
-        m.insert("34421", "imagines");
-        m.insert("34422", "imaging");
-        m.insert("34423", "imbecile");
-        m.insert("34424", "imitate");
-        m.insert("34425", "imitation");
-        m.insert("34426", "immature");
-        m.insert("34431", "immerse");
-        m.insert("34432", "immersion");
-        m.insert("34433", "imminent");
-        m.insert("34434", "immobile");
-        m.insert("34435", "immodest");
-        m.insert("34436", "immorally");
-        m.insert("34441", "immortal");
-        m.insert("34442", "immovable");
-        m.insert("34443", "immovably");
-        m.insert("34444", "immunity");
-        m.insert("34445", "immunize");
-        m.insert("34446", "impaired");
-        m.insert("34451", "impale");
-        m.insert("34452", "impart");
-        m.insert("34453", "impatient");
-        m.insert("34454", "impeach");
-        m.insert("34455", "impeding");
-        m.insert("34456", "impending");
-        m.insert("34461", "imperfect");
-        m.insert("34462", "imperial");
-        m.insert("34463", "impish");
-        m.insert("34464", "implant");
-        m.insert("34465", "implement");
-        m.insert("34466", "implicate");
-        m.insert("34511", "implicit");
-        m.insert("34512", "implode");
-        m.insert("34513", "implosion");
-        m.insert("34514", "implosive");
-        m.insert("34515", "imply");
-        m.insert("34516", "impolite");
-        m.insert("34521", "important");
-        m.insert("34522", "importer");
-        m.insert("34523", "impose");
-        m.insert("34524", "imposing");
-        m.insert("34525", "impotence");
-        m.insert("34526", "impotency");
-        m.insert("34531", "impotent");
-        m.insert("34532", "impound");
-        m.insert("34533", "imprecise");
-        m.insert("34534", "imprint");
-        m.insert("34535", "imprison");
-        m.insert("34536", "impromptu");
-        m.insert("34541", "improper");
-        m.insert("34542", "improve");
-        m.insert("34543", "improving");
-        m.insert("34544", "improvise");
-        m.insert("34545", "imprudent");
-        m.insert("34546", "impulse");
-        m.insert("34551", "impulsive");
-        m.insert("34552", "impure");
-        m.insert("34553", "impurity");
-        m.insert("34554", "iodine");
-        m.insert("34555", "iodize");
-        m.insert("34556", "ion");
-        m.insert("34561", "ipad");
-        m.insert("34562", "iphone");
-        m.insert("34563", "ipod");
-        m.insert("34564", "irate");
-        m.insert("34565", "irk");
-        m.insert("34566", "iron");
-        m.insert("34611", "irregular");
-        m.insert("34612", "irrigate");
-        m.insert("34613", "irritable");
-        m.insert("34614", "irritably");
-        m.insert("34615", "irritant");
-        m.insert("34616", "irritate");
-        m.insert("34621", "islamic");
-        m.insert("34622", "islamist");
-        m.insert("34623", "isolated");
-        m.insert("34624", "isolating");
-        m.insert("34625", "isolation");
-        m.insert("34626", "isotope");
-        m.insert("34631", "issue");
-        m.insert("34632", "issuing");
-        m.insert("34633", "italicize");
-        m.insert("34634", "italics");
-        m.insert("34635", "item");
-        m.insert("34636", "itinerary");
-        m.insert("34641", "itunes");
-        m.insert("34642", "ivory");
-        m.insert("34643", "ivy");
-        m.insert("34644", "jab");
-        m.insert("34645", "jackal");
-        m.insert("34646", "jacket");
-        m.insert("34651", "jackknife");
-        m.insert("34652", "jackpot");
-        m.insert("34653", "jailbird");
-        m.insert("34654", "jailbreak");
-        m.insert("34655", "jailer");
-        m.insert("34656", "jailhouse");
-        m.insert("34661", "jalapeno");
-        m.insert("34662", "jam");
-        m.insert("34663", "janitor");
-        m.insert("34664", "january");
-        m.insert("34665", "jargon");
-        m.insert("34666", "jarring");
-        m.insert("35111", "jasmine");
-        m.insert("35112", "jaundice");
-        m.insert("35113", "jaunt");
-        m.insert("35114", "java");
-        m.insert("35115", "jawed");
-        m.insert("35116", "jawless");
-        m.insert("35121", "jawline");
-        m.insert("35122", "jaws");
-        m.insert("35123", "jaybird");
-        m.insert("35124", "jaywalker");
-        m.insert("35125", "jazz");
-        m.insert("35126", "jeep");
-        m.insert("35131", "jeeringly");
-        m.insert("35132", "jellied");
-        m.insert("35133", "jelly");
-        m.insert("35134", "jersey");
-        m.insert("35135", "jester");
-        m.insert("35136", "jet");
-        m.insert("35141", "jiffy");
-        m.insert("35142", "jigsaw");
-        m.insert("35143", "jimmy");
-        m.insert("35144", "jingle");
-        m.insert("35145", "jingling");
-        m.insert("35146", "jinx");
-        m.insert("35151", "jitters");
-        m.insert("35152", "jittery");
-        m.insert("35153", "job");
-        m.insert("35154", "jockey");
-        m.insert("35155", "jockstrap");
-        m.insert("35156", "jogger");
-        m.insert("35161", "jogging");
-        m.insert("35162", "john");
-        m.insert("35163", "joining");
-        m.insert("35164", "jokester");
-        m.insert("35165", "jokingly");
-        m.insert("35166", "jolliness");
-        m.insert("35211", "jolly");
-        m.insert("35212", "jolt");
-        m.insert("35213", "jot");
-        m.insert("35214", "jovial");
-        m.insert("35215", "joyfully");
-        m.insert("35216", "joylessly");
-        m.insert("35221", "joyous");
-        m.insert("35222", "joyride");
-        m.insert("35223", "joystick");
-        m.insert("35224", "jubilance");
-        m.insert("35225", "jubilant");
-        m.insert("35226", "judge");
-        m.insert("35231", "judgingly");
-        m.insert("35232", "judicial");
-        m.insert("35233", "judiciary");
-        m.insert("35234", "judo");
-        m.insert("35235", "juggle");
-        m.insert("35236", "juggling");
-        m.insert("35241", "jugular");
-        m.insert("35242", "juice");
-        m.insert("35243", "juiciness");
-        m.insert("35244", "juicy");
-        m.insert("35245", "jujitsu");
-        m.insert("35246", "jukebox");
-        m.insert("35251", "july");
-        m.insert("35252", "jumble");
-        m.insert("35253", "jumbo");
-        m.insert("35254", "jump");
-        m.insert("35255", "junction");
-        m.insert("35256", "juncture");
-        m.insert("35261", "june");
-        m.insert("35262", "junior");
-        m.insert("35263", "juniper");
-        m.insert("35264", "junkie");
-        m.insert("35265", "junkman");
-        m.insert("35266", "junkyard");
-        m.insert("35311", "jurist");
-        m.insert("35312", "juror");
-        m.insert("35313", "jury");
-        m.insert("35314", "justice");
-        m.insert("35315", "justifier");
-        m.insert("35316", "justify");
-        m.insert("35321", "justly");
-        m.insert("35322", "justness");
-        m.insert("35323", "juvenile");
-        m.insert("35324", "kabob");
-        m.insert("35325", "kangaroo");
-        m.insert("35326", "karaoke");
-        m.insert("35331", "karate");
-        m.insert("35332", "karma");
-        m.insert("35333", "kebab");
-        m.insert("35334", "keenly");
-        m.insert("35335", "keenness");
-        m.insert("35336", "keep");
-        m.insert("35341", "keg");
-        m.insert("35342", "kelp");
-        m.insert("35343", "kennel");
-        m.insert("35344", "kept");
-        m.insert("35345", "kerchief");
-        m.insert("35346", "kerosene");
-        m.insert("35351", "kettle");
-        m.insert("35352", "kick");
-        m.insert("35353", "kiln");
-        m.insert("35354", "kilobyte");
-        m.insert("35355", "kilogram");
-        m.insert("35356", "kilometer");
-        m.insert("35361", "kilowatt");
-        m.insert("35362", "kilt");
-        m.insert("35363", "kimono");
-        m.insert("35364", "kindle");
-        m.insert("35365", "kindling");
-        m.insert("35366", "kindly");
-        m.insert("35411", "kindness");
-        m.insert("35412", "kindred");
-        m.insert("35413", "kinetic");
-        m.insert("35414", "kinfolk");
-        m.insert("35415", "king");
-        m.insert("35416", "kinship");
-        m.insert("35421", "kinsman");
-        m.insert("35422", "kinswoman");
-        m.insert("35423", "kissable");
-        m.insert("35424", "kisser");
-        m.insert("35425", "kissing");
-        m.insert("35426", "kitchen");
-        m.insert("35431", "kite");
-        m.insert("35432", "kitten");
-        m.insert("35433", "kitty");
-        m.insert("35434", "kiwi");
-        m.insert("35435", "kleenex");
-        m.insert("35436", "knapsack");
-        m.insert("35441", "knee");
-        m.insert("35442", "knelt");
-        m.insert("35443", "knickers");
-        m.insert("35444", "knoll");
-        m.insert("35445", "koala");
-        m.insert("35446", "kooky");
-        m.insert("35451", "kosher");
-        m.insert("35452", "krypton");
-        m.insert("35453", "kudos");
-        m.insert("35454", "kung");
-        m.insert("35455", "labored");
-        m.insert("35456", "laborer");
-        m.insert("35461", "laboring");
-        m.insert("35462", "laborious");
-        m.insert("35463", "labrador");
-        m.insert("35464", "ladder");
-        m.insert("35465", "ladies");
-        m.insert("35466", "ladle");
-        m.insert("35511", "ladybug");
-        m.insert("35512", "ladylike");
-        m.insert("35513", "lagged");
-        m.insert("35514", "lagging");
-        m.insert("35515", "lagoon");
-        m.insert("35516", "lair");
-        m.insert("35521", "lake");
-        m.insert("35522", "lance");
-        m.insert("35523", "landed");
-        m.insert("35524", "landfall");
-        m.insert("35525", "landfill");
-        m.insert("35526", "landing");
-        m.insert("35531", "landlady");
-        m.insert("35532", "landless");
-        m.insert("35533", "landline");
-        m.insert("35534", "landlord");
-        m.insert("35535", "landmark");
-        m.insert("35536", "landmass");
-        m.insert("35541", "landmine");
-        m.insert("35542", "landowner");
-        m.insert("35543", "landscape");
-        m.insert("35544", "landside");
-        m.insert("35545", "landslide");
-        m.insert("35546", "language");
-        m.insert("35551", "lankiness");
-        m.insert("35552", "lanky");
-        m.insert("35553", "lantern");
-        m.insert("35554", "lapdog");
-        m.insert("35555", "lapel");
-        m.insert("35556", "lapped");
-        m.insert("35561", "lapping");
-        m.insert("35562", "laptop");
-        m.insert("35563", "lard");
-        m.insert("35564", "large");
-        m.insert("35565", "lark");
-        m.insert("35566", "lash");
-        m.insert("35611", "lasso");
-        m.insert("35612", "last");
-        m.insert("35613", "latch");
-        m.insert("35614", "late");
-        m.insert("35615", "lather");
-        m.insert("35616", "latitude");
-        m.insert("35621", "latrine");
-        m.insert("35622", "latter");
-        m.insert("35623", "latticed");
-        m.insert("35624", "launch");
-        m.insert("35625", "launder");
-        m.insert("35626", "laundry");
-        m.insert("35631", "laurel");
-        m.insert("35632", "lavender");
-        m.insert("35633", "lavish");
-        m.insert("35634", "laxative");
-        m.insert("35635", "lazily");
-        m.insert("35636", "laziness");
-        m.insert("35641", "lazy");
-        m.insert("35642", "lecturer");
-        m.insert("35643", "left");
-        m.insert("35644", "legacy");
-        m.insert("35645", "legal");
-        m.insert("35646", "legend");
-        m.insert("35651", "legged");
-        m.insert("35652", "leggings");
-        m.insert("35653", "legible");
-        m.insert("35654", "legibly");
-        m.insert("35655", "legislate");
-        m.insert("35656", "lego");
-        m.insert("35661", "legroom");
-        m.insert("35662", "legume");
-        m.insert("35663", "legwarmer");
-        m.insert("35664", "legwork");
-        m.insert("35665", "lemon");
-        m.insert("35666", "lend");
-        m.insert("36111", "length");
-        m.insert("36112", "lens");
-        m.insert("36113", "lent");
-        m.insert("36114", "leotard");
-        m.insert("36115", "lesser");
-        m.insert("36116", "letdown");
-        m.insert("36121", "lethargic");
-        m.insert("36122", "lethargy");
-        m.insert("36123", "letter");
-        m.insert("36124", "lettuce");
-        m.insert("36125", "level");
-        m.insert("36126", "leverage");
-        m.insert("36131", "levers");
-        m.insert("36132", "levitate");
-        m.insert("36133", "levitator");
-        m.insert("36134", "liability");
-        m.insert("36135", "liable");
-        m.insert("36136", "liberty");
-        m.insert("36141", "librarian");
-        m.insert("36142", "library");
-        m.insert("36143", "licking");
-        m.insert("36144", "licorice");
-        m.insert("36145", "lid");
-        m.insert("36146", "life");
-        m.insert("36151", "lifter");
-        m.insert("36152", "lifting");
-        m.insert("36153", "liftoff");
-        m.insert("36154", "ligament");
-        m.insert("36155", "likely");
-        m.insert("36156", "likeness");
-        m.insert("36161", "likewise");
-        m.insert("36162", "liking");
-        m.insert("36163", "lilac");
-        m.insert("36164", "lilly");
-        m.insert("36165", "lily");
-        m.insert("36166", "limb");
-        m.insert("36211", "limeade");
-        m.insert("36212", "limelight");
-        m.insert("36213", "limes");
-        m.insert("36214", "limit");
-        m.insert("36215", "limping");
-        m.insert("36216", "limpness");
-        m.insert("36221", "line");
-        m.insert("36222", "lingo");
-        m.insert("36223", "linguini");
-        m.insert("36224", "linguist");
-        m.insert("36225", "lining");
-        m.insert("36226", "linked");
-        m.insert("36231", "linoleum");
-        m.insert("36232", "linseed");
-        m.insert("36233", "lint");
-        m.insert("36234", "lion");
-        m.insert("36235", "lip");
-        m.insert("36236", "liquefy");
-        m.insert("36241", "liqueur");
-        m.insert("36242", "liquid");
-        m.insert("36243", "lisp");
-        m.insert("36244", "list");
-        m.insert("36245", "litigate");
-        m.insert("36246", "litigator");
-        m.insert("36251", "litmus");
-        m.insert("36252", "litter");
-        m.insert("36253", "little");
-        m.insert("36254", "livable");
-        m.insert("36255", "lived");
-        m.insert("36256", "lively");
-        m.insert("36261", "liver");
-        m.insert("36262", "livestock");
-        m.insert("36263", "lividly");
-        m.insert("36264", "living");
-        m.insert("36265", "lizard");
-        m.insert("36266", "lubricant");
-        m.insert("36311", "lubricate");
-        m.insert("36312", "lucid");
-        m.insert("36313", "luckily");
-        m.insert("36314", "luckiness");
-        m.insert("36315", "luckless");
-        m.insert("36316", "lucrative");
-        m.insert("36321", "ludicrous");
-        m.insert("36322", "lugged");
-        m.insert("36323", "lukewarm");
-        m.insert("36324", "lullaby");
-        m.insert("36325", "lumber");
-        m.insert("36326", "luminance");
-        m.insert("36331", "luminous");
-        m.insert("36332", "lumpiness");
-        m.insert("36333", "lumping");
-        m.insert("36334", "lumpish");
-        m.insert("36335", "lunacy");
-        m.insert("36336", "lunar");
-        m.insert("36341", "lunchbox");
-        m.insert("36342", "luncheon");
-        m.insert("36343", "lunchroom");
-        m.insert("36344", "lunchtime");
-        m.insert("36345", "lung");
-        m.insert("36346", "lurch");
-        m.insert("36351", "lure");
-        m.insert("36352", "luridness");
-        m.insert("36353", "lurk");
-        m.insert("36354", "lushly");
-        m.insert("36355", "lushness");
-        m.insert("36356", "luster");
-        m.insert("36361", "lustfully");
-        m.insert("36362", "lustily");
-        m.insert("36363", "lustiness");
-        m.insert("36364", "lustrous");
-        m.insert("36365", "lusty");
-        m.insert("36366", "luxurious");
-        m.insert("36411", "luxury");
-        m.insert("36412", "lying");
-        m.insert("36413", "lyrically");
-        m.insert("36414", "lyricism");
-        m.insert("36415", "lyricist");
-        m.insert("36416", "lyrics");
-        m.insert("36421", "macarena");
-        m.insert("36422", "macaroni");
-        m.insert("36423", "macaw");
-        m.insert("36424", "mace");
-        m.insert("36425", "machine");
-        m.insert("36426", "machinist");
-        m.insert("36431", "magazine");
-        m.insert("36432", "magenta");
-        m.insert("36433", "maggot");
-        m.insert("36434", "magical");
-        m.insert("36435", "magician");
-        m.insert("36436", "magma");
-        m.insert("36441", "magnesium");
-        m.insert("36442", "magnetic");
-        m.insert("36443", "magnetism");
-        m.insert("36444", "magnetize");
-        m.insert("36445", "magnifier");
-        m.insert("36446", "magnify");
-        m.insert("36451", "magnitude");
-        m.insert("36452", "magnolia");
-        m.insert("36453", "mahogany");
-        m.insert("36454", "maimed");
-        m.insert("36455", "majestic");
-        m.insert("36456", "majesty");
-        m.insert("36461", "majorette");
-        m.insert("36462", "majority");
-        m.insert("36463", "makeover");
-        m.insert("36464", "maker");
-        m.insert("36465", "makeshift");
-        m.insert("36466", "making");
-        m.insert("36511", "malformed");
-        m.insert("36512", "malt");
-        m.insert("36513", "mama");
-        m.insert("36514", "mammal");
-        m.insert("36515", "mammary");
-        m.insert("36516", "mammogram");
-        m.insert("36521", "manager");
-        m.insert("36522", "managing");
-        m.insert("36523", "manatee");
-        m.insert("36524", "mandarin");
-        m.insert("36525", "mandate");
-        m.insert("36526", "mandatory");
-        m.insert("36531", "mandolin");
-        m.insert("36532", "manger");
-        m.insert("36533", "mangle");
-        m.insert("36534", "mango");
-        m.insert("36535", "mangy");
-        m.insert("36536", "manhandle");
-        m.insert("36541", "manhole");
-        m.insert("36542", "manhood");
-        m.insert("36543", "manhunt");
-        m.insert("36544", "manicotti");
-        m.insert("36545", "manicure");
-        m.insert("36546", "manifesto");
-        m.insert("36551", "manila");
-        m.insert("36552", "mankind");
-        m.insert("36553", "manlike");
-        m.insert("36554", "manliness");
-        m.insert("36555", "manly");
-        m.insert("36556", "manmade");
-        m.insert("36561", "manned");
-        m.insert("36562", "mannish");
-        m.insert("36563", "manor");
-        m.insert("36564", "manpower");
-        m.insert("36565", "mantis");
-        m.insert("36566", "mantra");
-        m.insert("36611", "manual");
-        m.insert("36612", "many");
-        m.insert("36613", "map");
-        m.insert("36614", "marathon");
-        m.insert("36615", "marauding");
-        m.insert("36616", "marbled");
-        m.insert("36621", "marbles");
-        m.insert("36622", "marbling");
-        m.insert("36623", "march");
-        m.insert("36624", "mardi");
-        m.insert("36625", "margarine");
-        m.insert("36626", "margarita");
-        m.insert("36631", "margin");
-        m.insert("36632", "marigold");
-        m.insert("36633", "marina");
-        m.insert("36634", "marine");
-        m.insert("36635", "marital");
-        m.insert("36636", "maritime");
-        m.insert("36641", "marlin");
-        m.insert("36642", "marmalade");
-        m.insert("36643", "maroon");
-        m.insert("36644", "married");
-        m.insert("36645", "marrow");
-        m.insert("36646", "marry");
-        m.insert("36651", "marshland");
-        m.insert("36652", "marshy");
-        m.insert("36653", "marsupial");
-        m.insert("36654", "marvelous");
-        m.insert("36655", "marxism");
-        m.insert("36656", "mascot");
-        m.insert("36661", "masculine");
-        m.insert("36662", "mashed");
-        m.insert("36663", "mashing");
-        m.insert("36664", "massager");
-        m.insert("36665", "masses");
-        m.insert("36666", "massive");
-        m.insert("41111", "mastiff");
-        m.insert("41112", "matador");
-        m.insert("41113", "matchbook");
-        m.insert("41114", "matchbox");
-        m.insert("41115", "matcher");
-        m.insert("41116", "matching");
-        m.insert("41121", "matchless");
-        m.insert("41122", "material");
-        m.insert("41123", "maternal");
-        m.insert("41124", "maternity");
-        m.insert("41125", "math");
-        m.insert("41126", "mating");
-        m.insert("41131", "matriarch");
-        m.insert("41132", "matrimony");
-        m.insert("41133", "matrix");
-        m.insert("41134", "matron");
-        m.insert("41135", "matted");
-        m.insert("41136", "matter");
-        m.insert("41141", "maturely");
-        m.insert("41142", "maturing");
-        m.insert("41143", "maturity");
-        m.insert("41144", "mauve");
-        m.insert("41145", "maverick");
-        m.insert("41146", "maximize");
-        m.insert("41151", "maximum");
-        m.insert("41152", "maybe");
-        m.insert("41153", "mayday");
-        m.insert("41154", "mayflower");
-        m.insert("41155", "moaner");
-        m.insert("41156", "moaning");
-        m.insert("41161", "mobile");
-        m.insert("41162", "mobility");
-        m.insert("41163", "mobilize");
-        m.insert("41164", "mobster");
-        m.insert("41165", "mocha");
-        m.insert("41166", "mocker");
-        m.insert("41211", "mockup");
-        m.insert("41212", "modified");
-        m.insert("41213", "modify");
-        m.insert("41214", "modular");
-        m.insert("41215", "modulator");
-        m.insert("41216", "module");
-        m.insert("41221", "moisten");
-        m.insert("41222", "moistness");
-        m.insert("41223", "moisture");
-        m.insert("41224", "molar");
-        m.insert("41225", "molasses");
-        m.insert("41226", "mold");
-        m.insert("41231", "molecular");
-        m.insert("41232", "molecule");
-        m.insert("41233", "molehill");
-        m.insert("41234", "mollusk");
-        m.insert("41235", "mom");
-        m.insert("41236", "monastery");
-        m.insert("41241", "monday");
-        m.insert("41242", "monetary");
-        m.insert("41243", "monetize");
-        m.insert("41244", "moneybags");
-        m.insert("41245", "moneyless");
-        m.insert("41246", "moneywise");
-        m.insert("41251", "mongoose");
-        m.insert("41252", "mongrel");
-        m.insert("41253", "monitor");
-        m.insert("41254", "monkhood");
-        m.insert("41255", "monogamy");
-        m.insert("41256", "monogram");
-        m.insert("41261", "monologue");
-        m.insert("41262", "monopoly");
-        m.insert("41263", "monorail");
-        m.insert("41264", "monotone");
-        m.insert("41265", "monotype");
-        m.insert("41266", "monoxide");
-        m.insert("41311", "monsieur");
-        m.insert("41312", "monsoon");
-        m.insert("41313", "monstrous");
-        m.insert("41314", "monthly");
-        m.insert("41315", "monument");
-        m.insert("41316", "moocher");
-        m.insert("41321", "moodiness");
-        m.insert("41322", "moody");
-        m.insert("41323", "mooing");
-        m.insert("41324", "moonbeam");
-        m.insert("41325", "mooned");
-        m.insert("41326", "moonlight");
-        m.insert("41331", "moonlike");
-        m.insert("41332", "moonlit");
-        m.insert("41333", "moonrise");
-        m.insert("41334", "moonscape");
-        m.insert("41335", "moonshine");
-        m.insert("41336", "moonstone");
-        m.insert("41341", "moonwalk");
-        m.insert("41342", "mop");
-        m.insert("41343", "morale");
-        m.insert("41344", "morality");
-        m.insert("41345", "morally");
-        m.insert("41346", "morbidity");
-        m.insert("41351", "morbidly");
-        m.insert("41352", "morphine");
-        m.insert("41353", "morphing");
-        m.insert("41354", "morse");
-        m.insert("41355", "mortality");
-        m.insert("41356", "mortally");
-        m.insert("41361", "mortician");
-        m.insert("41362", "mortified");
-        m.insert("41363", "mortify");
-        m.insert("41364", "mortuary");
-        m.insert("41365", "mosaic");
-        m.insert("41366", "mossy");
-        m.insert("41411", "most");
-        m.insert("41412", "mothball");
-        m.insert("41413", "mothproof");
-        m.insert("41414", "motion");
-        m.insert("41415", "motivate");
-        m.insert("41416", "motivator");
-        m.insert("41421", "motive");
-        m.insert("41422", "motocross");
-        m.insert("41423", "motor");
-        m.insert("41424", "motto");
-        m.insert("41425", "mountable");
-        m.insert("41426", "mountain");
-        m.insert("41431", "mounted");
-        m.insert("41432", "mounting");
-        m.insert("41433", "mourner");
-        m.insert("41434", "mournful");
-        m.insert("41435", "mouse");
-        m.insert("41436", "mousiness");
-        m.insert("41441", "moustache");
-        m.insert("41442", "mousy");
-        m.insert("41443", "mouth");
-        m.insert("41444", "movable");
-        m.insert("41445", "move");
-        m.insert("41446", "movie");
-        m.insert("41451", "moving");
-        m.insert("41452", "mower");
-        m.insert("41453", "mowing");
-        m.insert("41454", "much");
-        m.insert("41455", "muck");
-        m.insert("41456", "mud");
-        m.insert("41461", "mug");
-        m.insert("41462", "mulberry");
-        m.insert("41463", "mulch");
-        m.insert("41464", "mule");
-        m.insert("41465", "mulled");
-        m.insert("41466", "mullets");
-        m.insert("41511", "multiple");
-        m.insert("41512", "multiply");
-        m.insert("41513", "multitask");
-        m.insert("41514", "multitude");
-        m.insert("41515", "mumble");
-        m.insert("41516", "mumbling");
-        m.insert("41521", "mumbo");
-        m.insert("41522", "mummified");
-        m.insert("41523", "mummify");
-        m.insert("41524", "mummy");
-        m.insert("41525", "mumps");
-        m.insert("41526", "munchkin");
-        m.insert("41531", "mundane");
-        m.insert("41532", "municipal");
-        m.insert("41533", "muppet");
-        m.insert("41534", "mural");
-        m.insert("41535", "murkiness");
-        m.insert("41536", "murky");
-        m.insert("41541", "murmuring");
-        m.insert("41542", "muscular");
-        m.insert("41543", "museum");
-        m.insert("41544", "mushily");
-        m.insert("41545", "mushiness");
-        m.insert("41546", "mushroom");
-        m.insert("41551", "mushy");
-        m.insert("41552", "music");
-        m.insert("41553", "musket");
-        m.insert("41554", "muskiness");
-        m.insert("41555", "musky");
-        m.insert("41556", "mustang");
-        m.insert("41561", "mustard");
-        m.insert("41562", "muster");
-        m.insert("41563", "mustiness");
-        m.insert("41564", "musty");
-        m.insert("41565", "mutable");
-        m.insert("41566", "mutate");
-        m.insert("41611", "mutation");
-        m.insert("41612", "mute");
-        m.insert("41613", "mutilated");
-        m.insert("41614", "mutilator");
-        m.insert("41615", "mutiny");
-        m.insert("41616", "mutt");
-        m.insert("41621", "mutual");
-        m.insert("41622", "muzzle");
-        m.insert("41623", "myself");
-        m.insert("41624", "myspace");
-        m.insert("41625", "mystified");
-        m.insert("41626", "mystify");
-        m.insert("41631", "myth");
-        m.insert("41632", "nacho");
-        m.insert("41633", "nag");
-        m.insert("41634", "nail");
-        m.insert("41635", "name");
-        m.insert("41636", "naming");
-        m.insert("41641", "nanny");
-        m.insert("41642", "nanometer");
-        m.insert("41643", "nape");
-        m.insert("41644", "napkin");
-        m.insert("41645", "napped");
-        m.insert("41646", "napping");
-        m.insert("41651", "nappy");
-        m.insert("41652", "narrow");
-        m.insert("41653", "nastily");
-        m.insert("41654", "nastiness");
-        m.insert("41655", "national");
-        m.insert("41656", "native");
-        m.insert("41661", "nativity");
-        m.insert("41662", "natural");
-        m.insert("41663", "nature");
-        m.insert("41664", "naturist");
-        m.insert("41665", "nautical");
-        m.insert("41666", "navigate");
-        m.insert("42111", "navigator");
-        m.insert("42112", "navy");
-        m.insert("42113", "nearby");
-        m.insert("42114", "nearest");
-        m.insert("42115", "nearly");
-        m.insert("42116", "nearness");
-        m.insert("42121", "neatly");
-        m.insert("42122", "neatness");
-        m.insert("42123", "nebula");
-        m.insert("42124", "nebulizer");
-        m.insert("42125", "nectar");
-        m.insert("42126", "negate");
-        m.insert("42131", "negation");
-        m.insert("42132", "negative");
-        m.insert("42133", "neglector");
-        m.insert("42134", "negligee");
-        m.insert("42135", "negligent");
-        m.insert("42136", "negotiate");
-        m.insert("42141", "nemeses");
-        m.insert("42142", "nemesis");
-        m.insert("42143", "neon");
-        m.insert("42144", "nephew");
-        m.insert("42145", "nerd");
-        m.insert("42146", "nervous");
-        m.insert("42151", "nervy");
-        m.insert("42152", "nest");
-        m.insert("42153", "net");
-        m.insert("42154", "neurology");
-        m.insert("42155", "neuron");
-        m.insert("42156", "neurosis");
-        m.insert("42161", "neurotic");
-        m.insert("42162", "neuter");
-        m.insert("42163", "neutron");
-        m.insert("42164", "never");
-        m.insert("42165", "next");
-        m.insert("42166", "nibble");
-        m.insert("42211", "nickname");
-        m.insert("42212", "nicotine");
-        m.insert("42213", "niece");
-        m.insert("42214", "nifty");
-        m.insert("42215", "nimble");
-        m.insert("42216", "nimbly");
-        m.insert("42221", "nineteen");
-        m.insert("42222", "ninetieth");
-        m.insert("42223", "ninja");
-        m.insert("42224", "nintendo");
-        m.insert("42225", "ninth");
-        m.insert("42226", "nuclear");
-        m.insert("42231", "nuclei");
-        m.insert("42232", "nucleus");
-        m.insert("42233", "nugget");
-        m.insert("42234", "nullify");
-        m.insert("42235", "number");
-        m.insert("42236", "numbing");
-        m.insert("42241", "numbly");
-        m.insert("42242", "numbness");
-        m.insert("42243", "numeral");
-        m.insert("42244", "numerate");
-        m.insert("42245", "numerator");
-        m.insert("42246", "numeric");
-        m.insert("42251", "numerous");
-        m.insert("42252", "nuptials");
-        m.insert("42253", "nursery");
-        m.insert("42254", "nursing");
-        m.insert("42255", "nurture");
-        m.insert("42256", "nutcase");
-        m.insert("42261", "nutlike");
-        m.insert("42262", "nutmeg");
-        m.insert("42263", "nutrient");
-        m.insert("42264", "nutshell");
-        m.insert("42265", "nuttiness");
-        m.insert("42266", "nutty");
-        m.insert("42311", "nuzzle");
-        m.insert("42312", "nylon");
-        m.insert("42313", "oaf");
-        m.insert("42314", "oak");
-        m.insert("42315", "oasis");
-        m.insert("42316", "oat");
-        m.insert("42321", "obedience");
-        m.insert("42322", "obedient");
-        m.insert("42323", "obituary");
-        m.insert("42324", "object");
-        m.insert("42325", "obligate");
-        m.insert("42326", "obliged");
-        m.insert("42331", "oblivion");
-        m.insert("42332", "oblivious");
-        m.insert("42333", "oblong");
-        m.insert("42334", "obnoxious");
-        m.insert("42335", "oboe");
-        m.insert("42336", "obscure");
-        m.insert("42341", "obscurity");
-        m.insert("42342", "observant");
-        m.insert("42343", "observer");
-        m.insert("42344", "observing");
-        m.insert("42345", "obsessed");
-        m.insert("42346", "obsession");
-        m.insert("42351", "obsessive");
-        m.insert("42352", "obsolete");
-        m.insert("42353", "obstacle");
-        m.insert("42354", "obstinate");
-        m.insert("42355", "obstruct");
-        m.insert("42356", "obtain");
-        m.insert("42361", "obtrusive");
-        m.insert("42362", "obtuse");
-        m.insert("42363", "obvious");
-        m.insert("42364", "occultist");
-        m.insert("42365", "occupancy");
-        m.insert("42366", "occupant");
-        m.insert("42411", "occupier");
-        m.insert("42412", "occupy");
-        m.insert("42413", "ocean");
-        m.insert("42414", "ocelot");
-        m.insert("42415", "octagon");
-        m.insert("42416", "octane");
-        m.insert("42421", "october");
-        m.insert("42422", "octopus");
-        m.insert("42423", "ogle");
-        m.insert("42424", "oil");
-        m.insert("42425", "oink");
-        m.insert("42426", "ointment");
-        m.insert("42431", "okay");
-        m.insert("42432", "old");
-        m.insert("42433", "olive");
-        m.insert("42434", "olympics");
-        m.insert("42435", "omega");
-        m.insert("42436", "omen");
-        m.insert("42441", "ominous");
-        m.insert("42442", "omission");
-        m.insert("42443", "omit");
-        m.insert("42444", "omnivore");
-        m.insert("42445", "onboard");
-        m.insert("42446", "oncoming");
-        m.insert("42451", "ongoing");
-        m.insert("42452", "onion");
-        m.insert("42453", "online");
-        m.insert("42454", "onlooker");
-        m.insert("42455", "only");
-        m.insert("42456", "onscreen");
-        m.insert("42461", "onset");
-        m.insert("42462", "onshore");
-        m.insert("42463", "onslaught");
-        m.insert("42464", "onstage");
-        m.insert("42465", "onto");
-        m.insert("42466", "onward");
-        m.insert("42511", "onyx");
-        m.insert("42512", "oops");
-        m.insert("42513", "ooze");
-        m.insert("42514", "oozy");
-        m.insert("42515", "opacity");
-        m.insert("42516", "opal");
-        m.insert("42521", "open");
-        m.insert("42522", "operable");
-        m.insert("42523", "operate");
-        m.insert("42524", "operating");
-        m.insert("42525", "operation");
-        m.insert("42526", "operative");
-        m.insert("42531", "operator");
-        m.insert("42532", "opium");
-        m.insert("42533", "opossum");
-        m.insert("42534", "opponent");
-        m.insert("42535", "oppose");
-        m.insert("42536", "opposing");
-        m.insert("42541", "opposite");
-        m.insert("42542", "oppressed");
-        m.insert("42543", "oppressor");
-        m.insert("42544", "opt");
-        m.insert("42545", "opulently");
-        m.insert("42546", "osmosis");
-        m.insert("42551", "other");
-        m.insert("42552", "otter");
-        m.insert("42553", "ouch");
-        m.insert("42554", "ought");
-        m.insert("42555", "ounce");
-        m.insert("42556", "outage");
-        m.insert("42561", "outback");
-        m.insert("42562", "outbid");
-        m.insert("42563", "outboard");
-        m.insert("42564", "outbound");
-        m.insert("42565", "outbreak");
-        m.insert("42566", "outburst");
-        m.insert("42611", "outcast");
-        m.insert("42612", "outclass");
-        m.insert("42613", "outcome");
-        m.insert("42614", "outdated");
-        m.insert("42615", "outdoors");
-        m.insert("42616", "outer");
-        m.insert("42621", "outfield");
-        m.insert("42622", "outfit");
-        m.insert("42623", "outflank");
-        m.insert("42624", "outgoing");
-        m.insert("42625", "outgrow");
-        m.insert("42626", "outhouse");
-        m.insert("42631", "outing");
-        m.insert("42632", "outlast");
-        m.insert("42633", "outlet");
-        m.insert("42634", "outline");
-        m.insert("42635", "outlook");
-        m.insert("42636", "outlying");
-        m.insert("42641", "outmatch");
-        m.insert("42642", "outmost");
-        m.insert("42643", "outnumber");
-        m.insert("42644", "outplayed");
-        m.insert("42645", "outpost");
-        m.insert("42646", "outpour");
-        m.insert("42651", "output");
-        m.insert("42652", "outrage");
-        m.insert("42653", "outrank");
-        m.insert("42654", "outreach");
-        m.insert("42655", "outright");
-        m.insert("42656", "outscore");
-        m.insert("42661", "outsell");
-        m.insert("42662", "outshine");
-        m.insert("42663", "outshoot");
-        m.insert("42664", "outsider");
-        m.insert("42665", "outskirts");
-        m.insert("42666", "outsmart");
-        m.insert("43111", "outsource");
-        m.insert("43112", "outspoken");
-        m.insert("43113", "outtakes");
-        m.insert("43114", "outthink");
-        m.insert("43115", "outward");
-        m.insert("43116", "outweigh");
-        m.insert("43121", "outwit");
-        m.insert("43122", "oval");
-        m.insert("43123", "ovary");
-        m.insert("43124", "oven");
-        m.insert("43125", "overact");
-        m.insert("43126", "overall");
-        m.insert("43131", "overarch");
-        m.insert("43132", "overbid");
-        m.insert("43133", "overbill");
-        m.insert("43134", "overbite");
-        m.insert("43135", "overblown");
-        m.insert("43136", "overboard");
-        m.insert("43141", "overbook");
-        m.insert("43142", "overbuilt");
-        m.insert("43143", "overcast");
-        m.insert("43144", "overcoat");
-        m.insert("43145", "overcome");
-        m.insert("43146", "overcook");
-        m.insert("43151", "overcrowd");
-        m.insert("43152", "overdraft");
-        m.insert("43153", "overdrawn");
-        m.insert("43154", "overdress");
-        m.insert("43155", "overdrive");
-        m.insert("43156", "overdue");
-        m.insert("43161", "overeager");
-        m.insert("43162", "overeater");
-        m.insert("43163", "overexert");
-        m.insert("43164", "overfed");
-        m.insert("43165", "overfeed");
-        m.insert("43166", "overfill");
-        m.insert("43211", "overflow");
-        m.insert("43212", "overfull");
-        m.insert("43213", "overgrown");
-        m.insert("43214", "overhand");
-        m.insert("43215", "overhang");
-        m.insert("43216", "overhaul");
-        m.insert("43221", "overhead");
-        m.insert("43222", "overhear");
-        m.insert("43223", "overheat");
-        m.insert("43224", "overhung");
-        m.insert("43225", "overjoyed");
-        m.insert("43226", "overkill");
-        m.insert("43231", "overlabor");
-        m.insert("43232", "overlaid");
-        m.insert("43233", "overlap");
-        m.insert("43234", "overlay");
-        m.insert("43235", "overload");
-        m.insert("43236", "overlook");
-        m.insert("43241", "overlord");
-        m.insert("43242", "overlying");
-        m.insert("43243", "overnight");
-        m.insert("43244", "overpass");
-        m.insert("43245", "overpay");
-        m.insert("43246", "overplant");
-        m.insert("43251", "overplay");
-        m.insert("43252", "overpower");
-        m.insert("43253", "overprice");
-        m.insert("43254", "overrate");
-        m.insert("43255", "overreach");
-        m.insert("43256", "overreact");
-        m.insert("43261", "override");
-        m.insert("43262", "overripe");
-        m.insert("43263", "overrule");
-        m.insert("43264", "overrun");
-        m.insert("43265", "overshoot");
-        m.insert("43266", "overshot");
-        m.insert("43311", "oversight");
-        m.insert("43312", "oversized");
-        m.insert("43313", "oversleep");
-        m.insert("43314", "oversold");
-        m.insert("43315", "overspend");
-        m.insert("43316", "overstate");
-        m.insert("43321", "overstay");
-        m.insert("43322", "overstep");
-        m.insert("43323", "overstock");
-        m.insert("43324", "overstuff");
-        m.insert("43325", "oversweet");
-        m.insert("43326", "overtake");
-        m.insert("43331", "overthrow");
-        m.insert("43332", "overtime");
-        m.insert("43333", "overtly");
-        m.insert("43334", "overtone");
-        m.insert("43335", "overture");
-        m.insert("43336", "overturn");
-        m.insert("43341", "overuse");
-        m.insert("43342", "overvalue");
-        m.insert("43343", "overview");
-        m.insert("43344", "overwrite");
-        m.insert("43345", "owl");
-        m.insert("43346", "oxford");
-        m.insert("43351", "oxidant");
-        m.insert("43352", "oxidation");
-        m.insert("43353", "oxidize");
-        m.insert("43354", "oxidizing");
-        m.insert("43355", "oxygen");
-        m.insert("43356", "oxymoron");
-        m.insert("43361", "oyster");
-        m.insert("43362", "ozone");
-        m.insert("43363", "paced");
-        m.insert("43364", "pacemaker");
-        m.insert("43365", "pacific");
-        m.insert("43366", "pacifier");
-        m.insert("43411", "pacifism");
-        m.insert("43412", "pacifist");
-        m.insert("43413", "pacify");
-        m.insert("43414", "padded");
-        m.insert("43415", "padding");
-        m.insert("43416", "paddle");
-        m.insert("43421", "paddling");
-        m.insert("43422", "padlock");
-        m.insert("43423", "pagan");
-        m.insert("43424", "pager");
-        m.insert("43425", "paging");
-        m.insert("43426", "pajamas");
-        m.insert("43431", "palace");
-        m.insert("43432", "palatable");
-        m.insert("43433", "palm");
-        m.insert("43434", "palpable");
-        m.insert("43435", "palpitate");
-        m.insert("43436", "paltry");
-        m.insert("43441", "pampered");
-        m.insert("43442", "pamperer");
-        m.insert("43443", "pampers");
-        m.insert("43444", "pamphlet");
-        m.insert("43445", "panama");
-        m.insert("43446", "pancake");
-        m.insert("43451", "pancreas");
-        m.insert("43452", "panda");
-        m.insert("43453", "pandemic");
-        m.insert("43454", "pang");
-        m.insert("43455", "panhandle");
-        m.insert("43456", "panic");
-        m.insert("43461", "panning");
-        m.insert("43462", "panorama");
-        m.insert("43463", "panoramic");
-        m.insert("43464", "panther");
-        m.insert("43465", "pantomime");
-        m.insert("43466", "pantry");
-        m.insert("43511", "pants");
-        m.insert("43512", "pantyhose");
-        m.insert("43513", "paparazzi");
-        m.insert("43514", "papaya");
-        m.insert("43515", "paper");
-        m.insert("43516", "paprika");
-        m.insert("43521", "papyrus");
-        m.insert("43522", "parabola");
-        m.insert("43523", "parachute");
-        m.insert("43524", "parade");
-        m.insert("43525", "paradox");
-        m.insert("43526", "paragraph");
-        m.insert("43531", "parakeet");
-        m.insert("43532", "paralegal");
-        m.insert("43533", "paralyses");
-        m.insert("43534", "paralysis");
-        m.insert("43535", "paralyze");
-        m.insert("43536", "paramedic");
-        m.insert("43541", "parameter");
-        m.insert("43542", "paramount");
-        m.insert("43543", "parasail");
-        m.insert("43544", "parasite");
-        m.insert("43545", "parasitic");
-        m.insert("43546", "parcel");
-        m.insert("43551", "parched");
-        m.insert("43552", "parchment");
-        m.insert("43553", "pardon");
-        m.insert("43554", "parish");
-        m.insert("43555", "parka");
-        m.insert("43556", "parking");
-        m.insert("43561", "parkway");
-        m.insert("43562", "parlor");
-        m.insert("43563", "parmesan");
-        m.insert("43564", "parole");
-        m.insert("43565", "parrot");
-        m.insert("43566", "parsley");
-        m.insert("43611", "parsnip");
-        m.insert("43612", "partake");
-        m.insert("43613", "parted");
-        m.insert("43614", "parting");
-        m.insert("43615", "partition");
-        m.insert("43616", "partly");
-        m.insert("43621", "partner");
-        m.insert("43622", "partridge");
-        m.insert("43623", "party");
-        m.insert("43624", "passable");
-        m.insert("43625", "passably");
-        m.insert("43626", "passage");
-        m.insert("43631", "passcode");
-        m.insert("43632", "passenger");
-        m.insert("43633", "passerby");
-        m.insert("43634", "passing");
-        m.insert("43635", "passion");
-        m.insert("43636", "passive");
-        m.insert("43641", "passivism");
-        m.insert("43642", "passover");
-        m.insert("43643", "passport");
-        m.insert("43644", "password");
-        m.insert("43645", "pasta");
-        m.insert("43646", "pasted");
-        m.insert("43651", "pastel");
-        m.insert("43652", "pastime");
-        m.insert("43653", "pastor");
-        m.insert("43654", "pastrami");
-        m.insert("43655", "pasture");
-        m.insert("43656", "pasty");
-        m.insert("43661", "patchwork");
-        m.insert("43662", "patchy");
-        m.insert("43663", "paternal");
-        m.insert("43664", "paternity");
-        m.insert("43665", "path");
-        m.insert("43666", "patience");
-        m.insert("44111", "patient");
-        m.insert("44112", "patio");
-        m.insert("44113", "patriarch");
-        m.insert("44114", "patriot");
-        m.insert("44115", "patrol");
-        m.insert("44116", "patronage");
-        m.insert("44121", "patronize");
-        m.insert("44122", "pauper");
-        m.insert("44123", "pavement");
-        m.insert("44124", "paver");
-        m.insert("44125", "pavestone");
-        m.insert("44126", "pavilion");
-        m.insert("44131", "paving");
-        m.insert("44132", "pawing");
-        m.insert("44133", "payable");
-        m.insert("44134", "payback");
-        m.insert("44135", "paycheck");
-        m.insert("44136", "payday");
-        m.insert("44141", "payee");
-        m.insert("44142", "payer");
-        m.insert("44143", "paying");
-        m.insert("44144", "payment");
-        m.insert("44145", "payphone");
-        m.insert("44146", "payroll");
-        m.insert("44151", "pebble");
-        m.insert("44152", "pebbly");
-        m.insert("44153", "pecan");
-        m.insert("44154", "pectin");
-        m.insert("44155", "peculiar");
-        m.insert("44156", "peddling");
-        m.insert("44161", "pediatric");
-        m.insert("44162", "pedicure");
-        m.insert("44163", "pedigree");
-        m.insert("44164", "pedometer");
-        m.insert("44165", "pegboard");
-        m.insert("44166", "pelican");
-        m.insert("44211", "pellet");
-        m.insert("44212", "pelt");
-        m.insert("44213", "pelvis");
-        m.insert("44214", "penalize");
-        m.insert("44215", "penalty");
-        m.insert("44216", "pencil");
-        m.insert("44221", "pendant");
-        m.insert("44222", "pending");
-        m.insert("44223", "penholder");
-        m.insert("44224", "penknife");
-        m.insert("44225", "pennant");
-        m.insert("44226", "penniless");
-        m.insert("44231", "penny");
-        m.insert("44232", "penpal");
-        m.insert("44233", "pension");
-        m.insert("44234", "pentagon");
-        m.insert("44235", "pentagram");
-        m.insert("44236", "pep");
-        m.insert("44241", "perceive");
-        m.insert("44242", "percent");
-        m.insert("44243", "perch");
-        m.insert("44244", "percolate");
-        m.insert("44245", "perennial");
-        m.insert("44246", "perfected");
-        m.insert("44251", "perfectly");
-        m.insert("44252", "perfume");
-        m.insert("44253", "periscope");
-        m.insert("44254", "perish");
-        m.insert("44255", "perjurer");
-        m.insert("44256", "perjury");
-        m.insert("44261", "perkiness");
-        m.insert("44262", "perky");
-        m.insert("44263", "perm");
-        m.insert("44264", "peroxide");
-        m.insert("44265", "perpetual");
-        m.insert("44266", "perplexed");
-        m.insert("44311", "persecute");
-        m.insert("44312", "persevere");
-        m.insert("44313", "persuaded");
-        m.insert("44314", "persuader");
-        m.insert("44315", "pesky");
-        m.insert("44316", "peso");
-        m.insert("44321", "pessimism");
-        m.insert("44322", "pessimist");
-        m.insert("44323", "pester");
-        m.insert("44324", "pesticide");
-        m.insert("44325", "petal");
-        m.insert("44326", "petite");
-        m.insert("44331", "petition");
-        m.insert("44332", "petri");
-        m.insert("44333", "petroleum");
-        m.insert("44334", "petted");
-        m.insert("44335", "petticoat");
-        m.insert("44336", "pettiness");
-        m.insert("44341", "petty");
-        m.insert("44342", "petunia");
-        m.insert("44343", "phantom");
-        m.insert("44344", "phobia");
-        m.insert("44345", "phoenix");
-        m.insert("44346", "phonebook");
-        m.insert("44351", "phoney");
-        m.insert("44352", "phonics");
-        m.insert("44353", "phoniness");
-        m.insert("44354", "phony");
-        m.insert("44355", "phosphate");
-        m.insert("44356", "photo");
-        m.insert("44361", "phrase");
-        m.insert("44362", "phrasing");
-        m.insert("44363", "placard");
-        m.insert("44364", "placate");
-        m.insert("44365", "placidly");
-        m.insert("44366", "plank");
-        m.insert("44411", "planner");
-        m.insert("44412", "plant");
-        m.insert("44413", "plasma");
-        m.insert("44414", "plaster");
-        m.insert("44415", "plastic");
-        m.insert("44416", "plated");
-        m.insert("44421", "platform");
-        m.insert("44422", "plating");
-        m.insert("44423", "platinum");
-        m.insert("44424", "platonic");
-        m.insert("44425", "platter");
-        m.insert("44426", "platypus");
-        m.insert("44431", "plausible");
-        m.insert("44432", "plausibly");
-        m.insert("44433", "playable");
-        m.insert("44434", "playback");
-        m.insert("44435", "player");
-        m.insert("44436", "playful");
-        m.insert("44441", "playgroup");
-        m.insert("44442", "playhouse");
-        m.insert("44443", "playing");
-        m.insert("44444", "playlist");
-        m.insert("44445", "playmaker");
-        m.insert("44446", "playmate");
-        m.insert("44451", "playoff");
-        m.insert("44452", "playpen");
-        m.insert("44453", "playroom");
-        m.insert("44454", "playset");
-        m.insert("44455", "plaything");
-        m.insert("44456", "playtime");
-        m.insert("44461", "plaza");
-        m.insert("44462", "pleading");
-        m.insert("44463", "pleat");
-        m.insert("44464", "pledge");
-        m.insert("44465", "plentiful");
-        m.insert("44466", "plenty");
-        m.insert("44511", "plethora");
-        m.insert("44512", "plexiglas");
-        m.insert("44513", "pliable");
-        m.insert("44514", "plod");
-        m.insert("44515", "plop");
-        m.insert("44516", "plot");
-        m.insert("44521", "plow");
-        m.insert("44522", "ploy");
-        m.insert("44523", "pluck");
-        m.insert("44524", "plug");
-        m.insert("44525", "plunder");
-        m.insert("44526", "plunging");
-        m.insert("44531", "plural");
-        m.insert("44532", "plus");
-        m.insert("44533", "plutonium");
-        m.insert("44534", "plywood");
-        m.insert("44535", "poach");
-        m.insert("44536", "pod");
-        m.insert("44541", "poem");
-        m.insert("44542", "poet");
-        m.insert("44543", "pogo");
-        m.insert("44544", "pointed");
-        m.insert("44545", "pointer");
-        m.insert("44546", "pointing");
-        m.insert("44551", "pointless");
-        m.insert("44552", "pointy");
-        m.insert("44553", "poise");
-        m.insert("44554", "poison");
-        m.insert("44555", "poker");
-        m.insert("44556", "poking");
-        m.insert("44561", "polar");
-        m.insert("44562", "police");
-        m.insert("44563", "policy");
-        m.insert("44564", "polio");
-        m.insert("44565", "polish");
-        m.insert("44566", "politely");
-        m.insert("44611", "polka");
-        m.insert("44612", "polo");
-        m.insert("44613", "polyester");
-        m.insert("44614", "polygon");
-        m.insert("44615", "polygraph");
-        m.insert("44616", "polymer");
-        m.insert("44621", "poncho");
-        m.insert("44622", "pond");
-        m.insert("44623", "pony");
-        m.insert("44624", "popcorn");
-        m.insert("44625", "pope");
-        m.insert("44626", "poplar");
-        m.insert("44631", "popper");
-        m.insert("44632", "poppy");
-        m.insert("44633", "popsicle");
-        m.insert("44634", "populace");
-        m.insert("44635", "popular");
-        m.insert("44636", "populate");
-        m.insert("44641", "porcupine");
-        m.insert("44642", "pork");
-        m.insert("44643", "porous");
-        m.insert("44644", "porridge");
-        m.insert("44645", "portable");
-        m.insert("44646", "portal");
-        m.insert("44651", "portfolio");
-        m.insert("44652", "porthole");
-        m.insert("44653", "portion");
-        m.insert("44654", "portly");
-        m.insert("44655", "portside");
-        m.insert("44656", "poser");
-        m.insert("44661", "posh");
-        m.insert("44662", "posing");
-        m.insert("44663", "possible");
-        m.insert("44664", "possibly");
-        m.insert("44665", "possum");
-        m.insert("44666", "postage");
-        m.insert("45111", "postal");
-        m.insert("45112", "postbox");
-        m.insert("45113", "postcard");
-        m.insert("45114", "posted");
-        m.insert("45115", "poster");
-        m.insert("45116", "posting");
-        m.insert("45121", "postnasal");
-        m.insert("45122", "posture");
-        m.insert("45123", "postwar");
-        m.insert("45124", "pouch");
-        m.insert("45125", "pounce");
-        m.insert("45126", "pouncing");
-        m.insert("45131", "pound");
-        m.insert("45132", "pouring");
-        m.insert("45133", "pout");
-        m.insert("45134", "powdered");
-        m.insert("45135", "powdering");
-        m.insert("45136", "powdery");
-        m.insert("45141", "power");
-        m.insert("45142", "powwow");
-        m.insert("45143", "pox");
-        m.insert("45144", "praising");
-        m.insert("45145", "prance");
-        m.insert("45146", "prancing");
-        m.insert("45151", "pranker");
-        m.insert("45152", "prankish");
-        m.insert("45153", "prankster");
-        m.insert("45154", "prayer");
-        m.insert("45155", "praying");
-        m.insert("45156", "preacher");
-        m.insert("45161", "preaching");
-        m.insert("45162", "preachy");
-        m.insert("45163", "preamble");
-        m.insert("45164", "precinct");
-        m.insert("45165", "precise");
-        m.insert("45166", "precision");
-        m.insert("45211", "precook");
-        m.insert("45212", "precut");
-        m.insert("45213", "predator");
-        m.insert("45214", "predefine");
-        m.insert("45215", "predict");
-        m.insert("45216", "preface");
-        m.insert("45221", "prefix");
-        m.insert("45222", "preflight");
-        m.insert("45223", "preformed");
-        m.insert("45224", "pregame");
-        m.insert("45225", "pregnancy");
-        m.insert("45226", "pregnant");
-        m.insert("45231", "preheated");
-        m.insert("45232", "prelaunch");
-        m.insert("45233", "prelaw");
-        m.insert("45234", "prelude");
-        m.insert("45235", "premiere");
-        m.insert("45236", "premises");
-        m.insert("45241", "premium");
-        m.insert("45242", "prenatal");
-        m.insert("45243", "preoccupy");
-        m.insert("45244", "preorder");
-        m.insert("45245", "prepaid");
-        m.insert("45246", "prepay");
-        m.insert("45251", "preplan");
-        m.insert("45252", "preppy");
-        m.insert("45253", "preschool");
-        m.insert("45254", "prescribe");
-        m.insert("45255", "preseason");
-        m.insert("45256", "preset");
-        m.insert("45261", "preshow");
-        m.insert("45262", "president");
-        m.insert("45263", "presoak");
-        m.insert("45264", "press");
-        m.insert("45265", "presume");
-        m.insert("45266", "presuming");
-        m.insert("45311", "preteen");
-        m.insert("45312", "pretended");
-        m.insert("45313", "pretender");
-        m.insert("45314", "pretense");
-        m.insert("45315", "pretext");
-        m.insert("45316", "pretty");
-        m.insert("45321", "pretzel");
-        m.insert("45322", "prevail");
-        m.insert("45323", "prevalent");
-        m.insert("45324", "prevent");
-        m.insert("45325", "preview");
-        m.insert("45326", "previous");
-        m.insert("45331", "prewar");
-        m.insert("45332", "prewashed");
-        m.insert("45333", "prideful");
-        m.insert("45334", "pried");
-        m.insert("45335", "primal");
-        m.insert("45336", "primarily");
-        m.insert("45341", "primary");
-        m.insert("45342", "primate");
-        m.insert("45343", "primer");
-        m.insert("45344", "primp");
-        m.insert("45345", "princess");
-        m.insert("45346", "print");
-        m.insert("45351", "prior");
-        m.insert("45352", "prism");
-        m.insert("45353", "prison");
-        m.insert("45354", "prissy");
-        m.insert("45355", "pristine");
-        m.insert("45356", "privacy");
-        m.insert("45361", "private");
-        m.insert("45362", "privatize");
-        m.insert("45363", "prize");
-        m.insert("45364", "proactive");
-        m.insert("45365", "probable");
-        m.insert("45366", "probably");
-        m.insert("45411", "probation");
-        m.insert("45412", "probe");
-        m.insert("45413", "probing");
-        m.insert("45414", "probiotic");
-        m.insert("45415", "problem");
-        m.insert("45416", "procedure");
-        m.insert("45421", "process");
-        m.insert("45422", "proclaim");
-        m.insert("45423", "procreate");
-        m.insert("45424", "procurer");
-        m.insert("45425", "prodigal");
-        m.insert("45426", "prodigy");
-        m.insert("45431", "produce");
-        m.insert("45432", "product");
-        m.insert("45433", "profane");
-        m.insert("45434", "profanity");
-        m.insert("45435", "professed");
-        m.insert("45436", "professor");
-        m.insert("45441", "profile");
-        m.insert("45442", "profound");
-        m.insert("45443", "profusely");
-        m.insert("45444", "progeny");
-        m.insert("45445", "prognosis");
-        m.insert("45446", "program");
-        m.insert("45451", "progress");
-        m.insert("45452", "projector");
-        m.insert("45453", "prologue");
-        m.insert("45454", "prolonged");
-        m.insert("45455", "promenade");
-        m.insert("45456", "prominent");
-        m.insert("45461", "promoter");
-        m.insert("45462", "promotion");
-        m.insert("45463", "prompter");
-        m.insert("45464", "promptly");
-        m.insert("45465", "prone");
-        m.insert("45466", "prong");
-        m.insert("45511", "pronounce");
-        m.insert("45512", "pronto");
-        m.insert("45513", "proofing");
-        m.insert("45514", "proofread");
-        m.insert("45515", "proofs");
-        m.insert("45516", "propeller");
-        m.insert("45521", "properly");
-        m.insert("45522", "property");
-        m.insert("45523", "proponent");
-        m.insert("45524", "proposal");
-        m.insert("45525", "propose");
-        m.insert("45526", "props");
-        m.insert("45531", "prorate");
-        m.insert("45532", "protector");
-        m.insert("45533", "protegee");
-        m.insert("45534", "proton");
-        m.insert("45535", "prototype");
-        m.insert("45536", "protozoan");
-        m.insert("45541", "protract");
-        m.insert("45542", "protrude");
-        m.insert("45543", "proud");
-        m.insert("45544", "provable");
-        m.insert("45545", "proved");
-        m.insert("45546", "proven");
-        m.insert("45551", "provided");
-        m.insert("45552", "provider");
-        m.insert("45553", "providing");
-        m.insert("45554", "province");
-        m.insert("45555", "proving");
-        m.insert("45556", "provoke");
-        m.insert("45561", "provoking");
-        m.insert("45562", "provolone");
-        m.insert("45563", "prowess");
-        m.insert("45564", "prowler");
-        m.insert("45565", "prowling");
-        m.insert("45566", "proximity");
-        m.insert("45611", "proxy");
-        m.insert("45612", "prozac");
-        m.insert("45613", "prude");
-        m.insert("45614", "prudishly");
-        m.insert("45615", "prune");
-        m.insert("45616", "pruning");
-        m.insert("45621", "pry");
-        m.insert("45622", "psychic");
-        m.insert("45623", "public");
-        m.insert("45624", "publisher");
-        m.insert("45625", "pucker");
-        m.insert("45626", "pueblo");
-        m.insert("45631", "pug");
-        m.insert("45632", "pull");
-        m.insert("45633", "pulmonary");
-        m.insert("45634", "pulp");
-        m.insert("45635", "pulsate");
-        m.insert("45636", "pulse");
-        m.insert("45641", "pulverize");
-        m.insert("45642", "puma");
-        m.insert("45643", "pumice");
-        m.insert("45644", "pummel");
-        m.insert("45645", "punch");
-        m.insert("45646", "punctual");
-        m.insert("45651", "punctuate");
-        m.insert("45652", "punctured");
-        m.insert("45653", "pungent");
-        m.insert("45654", "punisher");
-        m.insert("45655", "punk");
-        m.insert("45656", "pupil");
-        m.insert("45661", "puppet");
-        m.insert("45662", "puppy");
-        m.insert("45663", "purchase");
-        m.insert("45664", "pureblood");
-        m.insert("45665", "purebred");
-        m.insert("45666", "purely");
-        m.insert("46111", "pureness");
-        m.insert("46112", "purgatory");
-        m.insert("46113", "purge");
-        m.insert("46114", "purging");
-        m.insert("46115", "purifier");
-        m.insert("46116", "purify");
-        m.insert("46121", "purist");
-        m.insert("46122", "puritan");
-        m.insert("46123", "purity");
-        m.insert("46124", "purple");
-        m.insert("46125", "purplish");
-        m.insert("46126", "purposely");
-        m.insert("46131", "purr");
-        m.insert("46132", "purse");
-        m.insert("46133", "pursuable");
-        m.insert("46134", "pursuant");
-        m.insert("46135", "pursuit");
-        m.insert("46136", "purveyor");
-        m.insert("46141", "pushcart");
-        m.insert("46142", "pushchair");
-        m.insert("46143", "pusher");
-        m.insert("46144", "pushiness");
-        m.insert("46145", "pushing");
-        m.insert("46146", "pushover");
-        m.insert("46151", "pushpin");
-        m.insert("46152", "pushup");
-        m.insert("46153", "pushy");
-        m.insert("46154", "putdown");
-        m.insert("46155", "putt");
-        m.insert("46156", "puzzle");
-        m.insert("46161", "puzzling");
-        m.insert("46162", "pyramid");
-        m.insert("46163", "pyromania");
-        m.insert("46164", "python");
-        m.insert("46165", "quack");
-        m.insert("46166", "quadrant");
-        m.insert("46211", "quail");
-        m.insert("46212", "quaintly");
-        m.insert("46213", "quake");
-        m.insert("46214", "quaking");
-        m.insert("46215", "qualified");
-        m.insert("46216", "qualifier");
-        m.insert("46221", "qualify");
-        m.insert("46222", "quality");
-        m.insert("46223", "qualm");
-        m.insert("46224", "quantum");
-        m.insert("46225", "quarrel");
-        m.insert("46226", "quarry");
-        m.insert("46231", "quartered");
-        m.insert("46232", "quarterly");
-        m.insert("46233", "quarters");
-        m.insert("46234", "quartet");
-        m.insert("46235", "quench");
-        m.insert("46236", "query");
-        m.insert("46241", "quicken");
-        m.insert("46242", "quickly");
-        m.insert("46243", "quickness");
-        m.insert("46244", "quicksand");
-        m.insert("46245", "quickstep");
-        m.insert("46246", "quiet");
-        m.insert("46251", "quill");
-        m.insert("46252", "quilt");
-        m.insert("46253", "quintet");
-        m.insert("46254", "quintuple");
-        m.insert("46255", "quirk");
-        m.insert("46256", "quit");
-        m.insert("46261", "quiver");
-        m.insert("46262", "quizzical");
-        m.insert("46263", "quotable");
-        m.insert("46264", "quotation");
-        m.insert("46265", "quote");
-        m.insert("46266", "rabid");
-        m.insert("46311", "race");
-        m.insert("46312", "racing");
-        m.insert("46313", "racism");
-        m.insert("46314", "rack");
-        m.insert("46315", "racoon");
-        m.insert("46316", "radar");
-        m.insert("46321", "radial");
-        m.insert("46322", "radiance");
-        m.insert("46323", "radiantly");
-        m.insert("46324", "radiated");
-        m.insert("46325", "radiation");
-        m.insert("46326", "radiator");
-        m.insert("46331", "radio");
-        m.insert("46332", "radish");
-        m.insert("46333", "raffle");
-        m.insert("46334", "raft");
-        m.insert("46335", "rage");
-        m.insert("46336", "ragged");
-        m.insert("46341", "raging");
-        m.insert("46342", "ragweed");
-        m.insert("46343", "raider");
-        m.insert("46344", "railcar");
-        m.insert("46345", "railing");
-        m.insert("46346", "railroad");
-        m.insert("46351", "railway");
-        m.insert("46352", "raisin");
-        m.insert("46353", "rake");
-        m.insert("46354", "raking");
-        m.insert("46355", "rally");
-        m.insert("46356", "ramble");
-        m.insert("46361", "rambling");
-        m.insert("46362", "ramp");
-        m.insert("46363", "ramrod");
-        m.insert("46364", "ranch");
-        m.insert("46365", "rancidity");
-        m.insert("46366", "random");
-        m.insert("46411", "ranged");
-        m.insert("46412", "ranger");
-        m.insert("46413", "ranging");
-        m.insert("46414", "ranked");
-        m.insert("46415", "ranking");
-        m.insert("46416", "ransack");
-        m.insert("46421", "ranting");
-        m.insert("46422", "rants");
-        m.insert("46423", "rare");
-        m.insert("46424", "rarity");
-        m.insert("46425", "rascal");
-        m.insert("46426", "rash");
-        m.insert("46431", "rasping");
-        m.insert("46432", "ravage");
-        m.insert("46433", "raven");
-        m.insert("46434", "ravine");
-        m.insert("46435", "raving");
-        m.insert("46436", "ravioli");
-        m.insert("46441", "ravishing");
-        m.insert("46442", "reabsorb");
-        m.insert("46443", "reach");
-        m.insert("46444", "reacquire");
-        m.insert("46445", "reaction");
-        m.insert("46446", "reactive");
-        m.insert("46451", "reactor");
-        m.insert("46452", "reaffirm");
-        m.insert("46453", "ream");
-        m.insert("46454", "reanalyze");
-        m.insert("46455", "reappear");
-        m.insert("46456", "reapply");
-        m.insert("46461", "reappoint");
-        m.insert("46462", "reapprove");
-        m.insert("46463", "rearrange");
-        m.insert("46464", "rearview");
-        m.insert("46465", "reason");
-        m.insert("46466", "reassign");
-        m.insert("46511", "reassure");
-        m.insert("46512", "reattach");
-        m.insert("46513", "reawake");
-        m.insert("46514", "rebalance");
-        m.insert("46515", "rebate");
-        m.insert("46516", "rebel");
-        m.insert("46521", "rebirth");
-        m.insert("46522", "reboot");
-        m.insert("46523", "reborn");
-        m.insert("46524", "rebound");
-        m.insert("46525", "rebuff");
-        m.insert("46526", "rebuild");
-        m.insert("46531", "rebuilt");
-        m.insert("46532", "reburial");
-        m.insert("46533", "rebuttal");
-        m.insert("46534", "recall");
-        m.insert("46535", "recant");
-        m.insert("46536", "recapture");
-        m.insert("46541", "recast");
-        m.insert("46542", "recede");
-        m.insert("46543", "recent");
-        m.insert("46544", "recess");
-        m.insert("46545", "recharger");
-        m.insert("46546", "recipient");
-        m.insert("46551", "recital");
-        m.insert("46552", "recite");
-        m.insert("46553", "reckless");
-        m.insert("46554", "reclaim");
-        m.insert("46555", "recliner");
-        m.insert("46556", "reclining");
-        m.insert("46561", "recluse");
-        m.insert("46562", "reclusive");
-        m.insert("46563", "recognize");
-        m.insert("46564", "recoil");
-        m.insert("46565", "recollect");
-        m.insert("46566", "recolor");
-        m.insert("46611", "reconcile");
-        m.insert("46612", "reconfirm");
-        m.insert("46613", "reconvene");
-        m.insert("46614", "recopy");
-        m.insert("46615", "record");
-        m.insert("46616", "recount");
-        m.insert("46621", "recoup");
-        m.insert("46622", "recovery");
-        m.insert("46623", "recreate");
-        m.insert("46624", "rectal");
-        m.insert("46625", "rectangle");
-        m.insert("46626", "rectified");
-        m.insert("46631", "rectify");
-        m.insert("46632", "recycled");
-        m.insert("46633", "recycler");
-        m.insert("46634", "recycling");
-        m.insert("46635", "reemerge");
-        m.insert("46636", "reenact");
-        m.insert("46641", "reenter");
-        m.insert("46642", "reentry");
-        m.insert("46643", "reexamine");
-        m.insert("46644", "referable");
-        m.insert("46645", "referee");
-        m.insert("46646", "reference");
-        m.insert("46651", "refill");
-        m.insert("46652", "refinance");
-        m.insert("46653", "refined");
-        m.insert("46654", "refinery");
-        m.insert("46655", "refining");
-        m.insert("46656", "refinish");
-        m.insert("46661", "reflected");
-        m.insert("46662", "reflector");
-        m.insert("46663", "reflex");
-        m.insert("46664", "reflux");
-        m.insert("46665", "refocus");
-        m.insert("46666", "refold");
-        m.insert("51111", "reforest");
-        m.insert("51112", "reformat");
-        m.insert("51113", "reformed");
-        m.insert("51114", "reformer");
-        m.insert("51115", "reformist");
-        m.insert("51116", "refract");
-        m.insert("51121", "refrain");
-        m.insert("51122", "refreeze");
-        m.insert("51123", "refresh");
-        m.insert("51124", "refried");
-        m.insert("51125", "refueling");
-        m.insert("51126", "refund");
-        m.insert("51131", "refurbish");
-        m.insert("51132", "refurnish");
-        m.insert("51133", "refusal");
-        m.insert("51134", "refuse");
-        m.insert("51135", "refusing");
-        m.insert("51136", "refutable");
-        m.insert("51141", "refute");
-        m.insert("51142", "regain");
-        m.insert("51143", "regalia");
-        m.insert("51144", "regally");
-        m.insert("51145", "reggae");
-        m.insert("51146", "regime");
-        m.insert("51151", "region");
-        m.insert("51152", "register");
-        m.insert("51153", "registrar");
-        m.insert("51154", "registry");
-        m.insert("51155", "regress");
-        m.insert("51156", "regretful");
-        m.insert("51161", "regroup");
-        m.insert("51162", "regular");
-        m.insert("51163", "regulate");
-        m.insert("51164", "regulator");
-        m.insert("51165", "rehab");
-        m.insert("51166", "reheat");
-        m.insert("51211", "rehire");
-        m.insert("51212", "rehydrate");
-        m.insert("51213", "reimburse");
-        m.insert("51214", "reissue");
-        m.insert("51215", "reiterate");
-        m.insert("51216", "rejoice");
-        m.insert("51221", "rejoicing");
-        m.insert("51222", "rejoin");
-        m.insert("51223", "rekindle");
-        m.insert("51224", "relapse");
-        m.insert("51225", "relapsing");
-        m.insert("51226", "relatable");
-        m.insert("51231", "related");
-        m.insert("51232", "relation");
-        m.insert("51233", "relative");
-        m.insert("51234", "relax");
-        m.insert("51235", "relay");
-        m.insert("51236", "relearn");
-        m.insert("51241", "release");
-        m.insert("51242", "relenting");
-        m.insert("51243", "reliable");
-        m.insert("51244", "reliably");
-        m.insert("51245", "reliance");
-        m.insert("51246", "reliant");
-        m.insert("51251", "relic");
-        m.insert("51252", "relieve");
-        m.insert("51253", "relieving");
-        m.insert("51254", "relight");
-        m.insert("51255", "relish");
-        m.insert("51256", "relive");
-        m.insert("51261", "reload");
-        m.insert("51262", "relocate");
-        m.insert("51263", "relock");
-        m.insert("51264", "reluctant");
-        m.insert("51265", "rely");
-        m.insert("51266", "remake");
-        m.insert("51311", "remark");
-        m.insert("51312", "remarry");
-        m.insert("51313", "rematch");
-        m.insert("51314", "remedial");
-        m.insert("51315", "remedy");
-        m.insert("51316", "remember");
-        m.insert("51321", "reminder");
-        m.insert("51322", "remindful");
-        m.insert("51323", "remission");
-        m.insert("51324", "remix");
-        m.insert("51325", "remnant");
-        m.insert("51326", "remodeler");
-        m.insert("51331", "remold");
-        m.insert("51332", "remorse");
-        m.insert("51333", "remote");
-        m.insert("51334", "removable");
-        m.insert("51335", "removal");
-        m.insert("51336", "removed");
-        m.insert("51341", "remover");
-        m.insert("51342", "removing");
-        m.insert("51343", "rename");
-        m.insert("51344", "renderer");
-        m.insert("51345", "rendering");
-        m.insert("51346", "rendition");
-        m.insert("51351", "renegade");
-        m.insert("51352", "renewable");
-        m.insert("51353", "renewably");
-        m.insert("51354", "renewal");
-        m.insert("51355", "renewed");
-        m.insert("51356", "renounce");
-        m.insert("51361", "renovate");
-        m.insert("51362", "renovator");
-        m.insert("51363", "rentable");
-        m.insert("51364", "rental");
-        m.insert("51365", "rented");
-        m.insert("51366", "renter");
-        m.insert("51411", "reoccupy");
-        m.insert("51412", "reoccur");
-        m.insert("51413", "reopen");
-        m.insert("51414", "reorder");
-        m.insert("51415", "repackage");
-        m.insert("51416", "repacking");
-        m.insert("51421", "repaint");
-        m.insert("51422", "repair");
-        m.insert("51423", "repave");
-        m.insert("51424", "repaying");
-        m.insert("51425", "repayment");
-        m.insert("51426", "repeal");
-        m.insert("51431", "repeated");
-        m.insert("51432", "repeater");
-        m.insert("51433", "repent");
-        m.insert("51434", "rephrase");
-        m.insert("51435", "replace");
-        m.insert("51436", "replay");
-        m.insert("51441", "replica");
-        m.insert("51442", "reply");
-        m.insert("51443", "reporter");
-        m.insert("51444", "repose");
-        m.insert("51445", "repossess");
-        m.insert("51446", "repost");
-        m.insert("51451", "repressed");
-        m.insert("51452", "reprimand");
-        m.insert("51453", "reprint");
-        m.insert("51454", "reprise");
-        m.insert("51455", "reproach");
-        m.insert("51456", "reprocess");
-        m.insert("51461", "reproduce");
-        m.insert("51462", "reprogram");
-        m.insert("51463", "reps");
-        m.insert("51464", "reptile");
-        m.insert("51465", "reptilian");
-        m.insert("51466", "repugnant");
-        m.insert("51511", "repulsion");
-        m.insert("51512", "repulsive");
-        m.insert("51513", "repurpose");
-        m.insert("51514", "reputable");
-        m.insert("51515", "reputably");
-        m.insert("51516", "request");
-        m.insert("51521", "require");
-        m.insert("51522", "requisite");
-        m.insert("51523", "reroute");
-        m.insert("51524", "rerun");
-        m.insert("51525", "resale");
-        m.insert("51526", "resample");
-        m.insert("51531", "rescuer");
-        m.insert("51532", "reseal");
-        m.insert("51533", "research");
-        m.insert("51534", "reselect");
-        m.insert("51535", "reseller");
-        m.insert("51536", "resemble");
-        m.insert("51541", "resend");
-        m.insert("51542", "resent");
-        m.insert("51543", "reset");
-        m.insert("51544", "reshape");
-        m.insert("51545", "reshoot");
-        m.insert("51546", "reshuffle");
-        m.insert("51551", "residence");
-        m.insert("51552", "residency");
-        m.insert("51553", "resident");
-        m.insert("51554", "residual");
-        m.insert("51555", "residue");
-        m.insert("51556", "resigned");
-        m.insert("51561", "resilient");
-        m.insert("51562", "resistant");
-        m.insert("51563", "resisting");
-        m.insert("51564", "resize");
-        m.insert("51565", "resolute");
-        m.insert("51566", "resolved");
-        m.insert("51611", "resonant");
-        m.insert("51612", "resonate");
-        m.insert("51613", "resort");
-        m.insert("51614", "resource");
-        m.insert("51615", "respect");
-        m.insert("51616", "resubmit");
-        m.insert("51621", "result");
-        m.insert("51622", "resume");
-        m.insert("51623", "resupply");
-        m.insert("51624", "resurface");
-        m.insert("51625", "resurrect");
-        m.insert("51626", "retail");
-        m.insert("51631", "retainer");
-        m.insert("51632", "retaining");
-        m.insert("51633", "retake");
-        m.insert("51634", "retaliate");
-        m.insert("51635", "retention");
-        m.insert("51636", "rethink");
-        m.insert("51641", "retinal");
-        m.insert("51642", "retired");
-        m.insert("51643", "retiree");
-        m.insert("51644", "retiring");
-        m.insert("51645", "retold");
-        m.insert("51646", "retool");
-        m.insert("51651", "retorted");
-        m.insert("51652", "retouch");
-        m.insert("51653", "retrace");
-        m.insert("51654", "retract");
-        m.insert("51655", "retrain");
-        m.insert("51656", "retread");
-        m.insert("51661", "retreat");
-        m.insert("51662", "retrial");
-        m.insert("51663", "retrieval");
-        m.insert("51664", "retriever");
-        m.insert("51665", "retry");
-        m.insert("51666", "return");
-        m.insert("52111", "retying");
-        m.insert("52112", "retype");
-        m.insert("52113", "reunion");
-        m.insert("52114", "reunite");
-        m.insert("52115", "reusable");
-        m.insert("52116", "reuse");
-        m.insert("52121", "reveal");
-        m.insert("52122", "reveler");
-        m.insert("52123", "revenge");
-        m.insert("52124", "revenue");
-        m.insert("52125", "reverb");
-        m.insert("52126", "revered");
-        m.insert("52131", "reverence");
-        m.insert("52132", "reverend");
-        m.insert("52133", "reversal");
-        m.insert("52134", "reverse");
-        m.insert("52135", "reversing");
-        m.insert("52136", "reversion");
-        m.insert("52141", "revert");
-        m.insert("52142", "revisable");
-        m.insert("52143", "revise");
-        m.insert("52144", "revision");
-        m.insert("52145", "revisit");
-        m.insert("52146", "revivable");
-        m.insert("52151", "revival");
-        m.insert("52152", "reviver");
-        m.insert("52153", "reviving");
-        m.insert("52154", "revocable");
-        m.insert("52155", "revoke");
-        m.insert("52156", "revolt");
-        m.insert("52161", "revolver");
-        m.insert("52162", "revolving");
-        m.insert("52163", "reward");
-        m.insert("52164", "rewash");
-        m.insert("52165", "rewind");
-        m.insert("52166", "rewire");
-        m.insert("52211", "reword");
-        m.insert("52212", "rework");
-        m.insert("52213", "rewrap");
-        m.insert("52214", "rewrite");
-        m.insert("52215", "rhyme");
-        m.insert("52216", "ribbon");
-        m.insert("52221", "ribcage");
-        m.insert("52222", "rice");
-        m.insert("52223", "riches");
-        m.insert("52224", "richly");
-        m.insert("52225", "richness");
-        m.insert("52226", "rickety");
-        m.insert("52231", "ricotta");
-        m.insert("52232", "riddance");
-        m.insert("52233", "ridden");
-        m.insert("52234", "ride");
-        m.insert("52235", "riding");
-        m.insert("52236", "rifling");
-        m.insert("52241", "rift");
-        m.insert("52242", "rigging");
-        m.insert("52243", "rigid");
-        m.insert("52244", "rigor");
-        m.insert("52245", "rimless");
-        m.insert("52246", "rimmed");
-        m.insert("52251", "rind");
-        m.insert("52252", "rink");
-        m.insert("52253", "rinse");
-        m.insert("52254", "rinsing");
-        m.insert("52255", "riot");
-        m.insert("52256", "ripcord");
-        m.insert("52261", "ripeness");
-        m.insert("52262", "ripening");
-        m.insert("52263", "ripping");
-        m.insert("52264", "ripple");
-        m.insert("52265", "rippling");
-        m.insert("52266", "riptide");
-        m.insert("52311", "rise");
-        m.insert("52312", "rising");
-        m.insert("52313", "risk");
-        m.insert("52314", "risotto");
-        m.insert("52315", "ritalin");
-        m.insert("52316", "ritzy");
-        m.insert("52321", "rival");
-        m.insert("52322", "riverbank");
-        m.insert("52323", "riverbed");
-        m.insert("52324", "riverboat");
-        m.insert("52325", "riverside");
-        m.insert("52326", "riveter");
-        m.insert("52331", "riveting");
-        m.insert("52332", "roamer");
-        m.insert("52333", "roaming");
-        m.insert("52334", "roast");
-        m.insert("52335", "robbing");
-        m.insert("52336", "robe");
-        m.insert("52341", "robin");
-        m.insert("52342", "robotics");
-        m.insert("52343", "robust");
-        m.insert("52344", "rockband");
-        m.insert("52345", "rocker");
-        m.insert("52346", "rocket");
-        m.insert("52351", "rockfish");
-        m.insert("52352", "rockiness");
-        m.insert("52353", "rocking");
-        m.insert("52354", "rocklike");
-        m.insert("52355", "rockslide");
-        m.insert("52356", "rockstar");
-        m.insert("52361", "rocky");
-        m.insert("52362", "rogue");
-        m.insert("52363", "roman");
-        m.insert("52364", "romp");
-        m.insert("52365", "rope");
-        m.insert("52366", "roping");
-        m.insert("52411", "roster");
-        m.insert("52412", "rosy");
-        m.insert("52413", "rotten");
-        m.insert("52414", "rotting");
-        m.insert("52415", "rotunda");
-        m.insert("52416", "roulette");
-        m.insert("52421", "rounding");
-        m.insert("52422", "roundish");
-        m.insert("52423", "roundness");
-        m.insert("52424", "roundup");
-        m.insert("52425", "roundworm");
-        m.insert("52426", "routine");
-        m.insert("52431", "routing");
-        m.insert("52432", "rover");
-        m.insert("52433", "roving");
-        m.insert("52434", "royal");
-        m.insert("52435", "rubbed");
-        m.insert("52436", "rubber");
-        m.insert("52441", "rubbing");
-        m.insert("52442", "rubble");
-        m.insert("52443", "rubdown");
-        m.insert("52444", "ruby");
-        m.insert("52445", "ruckus");
-        m.insert("52446", "rudder");
-        m.insert("52451", "rug");
-        m.insert("52452", "ruined");
-        m.insert("52453", "rule");
-        m.insert("52454", "rumble");
-        m.insert("52455", "rumbling");
-        m.insert("52456", "rummage");
-        m.insert("52461", "rumor");
-        m.insert("52462", "runaround");
-        m.insert("52463", "rundown");
-        m.insert("52464", "runner");
-        m.insert("52465", "running");
-        m.insert("52466", "runny");
-        m.insert("52511", "runt");
-        m.insert("52512", "runway");
-        m.insert("52513", "rupture");
-        m.insert("52514", "rural");
-        m.insert("52515", "ruse");
-        m.insert("52516", "rush");
-        m.insert("52521", "rust");
-        m.insert("52522", "rut");
-        m.insert("52523", "sabbath");
-        m.insert("52524", "sabotage");
-        m.insert("52525", "sacrament");
-        m.insert("52526", "sacred");
-        m.insert("52531", "sacrifice");
-        m.insert("52532", "sadden");
-        m.insert("52533", "saddlebag");
-        m.insert("52534", "saddled");
-        m.insert("52535", "saddling");
-        m.insert("52536", "sadly");
-        m.insert("52541", "sadness");
-        m.insert("52542", "safari");
-        m.insert("52543", "safeguard");
-        m.insert("52544", "safehouse");
-        m.insert("52545", "safely");
-        m.insert("52546", "safeness");
-        m.insert("52551", "saffron");
-        m.insert("52552", "saga");
-        m.insert("52553", "sage");
-        m.insert("52554", "sagging");
-        m.insert("52555", "saggy");
-        m.insert("52556", "said");
-        m.insert("52561", "saint");
-        m.insert("52562", "sake");
-        m.insert("52563", "salad");
-        m.insert("52564", "salami");
-        m.insert("52565", "salaried");
-        m.insert("52566", "salary");
-        m.insert("52611", "saline");
-        m.insert("52612", "salon");
-        m.insert("52613", "saloon");
-        m.insert("52614", "salsa");
-        m.insert("52615", "salt");
-        m.insert("52616", "salutary");
-        m.insert("52621", "salute");
-        m.insert("52622", "salvage");
-        m.insert("52623", "salvaging");
-        m.insert("52624", "salvation");
-        m.insert("52625", "same");
-        m.insert("52626", "sample");
-        m.insert("52631", "sampling");
-        m.insert("52632", "sanction");
-        m.insert("52633", "sanctity");
-        m.insert("52634", "sanctuary");
-        m.insert("52635", "sandal");
-        m.insert("52636", "sandbag");
-        m.insert("52641", "sandbank");
-        m.insert("52642", "sandbar");
-        m.insert("52643", "sandblast");
-        m.insert("52644", "sandbox");
-        m.insert("52645", "sanded");
-        m.insert("52646", "sandfish");
-        m.insert("52651", "sanding");
-        m.insert("52652", "sandlot");
-        m.insert("52653", "sandpaper");
-        m.insert("52654", "sandpit");
-        m.insert("52655", "sandstone");
-        m.insert("52656", "sandstorm");
-        m.insert("52661", "sandworm");
-        m.insert("52662", "sandy");
-        m.insert("52663", "sanitary");
-        m.insert("52664", "sanitizer");
-        m.insert("52665", "sank");
-        m.insert("52666", "santa");
-        m.insert("53111", "sapling");
-        m.insert("53112", "sappiness");
-        m.insert("53113", "sappy");
-        m.insert("53114", "sarcasm");
-        m.insert("53115", "sarcastic");
-        m.insert("53116", "sardine");
-        m.insert("53121", "sash");
-        m.insert("53122", "sasquatch");
-        m.insert("53123", "sassy");
-        m.insert("53124", "satchel");
-        m.insert("53125", "satiable");
-        m.insert("53126", "satin");
-        m.insert("53131", "satirical");
-        m.insert("53132", "satisfied");
-        m.insert("53133", "satisfy");
-        m.insert("53134", "saturate");
-        m.insert("53135", "saturday");
-        m.insert("53136", "sauciness");
-        m.insert("53141", "saucy");
-        m.insert("53142", "sauna");
-        m.insert("53143", "savage");
-        m.insert("53144", "savanna");
-        m.insert("53145", "saved");
-        m.insert("53146", "savings");
-        m.insert("53151", "savior");
-        m.insert("53152", "savor");
-        m.insert("53153", "saxophone");
-        m.insert("53154", "say");
-        m.insert("53155", "scabbed");
-        m.insert("53156", "scabby");
-        m.insert("53161", "scalded");
-        m.insert("53162", "scalding");
-        m.insert("53163", "scale");
-        m.insert("53164", "scaling");
-        m.insert("53165", "scallion");
-        m.insert("53166", "scallop");
-        m.insert("53211", "scalping");
-        m.insert("53212", "scam");
-        m.insert("53213", "scandal");
-        m.insert("53214", "scanner");
-        m.insert("53215", "scanning");
-        m.insert("53216", "scant");
-        m.insert("53221", "scapegoat");
-        m.insert("53222", "scarce");
-        m.insert("53223", "scarcity");
-        m.insert("53224", "scarecrow");
-        m.insert("53225", "scared");
-        m.insert("53226", "scarf");
-        m.insert("53231", "scarily");
-        m.insert("53232", "scariness");
-        m.insert("53233", "scarring");
-        m.insert("53234", "scary");
-        m.insert("53235", "scavenger");
-        m.insert("53236", "scenic");
-        m.insert("53241", "schedule");
-        m.insert("53242", "schematic");
-        m.insert("53243", "scheme");
-        m.insert("53244", "scheming");
-        m.insert("53245", "schilling");
-        m.insert("53246", "schnapps");
-        m.insert("53251", "scholar");
-        m.insert("53252", "science");
-        m.insert("53253", "scientist");
-        m.insert("53254", "scion");
-        m.insert("53255", "scoff");
-        m.insert("53256", "scolding");
-        m.insert("53261", "scone");
-        m.insert("53262", "scoop");
-        m.insert("53263", "scooter");
-        m.insert("53264", "scope");
-        m.insert("53265", "scorch");
-        m.insert("53266", "scorebook");
-        m.insert("53311", "scorecard");
-        m.insert("53312", "scored");
-        m.insert("53313", "scoreless");
-        m.insert("53314", "scorer");
-        m.insert("53315", "scoring");
-        m.insert("53316", "scorn");
-        m.insert("53321", "scorpion");
-        m.insert("53322", "scotch");
-        m.insert("53323", "scoundrel");
-        m.insert("53324", "scoured");
-        m.insert("53325", "scouring");
-        m.insert("53326", "scouting");
-        m.insert("53331", "scouts");
-        m.insert("53332", "scowling");
-        m.insert("53333", "scrabble");
-        m.insert("53334", "scraggly");
-        m.insert("53335", "scrambled");
-        m.insert("53336", "scrambler");
-        m.insert("53341", "scrap");
-        m.insert("53342", "scratch");
-        m.insert("53343", "scrawny");
-        m.insert("53344", "screen");
-        m.insert("53345", "scribble");
-        m.insert("53346", "scribe");
-        m.insert("53351", "scribing");
-        m.insert("53352", "scrimmage");
-        m.insert("53353", "script");
-        m.insert("53354", "scroll");
-        m.insert("53355", "scrooge");
-        m.insert("53356", "scrounger");
-        m.insert("53361", "scrubbed");
-        m.insert("53362", "scrubber");
-        m.insert("53363", "scruffy");
-        m.insert("53364", "scrunch");
-        m.insert("53365", "scrutiny");
-        m.insert("53366", "scuba");
-        m.insert("53411", "scuff");
-        m.insert("53412", "sculptor");
-        m.insert("53413", "sculpture");
-        m.insert("53414", "scurvy");
-        m.insert("53415", "scuttle");
-        m.insert("53416", "secluded");
-        m.insert("53421", "secluding");
-        m.insert("53422", "seclusion");
-        m.insert("53423", "second");
-        m.insert("53424", "secrecy");
-        m.insert("53425", "secret");
-        m.insert("53426", "sectional");
-        m.insert("53431", "sector");
-        m.insert("53432", "secular");
-        m.insert("53433", "securely");
-        m.insert("53434", "security");
-        m.insert("53435", "sedan");
-        m.insert("53436", "sedate");
-        m.insert("53441", "sedation");
-        m.insert("53442", "sedative");
-        m.insert("53443", "sediment");
-        m.insert("53444", "seduce");
-        m.insert("53445", "seducing");
-        m.insert("53446", "segment");
-        m.insert("53451", "seismic");
-        m.insert("53452", "seizing");
-        m.insert("53453", "seldom");
-        m.insert("53454", "selected");
-        m.insert("53455", "selection");
-        m.insert("53456", "selective");
-        m.insert("53461", "selector");
-        m.insert("53462", "self");
-        m.insert("53463", "seltzer");
-        m.insert("53464", "semantic");
-        m.insert("53465", "semester");
-        m.insert("53466", "semicolon");
-        m.insert("53511", "semifinal");
-        m.insert("53512", "seminar");
-        m.insert("53513", "semisoft");
-        m.insert("53514", "semisweet");
-        m.insert("53515", "senate");
-        m.insert("53516", "senator");
-        m.insert("53521", "send");
-        m.insert("53522", "senior");
-        m.insert("53523", "senorita");
-        m.insert("53524", "sensation");
-        m.insert("53525", "sensitive");
-        m.insert("53526", "sensitize");
-        m.insert("53531", "sensually");
-        m.insert("53532", "sensuous");
-        m.insert("53533", "sepia");
-        m.insert("53534", "september");
-        m.insert("53535", "septic");
-        m.insert("53536", "septum");
-        m.insert("53541", "sequel");
-        m.insert("53542", "sequence");
-        m.insert("53543", "sequester");
-        m.insert("53544", "series");
-        m.insert("53545", "sermon");
-        m.insert("53546", "serotonin");
-        m.insert("53551", "serpent");
-        m.insert("53552", "serrated");
-        m.insert("53553", "serve");
-        m.insert("53554", "service");
-        m.insert("53555", "serving");
-        m.insert("53556", "sesame");
-        m.insert("53561", "sessions");
-        m.insert("53562", "setback");
-        m.insert("53563", "setting");
-        m.insert("53564", "settle");
-        m.insert("53565", "settling");
-        m.insert("53566", "setup");
-        m.insert("53611", "sevenfold");
-        m.insert("53612", "seventeen");
-        m.insert("53613", "seventh");
-        m.insert("53614", "seventy");
-        m.insert("53615", "severity");
-        m.insert("53616", "shabby");
-        m.insert("53621", "shack");
-        m.insert("53622", "shaded");
-        m.insert("53623", "shadily");
-        m.insert("53624", "shadiness");
-        m.insert("53625", "shading");
-        m.insert("53626", "shadow");
-        m.insert("53631", "shady");
-        m.insert("53632", "shaft");
-        m.insert("53633", "shakable");
-        m.insert("53634", "shakily");
-        m.insert("53635", "shakiness");
-        m.insert("53636", "shaking");
-        m.insert("53641", "shaky");
-        m.insert("53642", "shale");
-        m.insert("53643", "shallot");
-        m.insert("53644", "shallow");
-        m.insert("53645", "shame");
-        m.insert("53646", "shampoo");
-        m.insert("53651", "shamrock");
-        m.insert("53652", "shank");
-        m.insert("53653", "shanty");
-        m.insert("53654", "shape");
-        m.insert("53655", "shaping");
-        m.insert("53656", "share");
-        m.insert("53661", "sharpener");
-        m.insert("53662", "sharper");
-        m.insert("53663", "sharpie");
-        m.insert("53664", "sharply");
-        m.insert("53665", "sharpness");
-        m.insert("53666", "shawl");
-        m.insert("54111", "sheath");
-        m.insert("54112", "shed");
-        m.insert("54113", "sheep");
-        m.insert("54114", "sheet");
-        m.insert("54115", "shelf");
-        m.insert("54116", "shell");
-        m.insert("54121", "shelter");
-        m.insert("54122", "shelve");
-        m.insert("54123", "shelving");
-        m.insert("54124", "sherry");
-        m.insert("54125", "shield");
-        m.insert("54126", "shifter");
-        m.insert("54131", "shifting");
-        m.insert("54132", "shiftless");
-        m.insert("54133", "shifty");
-        m.insert("54134", "shimmer");
-        m.insert("54135", "shimmy");
-        m.insert("54136", "shindig");
-        m.insert("54141", "shine");
-        m.insert("54142", "shingle");
-        m.insert("54143", "shininess");
-        m.insert("54144", "shining");
-        m.insert("54145", "shiny");
-        m.insert("54146", "ship");
-        m.insert("54151", "shirt");
-        m.insert("54152", "shivering");
-        m.insert("54153", "shock");
-        m.insert("54154", "shone");
-        m.insert("54155", "shoplift");
-        m.insert("54156", "shopper");
-        m.insert("54161", "shopping");
-        m.insert("54162", "shoptalk");
-        m.insert("54163", "shore");
-        m.insert("54164", "shortage");
-        m.insert("54165", "shortcake");
-        m.insert("54166", "shortcut");
-        m.insert("54211", "shorten");
-        m.insert("54212", "shorter");
-        m.insert("54213", "shorthand");
-        m.insert("54214", "shortlist");
-        m.insert("54215", "shortly");
-        m.insert("54216", "shortness");
-        m.insert("54221", "shorts");
-        m.insert("54222", "shortwave");
-        m.insert("54223", "shorty");
-        m.insert("54224", "shout");
-        m.insert("54225", "shove");
-        m.insert("54226", "showbiz");
-        m.insert("54231", "showcase");
-        m.insert("54232", "showdown");
-        m.insert("54233", "shower");
-        m.insert("54234", "showgirl");
-        m.insert("54235", "showing");
-        m.insert("54236", "showman");
-        m.insert("54241", "shown");
-        m.insert("54242", "showoff");
-        m.insert("54243", "showpiece");
-        m.insert("54244", "showplace");
-        m.insert("54245", "showroom");
-        m.insert("54246", "showy");
-        m.insert("54251", "shrank");
-        m.insert("54252", "shrapnel");
-        m.insert("54253", "shredder");
-        m.insert("54254", "shredding");
-        m.insert("54255", "shrewdly");
-        m.insert("54256", "shriek");
-        m.insert("54261", "shrill");
-        m.insert("54262", "shrimp");
-        m.insert("54263", "shrine");
-        m.insert("54264", "shrink");
-        m.insert("54265", "shrivel");
-        m.insert("54266", "shrouded");
-        m.insert("54311", "shrubbery");
-        m.insert("54312", "shrubs");
-        m.insert("54313", "shrug");
-        m.insert("54314", "shrunk");
-        m.insert("54315", "shucking");
-        m.insert("54316", "shudder");
-        m.insert("54321", "shuffle");
-        m.insert("54322", "shuffling");
-        m.insert("54323", "shun");
-        m.insert("54324", "shush");
-        m.insert("54325", "shut");
-        m.insert("54326", "shy");
-        m.insert("54331", "siamese");
-        m.insert("54332", "siberian");
-        m.insert("54333", "sibling");
-        m.insert("54334", "siding");
-        m.insert("54335", "sierra");
-        m.insert("54336", "siesta");
-        m.insert("54341", "sift");
-        m.insert("54342", "sighing");
-        m.insert("54343", "silenced");
-        m.insert("54344", "silencer");
-        m.insert("54345", "silent");
-        m.insert("54346", "silica");
-        m.insert("54351", "silicon");
-        m.insert("54352", "silk");
-        m.insert("54353", "silliness");
-        m.insert("54354", "silly");
-        m.insert("54355", "silo");
-        m.insert("54356", "silt");
-        m.insert("54361", "silver");
-        m.insert("54362", "similarly");
-        m.insert("54363", "simile");
-        m.insert("54364", "simmering");
-        m.insert("54365", "simple");
-        m.insert("54366", "simplify");
-        m.insert("54411", "simply");
-        m.insert("54412", "sincere");
-        m.insert("54413", "sincerity");
-        m.insert("54414", "singer");
-        m.insert("54415", "singing");
-        m.insert("54416", "single");
-        m.insert("54421", "singular");
-        m.insert("54422", "sinister");
-        m.insert("54423", "sinless");
-        m.insert("54424", "sinner");
-        m.insert("54425", "sinuous");
-        m.insert("54426", "sip");
-        m.insert("54431", "siren");
-        m.insert("54432", "sister");
-        m.insert("54433", "sitcom");
-        m.insert("54434", "sitter");
-        m.insert("54435", "sitting");
-        m.insert("54436", "situated");
-        m.insert("54441", "situation");
-        m.insert("54442", "sixfold");
-        m.insert("54443", "sixteen");
-        m.insert("54444", "sixth");
-        m.insert("54445", "sixties");
-        m.insert("54446", "sixtieth");
-        m.insert("54451", "sixtyfold");
-        m.insert("54452", "sizable");
-        m.insert("54453", "sizably");
-        m.insert("54454", "size");
-        m.insert("54455", "sizing");
-        m.insert("54456", "sizzle");
-        m.insert("54461", "sizzling");
-        m.insert("54462", "skater");
-        m.insert("54463", "skating");
-        m.insert("54464", "skedaddle");
-        m.insert("54465", "skeletal");
-        m.insert("54466", "skeleton");
-        m.insert("54511", "skeptic");
-        m.insert("54512", "sketch");
-        m.insert("54513", "skewed");
-        m.insert("54514", "skewer");
-        m.insert("54515", "skid");
-        m.insert("54516", "skied");
-        m.insert("54521", "skier");
-        m.insert("54522", "skies");
-        m.insert("54523", "skiing");
-        m.insert("54524", "skilled");
-        m.insert("54525", "skillet");
-        m.insert("54526", "skillful");
-        m.insert("54531", "skimmed");
-        m.insert("54532", "skimmer");
-        m.insert("54533", "skimming");
-        m.insert("54534", "skimpily");
-        m.insert("54535", "skincare");
-        m.insert("54536", "skinhead");
-        m.insert("54541", "skinless");
-        m.insert("54542", "skinning");
-        m.insert("54543", "skinny");
-        m.insert("54544", "skintight");
-        m.insert("54545", "skipper");
-        m.insert("54546", "skipping");
-        m.insert("54551", "skirmish");
-        m.insert("54552", "skirt");
-        m.insert("54553", "skittle");
-        m.insert("54554", "skydiver");
-        m.insert("54555", "skylight");
-        m.insert("54556", "skyline");
-        m.insert("54561", "skype");
-        m.insert("54562", "skyrocket");
-        m.insert("54563", "skyward");
-        m.insert("54564", "slab");
-        m.insert("54565", "slacked");
-        m.insert("54566", "slacker");
-        m.insert("54611", "slacking");
-        m.insert("54612", "slackness");
-        m.insert("54613", "slacks");
-        m.insert("54614", "slain");
-        m.insert("54615", "slam");
-        m.insert("54616", "slander");
-        m.insert("54621", "slang");
-        m.insert("54622", "slapping");
-        m.insert("54623", "slapstick");
-        m.insert("54624", "slashed");
-        m.insert("54625", "slashing");
-        m.insert("54626", "slate");
-        m.insert("54631", "slather");
-        m.insert("54632", "slaw");
-        m.insert("54633", "sled");
-        m.insert("54634", "sleek");
-        m.insert("54635", "sleep");
-        m.insert("54636", "sleet");
-        m.insert("54641", "sleeve");
-        m.insert("54642", "slept");
-        m.insert("54643", "sliceable");
-        m.insert("54644", "sliced");
-        m.insert("54645", "slicer");
-        m.insert("54646", "slicing");
-        m.insert("54651", "slick");
-        m.insert("54652", "slider");
-        m.insert("54653", "slideshow");
-        m.insert("54654", "sliding");
-        m.insert("54655", "slighted");
-        m.insert("54656", "slighting");
-        m.insert("54661", "slightly");
-        m.insert("54662", "slimness");
-        m.insert("54663", "slimy");
-        m.insert("54664", "slinging");
-        m.insert("54665", "slingshot");
-        m.insert("54666", "slinky");
-        m.insert("55111", "slip");
-        m.insert("55112", "slit");
-        m.insert("55113", "sliver");
-        m.insert("55114", "slobbery");
-        m.insert("55115", "slogan");
-        m.insert("55116", "sloped");
-        m.insert("55121", "sloping");
-        m.insert("55122", "sloppily");
-        m.insert("55123", "sloppy");
-        m.insert("55124", "slot");
-        m.insert("55125", "slouching");
-        m.insert("55126", "slouchy");
-        m.insert("55131", "sludge");
-        m.insert("55132", "slug");
-        m.insert("55133", "slum");
-        m.insert("55134", "slurp");
-        m.insert("55135", "slush");
-        m.insert("55136", "sly");
-        m.insert("55141", "small");
-        m.insert("55142", "smartly");
-        m.insert("55143", "smartness");
-        m.insert("55144", "smasher");
-        m.insert("55145", "smashing");
-        m.insert("55146", "smashup");
-        m.insert("55151", "smell");
-        m.insert("55152", "smelting");
-        m.insert("55153", "smile");
-        m.insert("55154", "smilingly");
-        m.insert("55155", "smirk");
-        m.insert("55156", "smite");
-        m.insert("55161", "smith");
-        m.insert("55162", "smitten");
-        m.insert("55163", "smock");
-        m.insert("55164", "smog");
-        m.insert("55165", "smoked");
-        m.insert("55166", "smokeless");
-        m.insert("55211", "smokiness");
-        m.insert("55212", "smoking");
-        m.insert("55213", "smoky");
-        m.insert("55214", "smolder");
-        m.insert("55215", "smooth");
-        m.insert("55216", "smother");
-        m.insert("55221", "smudge");
-        m.insert("55222", "smudgy");
-        m.insert("55223", "smuggler");
-        m.insert("55224", "smuggling");
-        m.insert("55225", "smugly");
-        m.insert("55226", "smugness");
-        m.insert("55231", "snack");
-        m.insert("55232", "snagged");
-        m.insert("55233", "snaking");
-        m.insert("55234", "snap");
-        m.insert("55235", "snare");
-        m.insert("55236", "snarl");
-        m.insert("55241", "snazzy");
-        m.insert("55242", "sneak");
-        m.insert("55243", "sneer");
-        m.insert("55244", "sneeze");
-        m.insert("55245", "sneezing");
-        m.insert("55246", "snide");
-        m.insert("55251", "sniff");
-        m.insert("55252", "snippet");
-        m.insert("55253", "snipping");
-        m.insert("55254", "snitch");
-        m.insert("55255", "snooper");
-        m.insert("55256", "snooze");
-        m.insert("55261", "snore");
-        m.insert("55262", "snoring");
-        m.insert("55263", "snorkel");
-        m.insert("55264", "snort");
-        m.insert("55265", "snout");
-        m.insert("55266", "snowbird");
-        m.insert("55311", "snowboard");
-        m.insert("55312", "snowbound");
-        m.insert("55313", "snowcap");
-        m.insert("55314", "snowdrift");
-        m.insert("55315", "snowdrop");
-        m.insert("55316", "snowfall");
-        m.insert("55321", "snowfield");
-        m.insert("55322", "snowflake");
-        m.insert("55323", "snowiness");
-        m.insert("55324", "snowless");
-        m.insert("55325", "snowman");
-        m.insert("55326", "snowplow");
-        m.insert("55331", "snowshoe");
-        m.insert("55332", "snowstorm");
-        m.insert("55333", "snowsuit");
-        m.insert("55334", "snowy");
-        m.insert("55335", "snub");
-        m.insert("55336", "snuff");
-        m.insert("55341", "snuggle");
-        m.insert("55342", "snugly");
-        m.insert("55343", "snugness");
-        m.insert("55344", "speak");
-        m.insert("55345", "spearfish");
-        m.insert("55346", "spearhead");
-        m.insert("55351", "spearman");
-        m.insert("55352", "spearmint");
-        m.insert("55353", "species");
-        m.insert("55354", "specimen");
-        m.insert("55355", "specked");
-        m.insert("55356", "speckled");
-        m.insert("55361", "specks");
-        m.insert("55362", "spectacle");
-        m.insert("55363", "spectator");
-        m.insert("55364", "spectrum");
-        m.insert("55365", "speculate");
-        m.insert("55366", "speech");
-        m.insert("55411", "speed");
-        m.insert("55412", "spellbind");
-        m.insert("55413", "speller");
-        m.insert("55414", "spelling");
-        m.insert("55415", "spendable");
-        m.insert("55416", "spender");
-        m.insert("55421", "spending");
-        m.insert("55422", "spent");
-        m.insert("55423", "spew");
-        m.insert("55424", "sphere");
-        m.insert("55425", "spherical");
-        m.insert("55426", "sphinx");
-        m.insert("55431", "spider");
-        m.insert("55432", "spied");
-        m.insert("55433", "spiffy");
-        m.insert("55434", "spill");
-        m.insert("55435", "spilt");
-        m.insert("55436", "spinach");
-        m.insert("55441", "spinal");
-        m.insert("55442", "spindle");
-        m.insert("55443", "spinner");
-        m.insert("55444", "spinning");
-        m.insert("55445", "spinout");
-        m.insert("55446", "spinster");
-        m.insert("55451", "spiny");
-        m.insert("55452", "spiral");
-        m.insert("55453", "spirited");
-        m.insert("55454", "spiritism");
-        m.insert("55455", "spirits");
-        m.insert("55456", "spiritual");
-        m.insert("55461", "splashed");
-        m.insert("55462", "splashing");
-        m.insert("55463", "splashy");
-        m.insert("55464", "splatter");
-        m.insert("55465", "spleen");
-        m.insert("55466", "splendid");
-        m.insert("55511", "splendor");
-        m.insert("55512", "splice");
-        m.insert("55513", "splicing");
-        m.insert("55514", "splinter");
-        m.insert("55515", "splotchy");
-        m.insert("55516", "splurge");
-        m.insert("55521", "spoilage");
-        m.insert("55522", "spoiled");
-        m.insert("55523", "spoiler");
-        m.insert("55524", "spoiling");
-        m.insert("55525", "spoils");
-        m.insert("55526", "spoken");
-        m.insert("55531", "spokesman");
-        m.insert("55532", "sponge");
-        m.insert("55533", "spongy");
-        m.insert("55534", "sponsor");
-        m.insert("55535", "spoof");
-        m.insert("55536", "spookily");
-        m.insert("55541", "spooky");
-        m.insert("55542", "spool");
-        m.insert("55543", "spoon");
-        m.insert("55544", "spore");
-        m.insert("55545", "sporting");
-        m.insert("55546", "sports");
-        m.insert("55551", "sporty");
-        m.insert("55552", "spotless");
-        m.insert("55553", "spotlight");
-        m.insert("55554", "spotted");
-        m.insert("55555", "spotter");
-        m.insert("55556", "spotting");
-        m.insert("55561", "spotty");
-        m.insert("55562", "spousal");
-        m.insert("55563", "spouse");
-        m.insert("55564", "spout");
-        m.insert("55565", "sprain");
-        m.insert("55566", "sprang");
-        m.insert("55611", "sprawl");
-        m.insert("55612", "spray");
-        m.insert("55613", "spree");
-        m.insert("55614", "sprig");
-        m.insert("55615", "spring");
-        m.insert("55616", "sprinkled");
-        m.insert("55621", "sprinkler");
-        m.insert("55622", "sprint");
-        m.insert("55623", "sprite");
-        m.insert("55624", "sprout");
-        m.insert("55625", "spruce");
-        m.insert("55626", "sprung");
-        m.insert("55631", "spry");
-        m.insert("55632", "spud");
-        m.insert("55633", "spur");
-        m.insert("55634", "sputter");
-        m.insert("55635", "spyglass");
-        m.insert("55636", "squabble");
-        m.insert("55641", "squad");
-        m.insert("55642", "squall");
-        m.insert("55643", "squander");
-        m.insert("55644", "squash");
-        m.insert("55645", "squatted");
-        m.insert("55646", "squatter");
-        m.insert("55651", "squatting");
-        m.insert("55652", "squeak");
-        m.insert("55653", "squealer");
-        m.insert("55654", "squealing");
-        m.insert("55655", "squeamish");
-        m.insert("55656", "squeegee");
-        m.insert("55661", "squeeze");
-        m.insert("55662", "squeezing");
-        m.insert("55663", "squid");
-        m.insert("55664", "squiggle");
-        m.insert("55665", "squiggly");
-        m.insert("55666", "squint");
-        m.insert("56111", "squire");
-        m.insert("56112", "squirt");
-        m.insert("56113", "squishier");
-        m.insert("56114", "squishy");
-        m.insert("56115", "stability");
-        m.insert("56116", "stabilize");
-        m.insert("56121", "stable");
-        m.insert("56122", "stack");
-        m.insert("56123", "stadium");
-        m.insert("56124", "staff");
-        m.insert("56125", "stage");
-        m.insert("56126", "staging");
-        m.insert("56131", "stagnant");
-        m.insert("56132", "stagnate");
-        m.insert("56133", "stainable");
-        m.insert("56134", "stained");
-        m.insert("56135", "staining");
-        m.insert("56136", "stainless");
-        m.insert("56141", "stalemate");
-        m.insert("56142", "staleness");
-        m.insert("56143", "stalling");
-        m.insert("56144", "stallion");
-        m.insert("56145", "stamina");
-        m.insert("56146", "stammer");
-        m.insert("56151", "stamp");
-        m.insert("56152", "stand");
-        m.insert("56153", "stank");
-        m.insert("56154", "staple");
-        m.insert("56155", "stapling");
-        m.insert("56156", "starboard");
-        m.insert("56161", "starch");
-        m.insert("56162", "stardom");
-        m.insert("56163", "stardust");
-        m.insert("56164", "starfish");
-        m.insert("56165", "stargazer");
-        m.insert("56166", "staring");
-        m.insert("56211", "stark");
-        m.insert("56212", "starless");
-        m.insert("56213", "starlet");
-        m.insert("56214", "starlight");
-        m.insert("56215", "starlit");
-        m.insert("56216", "starring");
-        m.insert("56221", "starry");
-        m.insert("56222", "starship");
-        m.insert("56223", "starter");
-        m.insert("56224", "starting");
-        m.insert("56225", "startle");
-        m.insert("56226", "startling");
-        m.insert("56231", "startup");
-        m.insert("56232", "starved");
-        m.insert("56233", "starving");
-        m.insert("56234", "stash");
-        m.insert("56235", "state");
-        m.insert("56236", "static");
-        m.insert("56241", "statistic");
-        m.insert("56242", "statue");
-        m.insert("56243", "stature");
-        m.insert("56244", "status");
-        m.insert("56245", "statute");
-        m.insert("56246", "statutory");
-        m.insert("56251", "staunch");
-        m.insert("56252", "stays");
-        m.insert("56253", "steadfast");
-        m.insert("56254", "steadier");
-        m.insert("56255", "steadily");
-        m.insert("56256", "steadying");
-        m.insert("56261", "steam");
-        m.insert("56262", "steed");
-        m.insert("56263", "steep");
-        m.insert("56264", "steerable");
-        m.insert("56265", "steering");
-        m.insert("56266", "steersman");
-        m.insert("56311", "stegosaur");
-        m.insert("56312", "stellar");
-        m.insert("56313", "stem");
-        m.insert("56314", "stench");
-        m.insert("56315", "stencil");
-        m.insert("56316", "step");
-        m.insert("56321", "stereo");
-        m.insert("56322", "sterile");
-        m.insert("56323", "sterility");
-        m.insert("56324", "sterilize");
-        m.insert("56325", "sterling");
-        m.insert("56326", "sternness");
-        m.insert("56331", "sternum");
-        m.insert("56332", "stew");
-        m.insert("56333", "stick");
-        m.insert("56334", "stiffen");
-        m.insert("56335", "stiffly");
-        m.insert("56336", "stiffness");
-        m.insert("56341", "stifle");
-        m.insert("56342", "stifling");
-        m.insert("56343", "stillness");
-        m.insert("56344", "stilt");
-        m.insert("56345", "stimulant");
-        m.insert("56346", "stimulate");
-        m.insert("56351", "stimuli");
-        m.insert("56352", "stimulus");
-        m.insert("56353", "stinger");
-        m.insert("56354", "stingily");
-        m.insert("56355", "stinging");
-        m.insert("56356", "stingray");
-        m.insert("56361", "stingy");
-        m.insert("56362", "stinking");
-        m.insert("56363", "stinky");
-        m.insert("56364", "stipend");
-        m.insert("56365", "stipulate");
-        m.insert("56366", "stir");
-        m.insert("56411", "stitch");
-        m.insert("56412", "stock");
-        m.insert("56413", "stoic");
-        m.insert("56414", "stoke");
-        m.insert("56415", "stole");
-        m.insert("56416", "stomp");
-        m.insert("56421", "stonewall");
-        m.insert("56422", "stoneware");
-        m.insert("56423", "stonework");
-        m.insert("56424", "stoning");
-        m.insert("56425", "stony");
-        m.insert("56426", "stood");
-        m.insert("56431", "stooge");
-        m.insert("56432", "stool");
-        m.insert("56433", "stoop");
-        m.insert("56434", "stoplight");
-        m.insert("56435", "stoppable");
-        m.insert("56436", "stoppage");
-        m.insert("56441", "stopped");
-        m.insert("56442", "stopper");
-        m.insert("56443", "stopping");
-        m.insert("56444", "stopwatch");
-        m.insert("56445", "storable");
-        m.insert("56446", "storage");
-        m.insert("56451", "storeroom");
-        m.insert("56452", "storewide");
-        m.insert("56453", "storm");
-        m.insert("56454", "stout");
-        m.insert("56455", "stove");
-        m.insert("56456", "stowaway");
-        m.insert("56461", "stowing");
-        m.insert("56462", "straddle");
-        m.insert("56463", "straggler");
-        m.insert("56464", "strained");
-        m.insert("56465", "strainer");
-        m.insert("56466", "straining");
-        m.insert("56511", "strangely");
-        m.insert("56512", "stranger");
-        m.insert("56513", "strangle");
-        m.insert("56514", "strategic");
-        m.insert("56515", "strategy");
-        m.insert("56516", "stratus");
-        m.insert("56521", "straw");
-        m.insert("56522", "stray");
-        m.insert("56523", "streak");
-        m.insert("56524", "stream");
-        m.insert("56525", "street");
-        m.insert("56526", "strength");
-        m.insert("56531", "strenuous");
-        m.insert("56532", "strep");
-        m.insert("56533", "stress");
-        m.insert("56534", "stretch");
-        m.insert("56535", "strewn");
-        m.insert("56536", "stricken");
-        m.insert("56541", "strict");
-        m.insert("56542", "stride");
-        m.insert("56543", "strife");
-        m.insert("56544", "strike");
-        m.insert("56545", "striking");
-        m.insert("56546", "strive");
-        m.insert("56551", "striving");
-        m.insert("56552", "strobe");
-        m.insert("56553", "strode");
-        m.insert("56554", "stroller");
-        m.insert("56555", "strongbox");
-        m.insert("56556", "strongly");
-        m.insert("56561", "strongman");
-        m.insert("56562", "struck");
-        m.insert("56563", "structure");
-        m.insert("56564", "strudel");
-        m.insert("56565", "struggle");
-        m.insert("56566", "strum");
-        m.insert("56611", "strung");
-        m.insert("56612", "strut");
-        m.insert("56613", "stubbed");
-        m.insert("56614", "stubble");
-        m.insert("56615", "stubbly");
-        m.insert("56616", "stubborn");
-        m.insert("56621", "stucco");
-        m.insert("56622", "stuck");
-        m.insert("56623", "student");
-        m.insert("56624", "studied");
-        m.insert("56625", "studio");
-        m.insert("56626", "study");
-        m.insert("56631", "stuffed");
-        m.insert("56632", "stuffing");
-        m.insert("56633", "stuffy");
-        m.insert("56634", "stumble");
-        m.insert("56635", "stumbling");
-        m.insert("56636", "stump");
-        m.insert("56641", "stung");
-        m.insert("56642", "stunned");
-        m.insert("56643", "stunner");
-        m.insert("56644", "stunning");
-        m.insert("56645", "stunt");
-        m.insert("56646", "stupor");
-        m.insert("56651", "sturdily");
-        m.insert("56652", "sturdy");
-        m.insert("56653", "styling");
-        m.insert("56654", "stylishly");
-        m.insert("56655", "stylist");
-        m.insert("56656", "stylized");
-        m.insert("56661", "stylus");
-        m.insert("56662", "suave");
-        m.insert("56663", "subarctic");
-        m.insert("56664", "subatomic");
-        m.insert("56665", "subdivide");
-        m.insert("56666", "subdued");
-        m.insert("61111", "subduing");
-        m.insert("61112", "subfloor");
-        m.insert("61113", "subgroup");
-        m.insert("61114", "subheader");
-        m.insert("61115", "subject");
-        m.insert("61116", "sublease");
-        m.insert("61121", "sublet");
-        m.insert("61122", "sublevel");
-        m.insert("61123", "sublime");
-        m.insert("61124", "submarine");
-        m.insert("61125", "submerge");
-        m.insert("61126", "submersed");
-        m.insert("61131", "submitter");
-        m.insert("61132", "subpanel");
-        m.insert("61133", "subpar");
-        m.insert("61134", "subplot");
-        m.insert("61135", "subprime");
-        m.insert("61136", "subscribe");
-        m.insert("61141", "subscript");
-        m.insert("61142", "subsector");
-        m.insert("61143", "subside");
-        m.insert("61144", "subsiding");
-        m.insert("61145", "subsidize");
-        m.insert("61146", "subsidy");
-        m.insert("61151", "subsoil");
-        m.insert("61152", "subsonic");
-        m.insert("61153", "substance");
-        m.insert("61154", "subsystem");
-        m.insert("61155", "subtext");
-        m.insert("61156", "subtitle");
-        m.insert("61161", "subtly");
-        m.insert("61162", "subtotal");
-        m.insert("61163", "subtract");
-        m.insert("61164", "subtype");
-        m.insert("61165", "suburb");
-        m.insert("61166", "subway");
-        m.insert("61211", "subwoofer");
-        m.insert("61212", "subzero");
-        m.insert("61213", "succulent");
-        m.insert("61214", "such");
-        m.insert("61215", "suction");
-        m.insert("61216", "sudden");
-        m.insert("61221", "sudoku");
-        m.insert("61222", "suds");
-        m.insert("61223", "sufferer");
-        m.insert("61224", "suffering");
-        m.insert("61225", "suffice");
-        m.insert("61226", "suffix");
-        m.insert("61231", "suffocate");
-        m.insert("61232", "suffrage");
-        m.insert("61233", "sugar");
-        m.insert("61234", "suggest");
-        m.insert("61235", "suing");
-        m.insert("61236", "suitable");
-        m.insert("61241", "suitably");
-        m.insert("61242", "suitcase");
-        m.insert("61243", "suitor");
-        m.insert("61244", "sulfate");
-        m.insert("61245", "sulfide");
-        m.insert("61246", "sulfite");
-        m.insert("61251", "sulfur");
-        m.insert("61252", "sulk");
-        m.insert("61253", "sullen");
-        m.insert("61254", "sulphate");
-        m.insert("61255", "sulphuric");
-        m.insert("61256", "sultry");
-        m.insert("61261", "superbowl");
-        m.insert("61262", "superglue");
-        m.insert("61263", "superhero");
-        m.insert("61264", "superior");
-        m.insert("61265", "superjet");
-        m.insert("61266", "superman");
-        m.insert("61311", "supermom");
-        m.insert("61312", "supernova");
-        m.insert("61313", "supervise");
-        m.insert("61314", "supper");
-        m.insert("61315", "supplier");
-        m.insert("61316", "supply");
-        m.insert("61321", "support");
-        m.insert("61322", "supremacy");
-        m.insert("61323", "supreme");
-        m.insert("61324", "surcharge");
-        m.insert("61325", "surely");
-        m.insert("61326", "sureness");
-        m.insert("61331", "surface");
-        m.insert("61332", "surfacing");
-        m.insert("61333", "surfboard");
-        m.insert("61334", "surfer");
-        m.insert("61335", "surgery");
-        m.insert("61336", "surgical");
-        m.insert("61341", "surging");
-        m.insert("61342", "surname");
-        m.insert("61343", "surpass");
-        m.insert("61344", "surplus");
-        m.insert("61345", "surprise");
-        m.insert("61346", "surreal");
-        m.insert("61351", "surrender");
-        m.insert("61352", "surrogate");
-        m.insert("61353", "surround");
-        m.insert("61354", "survey");
-        m.insert("61355", "survival");
-        m.insert("61356", "survive");
-        m.insert("61361", "surviving");
-        m.insert("61362", "survivor");
-        m.insert("61363", "sushi");
-        m.insert("61364", "suspect");
-        m.insert("61365", "suspend");
-        m.insert("61366", "suspense");
-        m.insert("61411", "sustained");
-        m.insert("61412", "sustainer");
-        m.insert("61413", "swab");
-        m.insert("61414", "swaddling");
-        m.insert("61415", "swagger");
-        m.insert("61416", "swampland");
-        m.insert("61421", "swan");
-        m.insert("61422", "swapping");
-        m.insert("61423", "swarm");
-        m.insert("61424", "sway");
-        m.insert("61425", "swear");
-        m.insert("61426", "sweat");
-        m.insert("61431", "sweep");
-        m.insert("61432", "swell");
-        m.insert("61433", "swept");
-        m.insert("61434", "swerve");
-        m.insert("61435", "swifter");
-        m.insert("61436", "swiftly");
-        m.insert("61441", "swiftness");
-        m.insert("61442", "swimmable");
-        m.insert("61443", "swimmer");
-        m.insert("61444", "swimming");
-        m.insert("61445", "swimsuit");
-        m.insert("61446", "swimwear");
-        m.insert("61451", "swinger");
-        m.insert("61452", "swinging");
-        m.insert("61453", "swipe");
-        m.insert("61454", "swirl");
-        m.insert("61455", "switch");
-        m.insert("61456", "swivel");
-        m.insert("61461", "swizzle");
-        m.insert("61462", "swooned");
-        m.insert("61463", "swoop");
-        m.insert("61464", "swoosh");
-        m.insert("61465", "swore");
-        m.insert("61466", "sworn");
-        m.insert("61511", "swung");
-        m.insert("61512", "sycamore");
-        m.insert("61513", "sympathy");
-        m.insert("61514", "symphonic");
-        m.insert("61515", "symphony");
-        m.insert("61516", "symptom");
-        m.insert("61521", "synapse");
-        m.insert("61522", "syndrome");
-        m.insert("61523", "synergy");
-        m.insert("61524", "synopses");
-        m.insert("61525", "synopsis");
-        m.insert("61526", "synthesis");
-        m.insert("61531", "synthetic");
-        m.insert("61532", "syrup");
-        m.insert("61533", "system");
-        m.insert("61534", "t-shirt");
-        m.insert("61535", "tabasco");
-        m.insert("61536", "tabby");
-        m.insert("61541", "tableful");
-        m.insert("61542", "tables");
-        m.insert("61543", "tablet");
-        m.insert("61544", "tableware");
-        m.insert("61545", "tabloid");
-        m.insert("61546", "tackiness");
-        m.insert("61551", "tacking");
-        m.insert("61552", "tackle");
-        m.insert("61553", "tackling");
-        m.insert("61554", "tacky");
-        m.insert("61555", "taco");
-        m.insert("61556", "tactful");
-        m.insert("61561", "tactical");
-        m.insert("61562", "tactics");
-        m.insert("61563", "tactile");
-        m.insert("61564", "tactless");
-        m.insert("61565", "tadpole");
-        m.insert("61566", "taekwondo");
-        m.insert("61611", "tag");
-        m.insert("61612", "tainted");
-        m.insert("61613", "take");
-        m.insert("61614", "taking");
-        m.insert("61615", "talcum");
-        m.insert("61616", "talisman");
-        m.insert("61621", "tall");
-        m.insert("61622", "talon");
-        m.insert("61623", "tamale");
-        m.insert("61624", "tameness");
-        m.insert("61625", "tamer");
-        m.insert("61626", "tamper");
-        m.insert("61631", "tank");
-        m.insert("61632", "tanned");
-        m.insert("61633", "tannery");
-        m.insert("61634", "tanning");
-        m.insert("61635", "tantrum");
-        m.insert("61636", "tapeless");
-        m.insert("61641", "tapered");
-        m.insert("61642", "tapering");
-        m.insert("61643", "tapestry");
-        m.insert("61644", "tapioca");
-        m.insert("61645", "tapping");
-        m.insert("61646", "taps");
-        m.insert("61651", "tarantula");
-        m.insert("61652", "target");
-        m.insert("61653", "tarmac");
-        m.insert("61654", "tarnish");
-        m.insert("61655", "tarot");
-        m.insert("61656", "tartar");
-        m.insert("61661", "tartly");
-        m.insert("61662", "tartness");
-        m.insert("61663", "task");
-        m.insert("61664", "tassel");
-        m.insert("61665", "taste");
-        m.insert("61666", "tastiness");
-        m.insert("62111", "tasting");
-        m.insert("62112", "tasty");
-        m.insert("62113", "tattered");
-        m.insert("62114", "tattle");
-        m.insert("62115", "tattling");
-        m.insert("62116", "tattoo");
-        m.insert("62121", "taunt");
-        m.insert("62122", "tavern");
-        m.insert("62123", "thank");
-        m.insert("62124", "that");
-        m.insert("62125", "thaw");
-        m.insert("62126", "theater");
-        m.insert("62131", "theatrics");
-        m.insert("62132", "thee");
-        m.insert("62133", "theft");
-        m.insert("62134", "theme");
-        m.insert("62135", "theology");
-        m.insert("62136", "theorize");
-        m.insert("62141", "thermal");
-        m.insert("62142", "thermos");
-        m.insert("62143", "thesaurus");
-        m.insert("62144", "these");
-        m.insert("62145", "thesis");
-        m.insert("62146", "thespian");
-        m.insert("62151", "thicken");
-        m.insert("62152", "thicket");
-        m.insert("62153", "thickness");
-        m.insert("62154", "thieving");
-        m.insert("62155", "thievish");
-        m.insert("62156", "thigh");
-        m.insert("62161", "thimble");
-        m.insert("62162", "thing");
-        m.insert("62163", "think");
-        m.insert("62164", "thinly");
-        m.insert("62165", "thinner");
-        m.insert("62166", "thinness");
-        m.insert("62211", "thinning");
-        m.insert("62212", "thirstily");
-        m.insert("62213", "thirsting");
-        m.insert("62214", "thirsty");
-        m.insert("62215", "thirteen");
-        m.insert("62216", "thirty");
-        m.insert("62221", "thong");
-        m.insert("62222", "thorn");
-        m.insert("62223", "those");
-        m.insert("62224", "thousand");
-        m.insert("62225", "thrash");
-        m.insert("62226", "thread");
-        m.insert("62231", "threaten");
-        m.insert("62232", "threefold");
-        m.insert("62233", "thrift");
-        m.insert("62234", "thrill");
-        m.insert("62235", "thrive");
-        m.insert("62236", "thriving");
-        m.insert("62241", "throat");
-        m.insert("62242", "throbbing");
-        m.insert("62243", "throng");
-        m.insert("62244", "throttle");
-        m.insert("62245", "throwaway");
-        m.insert("62246", "throwback");
-        m.insert("62251", "thrower");
-        m.insert("62252", "throwing");
-        m.insert("62253", "thud");
-        m.insert("62254", "thumb");
-        m.insert("62255", "thumping");
-        m.insert("62256", "thursday");
-        m.insert("62261", "thus");
-        m.insert("62262", "thwarting");
-        m.insert("62263", "thyself");
-        m.insert("62264", "tiara");
-        m.insert("62265", "tibia");
-        m.insert("62266", "tidal");
-        m.insert("62311", "tidbit");
-        m.insert("62312", "tidiness");
-        m.insert("62313", "tidings");
-        m.insert("62314", "tidy");
-        m.insert("62315", "tiger");
-        m.insert("62316", "tighten");
-        m.insert("62321", "tightly");
-        m.insert("62322", "tightness");
-        m.insert("62323", "tightrope");
-        m.insert("62324", "tightwad");
-        m.insert("62325", "tigress");
-        m.insert("62326", "tile");
-        m.insert("62331", "tiling");
-        m.insert("62332", "till");
-        m.insert("62333", "tilt");
-        m.insert("62334", "timid");
-        m.insert("62335", "timing");
-        m.insert("62336", "timothy");
-        m.insert("62341", "tinderbox");
-        m.insert("62342", "tinfoil");
-        m.insert("62343", "tingle");
-        m.insert("62344", "tingling");
-        m.insert("62345", "tingly");
-        m.insert("62346", "tinker");
-        m.insert("62351", "tinkling");
-        m.insert("62352", "tinsel");
-        m.insert("62353", "tinsmith");
-        m.insert("62354", "tint");
-        m.insert("62355", "tinwork");
-        m.insert("62356", "tiny");
-        m.insert("62361", "tipoff");
-        m.insert("62362", "tipped");
-        m.insert("62363", "tipper");
-        m.insert("62364", "tipping");
-        m.insert("62365", "tiptoeing");
-        m.insert("62366", "tiptop");
-        m.insert("62411", "tiring");
-        m.insert("62412", "tissue");
-        m.insert("62413", "trace");
-        m.insert("62414", "tracing");
-        m.insert("62415", "track");
-        m.insert("62416", "traction");
-        m.insert("62421", "tractor");
-        m.insert("62422", "trade");
-        m.insert("62423", "trading");
-        m.insert("62424", "tradition");
-        m.insert("62425", "traffic");
-        m.insert("62426", "tragedy");
-        m.insert("62431", "trailing");
-        m.insert("62432", "trailside");
-        m.insert("62433", "train");
-        m.insert("62434", "traitor");
-        m.insert("62435", "trance");
-        m.insert("62436", "tranquil");
-        m.insert("62441", "transfer");
-        m.insert("62442", "transform");
-        m.insert("62443", "translate");
-        m.insert("62444", "transpire");
-        m.insert("62445", "transport");
-        m.insert("62446", "transpose");
-        m.insert("62451", "trapdoor");
-        m.insert("62452", "trapeze");
-        m.insert("62453", "trapezoid");
-        m.insert("62454", "trapped");
-        m.insert("62455", "trapper");
-        m.insert("62456", "trapping");
-        m.insert("62461", "traps");
-        m.insert("62462", "trash");
-        m.insert("62463", "travel");
-        m.insert("62464", "traverse");
-        m.insert("62465", "travesty");
-        m.insert("62466", "tray");
-        m.insert("62511", "treachery");
-        m.insert("62512", "treading");
-        m.insert("62513", "treadmill");
-        m.insert("62514", "treason");
-        m.insert("62515", "treat");
-        m.insert("62516", "treble");
-        m.insert("62521", "tree");
-        m.insert("62522", "trekker");
-        m.insert("62523", "tremble");
-        m.insert("62524", "trembling");
-        m.insert("62525", "tremor");
-        m.insert("62526", "trench");
-        m.insert("62531", "trend");
-        m.insert("62532", "trespass");
-        m.insert("62533", "triage");
-        m.insert("62534", "trial");
-        m.insert("62535", "triangle");
-        m.insert("62536", "tribesman");
-        m.insert("62541", "tribunal");
-        m.insert("62542", "tribune");
-        m.insert("62543", "tributary");
-        m.insert("62544", "tribute");
-        m.insert("62545", "triceps");
-        m.insert("62546", "trickery");
-        m.insert("62551", "trickily");
-        m.insert("62552", "tricking");
-        m.insert("62553", "trickle");
-        m.insert("62554", "trickster");
-        m.insert("62555", "tricky");
-        m.insert("62556", "tricolor");
-        m.insert("62561", "tricycle");
-        m.insert("62562", "trident");
-        m.insert("62563", "tried");
-        m.insert("62564", "trifle");
-        m.insert("62565", "trifocals");
-        m.insert("62566", "trillion");
-        m.insert("62611", "trilogy");
-        m.insert("62612", "trimester");
-        m.insert("62613", "trimmer");
-        m.insert("62614", "trimming");
-        m.insert("62615", "trimness");
-        m.insert("62616", "trinity");
-        m.insert("62621", "trio");
-        m.insert("62622", "tripod");
-        m.insert("62623", "tripping");
-        m.insert("62624", "triumph");
-        m.insert("62625", "trivial");
-        m.insert("62626", "trodden");
-        m.insert("62631", "trolling");
-        m.insert("62632", "trombone");
-        m.insert("62633", "trophy");
-        m.insert("62634", "tropical");
-        m.insert("62635", "tropics");
-        m.insert("62636", "trouble");
-        m.insert("62641", "troubling");
-        m.insert("62642", "trough");
-        m.insert("62643", "trousers");
-        m.insert("62644", "trout");
-        m.insert("62645", "trowel");
-        m.insert("62646", "truce");
-        m.insert("62651", "truck");
-        m.insert("62652", "truffle");
-        m.insert("62653", "trump");
-        m.insert("62654", "trunks");
-        m.insert("62655", "trustable");
-        m.insert("62656", "trustee");
-        m.insert("62661", "trustful");
-        m.insert("62662", "trusting");
-        m.insert("62663", "trustless");
-        m.insert("62664", "truth");
-        m.insert("62665", "try");
-        m.insert("62666", "tubby");
-        m.insert("63111", "tubeless");
-        m.insert("63112", "tubular");
-        m.insert("63113", "tucking");
-        m.insert("63114", "tuesday");
-        m.insert("63115", "tug");
-        m.insert("63116", "tuition");
-        m.insert("63121", "tulip");
-        m.insert("63122", "tumble");
-        m.insert("63123", "tumbling");
-        m.insert("63124", "tummy");
-        m.insert("63125", "turban");
-        m.insert("63126", "turbine");
-        m.insert("63131", "turbofan");
-        m.insert("63132", "turbojet");
-        m.insert("63133", "turbulent");
-        m.insert("63134", "turf");
-        m.insert("63135", "turkey");
-        m.insert("63136", "turmoil");
-        m.insert("63141", "turret");
-        m.insert("63142", "turtle");
-        m.insert("63143", "tusk");
-        m.insert("63144", "tutor");
-        m.insert("63145", "tutu");
-        m.insert("63146", "tux");
-        m.insert("63151", "tweak");
-        m.insert("63152", "tweed");
-        m.insert("63153", "tweet");
-        m.insert("63154", "tweezers");
-        m.insert("63155", "twelve");
-        m.insert("63156", "twentieth");
-        m.insert("63161", "twenty");
-        m.insert("63162", "twerp");
-        m.insert("63163", "twice");
-        m.insert("63164", "twiddle");
-        m.insert("63165", "twiddling");
-        m.insert("63166", "twig");
-        m.insert("63211", "twilight");
-        m.insert("63212", "twine");
-        m.insert("63213", "twins");
-        m.insert("63214", "twirl");
-        m.insert("63215", "twistable");
-        m.insert("63216", "twisted");
-        m.insert("63221", "twister");
-        m.insert("63222", "twisting");
-        m.insert("63223", "twisty");
-        m.insert("63224", "twitch");
-        m.insert("63225", "twitter");
-        m.insert("63226", "tycoon");
-        m.insert("63231", "tying");
-        m.insert("63232", "tyke");
-        m.insert("63233", "udder");
-        m.insert("63234", "ultimate");
-        m.insert("63235", "ultimatum");
-        m.insert("63236", "ultra");
-        m.insert("63241", "umbilical");
-        m.insert("63242", "umbrella");
-        m.insert("63243", "umpire");
-        m.insert("63244", "unabashed");
-        m.insert("63245", "unable");
-        m.insert("63246", "unadorned");
-        m.insert("63251", "unadvised");
-        m.insert("63252", "unafraid");
-        m.insert("63253", "unaired");
-        m.insert("63254", "unaligned");
-        m.insert("63255", "unaltered");
-        m.insert("63256", "unarmored");
-        m.insert("63261", "unashamed");
-        m.insert("63262", "unaudited");
-        m.insert("63263", "unawake");
-        m.insert("63264", "unaware");
-        m.insert("63265", "unbaked");
-        m.insert("63266", "unbalance");
-        m.insert("63311", "unbeaten");
-        m.insert("63312", "unbend");
-        m.insert("63313", "unbent");
-        m.insert("63314", "unbiased");
-        m.insert("63315", "unbitten");
-        m.insert("63316", "unblended");
-        m.insert("63321", "unblessed");
-        m.insert("63322", "unblock");
-        m.insert("63323", "unbolted");
-        m.insert("63324", "unbounded");
-        m.insert("63325", "unboxed");
-        m.insert("63326", "unbraided");
-        m.insert("63331", "unbridle");
-        m.insert("63332", "unbroken");
-        m.insert("63333", "unbuckled");
-        m.insert("63334", "unbundle");
-        m.insert("63335", "unburned");
-        m.insert("63336", "unbutton");
-        m.insert("63341", "uncanny");
-        m.insert("63342", "uncapped");
-        m.insert("63343", "uncaring");
-        m.insert("63344", "uncertain");
-        m.insert("63345", "unchain");
-        m.insert("63346", "unchanged");
-        m.insert("63351", "uncharted");
-        m.insert("63352", "uncheck");
-        m.insert("63353", "uncivil");
-        m.insert("63354", "unclad");
-        m.insert("63355", "unclaimed");
-        m.insert("63356", "unclamped");
-        m.insert("63361", "unclasp");
-        m.insert("63362", "uncle");
-        m.insert("63363", "unclip");
-        m.insert("63364", "uncloak");
-        m.insert("63365", "unclog");
-        m.insert("63366", "unclothed");
-        m.insert("63411", "uncoated");
-        m.insert("63412", "uncoiled");
-        m.insert("63413", "uncolored");
-        m.insert("63414", "uncombed");
-        m.insert("63415", "uncommon");
-        m.insert("63416", "uncooked");
-        m.insert("63421", "uncork");
-        m.insert("63422", "uncorrupt");
-        m.insert("63423", "uncounted");
-        m.insert("63424", "uncouple");
-        m.insert("63425", "uncouth");
-        m.insert("63426", "uncover");
-        m.insert("63431", "uncross");
-        m.insert("63432", "uncrown");
-        m.insert("63433", "uncrushed");
-        m.insert("63434", "uncured");
-        m.insert("63435", "uncurious");
-        m.insert("63436", "uncurled");
-        m.insert("63441", "uncut");
-        m.insert("63442", "undamaged");
-        m.insert("63443", "undated");
-        m.insert("63444", "undaunted");
-        m.insert("63445", "undead");
-        m.insert("63446", "undecided");
-        m.insert("63451", "undefined");
-        m.insert("63452", "underage");
-        m.insert("63453", "underarm");
-        m.insert("63454", "undercoat");
-        m.insert("63455", "undercook");
-        m.insert("63456", "undercut");
-        m.insert("63461", "underdog");
-        m.insert("63462", "underdone");
-        m.insert("63463", "underfed");
-        m.insert("63464", "underfeed");
-        m.insert("63465", "underfoot");
-        m.insert("63466", "undergo");
-        m.insert("63511", "undergrad");
-        m.insert("63512", "underhand");
-        m.insert("63513", "underline");
-        m.insert("63514", "underling");
-        m.insert("63515", "undermine");
-        m.insert("63516", "undermost");
-        m.insert("63521", "underpaid");
-        m.insert("63522", "underpass");
-        m.insert("63523", "underpay");
-        m.insert("63524", "underrate");
-        m.insert("63525", "undertake");
-        m.insert("63526", "undertone");
-        m.insert("63531", "undertook");
-        m.insert("63532", "undertow");
-        m.insert("63533", "underuse");
-        m.insert("63534", "underwear");
-        m.insert("63535", "underwent");
-        m.insert("63536", "underwire");
-        m.insert("63541", "undesired");
-        m.insert("63542", "undiluted");
-        m.insert("63543", "undivided");
-        m.insert("63544", "undocked");
-        m.insert("63545", "undoing");
-        m.insert("63546", "undone");
-        m.insert("63551", "undrafted");
-        m.insert("63552", "undress");
-        m.insert("63553", "undrilled");
-        m.insert("63554", "undusted");
-        m.insert("63555", "undying");
-        m.insert("63556", "unearned");
-        m.insert("63561", "unearth");
-        m.insert("63562", "unease");
-        m.insert("63563", "uneasily");
-        m.insert("63564", "uneasy");
-        m.insert("63565", "uneatable");
-        m.insert("63566", "uneaten");
-        m.insert("63611", "unedited");
-        m.insert("63612", "unelected");
-        m.insert("63613", "unending");
-        m.insert("63614", "unengaged");
-        m.insert("63615", "unenvied");
-        m.insert("63616", "unequal");
-        m.insert("63621", "unethical");
-        m.insert("63622", "uneven");
-        m.insert("63623", "unexpired");
-        m.insert("63624", "unexposed");
-        m.insert("63625", "unfailing");
-        m.insert("63626", "unfair");
-        m.insert("63631", "unfasten");
-        m.insert("63632", "unfazed");
-        m.insert("63633", "unfeeling");
-        m.insert("63634", "unfiled");
-        m.insert("63635", "unfilled");
-        m.insert("63636", "unfitted");
-        m.insert("63641", "unfitting");
-        m.insert("63642", "unfixable");
-        m.insert("63643", "unfixed");
-        m.insert("63644", "unflawed");
-        m.insert("63645", "unfocused");
-        m.insert("63646", "unfold");
-        m.insert("63651", "unfounded");
-        m.insert("63652", "unframed");
-        m.insert("63653", "unfreeze");
-        m.insert("63654", "unfrosted");
-        m.insert("63655", "unfrozen");
-        m.insert("63656", "unfunded");
-        m.insert("63661", "unglazed");
-        m.insert("63662", "ungloved");
-        m.insert("63663", "unglue");
-        m.insert("63664", "ungodly");
-        m.insert("63665", "ungraded");
-        m.insert("63666", "ungreased");
-        m.insert("64111", "unguarded");
-        m.insert("64112", "unguided");
-        m.insert("64113", "unhappily");
-        m.insert("64114", "unhappy");
-        m.insert("64115", "unharmed");
-        m.insert("64116", "unhealthy");
-        m.insert("64121", "unheard");
-        m.insert("64122", "unhearing");
-        m.insert("64123", "unheated");
-        m.insert("64124", "unhelpful");
-        m.insert("64125", "unhidden");
-        m.insert("64126", "unhinge");
-        m.insert("64131", "unhitched");
-        m.insert("64132", "unholy");
-        m.insert("64133", "unhook");
-        m.insert("64134", "unicorn");
-        m.insert("64135", "unicycle");
-        m.insert("64136", "unified");
-        m.insert("64141", "unifier");
-        m.insert("64142", "uniformed");
-        m.insert("64143", "uniformly");
-        m.insert("64144", "unify");
-        m.insert("64145", "unimpeded");
-        m.insert("64146", "uninjured");
-        m.insert("64151", "uninstall");
-        m.insert("64152", "uninsured");
-        m.insert("64153", "uninvited");
-        m.insert("64154", "union");
-        m.insert("64155", "uniquely");
-        m.insert("64156", "unisexual");
-        m.insert("64161", "unison");
-        m.insert("64162", "unissued");
-        m.insert("64163", "unit");
-        m.insert("64164", "universal");
-        m.insert("64165", "universe");
-        m.insert("64166", "unjustly");
-        m.insert("64211", "unkempt");
-        m.insert("64212", "unkind");
-        m.insert("64213", "unknotted");
-        m.insert("64214", "unknowing");
-        m.insert("64215", "unknown");
-        m.insert("64216", "unlaced");
-        m.insert("64221", "unlatch");
-        m.insert("64222", "unlawful");
-        m.insert("64223", "unleaded");
-        m.insert("64224", "unlearned");
-        m.insert("64225", "unleash");
-        m.insert("64226", "unless");
-        m.insert("64231", "unleveled");
-        m.insert("64232", "unlighted");
-        m.insert("64233", "unlikable");
-        m.insert("64234", "unlimited");
-        m.insert("64235", "unlined");
-        m.insert("64236", "unlinked");
-        m.insert("64241", "unlisted");
-        m.insert("64242", "unlit");
-        m.insert("64243", "unlivable");
-        m.insert("64244", "unloaded");
-        m.insert("64245", "unloader");
-        m.insert("64246", "unlocked");
-        m.insert("64251", "unlocking");
-        m.insert("64252", "unlovable");
-        m.insert("64253", "unloved");
-        m.insert("64254", "unlovely");
-        m.insert("64255", "unloving");
-        m.insert("64256", "unluckily");
-        m.insert("64261", "unlucky");
-        m.insert("64262", "unmade");
-        m.insert("64263", "unmanaged");
-        m.insert("64264", "unmanned");
-        m.insert("64265", "unmapped");
-        m.insert("64266", "unmarked");
-        m.insert("64311", "unmasked");
-        m.insert("64312", "unmasking");
-        m.insert("64313", "unmatched");
-        m.insert("64314", "unmindful");
-        m.insert("64315", "unmixable");
-        m.insert("64316", "unmixed");
-        m.insert("64321", "unmolded");
-        m.insert("64322", "unmoral");
-        m.insert("64323", "unmovable");
-        m.insert("64324", "unmoved");
-        m.insert("64325", "unmoving");
-        m.insert("64326", "unnamable");
-        m.insert("64331", "unnamed");
-        m.insert("64332", "unnatural");
-        m.insert("64333", "unneeded");
-        m.insert("64334", "unnerve");
-        m.insert("64335", "unnerving");
-        m.insert("64336", "unnoticed");
-        m.insert("64341", "unopened");
-        m.insert("64342", "unopposed");
-        m.insert("64343", "unpack");
-        m.insert("64344", "unpadded");
-        m.insert("64345", "unpaid");
-        m.insert("64346", "unpainted");
-        m.insert("64351", "unpaired");
-        m.insert("64352", "unpaved");
-        m.insert("64353", "unpeeled");
-        m.insert("64354", "unpicked");
-        m.insert("64355", "unpiloted");
-        m.insert("64356", "unpinned");
-        m.insert("64361", "unplanned");
-        m.insert("64362", "unplanted");
-        m.insert("64363", "unpleased");
-        m.insert("64364", "unpledged");
-        m.insert("64365", "unplowed");
-        m.insert("64366", "unplug");
-        m.insert("64411", "unpopular");
-        m.insert("64412", "unproven");
-        m.insert("64413", "unquote");
-        m.insert("64414", "unranked");
-        m.insert("64415", "unrated");
-        m.insert("64416", "unraveled");
-        m.insert("64421", "unreached");
-        m.insert("64422", "unread");
-        m.insert("64423", "unreal");
-        m.insert("64424", "unreeling");
-        m.insert("64425", "unrefined");
-        m.insert("64426", "unrelated");
-        m.insert("64431", "unrented");
-        m.insert("64432", "unrest");
-        m.insert("64433", "unretired");
-        m.insert("64434", "unrevised");
-        m.insert("64435", "unrigged");
-        m.insert("64436", "unripe");
-        m.insert("64441", "unrivaled");
-        m.insert("64442", "unroasted");
-        m.insert("64443", "unrobed");
-        m.insert("64444", "unroll");
-        m.insert("64445", "unruffled");
-        m.insert("64446", "unruly");
-        m.insert("64451", "unrushed");
-        m.insert("64452", "unsaddle");
-        m.insert("64453", "unsafe");
-        m.insert("64454", "unsaid");
-        m.insert("64455", "unsalted");
-        m.insert("64456", "unsaved");
-        m.insert("64461", "unsavory");
-        m.insert("64462", "unscathed");
-        m.insert("64463", "unscented");
-        m.insert("64464", "unscrew");
-        m.insert("64465", "unsealed");
-        m.insert("64466", "unseated");
-        m.insert("64511", "unsecured");
-        m.insert("64512", "unseeing");
-        m.insert("64513", "unseemly");
-        m.insert("64514", "unseen");
-        m.insert("64515", "unselect");
-        m.insert("64516", "unselfish");
-        m.insert("64521", "unsent");
-        m.insert("64522", "unsettled");
-        m.insert("64523", "unshackle");
-        m.insert("64524", "unshaken");
-        m.insert("64525", "unshaved");
-        m.insert("64526", "unshaven");
-        m.insert("64531", "unsheathe");
-        m.insert("64532", "unshipped");
-        m.insert("64533", "unsightly");
-        m.insert("64534", "unsigned");
-        m.insert("64535", "unskilled");
-        m.insert("64536", "unsliced");
-        m.insert("64541", "unsmooth");
-        m.insert("64542", "unsnap");
-        m.insert("64543", "unsocial");
-        m.insert("64544", "unsoiled");
-        m.insert("64545", "unsold");
-        m.insert("64546", "unsolved");
-        m.insert("64551", "unsorted");
-        m.insert("64552", "unspoiled");
-        m.insert("64553", "unspoken");
-        m.insert("64554", "unstable");
-        m.insert("64555", "unstaffed");
-        m.insert("64556", "unstamped");
-        m.insert("64561", "unsteady");
-        m.insert("64562", "unsterile");
-        m.insert("64563", "unstirred");
-        m.insert("64564", "unstitch");
-        m.insert("64565", "unstopped");
-        m.insert("64566", "unstuck");
-        m.insert("64611", "unstuffed");
-        m.insert("64612", "unstylish");
-        m.insert("64613", "unsubtle");
-        m.insert("64614", "unsubtly");
-        m.insert("64615", "unsuited");
-        m.insert("64616", "unsure");
-        m.insert("64621", "unsworn");
-        m.insert("64622", "untagged");
-        m.insert("64623", "untainted");
-        m.insert("64624", "untaken");
-        m.insert("64625", "untamed");
-        m.insert("64626", "untangled");
-        m.insert("64631", "untapped");
-        m.insert("64632", "untaxed");
-        m.insert("64633", "unthawed");
-        m.insert("64634", "unthread");
-        m.insert("64635", "untidy");
-        m.insert("64636", "untie");
-        m.insert("64641", "until");
-        m.insert("64642", "untimed");
-        m.insert("64643", "untimely");
-        m.insert("64644", "untitled");
-        m.insert("64645", "untoasted");
-        m.insert("64646", "untold");
-        m.insert("64651", "untouched");
-        m.insert("64652", "untracked");
-        m.insert("64653", "untrained");
-        m.insert("64654", "untreated");
-        m.insert("64655", "untried");
-        m.insert("64656", "untrimmed");
-        m.insert("64661", "untrue");
-        m.insert("64662", "untruth");
-        m.insert("64663", "unturned");
-        m.insert("64664", "untwist");
-        m.insert("64665", "untying");
-        m.insert("64666", "unusable");
-        m.insert("65111", "unused");
-        m.insert("65112", "unusual");
-        m.insert("65113", "unvalued");
-        m.insert("65114", "unvaried");
-        m.insert("65115", "unvarying");
-        m.insert("65116", "unveiled");
-        m.insert("65121", "unveiling");
-        m.insert("65122", "unvented");
-        m.insert("65123", "unviable");
-        m.insert("65124", "unvisited");
-        m.insert("65125", "unvocal");
-        m.insert("65126", "unwanted");
-        m.insert("65131", "unwarlike");
-        m.insert("65132", "unwary");
-        m.insert("65133", "unwashed");
-        m.insert("65134", "unwatched");
-        m.insert("65135", "unweave");
-        m.insert("65136", "unwed");
-        m.insert("65141", "unwelcome");
-        m.insert("65142", "unwell");
-        m.insert("65143", "unwieldy");
-        m.insert("65144", "unwilling");
-        m.insert("65145", "unwind");
-        m.insert("65146", "unwired");
-        m.insert("65151", "unwitting");
-        m.insert("65152", "unwomanly");
-        m.insert("65153", "unworldly");
-        m.insert("65154", "unworn");
-        m.insert("65155", "unworried");
-        m.insert("65156", "unworthy");
-        m.insert("65161", "unwound");
-        m.insert("65162", "unwoven");
-        m.insert("65163", "unwrapped");
-        m.insert("65164", "unwritten");
-        m.insert("65165", "unzip");
-        m.insert("65166", "upbeat");
-        m.insert("65211", "upchuck");
-        m.insert("65212", "upcoming");
-        m.insert("65213", "upcountry");
-        m.insert("65214", "update");
-        m.insert("65215", "upfront");
-        m.insert("65216", "upgrade");
-        m.insert("65221", "upheaval");
-        m.insert("65222", "upheld");
-        m.insert("65223", "uphill");
-        m.insert("65224", "uphold");
-        m.insert("65225", "uplifted");
-        m.insert("65226", "uplifting");
-        m.insert("65231", "upload");
-        m.insert("65232", "upon");
-        m.insert("65233", "upper");
-        m.insert("65234", "upright");
-        m.insert("65235", "uprising");
-        m.insert("65236", "upriver");
-        m.insert("65241", "uproar");
-        m.insert("65242", "uproot");
-        m.insert("65243", "upscale");
-        m.insert("65244", "upside");
-        m.insert("65245", "upstage");
-        m.insert("65246", "upstairs");
-        m.insert("65251", "upstart");
-        m.insert("65252", "upstate");
-        m.insert("65253", "upstream");
-        m.insert("65254", "upstroke");
-        m.insert("65255", "upswing");
-        m.insert("65256", "uptake");
-        m.insert("65261", "uptight");
-        m.insert("65262", "uptown");
-        m.insert("65263", "upturned");
-        m.insert("65264", "upward");
-        m.insert("65265", "upwind");
-        m.insert("65266", "uranium");
-        m.insert("65311", "urban");
-        m.insert("65312", "urchin");
-        m.insert("65313", "urethane");
-        m.insert("65314", "urgency");
-        m.insert("65315", "urgent");
-        m.insert("65316", "urging");
-        m.insert("65321", "urologist");
-        m.insert("65322", "urology");
-        m.insert("65323", "usable");
-        m.insert("65324", "usage");
-        m.insert("65325", "useable");
-        m.insert("65326", "used");
-        m.insert("65331", "uselessly");
-        m.insert("65332", "user");
-        m.insert("65333", "usher");
-        m.insert("65334", "usual");
-        m.insert("65335", "utensil");
-        m.insert("65336", "utility");
-        m.insert("65341", "utilize");
-        m.insert("65342", "utmost");
-        m.insert("65343", "utopia");
-        m.insert("65344", "utter");
-        m.insert("65345", "vacancy");
-        m.insert("65346", "vacant");
-        m.insert("65351", "vacate");
-        m.insert("65352", "vacation");
-        m.insert("65353", "vagabond");
-        m.insert("65354", "vagrancy");
-        m.insert("65355", "vagrantly");
-        m.insert("65356", "vaguely");
-        m.insert("65361", "vagueness");
-        m.insert("65362", "valiant");
-        m.insert("65363", "valid");
-        m.insert("65364", "valium");
-        m.insert("65365", "valley");
-        m.insert("65366", "valuables");
-        m.insert("65411", "value");
-        m.insert("65412", "vanilla");
-        m.insert("65413", "vanish");
-        m.insert("65414", "vanity");
-        m.insert("65415", "vanquish");
-        m.insert("65416", "vantage");
-        m.insert("65421", "vaporizer");
-        m.insert("65422", "variable");
-        m.insert("65423", "variably");
-        m.insert("65424", "varied");
-        m.insert("65425", "variety");
-        m.insert("65426", "various");
-        m.insert("65431", "varmint");
-        m.insert("65432", "varnish");
-        m.insert("65433", "varsity");
-        m.insert("65434", "varying");
-        m.insert("65435", "vascular");
-        m.insert("65436", "vaseline");
-        m.insert("65441", "vastly");
-        m.insert("65442", "vastness");
-        m.insert("65443", "veal");
-        m.insert("65444", "vegan");
-        m.insert("65445", "veggie");
-        m.insert("65446", "vehicular");
-        m.insert("65451", "velcro");
-        m.insert("65452", "velocity");
-        m.insert("65453", "velvet");
-        m.insert("65454", "vendetta");
-        m.insert("65455", "vending");
-        m.insert("65456", "vendor");
-        m.insert("65461", "veneering");
-        m.insert("65462", "vengeful");
-        m.insert("65463", "venomous");
-        m.insert("65464", "ventricle");
-        m.insert("65465", "venture");
-        m.insert("65466", "venue");
-        m.insert("65511", "venus");
-        m.insert("65512", "verbalize");
-        m.insert("65513", "verbally");
-        m.insert("65514", "verbose");
-        m.insert("65515", "verdict");
-        m.insert("65516", "verify");
-        m.insert("65521", "verse");
-        m.insert("65522", "version");
-        m.insert("65523", "versus");
-        m.insert("65524", "vertebrae");
-        m.insert("65525", "vertical");
-        m.insert("65526", "vertigo");
-        m.insert("65531", "very");
-        m.insert("65532", "vessel");
-        m.insert("65533", "vest");
-        m.insert("65534", "veteran");
-        m.insert("65535", "veto");
-        m.insert("65536", "vexingly");
-        m.insert("65541", "viability");
-        m.insert("65542", "viable");
-        m.insert("65543", "vibes");
-        m.insert("65544", "vice");
-        m.insert("65545", "vicinity");
-        m.insert("65546", "victory");
-        m.insert("65551", "video");
-        m.insert("65552", "viewable");
-        m.insert("65553", "viewer");
-        m.insert("65554", "viewing");
-        m.insert("65555", "viewless");
-        m.insert("65556", "viewpoint");
-        m.insert("65561", "vigorous");
-        m.insert("65562", "village");
-        m.insert("65563", "villain");
-        m.insert("65564", "vindicate");
-        m.insert("65565", "vineyard");
-        m.insert("65566", "vintage");
-        m.insert("65611", "violate");
-        m.insert("65612", "violation");
-        m.insert("65613", "violator");
-        m.insert("65614", "violet");
-        m.insert("65615", "violin");
-        m.insert("65616", "viper");
-        m.insert("65621", "viral");
-        m.insert("65622", "virtual");
-        m.insert("65623", "virtuous");
-        m.insert("65624", "virus");
-        m.insert("65625", "visa");
-        m.insert("65626", "viscosity");
-        m.insert("65631", "viscous");
-        m.insert("65632", "viselike");
-        m.insert("65633", "visible");
-        m.insert("65634", "visibly");
-        m.insert("65635", "vision");
-        m.insert("65636", "visiting");
-        m.insert("65641", "visitor");
-        m.insert("65642", "visor");
-        m.insert("65643", "vista");
-        m.insert("65644", "vitality");
-        m.insert("65645", "vitalize");
-        m.insert("65646", "vitally");
-        m.insert("65651", "vitamins");
-        m.insert("65652", "vivacious");
-        m.insert("65653", "vividly");
-        m.insert("65654", "vividness");
-        m.insert("65655", "vixen");
-        m.insert("65656", "vocalist");
-        m.insert("65661", "vocalize");
-        m.insert("65662", "vocally");
-        m.insert("65663", "vocation");
-        m.insert("65664", "voice");
-        m.insert("65665", "voicing");
-        m.insert("65666", "void");
-        m.insert("66111", "volatile");
-        m.insert("66112", "volley");
-        m.insert("66113", "voltage");
-        m.insert("66114", "volumes");
-        m.insert("66115", "voter");
-        m.insert("66116", "voting");
-        m.insert("66121", "voucher");
-        m.insert("66122", "vowed");
-        m.insert("66123", "vowel");
-        m.insert("66124", "voyage");
-        m.insert("66125", "wackiness");
-        m.insert("66126", "wad");
-        m.insert("66131", "wafer");
-        m.insert("66132", "waffle");
-        m.insert("66133", "waged");
-        m.insert("66134", "wager");
-        m.insert("66135", "wages");
-        m.insert("66136", "waggle");
-        m.insert("66141", "wagon");
-        m.insert("66142", "wake");
-        m.insert("66143", "waking");
-        m.insert("66144", "walk");
-        m.insert("66145", "walmart");
-        m.insert("66146", "walnut");
-        m.insert("66151", "walrus");
-        m.insert("66152", "waltz");
-        m.insert("66153", "wand");
-        m.insert("66154", "wannabe");
-        m.insert("66155", "wanted");
-        m.insert("66156", "wanting");
-        m.insert("66161", "wasabi");
-        m.insert("66162", "washable");
-        m.insert("66163", "washbasin");
-        m.insert("66164", "washboard");
-        m.insert("66165", "washbowl");
-        m.insert("66166", "washcloth");
-        m.insert("66211", "washday");
-        m.insert("66212", "washed");
-        m.insert("66213", "washer");
-        m.insert("66214", "washhouse");
-        m.insert("66215", "washing");
-        m.insert("66216", "washout");
-        m.insert("66221", "washroom");
-        m.insert("66222", "washstand");
-        m.insert("66223", "washtub");
-        m.insert("66224", "wasp");
-        m.insert("66225", "wasting");
-        m.insert("66226", "watch");
-        m.insert("66231", "water");
-        m.insert("66232", "waviness");
-        m.insert("66233", "waving");
-        m.insert("66234", "wavy");
-        m.insert("66235", "whacking");
-        m.insert("66236", "whacky");
-        m.insert("66241", "wham");
-        m.insert("66242", "wharf");
-        m.insert("66243", "wheat");
-        m.insert("66244", "whenever");
-        m.insert("66245", "whiff");
-        m.insert("66246", "whimsical");
-        m.insert("66251", "whinny");
-        m.insert("66252", "whiny");
-        m.insert("66253", "whisking");
-        m.insert("66254", "whoever");
-        m.insert("66255", "whole");
-        m.insert("66256", "whomever");
-        m.insert("66261", "whoopee");
-        m.insert("66262", "whooping");
-        m.insert("66263", "whoops");
-        m.insert("66264", "why");
-        m.insert("66265", "wick");
-        m.insert("66266", "widely");
-        m.insert("66311", "widen");
-        m.insert("66312", "widget");
-        m.insert("66313", "widow");
-        m.insert("66314", "width");
-        m.insert("66315", "wieldable");
-        m.insert("66316", "wielder");
-        m.insert("66321", "wife");
-        m.insert("66322", "wifi");
-        m.insert("66323", "wikipedia");
-        m.insert("66324", "wildcard");
-        m.insert("66325", "wildcat");
-        m.insert("66326", "wilder");
-        m.insert("66331", "wildfire");
-        m.insert("66332", "wildfowl");
-        m.insert("66333", "wildland");
-        m.insert("66334", "wildlife");
-        m.insert("66335", "wildly");
-        m.insert("66336", "wildness");
-        m.insert("66341", "willed");
-        m.insert("66342", "willfully");
-        m.insert("66343", "willing");
-        m.insert("66344", "willow");
-        m.insert("66345", "willpower");
-        m.insert("66346", "wilt");
-        m.insert("66351", "wimp");
-        m.insert("66352", "wince");
-        m.insert("66353", "wincing");
-        m.insert("66354", "wind");
-        m.insert("66355", "wing");
-        m.insert("66356", "winking");
-        m.insert("66361", "winner");
-        m.insert("66362", "winnings");
-        m.insert("66363", "winter");
-        m.insert("66364", "wipe");
-        m.insert("66365", "wired");
-        m.insert("66366", "wireless");
-        m.insert("66411", "wiring");
-        m.insert("66412", "wiry");
-        m.insert("66413", "wisdom");
-        m.insert("66414", "wise");
-        m.insert("66415", "wish");
-        m.insert("66416", "wisplike");
-        m.insert("66421", "wispy");
-        m.insert("66422", "wistful");
-        m.insert("66423", "wizard");
-        m.insert("66424", "wobble");
-        m.insert("66425", "wobbling");
-        m.insert("66426", "wobbly");
-        m.insert("66431", "wok");
-        m.insert("66432", "wolf");
-        m.insert("66433", "wolverine");
-        m.insert("66434", "womanhood");
-        m.insert("66435", "womankind");
-        m.insert("66436", "womanless");
-        m.insert("66441", "womanlike");
-        m.insert("66442", "womanly");
-        m.insert("66443", "womb");
-        m.insert("66444", "woof");
-        m.insert("66445", "wooing");
-        m.insert("66446", "wool");
-        m.insert("66451", "woozy");
-        m.insert("66452", "word");
-        m.insert("66453", "work");
-        m.insert("66454", "worried");
-        m.insert("66455", "worrier");
-        m.insert("66456", "worrisome");
-        m.insert("66461", "worry");
-        m.insert("66462", "worsening");
-        m.insert("66463", "worshiper");
-        m.insert("66464", "worst");
-        m.insert("66465", "wound");
-        m.insert("66466", "woven");
-        m.insert("66511", "wow");
-        m.insert("66512", "wrangle");
-        m.insert("66513", "wrath");
-        m.insert("66514", "wreath");
-        m.insert("66515", "wreckage");
-        m.insert("66516", "wrecker");
-        m.insert("66521", "wrecking");
-        m.insert("66522", "wrench");
-        m.insert("66523", "wriggle");
-        m.insert("66524", "wriggly");
-        m.insert("66525", "wrinkle");
-        m.insert("66526", "wrinkly");
-        m.insert("66531", "wrist");
-        m.insert("66532", "writing");
-        m.insert("66533", "written");
-        m.insert("66534", "wrongdoer");
-        m.insert("66535", "wronged");
-        m.insert("66536", "wrongful");
-        m.insert("66541", "wrongly");
-        m.insert("66542", "wrongness");
-        m.insert("66543", "wrought");
-        m.insert("66544", "xbox");
-        m.insert("66545", "xerox");
-        m.insert("66546", "yahoo");
-        m.insert("66551", "yam");
-        m.insert("66552", "yanking");
-        m.insert("66553", "yapping");
-        m.insert("66554", "yard");
-        m.insert("66555", "yarn");
-        m.insert("66556", "yeah");
-        m.insert("66561", "yearbook");
-        m.insert("66562", "yearling");
-        m.insert("66563", "yearly");
-        m.insert("66564", "yearning");
-        m.insert("66565", "yeast");
-        m.insert("66566", "yelling");
-        m.insert("66611", "yelp");
-        m.insert("66612", "yen");
-        m.insert("66613", "yesterday");
-        m.insert("66614", "yiddish");
-        m.insert("66615", "yield");
-        m.insert("66616", "yin");
-        m.insert("66621", "yippee");
-        m.insert("66622", "yo-yo");
-        m.insert("66623", "yodel");
-        m.insert("66624", "yoga");
-        m.insert("66625", "yogurt");
-        m.insert("66626", "yonder");
-        m.insert("66631", "yoyo");
-        m.insert("66632", "yummy");
-        m.insert("66633", "zap");
-        m.insert("66634", "zealous");
-        m.insert("66635", "zebra");
-        m.insert("66636", "zen");
-        m.insert("66641", "zeppelin");
-        m.insert("66642", "zero");
-        m.insert("66643", "zestfully");
-        m.insert("66644", "zesty");
-        m.insert("66645", "zigzagged");
-        m.insert("66646", "zipfile");
-        m.insert("66651", "zipping");
-        m.insert("66652", "zippy");
-        m.insert("66653", "zips");
-        m.insert("66654", "zit");
-        m.insert("66655", "zodiac");
-        m.insert("66656", "zombie");
-        m.insert("66661", "zone");
-        m.insert("66662", "zoning");
-        m.insert("66663", "zookeeper");
-        m.insert("66664", "zoologist");
-        m.insert("66665", "zoology");
-        m.insert("66666", "zoom");
-        m
-    };
+    pub static ref WORD_LIST: HashMap<&'static str, &'static str> =
+        ENTRIES.iter().cloned().collect();
 }
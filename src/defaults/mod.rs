@@ -5,3 +5,5 @@
 pub mod eff_long;
 pub mod eff_short;
 pub mod eff_short_2;
+pub mod parts_of_speech;
+pub mod sensitive;
@@ -0,0 +1,702 @@
+//!
+//! The part-of-speech tagged word lists used by sentence templates.
+//!
+//! The words are short, common and inoffensive so that sentences built from them are easy to
+//! picture and remember.
+//!
+
+///
+/// Adjectives, for `{adj}` slots.
+///
+pub static ADJECTIVES: &[&str] = &[
+    "able", "absent", "active", "agile", "airy", "alert", "amber", "ample", "ancient", "antique",
+    "anxious", "arctic", "artful", "ashen", "astute", "atomic", "awake", "aware", "balmy",
+    "bashful", "basic", "beige", "bent", "best", "better", "big", "bitter", "bland", "blank",
+    "bleak", "blond", "blue", "blunt", "bold", "bony", "bouncy", "brainy", "brash", "brave",
+    "breezy", "brief", "bright", "brisk", "broad", "bronze", "brown", "bubbly", "bulky", "bumpy",
+    "burly", "busy", "calm", "candid", "carefree", "careful", "casual", "cheap", "cheerful",
+    "cheery", "chief", "chilly", "civic", "classic", "clean", "clever", "close", "cloudy",
+    "clumsy", "coarse", "cold", "comic", "common", "cool", "cosmic", "cosy", "crafty", "cranky",
+    "creamy", "crisp", "crooked", "crunchy", "cuddly", "curious", "curly", "curvy", "cute",
+    "daily", "damp", "dapper", "daring", "dark", "dazzling", "dear", "decent", "deep", "dense",
+    "dim", "direct", "dizzy", "double", "dreamy", "dry", "dull", "dusty", "eager", "early",
+    "earnest", "easy", "edgy", "eerie", "elastic", "elder", "electric", "elegant", "elfin",
+    "empty", "endless", "epic", "equal", "even", "exact", "exotic", "extra", "faded", "faint",
+    "fair", "famous", "fancy", "far", "fast", "fearless", "feisty", "fierce", "fiery", "final",
+    "fine", "firm", "first", "fit", "flaky", "flat", "fluffy", "foggy", "fond", "formal", "frail",
+    "frank", "free", "fresh", "friendly", "frosty", "frozen", "full", "funny", "fuzzy", "gentle",
+    "giant", "giddy", "gifted", "glad", "glassy", "gleaming", "glossy", "golden", "good", "goofy",
+    "graceful", "grand", "grassy", "gray", "great", "green", "grim", "grumpy", "hairy", "handy",
+    "happy", "hardy", "harsh", "hasty", "hazy", "healthy", "heavy", "hidden", "high", "hollow",
+    "honest", "hot", "huge", "humble", "hungry", "icy", "ideal", "idle", "jolly", "jumpy", "keen",
+    "kind", "lanky", "large", "late", "lazy", "leafy", "lean", "legal", "lime", "little", "lively",
+    "local", "lofty", "lone", "long", "loose", "loud", "lovely", "loyal", "lucky", "lumpy",
+    "lunar", "magic", "major", "mellow", "merry", "messy", "mighty", "mild", "minor", "misty",
+    "modern", "modest", "moody", "muddy", "murky", "mushy", "musical", "narrow", "neat", "nervous",
+    "new", "nice", "nimble", "noble", "noisy", "normal", "nosy", "odd", "oily", "olive", "open",
+    "orange", "ornate", "oval", "pale", "patient", "peaceful", "perky", "petite", "pink", "plain",
+    "plump", "polite", "posh", "prickly", "primal", "proud", "puffy", "pure", "purple", "quaint",
+    "quick", "quiet", "quirky", "rapid", "rare", "raw", "ready", "real", "regal", "rich", "rigid",
+    "ripe", "roomy", "rosy", "rough", "round", "royal", "rugged", "rusty", "sad", "safe", "salty",
+    "sandy", "scaly", "scary", "secret", "serene", "shaggy", "shaky", "shiny", "short", "shy",
+    "silent", "silky", "silly", "simple", "sleek", "sleepy", "slim", "slow", "small", "smart",
+    "smooth", "snappy", "snowy", "snug", "soft", "solar", "solid", "sour", "spare", "speedy",
+    "spicy", "spiky", "spotty", "steady", "steep", "sticky", "stiff", "stormy", "stout", "strange",
+    "strict", "strong", "sturdy", "sunny", "super", "sweet", "swift", "tall", "tame", "tangy",
+    "tart", "tasty", "tender", "tense", "thick", "thin", "thirsty", "tidy", "timid", "tiny",
+    "tired", "tough", "tranquil", "tropical", "true", "trusty", "upbeat", "urban", "usual",
+    "vague", "valid", "vast", "velvet", "violet", "vivid", "warm", "wary", "wavy", "weak", "weary",
+    "wet", "white", "whole", "wide", "wild", "windy", "wise", "witty", "wobbly", "wooden",
+    "woolly", "worthy", "yellow", "young", "zany", "zesty",
+];
+
+///
+/// Singular nouns, for `{noun}` slots.
+///
+pub static NOUNS: &[&str] = &[
+    "acorn",
+    "actor",
+    "anchor",
+    "angel",
+    "ant",
+    "antelope",
+    "anvil",
+    "apple",
+    "apricot",
+    "apron",
+    "archer",
+    "armchair",
+    "arrow",
+    "artist",
+    "atlas",
+    "attic",
+    "avocado",
+    "badger",
+    "bagel",
+    "bakery",
+    "ball",
+    "balloon",
+    "banana",
+    "banjo",
+    "barn",
+    "barrel",
+    "basket",
+    "bat",
+    "beach",
+    "beacon",
+    "beagle",
+    "bean",
+    "bear",
+    "beaver",
+    "bed",
+    "bee",
+    "beetle",
+    "bell",
+    "bench",
+    "berry",
+    "bicycle",
+    "bird",
+    "biscuit",
+    "bison",
+    "blanket",
+    "blender",
+    "blimp",
+    "boat",
+    "bobcat",
+    "bonnet",
+    "book",
+    "boot",
+    "bottle",
+    "boulder",
+    "bowl",
+    "box",
+    "bracelet",
+    "branch",
+    "bread",
+    "brick",
+    "bridge",
+    "broom",
+    "bubble",
+    "bucket",
+    "buffalo",
+    "bugle",
+    "bunny",
+    "butler",
+    "butter",
+    "button",
+    "cabin",
+    "cactus",
+    "cake",
+    "camel",
+    "camera",
+    "camp",
+    "canal",
+    "candle",
+    "canoe",
+    "canyon",
+    "cap",
+    "captain",
+    "car",
+    "carpet",
+    "carrot",
+    "castle",
+    "cat",
+    "cave",
+    "cello",
+    "chair",
+    "chalk",
+    "cheese",
+    "cherry",
+    "chess",
+    "chicken",
+    "chimney",
+    "chipmunk",
+    "cliff",
+    "clock",
+    "cloud",
+    "clown",
+    "coat",
+    "cobra",
+    "coconut",
+    "coffee",
+    "comet",
+    "compass",
+    "cookie",
+    "copper",
+    "cottage",
+    "cougar",
+    "cow",
+    "coyote",
+    "crab",
+    "crane",
+    "crayon",
+    "cricket",
+    "crow",
+    "crown",
+    "cupcake",
+    "curtain",
+    "cushion",
+    "daisy",
+    "deer",
+    "desert",
+    "desk",
+    "diamond",
+    "dingo",
+    "dinosaur",
+    "doctor",
+    "dog",
+    "dolphin",
+    "donkey",
+    "door",
+    "dove",
+    "dragon",
+    "drum",
+    "duck",
+    "eagle",
+    "easel",
+    "eel",
+    "elephant",
+    "elk",
+    "engine",
+    "falcon",
+    "farmer",
+    "feather",
+    "fence",
+    "ferret",
+    "fiddle",
+    "fig",
+    "finch",
+    "fire",
+    "fish",
+    "flag",
+    "flamingo",
+    "flute",
+    "forest",
+    "fork",
+    "fountain",
+    "fox",
+    "frog",
+    "garden",
+    "gazelle",
+    "gecko",
+    "giraffe",
+    "glacier",
+    "glove",
+    "goat",
+    "goose",
+    "gorilla",
+    "grape",
+    "guitar",
+    "gull",
+    "hammer",
+    "hamster",
+    "harbor",
+    "harp",
+    "hat",
+    "hawk",
+    "hedgehog",
+    "helmet",
+    "heron",
+    "hill",
+    "hippo",
+    "honey",
+    "horn",
+    "horse",
+    "hotel",
+    "iguana",
+    "island",
+    "jacket",
+    "jaguar",
+    "jam",
+    "jar",
+    "jelly",
+    "jellyfish",
+    "jester",
+    "jigsaw",
+    "kangaroo",
+    "kayak",
+    "kettle",
+    "key",
+    "kite",
+    "kitten",
+    "koala",
+    "ladder",
+    "ladle",
+    "lake",
+    "lamp",
+    "lantern",
+    "lemon",
+    "lemur",
+    "leopard",
+    "letter",
+    "library",
+    "lighthouse",
+    "lily",
+    "lion",
+    "lizard",
+    "llama",
+    "lobster",
+    "locket",
+    "lynx",
+    "magnet",
+    "mango",
+    "map",
+    "maple",
+    "marble",
+    "meadow",
+    "melon",
+    "mermaid",
+    "meteor",
+    "mitten",
+    "mole",
+    "monkey",
+    "moon",
+    "moose",
+    "mountain",
+    "mouse",
+    "muffin",
+    "mule",
+    "mushroom",
+    "napkin",
+    "needle",
+    "nest",
+    "newt",
+    "noodle",
+    "nut",
+    "oak",
+    "oar",
+    "ocean",
+    "octopus",
+    "olive",
+    "onion",
+    "orchard",
+    "orchid",
+    "ostrich",
+    "otter",
+    "owl",
+    "oyster",
+    "paddle",
+    "pancake",
+    "panda",
+    "panther",
+    "parrot",
+    "peach",
+    "peacock",
+    "peanut",
+    "pear",
+    "pebble",
+    "pelican",
+    "pencil",
+    "penguin",
+    "pepper",
+    "piano",
+    "pickle",
+    "pig",
+    "pigeon",
+    "pillow",
+    "pilot",
+    "pine",
+    "pirate",
+    "pizza",
+    "planet",
+    "plum",
+    "pond",
+    "pony",
+    "poodle",
+    "potato",
+    "pretzel",
+    "puffin",
+    "pumpkin",
+    "puppet",
+    "puppy",
+    "quail",
+    "queen",
+    "quilt",
+    "rabbit",
+    "raccoon",
+    "radish",
+    "raft",
+    "rainbow",
+    "raven",
+    "ribbon",
+    "river",
+    "robin",
+    "robot",
+    "rocket",
+    "roof",
+    "rooster",
+    "rose",
+    "sailor",
+    "salmon",
+    "sandal",
+    "saucer",
+    "scarf",
+    "scooter",
+    "seal",
+    "shark",
+    "sheep",
+    "shell",
+    "ship",
+    "shovel",
+    "skunk",
+    "sled",
+    "sloth",
+    "snail",
+    "snake",
+    "sock",
+    "sofa",
+    "spider",
+    "spoon",
+    "squid",
+    "squirrel",
+    "stable",
+    "star",
+    "statue",
+    "stone",
+    "stork",
+    "strawberry",
+    "submarine",
+    "sun",
+    "swan",
+    "sweater",
+    "table",
+    "tadpole",
+    "teapot",
+    "tent",
+    "tiger",
+    "toad",
+    "toaster",
+    "tomato",
+    "tortoise",
+    "towel",
+    "tower",
+    "tractor",
+    "train",
+    "tree",
+    "trumpet",
+    "tuba",
+    "tulip",
+    "turkey",
+    "turtle",
+    "umbrella",
+    "unicorn",
+    "valley",
+    "vase",
+    "violin",
+    "volcano",
+    "waffle",
+    "wagon",
+    "walrus",
+    "wand",
+    "wasp",
+    "whale",
+    "whistle",
+    "wizard",
+    "wolf",
+    "wombat",
+    "yak",
+    "yeti",
+    "zebra",
+];
+
+///
+/// Transitive verbs in the third person singular ("juggles"), for `{verb}` slots.
+///
+pub static VERBS: &[&str] = &[
+    "admires",
+    "admits",
+    "adopts",
+    "alarms",
+    "amuses",
+    "answers",
+    "applauds",
+    "approaches",
+    "arranges",
+    "attracts",
+    "awakens",
+    "bakes",
+    "balances",
+    "bathes",
+    "beckons",
+    "befriends",
+    "bends",
+    "blesses",
+    "boards",
+    "boosts",
+    "borrows",
+    "bounces",
+    "brands",
+    "brews",
+    "brushes",
+    "builds",
+    "bumps",
+    "buys",
+    "calls",
+    "calms",
+    "captures",
+    "carries",
+    "carves",
+    "catches",
+    "charms",
+    "chases",
+    "cheers",
+    "chews",
+    "chooses",
+    "circles",
+    "clasps",
+    "cleans",
+    "climbs",
+    "clips",
+    "coaches",
+    "collects",
+    "colors",
+    "combs",
+    "comforts",
+    "conducts",
+    "cooks",
+    "copies",
+    "counts",
+    "covers",
+    "cracks",
+    "crosses",
+    "crowns",
+    "cuddles",
+    "dazzles",
+    "decorates",
+    "defends",
+    "delivers",
+    "describes",
+    "digs",
+    "dips",
+    "discovers",
+    "drags",
+    "draws",
+    "dreams",
+    "dresses",
+    "drives",
+    "drops",
+    "dusts",
+    "ejects",
+    "embraces",
+    "enjoys",
+    "entertains",
+    "escorts",
+    "examines",
+    "fans",
+    "feeds",
+    "fetches",
+    "fills",
+    "finds",
+    "fixes",
+    "flags",
+    "flatters",
+    "flips",
+    "fluffs",
+    "folds",
+    "follows",
+    "forgives",
+    "frames",
+    "frightens",
+    "gathers",
+    "gives",
+    "glues",
+    "grabs",
+    "greets",
+    "grips",
+    "grows",
+    "guards",
+    "guides",
+    "hammers",
+    "handles",
+    "hatches",
+    "hauls",
+    "heats",
+    "helps",
+    "hides",
+    "hooks",
+    "hugs",
+    "hunts",
+    "imitates",
+    "inspects",
+    "invites",
+    "irons",
+    "joins",
+    "jolts",
+    "juggles",
+    "kicks",
+    "kisses",
+    "kneads",
+    "knits",
+    "labels",
+    "lassos",
+    "launches",
+    "leads",
+    "lends",
+    "lifts",
+    "lights",
+    "likes",
+    "loads",
+    "locates",
+    "loves",
+    "lures",
+    "mails",
+    "marks",
+    "measures",
+    "melts",
+    "mends",
+    "mimics",
+    "misses",
+    "mixes",
+    "names",
+    "nibbles",
+    "notices",
+    "nudges",
+    "observes",
+    "opens",
+    "orders",
+    "outruns",
+    "packs",
+    "paddles",
+    "paints",
+    "parks",
+    "passes",
+    "pats",
+    "peels",
+    "pets",
+    "photographs",
+    "picks",
+    "pinches",
+    "plants",
+    "pleases",
+    "plucks",
+    "pokes",
+    "polishes",
+    "pops",
+    "pours",
+    "praises",
+    "prints",
+    "protects",
+    "pulls",
+    "pumps",
+    "punts",
+    "pushes",
+    "questions",
+    "quizzes",
+    "races",
+    "raises",
+    "rattles",
+    "reaches",
+    "reads",
+    "records",
+    "recycles",
+    "repairs",
+    "rescues",
+    "returns",
+    "rides",
+    "rings",
+    "rocks",
+    "rolls",
+    "ropes",
+    "rubs",
+    "sails",
+    "salutes",
+    "saves",
+    "scares",
+    "scoops",
+    "scrubs",
+    "seeks",
+    "serves",
+    "shakes",
+    "shares",
+    "shelters",
+    "sketches",
+    "smells",
+    "snaps",
+    "sniffs",
+    "soaks",
+    "spies",
+    "spins",
+    "splashes",
+    "spots",
+    "sprinkles",
+    "squeezes",
+    "stacks",
+    "stamps",
+    "steers",
+    "stirs",
+    "stretches",
+    "strokes",
+    "surprises",
+    "swaps",
+    "swings",
+    "tackles",
+    "tames",
+    "taps",
+    "teaches",
+    "tickles",
+    "tosses",
+    "tows",
+    "tracks",
+    "trades",
+    "trims",
+    "tugs",
+    "tunes",
+    "twirls",
+    "unpacks",
+    "unwraps",
+    "uses",
+    "visits",
+    "wakes",
+    "walks",
+    "warms",
+    "washes",
+    "watches",
+    "waters",
+    "waves",
+    "weighs",
+    "welcomes",
+    "whistles",
+    "wins",
+    "wraps",
+    "writes",
+];
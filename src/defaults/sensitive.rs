@@ -0,0 +1,80 @@
+//!
+//! A bundled list of words from the EFF word lists that may be inappropriate in some contexts.
+//!
+//! These are words relating to violence, crime, death, illness, or the body, as well as words
+//! with strongly negative connotations.  The list is intentionally conservative; callers with
+//! stricter requirements should add their own words to a `Blocklist`.
+//!
+
+///
+/// Words that are excluded from generated passphrases when a `Blocklist` includes the bundled
+/// sensitive-word list.
+///
+pub static WORDS: &[&str] = &[
+    "affair",
+    "arson",
+    "bribe",
+    "buckshot",
+    "carnage",
+    "casket",
+    "catfight",
+    "coroner",
+    "crook",
+    "cult",
+    "dagger",
+    "debtor",
+    "deceit",
+    "defame",
+    "demise",
+    "disown",
+    "evil",
+    "exorcism",
+    "filth",
+    "flogging",
+    "gallows",
+    "gory",
+    "grave",
+    "greedy",
+    "groin",
+    "grope",
+    "handgun",
+    "hangnail",
+    "hate",
+    "hatred",
+    "hunchback",
+    "impeach",
+    "jailbird",
+    "jailhouse",
+    "maggot",
+    "mobster",
+    "nuclear",
+    "obituary",
+    "opium",
+    "outcast",
+    "poison",
+    "prison",
+    "punch",
+    "racism",
+    "radiation",
+    "ransack",
+    "rash",
+    "reckless",
+    "rectal",
+    "relapse",
+    "riot",
+    "scandal",
+    "scorn",
+    "slander",
+    "strangle",
+    "tarnish",
+    "taunt",
+    "thong",
+    "traitor",
+    "unclothed",
+    "unlawful",
+    "vengeful",
+    "villain",
+    "widow",
+    "wound",
+    "wrath",
+];
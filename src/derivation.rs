@@ -0,0 +1,447 @@
+//!
+//! Contains logic for deriving passphrases deterministically from a master secret, as in a
+//! "stateless password manager".
+//!
+//! The master secret is stretched once into a `MasterKey` with Argon2id, salted with an identity
+//! (such as an email address).  A passphrase is then derived for each `Context` (a site, an
+//! account and a counter) by expanding the master key with HKDF-SHA256 into a seed for word
+//! sampling.  Nothing needs to be stored: the same secret, identity, parameters, context and
+//! config always produce the same passphrase, and bumping the counter rotates a single site's
+//! passphrase without affecting any other.
+//!
+//! Every step of the derivation is pinned by `Version`.  The output of an existing version never
+//! changes between releases of this crate; changes to the scheme are made by adding a version.
+//!
+//! ```
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::derivation::{Context, DerivationParams, MasterKey};
+//!
+//! // cheap parameters keep this example fast; use `DerivationParams::default()` in practice
+//! let params = DerivationParams {
+//!     memory_kib: 1024,
+//!     iterations: 1,
+//!     parallelism: 1,
+//!     ..DerivationParams::default()
+//! };
+//! let key = MasterKey::new(b"correct horse battery staple", "alice@example.com", &params).unwrap();
+//!
+//! let config = PassGenConfig::from_eff_long(WordCount::Default);
+//! let mut context = Context::new("example.com");
+//! let first = key.derive(&context, &config).unwrap();
+//! assert_eq!(first, key.derive(&context, &config).unwrap());
+//!
+//! // rotate the passphrase for this site only
+//! context.counter += 1;
+//! assert_ne!(first, key.derive(&context, &config).unwrap());
+//! ```
+//!
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ptr;
+
+use argon2::{self, Algorithm, Argon2, Params};
+use hkdf::Hkdf;
+use rand::chacha::ChaChaRng;
+use rand::SeedableRng;
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+use error::{ConfigError, Error};
+use pass_gen::{generate_with, PassGenConfig, Passphrase, Sampling};
+
+const KEY_LEN: usize = 32;
+
+///
+/// Identifies a version of the derivation scheme.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Version {
+    ///
+    /// The master key is Argon2id (version 0x13) of the master secret, salted with
+    /// `lib_dicepass_gen/derivation/v1/` followed by the identity.  Each passphrase is seeded
+    /// with 32 bytes of HKDF-SHA256 output keyed with the master key, using the encoded context
+    /// as `info`, and its words are drawn from the seeded ChaCha20 stream with
+    /// `Sampling::Uniform`.
+    ///
+    #[default]
+    V1,
+}
+
+impl Version {
+    fn salt_prefix(&self) -> &'static [u8] {
+        match *self {
+            Version::V1 => b"lib_dicepass_gen/derivation/v1/",
+        }
+    }
+
+    fn info_prefix(&self) -> &'static [u8] {
+        match *self {
+            Version::V1 => b"lib_dicepass_gen/derivation/v1/context",
+        }
+    }
+}
+
+///
+/// The parameters used to stretch a master secret into a `MasterKey`.
+///
+/// Changing any of these changes every derived passphrase, so they should be recorded alongside
+/// the identity.  The defaults are the second recommended Argon2id option from RFC 9106: 64 MiB
+/// of memory, 3 iterations and 4 lanes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DerivationParams {
+    ///
+    /// The version of the derivation scheme.
+    ///
+    pub version: Version,
+
+    ///
+    /// The Argon2id memory cost, in KiB.  Must be at least 8 times `parallelism`.
+    ///
+    pub memory_kib: u32,
+
+    ///
+    /// The Argon2id time cost (number of passes over memory).
+    ///
+    pub iterations: u32,
+
+    ///
+    /// The Argon2id degree of parallelism (number of lanes).
+    ///
+    pub parallelism: u32,
+}
+
+impl Default for DerivationParams {
+    fn default() -> DerivationParams {
+        DerivationParams {
+            version: Version::V1,
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 4,
+        }
+    }
+}
+
+///
+/// Identifies a single derived passphrase.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Context {
+    ///
+    /// The site or service the passphrase is for, for example `example.com`.
+    ///
+    pub site: String,
+
+    ///
+    /// The account name on `site`.  May be empty.
+    ///
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub account: String,
+
+    ///
+    /// Incremented to rotate the passphrase for `site` and `account`.
+    ///
+    #[cfg_attr(feature = "serde", serde(default = "default_counter"))]
+    pub counter: u32,
+}
+
+#[cfg(feature = "serde")]
+fn default_counter() -> u32 {
+    1
+}
+
+impl Context {
+    ///
+    /// Returns a `Context` for `site` with an empty account and a counter of 1.
+    ///
+    pub fn new(site: &str) -> Context {
+        Context {
+            site: site.to_string(),
+            account: String::new(),
+            counter: 1,
+        }
+    }
+
+    ///
+    /// Returns a `Context` for `account` on `site` with a counter of 1.
+    ///
+    pub fn with_account(site: &str, account: &str) -> Context {
+        Context {
+            account: account.to_string(),
+            ..Context::new(site)
+        }
+    }
+
+    fn info(&self, version: Version) -> Vec<u8> {
+        let mut info = version.info_prefix().to_vec();
+        for field in &[self.site.as_bytes(), self.account.as_bytes()] {
+            info.extend_from_slice(&(field.len() as u32).to_be_bytes());
+            info.extend_from_slice(field);
+        }
+        info.extend_from_slice(&self.counter.to_be_bytes());
+        info
+    }
+}
+
+///
+/// A master secret after stretching, from which passphrases are derived.
+///
+/// The key is zeroed when the `MasterKey` is dropped and is left out of its `Debug` output.
+///
+pub struct MasterKey {
+    key: [u8; KEY_LEN],
+    version: Version,
+}
+
+impl MasterKey {
+    ///
+    /// Stretches `secret` into a `MasterKey` with Argon2id, salted with `identity`.
+    ///
+    /// This is deliberately slow.  Returns an error if `params` are rejected by Argon2.
+    ///
+    pub fn new(
+        secret: &[u8],
+        identity: &str,
+        params: &DerivationParams,
+    ) -> Result<MasterKey, Error> {
+        let argon2_params = Params::new(
+            params.memory_kib,
+            params.iterations,
+            params.parallelism,
+            Some(KEY_LEN),
+        )
+        .map_err(|err| Error::InvalidKdfParams(err.to_string()))?;
+
+        let mut salt = params.version.salt_prefix().to_vec();
+        salt.extend_from_slice(identity.as_bytes());
+
+        let mut key = [0; KEY_LEN];
+        Argon2::new(Algorithm::Argon2id, argon2::Version::V0x13, argon2_params)
+            .hash_password_into(secret, &salt, &mut key)
+            .map_err(|err| Error::InvalidKdfParams(err.to_string()))?;
+
+        Ok(MasterKey {
+            key,
+            version: params.version,
+        })
+    }
+
+    ///
+    /// Derives the passphrase for `context`.
+    ///
+    /// The word list, word count, blocklist, policy, separator and casing are taken from
+    /// `config`.  `config.sampling` and `config.rng` are ignored; words are always sampled as
+    /// described by the key's `Version`.  Returns an error if `config.blocklist` and
+    /// `config.policy` exclude every word.
+    ///
+    pub fn derive(&self, context: &Context, config: &PassGenConfig) -> Result<Passphrase, Error> {
+        if config.available_words() == 0 {
+            return Err(ConfigError::NoWordsAvailable.into());
+        }
+
+        let mut config = config.clone();
+        config.sampling = Sampling::Uniform;
+        let mut seed = self.seed(context);
+        let mut rng = SeededRng(ChaChaRng::from_seed(&seed));
+        seed.zeroize();
+        Ok(generate_with(&config, &mut rng.0))
+    }
+
+    fn seed(&self, context: &Context) -> [u32; 8] {
+        let mut okm = [0u8; 32];
+        Hkdf::<Sha256>::new(None, &self.key)
+            .expand(&context.info(self.version), &mut okm)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+
+        let mut seed = [0u32; 8];
+        for (word, bytes) in seed.iter_mut().zip(okm.chunks(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        okm.zeroize();
+        seed
+    }
+}
+
+///
+/// The generator a passphrase's words are drawn from.  Its state holds the seed and buffered
+/// output, so it's overwritten when dropped.
+///
+struct SeededRng(ChaChaRng);
+
+impl Drop for SeededRng {
+    fn drop(&mut self) {
+        // a volatile write can't be optimized away, unlike an assignment just before a drop;
+        // `ChaChaRng` is plain data, so overwriting it in place is sound
+        unsafe { ptr::write_volatile(&mut self.0, ChaChaRng::new_unseeded()) };
+    }
+}
+
+impl Drop for MasterKey {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl fmt::Debug for MasterKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MasterKey")
+            .field("key", &"<redacted>")
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+///
+/// Stretches `secret` and derives the passphrase for `context` in one step.
+///
+/// Prefer `MasterKey` when deriving more than one passphrase, since stretching is slow.
+///
+pub fn derive(
+    secret: &[u8],
+    identity: &str,
+    params: &DerivationParams,
+    context: &Context,
+    config: &PassGenConfig,
+) -> Result<Passphrase, Error> {
+    MasterKey::new(secret, identity, params)?.derive(context, config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use blocklist::Blocklist;
+    use custom_list::CustomList;
+    use pass_gen::{WordCount, WordList};
+
+    fn params() -> DerivationParams {
+        DerivationParams {
+            memory_kib: 64,
+            iterations: 1,
+            parallelism: 1,
+            ..DerivationParams::default()
+        }
+    }
+
+    fn master_key(secret: &str, identity: &str) -> MasterKey {
+        MasterKey::new(secret.as_bytes(), identity, &params()).unwrap()
+    }
+
+    #[test]
+    fn stable_output() {
+        // pins `Version::V1`; if this fails, existing derived passphrases have changed
+        let config = PassGenConfig::from_eff_long(WordCount::Default);
+        let pass = master_key("master secret", "alice@example.com")
+            .derive(&Context::new("example.com"), &config)
+            .unwrap();
+        assert_eq!(pass.as_str(), "gumball yo-yo unweave awkward frenzy");
+    }
+
+    #[test]
+    fn stable_output_for_every_list() {
+        // pins `Version::V1` with each built in list; if this fails, existing derived
+        // passphrases have changed
+        let expected = [
+            ("eff_long", "gumball yo-yo unweave awkward frenzy"),
+            ("eff_short", "gains wool rival fade cake baker comic gulp"),
+            (
+                "eff_short_2",
+                "frosting wrangle pewter eulogy blurred angelfish cotton gumdrop",
+            ),
+            ("pseudo", "kota kubu suma poso mugo pobo"),
+            ("pseudo_1", "ta bu ma so go bo gu li zu bo jo"),
+            ("pseudo_2", "kota kubu suma poso mugo pobo"),
+            ("pseudo_3", "tikota rakubu pusuma vaposo"),
+        ];
+        let key = master_key("master secret", "alice@example.com");
+        let context = Context::new("example.com");
+        for &(name, phrase) in &expected {
+            let word_list: WordList = name.parse().unwrap();
+            let config = PassGenConfig::builder()
+                .word_list(word_list)
+                .build()
+                .unwrap();
+            assert_eq!(key.derive(&context, &config).unwrap().as_str(), phrase);
+        }
+
+        let list = CustomList::new((0..10).map(|digit| digit.to_string())).unwrap();
+        let config = PassGenConfig::from_custom(list, WordCount::Custom(8));
+        let pass = key.derive(&context, &config).unwrap();
+        assert_eq!(pass.as_str(), "4 5 4 4 6 4 3 3");
+    }
+
+    #[test]
+    fn inputs_change_output() {
+        let config = PassGenConfig::from_eff_long(WordCount::Default);
+        let key = master_key("master secret", "alice@example.com");
+        let base = key.derive(&Context::new("example.com"), &config).unwrap();
+
+        let mut rotated = Context::new("example.com");
+        rotated.counter = 2;
+        let others = vec![
+            key.derive(&Context::new("example.org"), &config).unwrap(),
+            key.derive(&Context::with_account("example.com", "bob"), &config)
+                .unwrap(),
+            key.derive(&rotated, &config).unwrap(),
+            master_key("master secret", "bob@example.com")
+                .derive(&Context::new("example.com"), &config)
+                .unwrap(),
+            master_key("other secret", "alice@example.com")
+                .derive(&Context::new("example.com"), &config)
+                .unwrap(),
+        ];
+        for other in others {
+            assert_ne!(base, other);
+        }
+    }
+
+    #[test]
+    fn context_fields_are_delimited() {
+        let a = Context::with_account("ab", "c").info(Version::V1);
+        let b = Context::with_account("a", "bc").info(Version::V1);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn uses_config() {
+        let key = master_key("master secret", "alice@example.com");
+        let context = Context::new("example.com");
+
+        let mut config = PassGenConfig::from_eff_short(WordCount::Custom(12));
+        config.separator = ".".to_string();
+        config.blocklist = Blocklist::sensitive();
+        let pass = key.derive(&context, &config).unwrap();
+        assert_eq!(pass.word_count, 12);
+        let words: Vec<&str> = pass.as_str().split('.').collect();
+        assert_eq!(words.len(), 12);
+        assert!(words.iter().all(|word| config.allows(word)));
+
+        // the sampling mode doesn't affect the derivation
+        config.sampling = Sampling::Dice(::dice::Die::D20);
+        assert_eq!(key.derive(&context, &config).unwrap(), pass);
+    }
+
+    #[test]
+    fn invalid_params() {
+        let params = DerivationParams {
+            memory_kib: 1,
+            ..params()
+        };
+        match MasterKey::new(b"secret", "alice", &params) {
+            Err(Error::InvalidKdfParams(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        let key = master_key("master secret", "alice@example.com");
+        assert!(format!("{:?}", key).contains("<redacted>"));
+    }
+}
@@ -0,0 +1,359 @@
+//!
+//! Contains logic and structures for turning dice rolls into word indices.
+//!
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+
+use rand::Rng;
+
+use error::Error;
+
+///
+/// The largest number of sides a `Die` may have; far more than any physical die.
+///
+pub const MAX_SIDES: u32 = 65_536;
+
+///
+/// A fair die with two or more sides, numbered from 1.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u32", into = "u32"))]
+pub struct Die {
+    sides: u32,
+}
+
+impl Die {
+    /// A four-sided die.
+    pub const D4: Die = Die { sides: 4 };
+
+    /// A six-sided die, as used by the Diceware method.
+    pub const D6: Die = Die { sides: 6 };
+
+    /// An eight-sided die.
+    pub const D8: Die = Die { sides: 8 };
+
+    /// A ten-sided die.  Faces are numbered 1 through 10, so a face showing "0" should be read
+    /// as 10.
+    pub const D10: Die = Die { sides: 10 };
+
+    /// A twelve-sided die.
+    pub const D12: Die = Die { sides: 12 };
+
+    /// A twenty-sided die.
+    pub const D20: Die = Die { sides: 20 };
+
+    ///
+    /// Returns a die with the given number of sides.
+    ///
+    /// Returns an error if `sides` is less than 2 or greater than `MAX_SIDES`.
+    ///
+    pub fn new(sides: u32) -> Result<Die, Error> {
+        if !(2..=MAX_SIDES).contains(&sides) {
+            return Err(Error::InvalidDie(sides));
+        }
+        Ok(Die { sides })
+    }
+
+    ///
+    /// Returns the number of sides on this die.
+    ///
+    pub fn sides(&self) -> u32 {
+        self.sides
+    }
+}
+
+impl TryFrom<u32> for Die {
+    type Error = Error;
+
+    fn try_from(sides: u32) -> Result<Die, Error> {
+        Die::new(sides)
+    }
+}
+
+impl From<Die> for u32 {
+    fn from(die: Die) -> u32 {
+        die.sides
+    }
+}
+
+impl Default for Die {
+    fn default() -> Die {
+        Die::D6
+    }
+}
+
+///
+/// Describes how to select a word from a list of a given length using a sequence of dice.
+///
+/// The dice are read as the digits of a mixed-radix number with the first die being the most
+/// significant.  When the dice can produce more outcomes than there are words, outcomes past the
+/// end of the list are rejected and the whole sequence must be rolled again.  This keeps the
+/// selection unbiased at the cost of sometimes needing extra rolls.
+///
+/// ```
+/// use lib_dicepass_gen::dice::{DicePlan, Die};
+///
+/// // a 7776 word list needs three d20 rolls per word, and under 3% of sequences are rerolled
+/// let plan = DicePlan::new(7776, Die::D20);
+/// assert_eq!(plan.rolls(), 3);
+/// assert_eq!(plan.dice(), &[Die::D20, Die::D20, Die::D20]);
+/// assert!((plan.rejection_probability() - 0.028).abs() < 0.001);
+///
+/// assert_eq!(plan.index(&[1, 1, 1]), Ok(Some(0)));
+/// assert_eq!(plan.index(&[20, 20, 20]), Ok(None));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DicePlan {
+    dice: Vec<Die>,
+    list_len: usize,
+}
+
+impl DicePlan {
+    ///
+    /// Returns a `DicePlan` using as few rolls of `die` as are needed to cover `list_len` words.
+    ///
+    pub fn new(list_len: usize, die: Die) -> DicePlan {
+        let mut dice = Vec::new();
+        let mut outcomes = 1u64;
+        while outcomes < list_len as u64 {
+            dice.push(die);
+            outcomes = outcomes.saturating_mul(u64::from(die.sides));
+        }
+        DicePlan { dice, list_len }
+    }
+
+    ///
+    /// Returns a `DicePlan` using the given sequence of (possibly different) dice.
+    ///
+    /// Returns an error if the dice cannot produce at least `list_len` outcomes, or if they
+    /// produce more outcomes than fit in a `u64`.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::dice::{DicePlan, Die};
+    ///
+    /// // a d20 and a d10 give 200 outcomes, not enough for a 1296 word list
+    /// assert!(DicePlan::mixed(1296, vec![Die::D20, Die::D10]).is_err());
+    ///
+    /// let plan = DicePlan::mixed(1296, vec![Die::D20, Die::D10, Die::D8]).unwrap();
+    /// assert_eq!(plan.outcomes(), 1600);
+    /// ```
+    ///
+    pub fn mixed(list_len: usize, dice: Vec<Die>) -> Result<DicePlan, Error> {
+        let plan = DicePlan { dice, list_len };
+        let outcomes = plan
+            .checked_outcomes()
+            .ok_or_else(|| Error::TooManyDice(plan.rolls()))?;
+        if outcomes < list_len as u64 {
+            return Err(Error::InsufficientDice { outcomes, list_len });
+        }
+        Ok(plan)
+    }
+
+    ///
+    /// Returns the `DicePlan` which needs the fewest rolls on average to select a word from a list
+    /// of `list_len` words, using only one kind of die from `available`.
+    ///
+    /// Returns `None` if `available` is empty.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::dice::{DicePlan, Die};
+    ///
+    /// let plan = DicePlan::best(7776, &[Die::D6, Die::D10, Die::D20]).unwrap();
+    /// assert_eq!(plan.dice(), &[Die::D20; 3]);
+    /// ```
+    ///
+    pub fn best(list_len: usize, available: &[Die]) -> Option<DicePlan> {
+        available
+            .iter()
+            .map(|die| DicePlan::new(list_len, *die))
+            .min_by(|a, b| {
+                a.expected_rolls()
+                    .partial_cmp(&b.expected_rolls())
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+
+    ///
+    /// Returns the dice to roll for each word, in order.
+    ///
+    pub fn dice(&self) -> &[Die] {
+        &self.dice
+    }
+
+    ///
+    /// Returns the number of dice rolled for each attempt at selecting a word.
+    ///
+    pub fn rolls(&self) -> usize {
+        self.dice.len()
+    }
+
+    ///
+    /// Returns the number of distinct sequences the dice can produce, or `u64::MAX` if there are
+    /// more than that.
+    ///
+    pub fn outcomes(&self) -> u64 {
+        self.checked_outcomes().unwrap_or(u64::MAX)
+    }
+
+    fn checked_outcomes(&self) -> Option<u64> {
+        self.dice.iter().try_fold(1u64, |outcomes, die| {
+            outcomes.checked_mul(u64::from(die.sides))
+        })
+    }
+
+    ///
+    /// Returns the probability that a sequence of rolls doesn't correspond to a word and must be
+    /// rolled again.
+    ///
+    pub fn rejection_probability(&self) -> f64 {
+        1.0 - self.list_len as f64 / self.outcomes() as f64
+    }
+
+    ///
+    /// Returns the average number of dice rolled to select one word, including rerolls.
+    ///
+    pub fn expected_rolls(&self) -> f64 {
+        self.rolls() as f64 / (1.0 - self.rejection_probability())
+    }
+
+    ///
+    /// Converts a sequence of physical dice rolls into a word index.
+    ///
+    /// Returns `Ok(None)` if the sequence falls past the end of the list, in which case the whole
+    /// sequence must be rolled again.  Returns an error if the wrong number of rolls is given or
+    /// a roll isn't a face of its die.
+    ///
+    pub fn index(&self, rolls: &[u32]) -> Result<Option<usize>, Error> {
+        let mut past_end = false;
+        if rolls.len() != self.dice.len() {
+            return Err(Error::WrongRollCount {
+                expected: self.dice.len(),
+                actual: rolls.len(),
+            });
+        }
+
+        let mut index = 0u64;
+        for (die, roll) in self.dice.iter().zip(rolls) {
+            if *roll < 1 || *roll > die.sides {
+                return Err(Error::InvalidRoll {
+                    roll: *roll,
+                    sides: die.sides,
+                });
+            }
+            // an index too large for a u64 is past the end of any list
+            match index
+                .checked_mul(u64::from(die.sides))
+                .and_then(|index| index.checked_add(u64::from(*roll - 1)))
+            {
+                Some(next) => index = next,
+                None => past_end = true,
+            }
+        }
+
+        if !past_end && index < self.list_len as u64 {
+            Ok(Some(index as usize))
+        } else {
+            Ok(None)
+        }
+    }
+
+    ///
+    /// Simulates rolling each die in this plan once.
+    ///
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> Vec<u32> {
+        self.dice
+            .iter()
+            .map(|die| rng.gen_range(1u32, die.sides + 1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::chacha::ChaChaRng;
+    #[cfg(feature = "std")]
+    use rand::OsRng;
+
+    #[test]
+    fn invalid_die() {
+        assert_eq!(Die::new(1), Err(Error::InvalidDie(1)));
+        assert_eq!(Die::new(6), Ok(Die::D6));
+        assert!(Die::new(MAX_SIDES).is_ok());
+        assert_eq!(Die::new(u32::MAX), Err(Error::InvalidDie(u32::MAX)));
+    }
+
+    #[test]
+    fn too_many_dice() {
+        assert_eq!(
+            DicePlan::mixed(10, vec![Die::D20; 20]),
+            Err(Error::TooManyDice(20))
+        );
+        let plan = DicePlan::mixed(10, vec![Die::D20; 14]).unwrap();
+        assert_eq!(plan.index(&[1; 14]), Ok(Some(0)));
+        assert_eq!(plan.index(&[20; 14]), Ok(None));
+
+        let big = Die::new(MAX_SIDES).unwrap();
+        let plan = DicePlan::new(usize::MAX, big);
+        assert_eq!(plan.outcomes(), u64::MAX);
+        assert_eq!(plan.index(&vec![MAX_SIDES; plan.rolls()]), Ok(None));
+        let mut rng = ChaChaRng::new_unseeded();
+        assert_eq!(plan.roll(&mut rng).len(), plan.rolls());
+    }
+
+    #[test]
+    fn d6_matches_eff() {
+        let plan = DicePlan::new(7776, Die::D6);
+        assert_eq!(plan.rolls(), 5);
+        assert_eq!(plan.rejection_probability(), 0.0);
+        assert_eq!(plan.index(&[1, 1, 1, 1, 1]), Ok(Some(0)));
+        assert_eq!(plan.index(&[1, 1, 1, 2, 1]), Ok(Some(6)));
+        assert_eq!(plan.index(&[6, 6, 6, 6, 6]), Ok(Some(7775)));
+    }
+
+    #[test]
+    fn mixed_radix() {
+        let plan = DicePlan::mixed(200, vec![Die::D20, Die::D10]).unwrap();
+        assert_eq!(plan.index(&[1, 10]), Ok(Some(9)));
+        assert_eq!(plan.index(&[2, 1]), Ok(Some(10)));
+        assert_eq!(plan.index(&[20, 10]), Ok(Some(199)));
+    }
+
+    #[test]
+    fn invalid_rolls() {
+        let plan = DicePlan::new(100, Die::D10);
+        assert_eq!(
+            plan.index(&[1]),
+            Err(Error::WrongRollCount {
+                expected: 2,
+                actual: 1,
+            })
+        );
+        assert_eq!(
+            plan.index(&[0, 1]),
+            Err(Error::InvalidRoll { roll: 0, sides: 10 })
+        );
+        assert_eq!(
+            plan.index(&[1, 11]),
+            Err(Error::InvalidRoll {
+                roll: 11,
+                sides: 10
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn simulated_rolls_in_range() {
+        let mut rng = OsRng::new().unwrap();
+        let plan = DicePlan::mixed(1000, vec![Die::D4, Die::D12, Die::D20, Die::D8]).unwrap();
+        for _ in 0..100 {
+            let rolls = plan.roll(&mut rng);
+            assert!(plan.index(&rolls).is_ok());
+        }
+    }
+}
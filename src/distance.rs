@@ -0,0 +1,50 @@
+//!
+//! Contains the edit distance used to suggest corrections for mistyped words.
+//!
+
+use alloc::vec::Vec;
+
+///
+/// Returns the number of single character insertions, deletions, substitutions and transpositions
+/// of adjacent characters needed to turn `a` into `b` (the optimal string alignment distance).
+///
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // three rows of the distance matrix: two rows back, the previous row and the current row
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        core::mem::swap(&mut before, &mut previous);
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("acid", "acid"), 0);
+        assert_eq!(edit_distance("", "acid"), 4);
+        assert_eq!(edit_distance("acid", "acrid"), 1);
+        assert_eq!(edit_distance("acid", "aicd"), 1);
+        assert_eq!(edit_distance("acid", "acorn"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,415 @@
+//!
+//! Contains the error type returned by fallible operations in this library.
+//!
+
+use alloc::string::String;
+use core::error;
+use core::fmt;
+
+///
+/// Describes the ways in which an operation in this library can fail.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    ///
+    /// A word list contained fewer than two words, so it cannot provide any entropy.
+    ///
+    WordListTooSmall(usize),
+
+    ///
+    /// A word list contained the same word more than once, which would bias sampling towards it.
+    ///
+    DuplicateWord(String),
+
+    ///
+    /// A word list contained a word that was empty or contained whitespace.
+    ///
+    InvalidWord(String),
+
+    ///
+    /// A die was requested with fewer than two sides, or more than `dice::MAX_SIDES`.
+    ///
+    InvalidDie(u32),
+
+    ///
+    /// A roll was outside the range of faces on its die.
+    ///
+    InvalidRoll { roll: u32, sides: u32 },
+
+    ///
+    /// The number of rolls given didn't match the number of dice needed to select a word.
+    ///
+    WrongRollCount { expected: usize, actual: usize },
+
+    ///
+    /// A combination of dice cannot produce enough outcomes to select every word in a list.
+    ///
+    InsufficientDice { outcomes: u64, list_len: usize },
+
+    ///
+    /// A combination of dice can produce more outcomes than fit in a `u64`.
+    ///
+    TooManyDice(usize),
+
+    ///
+    /// Manual input contained a symbol or token that isn't valid for its source.
+    ///
+    InvalidInput(String),
+
+    ///
+    /// The same playing card appeared more than once in a shuffled deck.
+    ///
+    DuplicateCard(String),
+
+    ///
+    /// A word list name didn't match any of the built in word lists.
+    ///
+    UnknownWordList(String),
+
+    ///
+    /// A word wasn't in the word list it was expected to come from.
+    ///
+    UnknownWord(String),
+
+    ///
+    /// A word prefix matched more than one word in the word list.
+    ///
+    AmbiguousPrefix(String),
+
+    ///
+    /// A sentence template was malformed or contained no word slots.
+    ///
+    InvalidTemplate(String),
+
+    ///
+    /// Pseudo-words were requested with an unsupported number of syllables.
+    ///
+    InvalidSyllableCount(u32),
+
+    ///
+    /// Key derivation parameters were rejected, for example because the memory cost is too low.
+    ///
+    InvalidKdfParams(String),
+
+    ///
+    /// A secret sharing threshold was less than 2 or greater than the number of shares.
+    ///
+    InvalidThreshold { threshold: u8, shares: u8 },
+
+    ///
+    /// A word list contained more words than secret sharing supports (65536).
+    ///
+    WordListTooLarge(usize),
+
+    ///
+    /// A secret share was malformed or didn't belong with the other shares.
+    ///
+    InvalidShare(String),
+
+    ///
+    /// Fewer shares were given than the threshold they were split with.
+    ///
+    InsufficientShares { required: usize, actual: usize },
+
+    ///
+    /// A PIN or code would contain no characters, or more than fit in a `u32`.
+    ///
+    InvalidLength(u64),
+
+    ///
+    /// More distinct passphrases were requested than a config can produce.
+    ///
+    TooManyPassphrases(usize),
+
+    ///
+    /// A setting from a configuration file, environment variable or command line flag was
+    /// invalid.  `origin` names where it came from.
+    ///
+    InvalidSetting { origin: String, reason: String },
+
+    ///
+    /// A passphrase couldn't be encoded as a QR code, usually because it's too long.
+    ///
+    QrCode(String),
+
+    ///
+    /// A `PassGenConfig` failed validation.
+    ///
+    Config(ConfigError),
+
+    ///
+    /// A step of a collaborative generation ceremony was invalid.
+    ///
+    Ceremony(CeremonyError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::WordListTooSmall(len) => write!(
+                f,
+                "word list contains {} word(s) but at least 2 are required",
+                len
+            ),
+            Error::DuplicateWord(ref word) => {
+                write!(f, "word list contains \"{}\" more than once", word)
+            }
+            Error::InvalidWord(ref word) => write!(f, "\"{}\" is not a valid word", word),
+            Error::InvalidDie(sides) => write!(f, "a die cannot have {} side(s)", sides),
+            Error::InvalidRoll { roll, sides } => {
+                write!(f, "{} is not a valid roll of a {}-sided die", roll, sides)
+            }
+            Error::WrongRollCount { expected, actual } => {
+                write!(f, "expected {} roll(s) but got {}", expected, actual)
+            }
+            Error::InsufficientDice { outcomes, list_len } => write!(
+                f,
+                "dice produce {} outcome(s) but the word list contains {} words",
+                outcomes, list_len
+            ),
+            Error::TooManyDice(count) => {
+                write!(f, "{} dice produce too many outcomes to count", count)
+            }
+            Error::InvalidInput(ref input) => write!(f, "\"{}\" is not valid input", input),
+            Error::DuplicateCard(ref card) => {
+                write!(f, "the card \"{}\" appears more than once", card)
+            }
+            Error::UnknownWordList(ref name) => write!(f, "unknown word list \"{}\"", name),
+            Error::UnknownWord(ref word) => write!(f, "\"{}\" is not in the word list", word),
+            Error::AmbiguousPrefix(ref prefix) => {
+                write!(f, "\"{}\" is the start of more than one word", prefix)
+            }
+            Error::InvalidTemplate(ref reason) => write!(f, "invalid template: {}", reason),
+            Error::InvalidSyllableCount(count) => {
+                write!(f, "pseudo-words cannot have {} syllable(s)", count)
+            }
+            Error::InvalidKdfParams(ref reason) => {
+                write!(f, "invalid key derivation parameters: {}", reason)
+            }
+            Error::InvalidThreshold { threshold, shares } => write!(
+                f,
+                "a threshold of {} is invalid for {} share(s)",
+                threshold, shares
+            ),
+            Error::WordListTooLarge(len) => write!(
+                f,
+                "word list contains {} words but at most 65536 are supported",
+                len
+            ),
+            Error::InvalidShare(ref reason) => write!(f, "invalid share: {}", reason),
+            Error::InsufficientShares { required, actual } => write!(
+                f,
+                "{} share(s) are required but only {} were given",
+                required, actual
+            ),
+            Error::InvalidLength(length) => {
+                write!(f, "a PIN or code cannot contain {} characters", length)
+            }
+            Error::TooManyPassphrases(count) => write!(
+                f,
+                "the config cannot produce {} distinct passphrases",
+                count
+            ),
+            Error::InvalidSetting {
+                ref origin,
+                ref reason,
+            } => write!(f, "invalid setting in {}: {}", origin, reason),
+            Error::QrCode(ref reason) => write!(f, "couldn't encode QR code: {}", reason),
+            Error::Config(ref err) => write!(f, "invalid config: {}", err),
+            Error::Ceremony(ref err) => write!(f, "ceremony failed: {}", err),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<ConfigError> for Error {
+    fn from(err: ConfigError) -> Error {
+        Error::Config(err)
+    }
+}
+
+impl From<CeremonyError> for Error {
+    fn from(err: CeremonyError) -> Error {
+        Error::Ceremony(err)
+    }
+}
+
+///
+/// Describes the ways in which a `PassGenConfig` can fail validation in
+/// `PassGenConfigBuilder::build` or `PassGenConfig::validate`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    ///
+    /// The passphrase would contain no words.
+    ///
+    ZeroWords,
+
+    ///
+    /// The entropy target was not a positive, finite number of bits, or the policy's minimum
+    /// entropy was not a finite, non-negative number of bits.
+    ///
+    InvalidEntropyTarget(f64),
+
+    ///
+    /// The passphrase would contain more than `builder::MAX_WORDS` words.
+    ///
+    TooManyWords(u32),
+
+    ///
+    /// The separator appears inside a word that may be generated, so the passphrase couldn't be
+    /// split back into its words.
+    ///
+    SeparatorInWord { separator: String, word: String },
+
+    ///
+    /// The policy's minimum word length is greater than its maximum word length.
+    ///
+    InvalidWordLengths { min: usize, max: usize },
+
+    ///
+    /// The blocklist and policy together exclude every word in the word list.
+    ///
+    NoWordsAvailable,
+
+    ///
+    /// The passphrase would provide less entropy than the policy requires.
+    ///
+    InsufficientEntropy { required: f64, actual: f64 },
+
+    ///
+    /// The separator is empty but the passphrase has several words, so different words could
+    /// join into the same passphrase.
+    ///
+    EmptySeparator,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigError::ZeroWords => write!(f, "a passphrase must contain at least one word"),
+            ConfigError::InvalidEntropyTarget(bits) => {
+                write!(f, "{} is not a valid entropy target", bits)
+            }
+            ConfigError::TooManyWords(count) => {
+                write!(f, "a passphrase cannot contain {} words", count)
+            }
+            ConfigError::SeparatorInWord {
+                ref separator,
+                ref word,
+            } => write!(
+                f,
+                "the separator \"{}\" appears in the word \"{}\"",
+                separator, word
+            ),
+            ConfigError::InvalidWordLengths { min, max } => write!(
+                f,
+                "minimum word length {} is greater than maximum word length {}",
+                min, max
+            ),
+            ConfigError::NoWordsAvailable => write!(
+                f,
+                "the blocklist and policy exclude every word in the word list"
+            ),
+            ConfigError::InsufficientEntropy { required, actual } => write!(
+                f,
+                "passphrase provides {:.1} bits of entropy but {:.1} are required",
+                actual, required
+            ),
+            ConfigError::EmptySeparator => write!(
+                f,
+                "the separator must not be empty when a passphrase has several words"
+            ),
+        }
+    }
+}
+
+impl error::Error for ConfigError {}
+
+///
+/// Describes the ways in which a step of a `ceremony::Ceremony` can fail.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CeremonyError {
+    ///
+    /// The commitment phase was closed before anyone committed.
+    ///
+    NoParticipants,
+
+    ///
+    /// A participant committed more than once.
+    ///
+    DuplicateParticipant(String),
+
+    ///
+    /// A participant revealed a contribution without having committed to it.
+    ///
+    UnknownParticipant(String),
+
+    ///
+    /// A commitment was made after the commitment phase was closed.
+    ///
+    CommitmentsClosed,
+
+    ///
+    /// A contribution was revealed before the commitment phase was closed.
+    ///
+    CommitmentsOpen,
+
+    ///
+    /// A revealed contribution doesn't match the participant's commitment.
+    ///
+    CommitmentMismatch(String),
+
+    ///
+    /// A participant's contribution has too few word indices, or an index past the end of the
+    /// word list.
+    ///
+    InvalidContribution(String),
+
+    ///
+    /// The ceremony was finished before a participant revealed their contribution.
+    ///
+    MissingReveal(String),
+
+    ///
+    /// A transcript's result doesn't match the combination of its contributions, or contains an
+    /// index past the end of its word list.
+    ///
+    ResultMismatch,
+}
+
+impl fmt::Display for CeremonyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CeremonyError::NoParticipants => write!(f, "no participants have committed"),
+            CeremonyError::DuplicateParticipant(ref name) => {
+                write!(f, "\"{}\" has already committed", name)
+            }
+            CeremonyError::UnknownParticipant(ref name) => {
+                write!(f, "\"{}\" hasn't committed", name)
+            }
+            CeremonyError::CommitmentsClosed => write!(f, "the commitment phase is closed"),
+            CeremonyError::CommitmentsOpen => write!(f, "the commitment phase is still open"),
+            CeremonyError::CommitmentMismatch(ref name) => write!(
+                f,
+                "\"{}\"'s contribution doesn't match their commitment",
+                name
+            ),
+            CeremonyError::InvalidContribution(ref name) => write!(
+                f,
+                "\"{}\"'s contribution doesn't contain enough valid word indices",
+                name
+            ),
+            CeremonyError::MissingReveal(ref name) => {
+                write!(f, "\"{}\" hasn't revealed their contribution", name)
+            }
+            CeremonyError::ResultMismatch => {
+                write!(f, "the result doesn't match the contributions")
+            }
+        }
+    }
+}
+
+impl error::Error for CeremonyError {}
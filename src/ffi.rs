@@ -0,0 +1,273 @@
+//!
+//! Contains `extern "C"` bindings for generating passphrases from C and C++.
+//!
+//! The `dicepass_ffi` package in `ffi/` builds these functions as a C library
+//! (`cargo build --release -p dicepass_ffi` produces `libdicepass.a` and `libdicepass.so`).  Their
+//! C declarations live in `include/dicepass.h`, which `cbindgen` regenerates when the crate is
+//! built with the `ffi` feature and `DICEPASS_GENERATE_HEADER` set.  Every function reports
+//! failure through a `DicepassStatus` code rather than unwinding across the FFI boundary.
+//!
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic;
+use std::ptr;
+use std::slice;
+
+use error::{ConfigError, Error};
+use pass_gen::{generate, PassGenConfig, WordCount, WordList};
+
+///
+/// Status codes returned by the `dicepass_*` functions.
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DicepassStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// The caller-provided buffer was too small to hold the passphrase and its terminating NUL.
+    BufferTooSmall = 2,
+    /// The word list argument wasn't one of the `DicepassWordList` values.
+    InvalidWordList = 3,
+    /// The word count argument produced an invalid configuration.
+    InvalidConfig = 4,
+    /// Input (such as dice rolls or a word list) was malformed.
+    InvalidInput = 5,
+    /// An unexpected internal error occurred.
+    Internal = 6,
+}
+
+///
+/// The word lists available to the `dicepass_*` functions.
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DicepassWordList {
+    /// The EFF's "long" word list (7776 words, ~12.9 bits/word).
+    EffLong = 0,
+    /// The EFF's standard "short" word list (1296 words, ~10.3 bits/word).
+    EffShort = 1,
+    /// The EFF's "special" short word list with unique three character prefixes.
+    EffShort2 = 2,
+}
+
+impl<'a> From<&'a Error> for DicepassStatus {
+    fn from(err: &'a Error) -> DicepassStatus {
+        match *err {
+            Error::UnknownWordList(_) => DicepassStatus::InvalidWordList,
+            Error::Config(_) => DicepassStatus::InvalidConfig,
+            _ => DicepassStatus::InvalidInput,
+        }
+    }
+}
+
+impl<'a> From<&'a ConfigError> for DicepassStatus {
+    fn from(_: &'a ConfigError) -> DicepassStatus {
+        DicepassStatus::InvalidConfig
+    }
+}
+
+fn word_list(list: u32) -> Result<WordList, DicepassStatus> {
+    match list {
+        0 => Ok(WordList::EffLong),
+        1 => Ok(WordList::EffShort),
+        2 => Ok(WordList::EffShort2),
+        _ => Err(DicepassStatus::InvalidWordList),
+    }
+}
+
+fn generate_checked(list: u32, word_count: u32) -> Result<String, DicepassStatus> {
+    let word_count = if word_count == 0 {
+        WordCount::Default
+    } else {
+        WordCount::Custom(word_count)
+    };
+    let config = PassGenConfig::builder()
+        .word_list(word_list(list)?)
+        .word_count(word_count)
+        .build()
+        .map_err(|err| DicepassStatus::from(&err))?;
+
+    panic::catch_unwind(|| generate(config)).map_err(|_| DicepassStatus::Internal)
+}
+
+///
+/// Generates a passphrase into a caller-provided buffer.
+///
+/// `list` is one of the `DicepassWordList` values and `word_count` is the number of words to
+/// generate, or 0 to use the list's default.  On success the NUL-terminated passphrase is
+/// written to `buf` and its length (excluding the NUL) is stored in `*written`.  If `buf_len` is
+/// too small, nothing is written to `buf`, `*written` is set to the buffer size required and
+/// `DicepassStatus::BufferTooSmall` is returned.
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `buf_len` bytes and `written` must be valid for a write of
+/// one `size_t`.
+///
+#[no_mangle]
+pub unsafe extern "C" fn dicepass_generate(
+    list: u32,
+    word_count: u32,
+    buf: *mut c_char,
+    buf_len: usize,
+    written: *mut usize,
+) -> DicepassStatus {
+    if buf.is_null() || written.is_null() {
+        return DicepassStatus::NullPointer;
+    }
+
+    let pass = match generate_checked(list, word_count) {
+        Ok(pass) => pass,
+        Err(status) => return status,
+    };
+
+    let bytes = pass.as_bytes();
+    if bytes.len() + 1 > buf_len {
+        *written = bytes.len() + 1;
+        return DicepassStatus::BufferTooSmall;
+    }
+
+    let out = slice::from_raw_parts_mut(buf as *mut u8, buf_len);
+    out[..bytes.len()].copy_from_slice(bytes);
+    out[bytes.len()] = 0;
+    *written = bytes.len();
+    DicepassStatus::Ok
+}
+
+///
+/// Generates a passphrase and returns it as a newly allocated NUL-terminated string.
+///
+/// `list` and `word_count` are interpreted as for `dicepass_generate`.  Returns null on failure,
+/// in which case the reason is stored in `*status` if `status` isn't null.  The returned string
+/// must be released with `dicepass_string_free`.
+///
+/// # Safety
+///
+/// `status` must be null or valid for a write of one `DicepassStatus`.
+///
+#[no_mangle]
+pub unsafe extern "C" fn dicepass_generate_string(
+    list: u32,
+    word_count: u32,
+    status: *mut DicepassStatus,
+) -> *mut c_char {
+    let result = generate_checked(list, word_count)
+        .and_then(|pass| CString::new(pass).map_err(|_| DicepassStatus::Internal));
+
+    let (code, ptr) = match result {
+        Ok(pass) => (DicepassStatus::Ok, pass.into_raw()),
+        Err(code) => (code, ptr::null_mut()),
+    };
+    if !status.is_null() {
+        *status = code;
+    }
+    ptr
+}
+
+///
+/// Releases a string returned by `dicepass_generate_string`.  Passing null does nothing.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by `dicepass_generate_string` which hasn't already
+/// been freed.
+///
+#[no_mangle]
+pub unsafe extern "C" fn dicepass_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+///
+/// Returns the default number of words for `list`, or 0 if `list` isn't a `DicepassWordList`
+/// value.
+///
+#[no_mangle]
+pub extern "C" fn dicepass_default_word_count(list: u32) -> u32 {
+    word_list(list).map_or(0, |list| list.default_word_count())
+}
+
+///
+/// Returns a static, NUL-terminated description of a `DicepassStatus` value.  The returned string
+/// must not be freed.
+///
+#[no_mangle]
+pub extern "C" fn dicepass_status_message(status: u32) -> *const c_char {
+    let message: &'static [u8] = match status {
+        0 => b"success\0",
+        1 => b"a required pointer was null\0",
+        2 => b"the buffer is too small\0",
+        3 => b"unknown word list\0",
+        4 => b"invalid configuration\0",
+        5 => b"invalid input\0",
+        6 => b"internal error\0",
+        _ => b"unknown status\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn generate_into_buffer() {
+        let mut buf = [0 as c_char; 256];
+        let mut written = 0;
+        let status = unsafe { dicepass_generate(1, 3, buf.as_mut_ptr(), buf.len(), &mut written) };
+        assert_eq!(status, DicepassStatus::Ok);
+
+        let pass = unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap();
+        assert_eq!(pass.len(), written);
+        assert_eq!(pass.split_whitespace().count(), 3);
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut buf = [0 as c_char; 4];
+        let mut written = 0;
+        let status = unsafe { dicepass_generate(0, 0, buf.as_mut_ptr(), buf.len(), &mut written) };
+        assert_eq!(status, DicepassStatus::BufferTooSmall);
+        assert!(written > 4);
+    }
+
+    #[test]
+    fn errors() {
+        let mut buf = [0 as c_char; 256];
+        let mut written = 0;
+        let status = unsafe { dicepass_generate(7, 0, buf.as_mut_ptr(), buf.len(), &mut written) };
+        assert_eq!(status, DicepassStatus::InvalidWordList);
+
+        let status = unsafe { dicepass_generate(0, 0, ptr::null_mut(), 0, &mut written) };
+        assert_eq!(status, DicepassStatus::NullPointer);
+
+        let mut status = DicepassStatus::Ok;
+        let pass = unsafe { dicepass_generate_string(3, 0, &mut status) };
+        assert!(pass.is_null());
+        assert_eq!(status, DicepassStatus::InvalidWordList);
+
+        let message = unsafe { CStr::from_ptr(dicepass_status_message(status as u32)) };
+        assert_eq!(message.to_str(), Ok("unknown word list"));
+    }
+
+    #[test]
+    fn generate_string() {
+        let mut status = DicepassStatus::Internal;
+        let pass = unsafe { dicepass_generate_string(2, 0, &mut status) };
+        assert_eq!(status, DicepassStatus::Ok);
+        assert_eq!(
+            unsafe { CStr::from_ptr(pass) }
+                .to_str()
+                .unwrap()
+                .split_whitespace()
+                .count(),
+            dicepass_default_word_count(2) as usize
+        );
+        unsafe { dicepass_string_free(pass) };
+    }
+}
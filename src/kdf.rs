@@ -0,0 +1,394 @@
+//!
+//! Contains logic for stretching a generated passphrase into an encryption key.
+//!
+//! A passphrase is fed to Argon2id or scrypt along with a random salt, producing a key of the
+//! requested length.  The salt and the `Kdf` (including its parameters) must be stored alongside
+//! whatever the key protects, since both are needed to derive the same key again.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::kdf::{self, Kdf};
+//!
+//! let pass = generate_passphrase(PassGenConfig::from_eff_long(WordCount::Default));
+//! let salt = kdf::generate_salt();
+//!
+//! // cheap parameters keep this example fast; `Kdf::for_entropy(pass.entropy)` picks real ones
+//! let kdf = Kdf::Argon2id {
+//!     memory_kib: 1024,
+//!     iterations: 1,
+//!     parallelism: 1,
+//! };
+//! let key = pass.derive_key(&kdf, &salt, 32).unwrap();
+//! assert_eq!(key.as_bytes().len(), 32);
+//! assert_eq!(key, kdf.derive_key(pass.as_str().as_bytes(), &salt, 32).unwrap());
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use argon2::{self, Algorithm, Argon2};
+use scrypt;
+use zeroize::Zeroize;
+
+use error::Error;
+use pass_gen::Passphrase;
+
+///
+/// The minimum salt length (in bytes) accepted by `Kdf::derive_key`.
+///
+pub const MIN_SALT_LEN: usize = 8;
+
+///
+/// The length (in bytes) of salts returned by `generate_salt`.
+///
+pub const SALT_LEN: usize = 16;
+
+///
+/// A password-based key derivation function and its cost parameters.
+///
+/// Every doubling of the work needed to test a guess adds roughly one bit to the effective
+/// entropy of a passphrase, so a passphrase that is already far beyond brute force needs less
+/// stretching than a short one.  `Kdf::for_entropy` chooses parameters on that basis.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Kdf {
+    ///
+    /// Argon2id (version 0x13), the winner of the Password Hashing Competition.
+    ///
+    Argon2id {
+        ///
+        /// The memory cost, in KiB.  Must be at least 8 times `parallelism`.
+        ///
+        memory_kib: u32,
+
+        ///
+        /// The time cost (number of passes over memory).
+        ///
+        iterations: u32,
+
+        ///
+        /// The degree of parallelism (number of lanes).
+        ///
+        parallelism: u32,
+    },
+
+    ///
+    /// scrypt, for compatibility with systems that don't support Argon2.
+    ///
+    Scrypt {
+        ///
+        /// The base 2 logarithm of the CPU/memory cost `N`.
+        ///
+        log_n: u8,
+
+        ///
+        /// The block size.
+        ///
+        r: u32,
+
+        ///
+        /// The parallelization parameter.
+        ///
+        p: u32,
+    },
+}
+
+impl Kdf {
+    ///
+    /// Returns Argon2id with the second recommended option from RFC 9106: 64 MiB of memory,
+    /// 3 iterations and 4 lanes.
+    ///
+    pub fn argon2id() -> Kdf {
+        Kdf::Argon2id {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 4,
+        }
+    }
+
+    ///
+    /// Returns scrypt with `N = 2^17`, `r = 8` and `p = 1` (128 MiB of memory).
+    ///
+    pub fn scrypt() -> Kdf {
+        Kdf::Scrypt {
+            log_n: 17,
+            r: 8,
+            p: 1,
+        }
+    }
+
+    ///
+    /// Returns Argon2id parameters suited to a passphrase with `bits` bits of entropy.
+    ///
+    ///   - 128 bits or more (10 or more `EffLong` words): 19 MiB and 2 iterations, the OWASP
+    ///     minimum.  Brute force is already infeasible, so stretching only adds a margin.
+    ///   - 80 to 128 bits (7 to 9 `EffLong` words): `Kdf::argon2id()`.
+    ///   - under 80 bits (including the default 5 `EffLong` words, ~64.6 bits): 256 MiB,
+    ///     4 iterations and 4 lanes, making each guess as expensive as is practical on a typical
+    ///     desktop.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::kdf::Kdf;
+    ///
+    /// assert_eq!(Kdf::for_entropy(103.4), Kdf::argon2id());
+    /// ```
+    ///
+    pub fn for_entropy(bits: f64) -> Kdf {
+        if bits >= 128.0 {
+            Kdf::Argon2id {
+                memory_kib: 19 * 1024,
+                iterations: 2,
+                parallelism: 1,
+            }
+        } else if bits >= 80.0 {
+            Kdf::argon2id()
+        } else {
+            Kdf::Argon2id {
+                memory_kib: 256 * 1024,
+                iterations: 4,
+                parallelism: 4,
+            }
+        }
+    }
+
+    ///
+    /// Derives a `key_len` byte key from `passphrase` and `salt`.
+    ///
+    /// Returns an error if the salt is shorter than `MIN_SALT_LEN` bytes, or if the parameters
+    /// or key length are rejected by the underlying function.  Argon2id accepts keys of 4 bytes
+    /// or more; scrypt accepts keys of 10 to 64 bytes.
+    ///
+    pub fn derive_key(
+        &self,
+        passphrase: &[u8],
+        salt: &[u8],
+        key_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        if salt.len() < MIN_SALT_LEN {
+            return Err(Error::InvalidKdfParams(
+                "salt must be at least 8 bytes".to_string(),
+            ));
+        }
+
+        let mut key = DerivedKey {
+            bytes: vec![0; key_len],
+        };
+        match *self {
+            Kdf::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                let params =
+                    argon2::Params::new(memory_kib, iterations, parallelism, Some(key_len))
+                        .map_err(|err| Error::InvalidKdfParams(err.to_string()))?;
+                Argon2::new(Algorithm::Argon2id, argon2::Version::V0x13, params)
+                    .hash_password_into(passphrase, salt, &mut key.bytes)
+                    .map_err(|err| Error::InvalidKdfParams(err.to_string()))?;
+            }
+            Kdf::Scrypt { log_n, r, p } => {
+                let params = scrypt::Params::new(log_n, r, p, key_len)
+                    .map_err(|err| Error::InvalidKdfParams(err.to_string()))?;
+                scrypt::scrypt(passphrase, salt, &params, &mut key.bytes)
+                    .map_err(|err| Error::InvalidKdfParams(err.to_string()))?;
+            }
+        }
+        Ok(key)
+    }
+}
+
+impl Default for Kdf {
+    fn default() -> Kdf {
+        Kdf::argon2id()
+    }
+}
+
+///
+/// A key derived from a passphrase.
+///
+/// The key is zeroed when the `DerivedKey` is dropped and is left out of its `Debug` output.
+/// Keys are compared in constant time, taking the same time wherever they differ.
+///
+#[derive(Clone)]
+pub struct DerivedKey {
+    bytes: Vec<u8>,
+}
+
+impl DerivedKey {
+    ///
+    /// Returns the key.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl PartialEq for DerivedKey {
+    fn eq(&self, other: &DerivedKey) -> bool {
+        self.bytes.len() == other.bytes.len()
+            && self
+                .bytes
+                .iter()
+                .zip(&other.bytes)
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+impl Eq for DerivedKey {}
+
+impl Drop for DerivedKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl fmt::Debug for DerivedKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DerivedKey")
+            .field("bytes", &"<redacted>")
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+impl Passphrase {
+    ///
+    /// Derives a `key_len` byte key from this passphrase and `salt` using `kdf`.
+    ///
+    /// See `Kdf::derive_key`.
+    ///
+    pub fn derive_key(&self, kdf: &Kdf, salt: &[u8], key_len: usize) -> Result<DerivedKey, Error> {
+        kdf.derive_key(self.as_str().as_bytes(), salt, key_len)
+    }
+}
+
+///
+/// Returns a random `SALT_LEN` byte salt from the operating system's secure random number
+/// generator.
+///
+#[cfg(feature = "std")]
+pub fn generate_salt() -> [u8; SALT_LEN] {
+    use rand::Rng;
+
+    let mut salt = [0; SALT_LEN];
+    ::pass_gen::os_rng().fill_bytes(&mut salt);
+    salt
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ARGON2ID: Kdf = Kdf::Argon2id {
+        memory_kib: 64,
+        iterations: 1,
+        parallelism: 1,
+    };
+    const SCRYPT: Kdf = Kdf::Scrypt {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn deterministic() {
+        for kdf in &[ARGON2ID, SCRYPT] {
+            let key = kdf.derive_key(b"acid acorn acre", b"saltsalt", 32).unwrap();
+            assert_eq!(key.as_bytes().len(), 32);
+            assert_eq!(
+                key,
+                kdf.derive_key(b"acid acorn acre", b"saltsalt", 32).unwrap()
+            );
+            assert_ne!(
+                key,
+                kdf.derive_key(b"acid acorn acre", b"saltpepper", 32)
+                    .unwrap()
+            );
+            assert_ne!(
+                key,
+                kdf.derive_key(b"acid acorn acre", b"saltsalt", 16).unwrap()
+            );
+            assert_ne!(
+                key,
+                kdf.derive_key(b"acid acorn acts", b"saltsalt", 32).unwrap()
+            );
+        }
+        assert_ne!(
+            ARGON2ID.derive_key(b"acid", b"saltsalt", 32).unwrap(),
+            SCRYPT.derive_key(b"acid", b"saltsalt", 32).unwrap()
+        );
+    }
+
+    #[test]
+    fn scrypt_test_vector() {
+        // RFC 7914 section 12, third vector
+        let kdf = Kdf::Scrypt {
+            log_n: 14,
+            r: 8,
+            p: 1,
+        };
+        let key = kdf
+            .derive_key(b"pleaseletmein", b"SodiumChloride", 64)
+            .unwrap();
+        assert_eq!(
+            &key.as_bytes()[..8],
+            &[0x70, 0x23, 0xbd, 0xcb, 0x3a, 0xfd, 0x73, 0x48]
+        );
+    }
+
+    #[test]
+    fn invalid_params() {
+        let bad_memory = Kdf::Argon2id {
+            memory_kib: 1,
+            iterations: 1,
+            parallelism: 1,
+        };
+        let bad_block_size = Kdf::Scrypt {
+            log_n: 4,
+            r: 0,
+            p: 1,
+        };
+        for &(kdf, salt) in &[
+            (ARGON2ID, &b"short"[..]),
+            (bad_memory, &b"saltsalt"[..]),
+            (bad_block_size, &b"saltsalt"[..]),
+        ] {
+            match kdf.derive_key(b"acid", salt, 32) {
+                Err(Error::InvalidKdfParams(_)) => {}
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn for_entropy() {
+        assert_eq!(Kdf::for_entropy(103.4), Kdf::argon2id());
+        match (Kdf::for_entropy(64.6), Kdf::for_entropy(129.2)) {
+            (
+                Kdf::Argon2id {
+                    memory_kib: short, ..
+                },
+                Kdf::Argon2id {
+                    memory_kib: long, ..
+                },
+            ) => assert!(short > long),
+            other => panic!("unexpected kdfs: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn salts_differ() {
+        assert_ne!(generate_salt(), generate_salt());
+    }
+}
@@ -10,6 +10,7 @@
 //! use lib_dicepass_gen::*;
 //! use lib_dicepass_gen::WordCount::*;
 //!
+//! # #[cfg(feature = "std")]
 //! fn main() {
 //!     let config = PassGenConfig::from_eff_long(Default);
 //!     let pass = generate(config);
@@ -19,6 +20,8 @@
 //!     // "resubmit gallon faceted duplex octagon"
 //!     # assert_eq!(pass.split_whitespace().count(), 5);
 //! }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
 //! Custom length pass-phrases can be generated like so:
 //!
@@ -26,6 +29,7 @@
 //! use lib_dicepass_gen::*;
 //! use lib_dicepass_gen::WordCount::*;
 //!
+//! # #[cfg(feature = "std")]
 //! fn main() {
 //!     let config = PassGenConfig::from_eff_long(Custom(7));
 //!     let pass = generate(config);
@@ -35,7 +39,98 @@
 //!     // "untouched scouting pronto gauging tripping resume derived"
 //!     # assert_eq!(pass.split_whitespace().count(), 7);
 //! }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
+//! Words can be kept out of generated pass-phrases with a `Blocklist`:
+//!
+//! ```
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::WordCount::*;
+//!
+//! # #[cfg(feature = "std")]
+//! fn main() {
+//!     let mut config = PassGenConfig::from_eff_long(Default);
+//!     config.blocklist = Blocklist::sensitive();
+//!     config.blocklist.insert("tripping");
+//!     let pass = generate(config);
+//!
+//!     println!("{}", pass);
+//!     # assert!(!pass.split_whitespace().any(|word| word == "tripping"));
+//! }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+//! Other options (separators, capitalization, entropy targets and policy constraints) can be set
+//! with `PassGenConfig::builder`, which checks that they make sense together:
+//!
+//! ```
+//! use lib_dicepass_gen::*;
+//!
+//! # #[cfg(feature = "std")]
+//! fn main() {
+//!     let config = PassGenConfig::builder()
+//!         .word_list(WordList::EffShort2)
+//!         .entropy(80.0)
+//!         .separator(".")
+//!         .casing(Casing::Capitalized)
+//!         .build()
+//!         .expect("invalid config");
+//!     let pass = generate(config);
+//!
+//!     println!("{}", pass);
+//!     // should result in something like:
+//!     // "Nylon.Shrimp.Feverish.Dresser.Twins.Lakefront.Aghast.Upbeat"
+//!     # assert_eq!(pass.split('.').count(), 8);
+//! }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+//! ### Optional Features
+//! - `std` (enabled by default): provides `generate` and `generate_passphrase`, which draw
+//!   randomness from the operating system, and the `HashMap` views of the default word lists.
+//!   Without it the crate is `#![no_std]` and only needs `alloc`; passphrases are generated with
+//!   `generate_with` and a caller-supplied `rand::Rng`.
+//!
+//! - `serde`: implements `Serialize` and `Deserialize` for `PassGenConfig` and the types it
+//!   contains, as well as for the `Passphrase` returned by `generate_passphrase`.  The passphrase
+//!   itself is only serialized when explicitly requested with `Passphrase::revealed`.
+//!
+//! - `ffi`: exposes `extern "C"` functions for generating passphrases from C and C++ (see the
+//!   `ffi` module and `include/dicepass.h`).  Build the C library (`libdicepass.a` and
+//!   `libdicepass.so`) with `cargo build --release -p dicepass_ffi`.
+//!
+//! - `wasm`: exposes `wasm-bindgen` functions for generating passphrases in the browser (see the
+//!   `wasm` module), and draws randomness from `crypto.getRandomValues` on
+//!   `wasm32-unknown-unknown` instead of `OsRng`.
+//!
+//! - `python`: exposes the `dicepass` Python module through PyO3 (see the `python` module).  Build
+//!   it with `maturin develop` or `maturin build`.
+//!
+//! - `derivation`: derives passphrases deterministically from a master secret and a site, account
+//!   and counter using Argon2id and HKDF-SHA256 (see the `derivation` module).  Works without
+//!   `std`.
+//!
+//! - `kdf`: stretches a generated passphrase into an encryption key with Argon2id or scrypt (see
+//!   the `kdf` module).  Works without `std`.
+//!
+//! - `ceremony`: generates a passphrase collaboratively with a commit-reveal protocol, so that no
+//!   single participant controls the result (see the `ceremony` module).  Works without `std`.
+//!
+//! - `recovery`: generates sheets of distinct single-use recovery passphrases, formats them as
+//!   text, Markdown or HTML, and hashes them for storage with salted SHA-256 or, with `kdf`, a
+//!   `Kdf` (see the `recovery` module).  Works without `std`.
+//!
+//! - `cli`: builds the `dicepass` command (`cargo install --path . --features cli`).  Defaults are
+//!   read from `~/.config/dicepass/config.toml` (or the file named by `DICEPASS_CONFIG`), then
+//!   from `DICEPASS_*` environment variables, and command line flags override both (see the
+//!   `settings` module).
+//!
+//! - `qr`: renders a passphrase as a QR code with a pure Rust encoder, for a terminal or as an SVG
+//!   or PNG image (see the `qr` module).  With `cli`, `dicepass --qr` prints the code after the
+//!   passphrase.
 //!
 //! ### About
 //! `lib_dicepass_gen` uses the [Diceware](http://world.std.com/%7Ereinhold/diceware.html) method
@@ -48,13 +143,104 @@
 //! added in the future.
 //!
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// `vec!` and `format!` are only in the prelude with `std`
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+// `core` is only injected automatically under `no_std`
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate lazy_static;
+#[cfg(not(feature = "std"))]
+extern crate libm;
 extern crate rand;
+#[cfg(any(feature = "derivation", feature = "kdf"))]
+extern crate argon2;
+#[cfg(feature = "derivation")]
+extern crate hkdf;
+#[cfg(feature = "kdf")]
+extern crate scrypt;
+#[cfg(any(feature = "derivation", feature = "ceremony", feature = "recovery"))]
+extern crate sha2;
+#[cfg(any(feature = "derivation", feature = "kdf"))]
+extern crate zeroize;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(feature = "qr")]
+extern crate png;
+#[cfg(feature = "qr")]
+extern crate qrcode;
+#[cfg(feature = "cli")]
+extern crate toml;
+#[cfg(feature = "wasm")]
+extern crate getrandom;
+#[cfg(feature = "python")]
+extern crate pyo3;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(all(test, target_arch = "wasm32"))]
+extern crate wasm_bindgen_test;
 
+pub mod blocklist;
+pub mod builder;
+#[cfg(feature = "ceremony")]
+pub mod ceremony;
+pub mod checksum;
+pub mod codes;
+pub mod complete;
+pub mod custom_list;
 pub mod defaults;
+#[cfg(feature = "derivation")]
+pub mod derivation;
+pub mod dice;
+mod distance;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "kdf")]
+pub mod kdf;
+pub mod manual;
+mod math;
+pub mod mnemonic;
 pub mod pass_gen;
+pub mod pseudo;
+#[cfg(feature = "qr")]
+pub mod qr;
+#[cfg(feature = "recovery")]
+pub mod recovery;
+#[cfg(feature = "cli")]
+pub mod settings;
+pub mod shamir;
+pub mod strength;
+pub mod template;
+#[cfg(test)]
+mod uniformity;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use blocklist::Blocklist;
+pub use builder::PassGenConfigBuilder;
+pub use custom_list::CustomList;
+pub use error::CeremonyError;
+pub use error::ConfigError;
+pub use error::Error;
 pub use pass_gen::WordList;
 pub use pass_gen::WordCount;
 pub use pass_gen::PassGenConfig;
+pub use pass_gen::Sampling;
+pub use pass_gen::Casing;
+pub use pass_gen::Policy;
+pub use pass_gen::RngSource;
+#[cfg(feature = "std")]
 pub use pass_gen::generate;
+#[cfg(feature = "std")]
+pub use pass_gen::generate_passphrase;
+pub use pass_gen::generate_with;
+pub use pass_gen::Passphrase;
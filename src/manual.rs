@@ -0,0 +1,362 @@
+//!
+//! Contains logic for turning manually generated entropy (coin flips, hex digits or a shuffled
+//! deck of playing cards) into word indices.
+//!
+//! Every input symbol is treated as a uniformly random digit with a known radix: 2 for a coin
+//! flip, 16 for a hex digit, and 52, 51, 50, ... for successive cards dealt from a shuffled deck.
+//! Digits are accumulated into a uniformly distributed value until it covers at least as many
+//! outcomes as there are words, at which point a word index is extracted.  Values which fall in
+//! the final, incomplete block of outcomes (those that would introduce modulo bias) are rejected
+//! and only the unbiased remainder is carried forward, so no input is ever used twice and no
+//! word is more likely than any other.
+//!
+
+use alloc::collections::BTreeSet;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use error::Error;
+use math;
+use pass_gen::WordList;
+
+///
+/// Describes a kind of manual entropy input.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    ///
+    /// A sequence of coin flips, written as `H`/`T` or `1`/`0`.  Each flip provides 1 bit of
+    /// entropy.
+    ///
+    CoinFlips,
+
+    ///
+    /// A sequence of hexadecimal digits (`0`-`9`, `a`-`f`), for example from a d16 or from
+    /// copying the output of a hardware random number generator.  Each digit provides 4 bits of
+    /// entropy.
+    ///
+    HexDigits,
+
+    ///
+    /// Cards dealt in order from a well shuffled standard 52 card deck, written as a rank (`A`,
+    /// `2`-`10`, `T`, `J`, `Q`, `K`) followed by a suit (`C`, `D`, `H`, `S`) and separated by
+    /// whitespace or commas.  A full deck provides ~225.6 bits of entropy; each card provides
+    /// slightly less than the one before it.
+    ///
+    Cards,
+}
+
+///
+/// The result of decoding manual input into word indices.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    ///
+    /// The word indices extracted from the input, in order.
+    ///
+    pub indices: Vec<usize>,
+
+    ///
+    /// The number of input symbols (flips, digits or cards) consumed to produce `indices`.
+    ///
+    pub used: usize,
+
+    ///
+    /// The number of input symbols after the last word which weren't enough to produce another
+    /// word.  These should be discarded rather than reused.
+    ///
+    pub unused: usize,
+}
+
+impl Source {
+    ///
+    /// Returns the entropy (in bits) provided by the first `symbols` input symbols.
+    ///
+    pub fn entropy(&self, symbols: usize) -> f64 {
+        match *self {
+            Source::CoinFlips => symbols as f64,
+            Source::HexDigits => symbols as f64 * 4.0,
+            Source::Cards => (0..symbols.min(DECK_SIZE))
+                .map(|dealt| math::log2((DECK_SIZE - dealt) as f64))
+                .sum(),
+        }
+    }
+
+    ///
+    /// Returns the minimum number of input symbols needed to select `words` words from a list of
+    /// `list_len` words.
+    ///
+    /// This is a lower bound: input falling in a rejected block is discarded, so more symbols
+    /// are occasionally needed.  Returns `None` if the source can never provide enough entropy
+    /// (a single deck of cards holds ~225.6 bits).
+    ///
+    /// ```
+    /// use lib_dicepass_gen::manual::Source;
+    ///
+    /// // 5 words from the EffLong list need ~64.6 bits
+    /// assert_eq!(Source::CoinFlips.min_symbols(7776, 5), Some(65));
+    /// assert_eq!(Source::HexDigits.min_symbols(7776, 5), Some(17));
+    /// assert_eq!(Source::Cards.min_symbols(7776, 5), Some(12));
+    /// assert_eq!(Source::Cards.min_symbols(7776, 20), None);
+    /// ```
+    ///
+    pub fn min_symbols(&self, list_len: usize, words: usize) -> Option<usize> {
+        let needed = words as f64 * math::log2(list_len as f64);
+        let limit = match *self {
+            Source::Cards => DECK_SIZE,
+            _ => math::ceil(needed) as usize,
+        };
+        (0..limit + 1).find(|symbols| self.entropy(*symbols) >= needed)
+    }
+
+    ///
+    /// Decodes manual input into as many indices into a list of `list_len` words as it allows.
+    ///
+    /// Whitespace in the input is ignored.  Returns an error if `list_len` is less than 2, if
+    /// the input contains a symbol that isn't valid for this source, or if a card appears more
+    /// than once.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::manual::Source;
+    ///
+    /// let decoded = Source::CoinFlips.decode("HHTT HTHT", 4).unwrap();
+    /// assert_eq!(decoded.indices, vec![3, 0, 2, 2]);
+    /// assert_eq!(decoded.used, 8);
+    /// assert_eq!(decoded.unused, 0);
+    /// ```
+    ///
+    pub fn decode(&self, input: &str, list_len: usize) -> Result<Decoded, Error> {
+        if list_len < 2 {
+            return Err(Error::WordListTooSmall(list_len));
+        }
+        let digits = match *self {
+            Source::CoinFlips => parse_flips(input)?,
+            Source::HexDigits => parse_hex(input)?,
+            Source::Cards => parse_cards(input)?,
+        };
+
+        let mut extractor = Extractor::new(list_len);
+        let mut decoded = Decoded {
+            indices: Vec::new(),
+            used: 0,
+            unused: 0,
+        };
+
+        for (consumed, &(digit, radix)) in digits.iter().enumerate() {
+            let before = decoded.indices.len();
+            extractor.push(digit, radix, &mut decoded.indices);
+            if decoded.indices.len() > before {
+                decoded.used = consumed + 1;
+            }
+        }
+        decoded.unused = digits.len() - decoded.used;
+
+        Ok(decoded)
+    }
+
+    ///
+    /// Decodes manual input into words from `word_list`.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    /// use lib_dicepass_gen::manual::Source;
+    ///
+    /// // 48 bits of input, enough for four ~10.3 bit words
+    /// let words = Source::HexDigits.words(&WordList::EffShort, "0000 0000 0000").unwrap();
+    /// assert_eq!(words, vec!["acid", "acid", "acid", "acid"]);
+    /// ```
+    ///
+    pub fn words<'a>(&self, word_list: &'a WordList, input: &str) -> Result<Vec<&'a str>, Error> {
+        let words = word_list.words();
+        let decoded = self.decode(input, words.len())?;
+        Ok(decoded
+            .indices
+            .into_iter()
+            .map(|index| words[index])
+            .collect())
+    }
+}
+
+const DECK_SIZE: usize = 52;
+const RANKS: [&str; 13] = [
+    "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K",
+];
+const SUITS: [char; 4] = ['C', 'D', 'H', 'S'];
+
+///
+/// Accumulates uniformly random digits and extracts uniformly random indices from them.
+///
+/// `value` is always uniformly distributed over `0..range`.  `list_len` must be at least 2, or
+/// extraction never shrinks `range`.
+///
+struct Extractor {
+    value: u128,
+    range: u128,
+    list_len: u128,
+}
+
+impl Extractor {
+    fn new(list_len: usize) -> Extractor {
+        Extractor {
+            value: 0,
+            range: 1,
+            list_len: list_len as u128,
+        }
+    }
+
+    fn push(&mut self, digit: u32, radix: u32, indices: &mut Vec<usize>) {
+        self.value = self.value * u128::from(radix) + u128::from(digit);
+        self.range *= u128::from(radix);
+
+        while self.range >= self.list_len {
+            let blocks = self.range / self.list_len;
+            let limit = blocks * self.list_len;
+            if self.value < limit {
+                indices.push((self.value % self.list_len) as usize);
+                self.value /= self.list_len;
+                self.range = blocks;
+            } else {
+                self.value -= limit;
+                self.range -= limit;
+            }
+        }
+    }
+}
+
+fn parse_flips(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            'H' | 'h' | '1' => Ok((1, 2)),
+            'T' | 't' | '0' => Ok((0, 2)),
+            _ => Err(Error::InvalidInput(c.to_string())),
+        })
+        .collect()
+}
+
+fn parse_hex(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c.to_digit(16) {
+            Some(digit) => Ok((digit, 16)),
+            None => Err(Error::InvalidInput(c.to_string())),
+        })
+        .collect()
+}
+
+fn parse_cards(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    let mut seen = BTreeSet::new();
+    let mut digits = Vec::new();
+
+    for token in input.split(|c: char| c.is_whitespace() || c == ',') {
+        if token.is_empty() {
+            continue;
+        }
+
+        let card = parse_card(token).ok_or_else(|| Error::InvalidInput(token.to_string()))?;
+        if !seen.insert(card) {
+            return Err(Error::DuplicateCard(token.to_string()));
+        }
+
+        // the card's position among those not yet dealt (its Lehmer code digit)
+        let digit = (0..card).filter(|other| !seen.contains(other)).count();
+        digits.push((digit as u32, (DECK_SIZE - digits.len()) as u32));
+    }
+
+    Ok(digits)
+}
+
+fn parse_card(token: &str) -> Option<usize> {
+    let token = token.to_uppercase();
+    let suit = token.chars().last()?;
+    let rank = &token[..token.len() - suit.len_utf8()];
+    let rank = if rank == "T" { "10" } else { rank };
+
+    let suit = SUITS.iter().position(|s| *s == suit)?;
+    let rank = RANKS.iter().position(|r| *r == rank)?;
+    Some(suit * RANKS.len() + rank)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn coin_flips_exact_power_of_two() {
+        let decoded = Source::CoinFlips.decode("1111 0000 101", 16).unwrap();
+        assert_eq!(decoded.indices, vec![15, 0]);
+        assert_eq!(decoded.used, 8);
+        assert_eq!(decoded.unused, 3);
+    }
+
+    #[test]
+    fn coin_flips_rejection() {
+        // 3 bits give 8 outcomes for a 6 word list; 6 and 7 are rejected, leaving a single
+        // uniformly random bit to carry forward
+        let decoded = Source::CoinFlips.decode("111", 6).unwrap();
+        assert!(decoded.indices.is_empty());
+        assert_eq!(decoded.unused, 3);
+
+        let decoded = Source::CoinFlips.decode("101", 6).unwrap();
+        assert_eq!(decoded.indices, vec![5]);
+    }
+
+    #[test]
+    fn list_too_small() {
+        let flips = "H".repeat(200);
+        assert_eq!(
+            Source::CoinFlips.decode(&flips, 1),
+            Err(Error::WordListTooSmall(1))
+        );
+        assert_eq!(
+            Source::HexDigits.decode("00", 0),
+            Err(Error::WordListTooSmall(0))
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            Source::CoinFlips.decode("HTX", 2),
+            Err(Error::InvalidInput("X".to_string()))
+        );
+        assert_eq!(
+            Source::HexDigits.decode("0g", 2),
+            Err(Error::InvalidInput("g".to_string()))
+        );
+        assert_eq!(
+            Source::Cards.decode("AS 1S", 2),
+            Err(Error::InvalidInput("1S".to_string()))
+        );
+        assert_eq!(
+            Source::Cards.decode("AS, 10h, as", 2),
+            Err(Error::DuplicateCard("as".to_string()))
+        );
+    }
+
+    #[test]
+    fn cards() {
+        // the first card dealt is the ace of clubs (digit 0 of 52), the second is the ace of
+        // diamonds (digit 12 of 51, since the ace of clubs is gone)
+        let decoded = Source::Cards.decode("AC AD", 52 * 51).unwrap();
+        assert_eq!(decoded.indices, vec![12]);
+
+        let deck: Vec<String> = SUITS
+            .iter()
+            .flat_map(|suit| RANKS.iter().map(move |rank| format!("{}{}", rank, suit)))
+            .collect();
+        let decoded = Source::Cards.decode(&deck.join(" "), 7776).unwrap();
+        assert_eq!(decoded.used + decoded.unused, DECK_SIZE);
+        assert!(decoded.indices.len() >= 16);
+    }
+
+    #[test]
+    fn entropy() {
+        assert_eq!(Source::HexDigits.entropy(4), 16.0);
+        assert!((Source::Cards.entropy(52) - 225.58).abs() < 0.01);
+        assert_eq!(Source::Cards.entropy(100), Source::Cards.entropy(52));
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! Contains the floating point functions used for entropy calculations.
+//!
+//! `core` doesn't provide these, so without the `std` feature they come from `libm`.
+//!
+
+#[cfg(feature = "std")]
+pub fn log2(x: f64) -> f64 {
+    x.log2()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn log2(x: f64) -> f64 {
+    ::libm::log2(x)
+}
+
+#[cfg(feature = "std")]
+pub fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn ceil(x: f64) -> f64 {
+    ::libm::ceil(x)
+}
+
+#[cfg(feature = "std")]
+pub fn exp2(x: f64) -> f64 {
+    x.exp2()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn exp2(x: f64) -> f64 {
+    ::libm::exp2(x)
+}
@@ -0,0 +1,266 @@
+//!
+//! Contains helpers which turn a passphrase's words into memorization aids.
+//!
+//! Every aid is built locally and deterministically from the words alone, so the same passphrase
+//! always gets the same aid and nothing random is mixed in.  The passphrase itself is never
+//! changed, but each aid contains or hints at every word, so it's as sensitive as the passphrase
+//! and should only be shown to the person memorizing it.
+//!
+//! ```
+//! use lib_dicepass_gen::mnemonic::{self, Aid};
+//!
+//! let words = ["ranking", "partition", "chewer", "crayfish", "hardener"];
+//! println!("{}", mnemonic::memory_aid(Aid::Story, &words));
+//! // prints:
+//! // Picture a RANKING the size of a house. Along comes a PARTITION, dancing. Behind it hides
+//! // a CHEWER. It balances on top of a CRAYFISH. Finally, everything vanishes into a HARDENER.
+//!
+//! let pegs = mnemonic::memory_aid(Aid::PegList, &words);
+//! assert!(pegs.starts_with("1 (bun): ranking"));
+//! ```
+//!
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use defaults::parts_of_speech;
+
+///
+/// The kinds of memorization aid.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Aid {
+    ///
+    /// A sentence whose words start with the same letters as the passphrase's words, to recall
+    /// their initials.  See `acrostic`.
+    ///
+    Acrostic,
+
+    ///
+    /// A short, vivid story mentioning each word in order.  See `story`.
+    ///
+    Story,
+
+    ///
+    /// A numbered list pairing each word with a rhyming peg word.  See `peg_list`.
+    ///
+    PegList,
+}
+
+///
+/// Returns the memorization aid of the given kind for `words`.
+///
+pub fn memory_aid<S: AsRef<str>>(aid: Aid, words: &[S]) -> String {
+    match aid {
+        Aid::Acrostic => acrostic(words),
+        Aid::Story => story(words),
+        Aid::PegList => peg_list(words).join("\n"),
+    }
+}
+
+///
+/// Returns a sentence of adjectives, nouns and verbs whose initials match those of `words`, such
+/// as "Ripe Penguin Captures Crooked Helmet" for "ranking partition chewer crayfish hardener".
+///
+/// Where no bundled word has the right initial, the passphrase word itself is used.
+///
+pub fn acrostic<S: AsRef<str>>(words: &[S]) -> String {
+    let seed = seed(words);
+    let pattern = [
+        parts_of_speech::ADJECTIVES,
+        parts_of_speech::NOUNS,
+        parts_of_speech::VERBS,
+    ];
+    words
+        .iter()
+        .enumerate()
+        .map(|(position, word)| {
+            let word = word.as_ref();
+            let first = initial(word);
+            let candidates: Vec<&str> = pattern[position % pattern.len()]
+                .iter()
+                .filter(|candidate| initial(candidate) == first)
+                .cloned()
+                .collect();
+            let chosen = if candidates.is_empty() {
+                word
+            } else {
+                candidates[pick(seed, position, candidates.len())]
+            };
+            capitalize(chosen)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+const OPENINGS: &[&str] = &[
+    "Picture a {} the size of a house.",
+    "Imagine a glowing {}.",
+    "You wake up holding a {}.",
+];
+
+const MIDDLES: &[&str] = &[
+    "It crashes into a {}.",
+    "Out jumps a {}.",
+    "Behind it hides a {}.",
+    "It slowly turns into a {}.",
+    "Along comes a {}, dancing.",
+    "It balances on top of a {}.",
+];
+
+const ENDINGS: &[&str] = &[
+    "Finally, everything vanishes into a {}.",
+    "It all ends with a {}.",
+];
+
+///
+/// Returns a short story mentioning each of `words` in order, in capitals so they stand out.
+///
+pub fn story<S: AsRef<str>>(words: &[S]) -> String {
+    let seed = seed(words);
+    let last = words.len().saturating_sub(1);
+    words
+        .iter()
+        .enumerate()
+        .map(|(position, word)| {
+            let frames = match position {
+                0 => OPENINGS,
+                p if p == last => ENDINGS,
+                _ => MIDDLES,
+            };
+            let frame = frames[pick(seed, position, frames.len())];
+            let word = word.as_ref().to_uppercase();
+            let frame = if starts_with_vowel(&word) {
+                frame.replace("a {}", "an {}")
+            } else {
+                frame.to_string()
+            };
+            frame.replace("{}", &word)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+const PEGS: &[&str] = &[
+    "bun", "shoe", "tree", "door", "hive", "sticks", "heaven", "gate", "vine", "hen",
+];
+
+///
+/// Returns a numbered list of `words` in their original order, pairing each of the first ten
+/// with a peg word that rhymes with its number ("1 (bun): ranking") to picture them together.
+///
+pub fn peg_list<S: AsRef<str>>(words: &[S]) -> Vec<String> {
+    words
+        .iter()
+        .enumerate()
+        .map(|(position, word)| match PEGS.get(position) {
+            Some(peg) => format!("{} ({}): {}", position + 1, peg, word.as_ref()),
+            None => format!("{}: {}", position + 1, word.as_ref()),
+        })
+        .collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns the first letter of `word`, lowercased so recased passphrases match the word lists.
+fn initial(word: &str) -> Option<char> {
+    word.chars().next().and_then(|c| c.to_lowercase().next())
+}
+
+fn starts_with_vowel(word: &str) -> bool {
+    word.starts_with(|c: char| "AEIOU".contains(c))
+}
+
+/// Returns a 64-bit FNV-1a hash of the words, so choices depend on the whole passphrase.  ASCII
+/// letters are hashed lowercased, so a recased passphrase gets the same aid.
+fn seed<S: AsRef<str>>(words: &[S]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in words {
+        for byte in word.as_ref().bytes().chain(Some(0)) {
+            hash ^= u64::from(byte.to_ascii_lowercase());
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+fn pick(seed: u64, position: usize, len: usize) -> usize {
+    let mixed = (seed ^ (position as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .wrapping_mul(0x0100_0000_01b3);
+    ((mixed >> 32) % len as u64) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WORDS: [&str; 5] = ["ranking", "partition", "chewer", "crayfish", "hardener"];
+
+    #[test]
+    fn deterministic() {
+        for &aid in &[Aid::Acrostic, Aid::Story, Aid::PegList] {
+            assert_eq!(memory_aid(aid, &WORDS), memory_aid(aid, &WORDS));
+        }
+        let other = ["ranking", "partition", "chewer", "crayfish", "hammock"];
+        assert_ne!(story(&WORDS), story(&other));
+    }
+
+    #[test]
+    fn acrostic_initials() {
+        let sentence = acrostic(&WORDS);
+        let initials: String = sentence
+            .split(' ')
+            .map(|word| word.chars().next().unwrap())
+            .collect();
+        assert_eq!(initials, "RPCCH");
+
+        // no bundled verb starts with x, so the word itself is used
+        assert_eq!(
+            acrostic(&["apple", "banana", "xylophone"])
+                .rsplit(' ')
+                .next(),
+            Some("Xylophone")
+        );
+    }
+
+    #[test]
+    fn acrostic_ignores_casing() {
+        let capitalized = ["Ranking", "Partition", "Chewer", "Crayfish", "Hardener"];
+        let upper = ["RANKING", "PARTITION", "CHEWER", "CRAYFISH", "HARDENER"];
+        assert_eq!(acrostic(&capitalized), acrostic(&WORDS));
+        assert_eq!(acrostic(&upper), acrostic(&WORDS));
+        assert_ne!(acrostic(&capitalized), capitalized.join(" "));
+    }
+
+    #[test]
+    fn story_mentions_words_in_order() {
+        let text = story(&WORDS);
+        let mut rest = text.as_str();
+        for word in &WORDS {
+            let at = rest
+                .find(&word.to_uppercase()[..])
+                .expect("word is in the story");
+            rest = &rest[at..];
+        }
+        assert!(story(&["acorn", "egg"]).contains("an ACORN"));
+        assert!(story(&["acorn", "egg"]).contains("an EGG"));
+        assert_eq!(story::<&str>(&[]), "");
+    }
+
+    #[test]
+    fn pegs() {
+        let words: Vec<String> = (0..11).map(|i| i.to_string()).collect();
+        let list = peg_list(&words);
+        assert_eq!(list[0], "1 (bun): 0");
+        assert_eq!(list[9], "10 (hen): 9");
+        assert_eq!(list[10], "11: 10");
+    }
+}
@@ -2,15 +2,39 @@
 //! Contains logic and structures relevant to passphrase generation.
 //!
 
-use std::collections::HashMap;
-use rand::{OsRng, Rng};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
 
+#[cfg(feature = "std")]
+use rand::chacha::ChaChaRng;
+#[cfg(all(feature = "std", not(feature = "wasm")))]
+use rand::OsRng;
+use rand::Rng;
+#[cfg(feature = "std")]
+use rand::SeedableRng;
+
+use blocklist::Blocklist;
+use builder::PassGenConfigBuilder;
+use checksum;
+use custom_list::CustomList;
 use defaults;
+use dice::{DicePlan, Die};
+#[cfg(feature = "serde")]
+use error::ConfigError;
+use error::Error;
+use math;
+use pseudo::PseudoWords;
 
 ///
 /// Describes the possible word lists that can be used to generate a passphrase.
 ///
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WordList {
     ///
     /// The EFF's "long" word list.  This list contains 7776 words each of which provide ~12.9 bits
@@ -29,22 +53,252 @@ pub enum WordList {
     /// The EFF's "special" short word list.  This list provides similar entropy per word as
     /// `EffShort` (~10.3 bits/word), but has some extra bonuses:
     ///
-    ///   1. Each word has a prefix of three unique characters
+    ///   1. Each word has a prefix of three unique characters (see `complete::Completer`)
     ///   2. Each word is at least an edit distance of 3 from every other word
     ///
     /// This list also contains 1296 words.
     ///
     EffShort2,
+
+    ///
+    /// A user-supplied word list of arbitrary size.  The entropy per word depends on the number
+    /// of words in the list.
+    ///
+    Custom(CustomList),
+
+    ///
+    /// Pronounceable pseudo-words built from a table of syllables (see `pseudo`).  Each syllable
+    /// provides exactly 6 bits of entropy, so two-syllable pseudo-words like "buzo" provide 12
+    /// bits from four letters.
+    ///
+    Pseudo(PseudoWords),
+}
+
+///
+/// The names of the built in word lists, as accepted by `WordList::from_str`.
+///
+pub const WORD_LIST_NAMES: [&str; 7] = [
+    "eff_long",
+    "eff_short",
+    "eff_short_2",
+    "pseudo",
+    "pseudo_1",
+    "pseudo_2",
+    "pseudo_3",
+];
+
+impl FromStr for WordList {
+    type Err = Error;
+
+    ///
+    /// Parses the name of one of the EFF word lists: `eff_long`, `eff_short` or `eff_short_2`.
+    ///
+    /// Pseudo-word lists are named `pseudo_1`, `pseudo_2` or `pseudo_3` by their number of
+    /// syllables, and `pseudo` is the same as `pseudo_2`.
+    ///
+    fn from_str(name: &str) -> Result<WordList, Error> {
+        match name {
+            "eff_long" => Ok(WordList::EffLong),
+            "eff_short" => Ok(WordList::EffShort),
+            "eff_short_2" => Ok(WordList::EffShort2),
+            "pseudo" => Ok(WordList::Pseudo(PseudoWords::default())),
+            "pseudo_1" => Ok(WordList::Pseudo(PseudoWords::new(1)?)),
+            "pseudo_2" => Ok(WordList::Pseudo(PseudoWords::new(2)?)),
+            "pseudo_3" => Ok(WordList::Pseudo(PseudoWords::new(3)?)),
+            _ => Err(Error::UnknownWordList(name.to_string())),
+        }
+    }
+}
+
+impl WordList {
+    ///
+    /// Returns the words in this list, in order.  For the EFF lists this is dice-sequence order.
+    ///
+    pub fn words(&self) -> Vec<&str> {
+        match *self {
+            WordList::EffLong => entry_words(defaults::eff_long::ENTRIES),
+            WordList::EffShort => entry_words(defaults::eff_short::ENTRIES),
+            WordList::EffShort2 => entry_words(defaults::eff_short_2::ENTRIES),
+            WordList::Custom(ref list) => list.words(),
+            WordList::Pseudo(ref words) => words.words(),
+        }
+    }
+
+    ///
+    /// Returns the word at `index` (in the same order as `words`), or `None` if it's out of
+    /// range.  Unlike `words`, this doesn't copy the list.
+    ///
+    pub fn word(&self, index: usize) -> Option<&str> {
+        match *self {
+            WordList::EffLong => entry_word(defaults::eff_long::ENTRIES, index),
+            WordList::EffShort => entry_word(defaults::eff_short::ENTRIES, index),
+            WordList::EffShort2 => entry_word(defaults::eff_short_2::ENTRIES, index),
+            WordList::Custom(ref list) => list.word(index),
+            WordList::Pseudo(ref words) => words.word(index),
+        }
+    }
+
+    ///
+    /// Returns an iterator over the words in this list, in order, without copying the list.
+    ///
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &str> {
+        (0..self.len()).filter_map(move |index| self.word(index))
+    }
+
+    ///
+    /// Returns the number of words in this list.
+    ///
+    pub fn len(&self) -> usize {
+        match *self {
+            WordList::EffLong => defaults::eff_long::ENTRIES.len(),
+            WordList::EffShort => defaults::eff_short::ENTRIES.len(),
+            WordList::EffShort2 => defaults::eff_short_2::ENTRIES.len(),
+            WordList::Custom(ref list) => list.len(),
+            WordList::Pseudo(ref words) => words.len(),
+        }
+    }
+
+    ///
+    /// Always returns `false`; every word list contains at least two words.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Returns the number of six-sided dice rolls needed to select a word from this list.
+    ///
+    /// For a `Custom` or `Pseudo` list whose length is not a power of six, some roll sequences do
+    /// not correspond to a word and must be rolled again.
+    ///
+    pub fn rolls_per_word(&self) -> u32 {
+        match *self {
+            WordList::EffLong => defaults::eff_long::ROLLS_PER_WORD,
+            WordList::EffShort => defaults::eff_short::ROLLS_PER_WORD,
+            WordList::EffShort2 => defaults::eff_short_2::ROLLS_PER_WORD,
+            WordList::Custom(_) | WordList::Pseudo(_) => {
+                let mut rolls = 0;
+                let mut sequences = 1;
+                while sequences < self.len() {
+                    rolls += 1;
+                    sequences *= 6;
+                }
+                rolls
+            }
+        }
+    }
+
+    ///
+    /// Looks up the word selected by a sequence of physical dice rolls.
+    ///
+    /// The rolls are interpreted according to `DicePlan::new(self.len(), die)`, so for the EFF
+    /// lists and `Die::D6` this is the same lookup as the printed Diceware tables.  Returns
+    /// `Ok(None)` if the sequence doesn't correspond to a word and must be rolled again.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    /// use lib_dicepass_gen::dice::Die;
+    ///
+    /// let word = WordList::EffLong.lookup(Die::D6, &[1, 1, 1, 2, 1]).unwrap();
+    /// assert_eq!(word, Some("ablaze"));
+    ///
+    /// // 20^3 = 8000 outcomes, the last 224 of which are rejected
+    /// let word = WordList::EffLong.lookup(Die::D20, &[20, 20, 20]).unwrap();
+    /// assert_eq!(word, None);
+    /// ```
+    ///
+    pub fn lookup(&self, die: Die, rolls: &[u32]) -> Result<Option<&str>, Error> {
+        let index = DicePlan::new(self.len(), die).index(rolls)?;
+        Ok(index.and_then(|index| self.word(index)))
+    }
+
+    ///
+    /// Returns the entropy (in bits) provided by each word chosen uniformly from this list: the
+    /// base 2 logarithm of its length.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    ///
+    /// assert!((WordList::EffLong.bits_per_word() - 12.9).abs() < 0.1);
+    /// assert!((WordList::EffShort.bits_per_word() - 10.3).abs() < 0.1);
+    /// ```
+    ///
+    pub fn bits_per_word(&self) -> f64 {
+        math::log2(self.len() as f64)
+    }
+
+    ///
+    /// Returns the number of words used when a passphrase is generated from this list with
+    /// `WordCount::Default`.
+    ///
+    /// For a `Custom` or `Pseudo` list this is the smallest number of words providing at least as
+    /// much entropy as the default `EffLong` passphrase.
+    ///
+    pub fn default_word_count(&self) -> u32 {
+        match *self {
+            WordList::EffLong => defaults::eff_long::WORD_COUNT,
+            WordList::EffShort => defaults::eff_short::WORD_COUNT,
+            WordList::EffShort2 => defaults::eff_short_2::WORD_COUNT,
+            WordList::Custom(_) | WordList::Pseudo(_) => {
+                let target = f64::from(defaults::eff_long::WORD_COUNT)
+                    * math::log2(defaults::eff_long::ENTRIES.len() as f64);
+                math::ceil(target / self.bits_per_word()) as u32
+            }
+        }
+    }
+}
+
+fn entry_words(entries: &'static [(&'static str, &'static str)]) -> Vec<&'static str> {
+    entries.iter().map(|&(_, word)| word).collect()
+}
+
+fn entry_word(
+    entries: &'static [(&'static str, &'static str)],
+    index: usize,
+) -> Option<&'static str> {
+    entries.get(index).map(|&(_, word)| word)
+}
+
+///
+/// Describes how words are selected from a word list.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Sampling {
+    ///
+    /// Simulates rolling the given kind of die as many times as `DicePlan::new` requires and looks
+    /// up the word for the resulting sequence, as described by the Diceware method.  Sequences
+    /// which don't correspond to a word are rolled again.
+    ///
+    /// With `Die::D6` and one of the EFF lists every sequence corresponds to a word.
+    ///
+    Dice(Die),
+
+    ///
+    /// Picks a word index uniformly from `0..len`.  Random values which would introduce modulo
+    /// bias are rejected and drawn again, so this mode is unbiased for lists of any size.
+    ///
+    Uniform,
+}
+
+impl Default for Sampling {
+    fn default() -> Sampling {
+        Sampling::Dice(Die::D6)
+    }
 }
 
 ///
 /// Describes the length (in words) of a passphrase to generate.
 ///
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WordCount {
     ///
     /// This value depends on the `WordList` in use.
     ///
+    #[default]
     Default,
 
     ///
@@ -71,10 +325,124 @@ impl From<Option<u32>> for WordCount {
     }
 }
 
+///
+/// Describes how the letters of each word in a passphrase are capitalized.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Casing {
+    ///
+    /// Every letter is lower case, as in the word lists ("correct horse").
+    ///
+    #[default]
+    Lower,
+
+    ///
+    /// Every letter is upper case ("CORRECT HORSE").
+    ///
+    Upper,
+
+    ///
+    /// The first letter of each word is upper case ("Correct Horse").
+    ///
+    Capitalized,
+}
+
+impl FromStr for Casing {
+    type Err = Error;
+
+    ///
+    /// Parses the name of a casing: `lower`, `upper` or `capitalized`.
+    ///
+    fn from_str(name: &str) -> Result<Casing, Error> {
+        match name {
+            "lower" => Ok(Casing::Lower),
+            "upper" => Ok(Casing::Upper),
+            "capitalized" => Ok(Casing::Capitalized),
+            _ => Err(Error::InvalidInput(name.to_string())),
+        }
+    }
+}
+
+impl Casing {
+    pub(crate) fn apply(&self, word: &str) -> String {
+        match *self {
+            Casing::Lower => word.to_lowercase(),
+            Casing::Upper => word.to_uppercase(),
+            Casing::Capitalized => {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+///
+/// Constraints a passphrase must satisfy, for example to comply with an organization's password
+/// policy.
+///
+/// Word length limits are applied like a `Blocklist`: words outside the limits are removed from
+/// the word list before sampling, and the entropy per word is reduced accordingly.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Policy {
+    ///
+    /// The minimum number of characters in each word.
+    ///
+    pub min_word_length: Option<usize>,
+
+    ///
+    /// The maximum number of characters in each word.
+    ///
+    pub max_word_length: Option<usize>,
+
+    ///
+    /// The minimum total entropy (in bits) a passphrase must provide.
+    ///
+    pub min_entropy: Option<f64>,
+}
+
+impl Policy {
+    fn allows(&self, word: &str) -> bool {
+        let len = word.chars().count();
+        self.min_word_length.is_none_or(|min| len >= min)
+            && self.max_word_length.is_none_or(|max| len <= max)
+    }
+}
+
+///
+/// Describes the source of randomness used to generate a passphrase.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RngSource {
+    ///
+    /// The operating system's secure random number generator.
+    ///
+    #[default]
+    Os,
+
+    ///
+    /// A ChaCha20 stream keyed with the given seed.  Every passphrase generated from the same
+    /// seed and config is identical, so this should only be used for testing or when the seed is
+    /// itself a secret derived from a secure source.
+    ///
+    Seeded([u32; 8]),
+}
+
 ///
 /// A config object describing the rules for how to generate a passphrase.
 ///
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "PassGenConfigData"))]
 pub struct PassGenConfig {
     ///
     /// The word list to use when generating the passphrase.
@@ -83,6 +451,97 @@ pub struct PassGenConfig {
 
     /// The length of the passphrase (in words) to generate.
     pub word_count: WordCount,
+
+    ///
+    /// How words are selected from `word_list`.
+    ///
+    pub sampling: Sampling,
+
+    ///
+    /// Words which must never appear in the passphrase.  These are removed from `word_list`
+    /// before sampling.
+    ///
+    pub blocklist: Blocklist,
+
+    ///
+    /// The text placed between words.
+    ///
+    pub separator: String,
+
+    ///
+    /// How the letters of each word are capitalized.
+    ///
+    pub casing: Casing,
+
+    ///
+    /// Constraints the passphrase must satisfy.
+    ///
+    pub policy: Policy,
+
+    ///
+    /// The source of randomness used to select words.
+    ///
+    pub rng: RngSource,
+
+    ///
+    /// Whether to append a checksum word, which lets typos be found when the passphrase is
+    /// entered again (see the `checksum` module).  The checksum word is in addition to
+    /// `word_count` and adds no entropy.  It's chosen from the whole of `word_list`, so it may be a
+    /// word that `blocklist` or `policy` would otherwise exclude.
+    ///
+    pub checksum: bool,
+}
+
+///
+/// The serialized form of a `PassGenConfig`, checked with `PassGenConfig::validate` before it's
+/// accepted.
+///
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct PassGenConfigData {
+    word_list: WordList,
+    #[serde(default)]
+    word_count: WordCount,
+    #[serde(default)]
+    sampling: Sampling,
+    #[serde(default)]
+    blocklist: Blocklist,
+    #[serde(default = "default_separator")]
+    separator: String,
+    #[serde(default)]
+    casing: Casing,
+    #[serde(default)]
+    policy: Policy,
+    #[serde(default)]
+    rng: RngSource,
+    #[serde(default)]
+    checksum: bool,
+}
+
+#[cfg(feature = "serde")]
+fn default_separator() -> String {
+    " ".to_string()
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<PassGenConfigData> for PassGenConfig {
+    type Error = ConfigError;
+
+    fn try_from(data: PassGenConfigData) -> Result<PassGenConfig, ConfigError> {
+        let config = PassGenConfig {
+            word_list: data.word_list,
+            word_count: data.word_count,
+            sampling: data.sampling,
+            blocklist: data.blocklist,
+            separator: data.separator,
+            casing: data.casing,
+            policy: data.policy,
+            rng: data.rng,
+            checksum: data.checksum,
+        };
+        config.validate()?;
+        Ok(config)
+    }
 }
 
 impl PassGenConfig {
@@ -93,10 +552,7 @@ impl PassGenConfig {
     /// words.
     ///
     pub fn from_eff_long(word_count: WordCount) -> PassGenConfig {
-        PassGenConfig {
-            word_list: WordList::EffLong,
-            word_count,
-        }
+        PassGenConfig::new(WordList::EffLong, word_count, Sampling::Dice(Die::D6))
     }
 
     ///
@@ -106,10 +562,7 @@ impl PassGenConfig {
     /// words.
     ///
     pub fn from_eff_short(word_count: WordCount) -> PassGenConfig {
-        PassGenConfig {
-            word_list: WordList::EffShort,
-            word_count,
-        }
+        PassGenConfig::new(WordList::EffShort, word_count, Sampling::Dice(Die::D6))
     }
 
     ///
@@ -119,11 +572,243 @@ impl PassGenConfig {
     /// of words.
     ///
     pub fn from_eff_short_2(word_count: WordCount) -> PassGenConfig {
+        PassGenConfig::new(WordList::EffShort2, word_count, Sampling::Dice(Die::D6))
+    }
+
+    ///
+    /// Helper function for creating `PassGenConfig` objects.
+    ///
+    /// Returns a `PassGenConfig` configured for the given `CustomList` with `WordCount` number of
+    /// words, using `Sampling::Uniform`.
+    ///
+    pub fn from_custom(list: CustomList, word_count: WordCount) -> PassGenConfig {
+        PassGenConfig::new(WordList::Custom(list), word_count, Sampling::Uniform)
+    }
+
+    ///
+    /// Helper function for creating `PassGenConfig` objects.
+    ///
+    /// Returns a `PassGenConfig` configured for the given `PseudoWords` with `WordCount` number of
+    /// pseudo-words, using `Sampling::Uniform`.
+    ///
+    pub fn from_pseudo(words: PseudoWords, word_count: WordCount) -> PassGenConfig {
+        PassGenConfig::new(WordList::Pseudo(words), word_count, Sampling::Uniform)
+    }
+
+    ///
+    /// Returns a `PassGenConfigBuilder` for configuring every option of a `PassGenConfig` and
+    /// validating them together.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use lib_dicepass_gen::*;
+    ///
+    /// let config = PassGenConfig::builder()
+    ///     .word_list(WordList::EffShort2)
+    ///     .entropy(100.0)
+    ///     .separator(".")
+    ///     .casing(Casing::Capitalized)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(config.word_count, WordCount::Custom(10));
+    /// assert_eq!(generate(config).split('.').count(), 10);
+    /// # }
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    pub fn builder() -> PassGenConfigBuilder {
+        PassGenConfigBuilder::new()
+    }
+
+    fn new(word_list: WordList, word_count: WordCount, sampling: Sampling) -> PassGenConfig {
         PassGenConfig {
-            word_list: WordList::EffShort2,
+            word_list,
+            word_count,
+            sampling,
+            blocklist: Blocklist::new(),
+            separator: " ".to_string(),
+            casing: Casing::Lower,
+            policy: Policy::default(),
+            rng: RngSource::Os,
+            checksum: false,
+        }
+    }
+
+    ///
+    /// Returns the number of words a passphrase generated from this config will contain.
+    ///
+    pub fn words(&self) -> u32 {
+        Option::from(self.word_count).unwrap_or_else(|| self.word_list.default_word_count())
+    }
+
+    ///
+    /// Returns `true` if `word` may appear in a passphrase generated from this config; that is,
+    /// it isn't excluded by `blocklist` or `policy`.
+    ///
+    pub fn allows(&self, word: &str) -> bool {
+        self.policy.allows(word) && !self.blocklist.contains(word)
+    }
+
+    ///
+    /// Returns the number of words in `word_list` that are not excluded by `blocklist` or
+    /// `policy`.
+    ///
+    pub fn available_words(&self) -> usize {
+        self.word_list
+            .iter()
+            .filter(|word| self.allows(word))
+            .count()
+    }
+
+    ///
+    /// Returns the entropy (in bits) provided by each word of a passphrase generated from this
+    /// config.
+    ///
+    /// This accounts for any words removed by `blocklist` or `policy`, so it may be lower than
+    /// the figure quoted for the full `word_list`.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    ///
+    /// let mut config = PassGenConfig::from_eff_long(WordCount::Default);
+    /// let full = config.bits_per_word();
+    ///
+    /// config.blocklist = Blocklist::sensitive();
+    /// assert!(config.bits_per_word() < full);
+    /// ```
+    ///
+    pub fn bits_per_word(&self) -> f64 {
+        math::log2(self.available_words() as f64)
+    }
+
+    ///
+    /// Returns the total entropy (in bits) of a passphrase generated from this config.  A
+    /// checksum word adds no entropy.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::*;
+    ///
+    /// let config = PassGenConfig::from_eff_long(WordCount::Default);
+    /// assert!((config.entropy() - 64.6).abs() < 0.1);
+    /// ```
+    ///
+    pub fn entropy(&self) -> f64 {
+        self.bits_per_word() * f64::from(self.words())
+    }
+}
+
+///
+/// A generated passphrase along with details about how strong it is.
+///
+/// The passphrase itself is only available through `as_str` and `into_string`.  It is left out
+/// of this type's `Debug` output and, when the `serde` feature is enabled, out of its serialized
+/// form; use `revealed` to serialize it with the passphrase included.
+///
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Passphrase {
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing))]
+    phrase: String,
+
+    ///
+    /// The number of words in the passphrase, including any checksum word.
+    ///
+    pub word_count: u32,
+
+    ///
+    /// The entropy (in bits) provided by each word.
+    ///
+    pub bits_per_word: f64,
+
+    ///
+    /// The total entropy (in bits) of the passphrase.
+    ///
+    pub entropy: f64,
+}
+
+impl Passphrase {
+    ///
+    /// Returns the passphrase.
+    ///
+    pub fn as_str(&self) -> &str {
+        &self.phrase
+    }
+
+    ///
+    /// Consumes this `Passphrase` and returns the passphrase.
+    ///
+    pub fn into_string(self) -> String {
+        self.phrase
+    }
+
+    ///
+    /// Returns a `Passphrase` of `word_count` words providing `entropy` bits in total, for
+    /// generation modes whose words don't all provide the same entropy.
+    ///
+    pub(crate) fn new(phrase: String, word_count: u32, entropy: f64) -> Passphrase {
+        Passphrase {
+            phrase,
             word_count,
+            bits_per_word: entropy / f64::from(word_count),
+            entropy,
         }
     }
+
+    ///
+    /// Returns a wrapper which serializes this `Passphrase` with the passphrase included under
+    /// the `phrase` key.
+    ///
+    /// ```
+    /// # extern crate lib_dicepass_gen;
+    /// # extern crate serde_json;
+    /// use lib_dicepass_gen::*;
+    ///
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// let pass = generate_passphrase(PassGenConfig::from_eff_long(WordCount::Default));
+    ///
+    /// let hidden = serde_json::to_value(&pass).unwrap();
+    /// assert!(hidden.get("phrase").is_none());
+    ///
+    /// let revealed = serde_json::to_value(pass.revealed()).unwrap();
+    /// assert_eq!(revealed["phrase"], pass.as_str());
+    /// # }
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    pub fn revealed(&self) -> Revealed<'_> {
+        Revealed {
+            phrase: &self.phrase,
+            details: self,
+        }
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Passphrase")
+            .field("phrase", &"<redacted>")
+            .field("word_count", &self.word_count)
+            .field("bits_per_word", &self.bits_per_word)
+            .field("entropy", &self.entropy)
+            .finish()
+    }
+}
+
+///
+/// Serializes a `Passphrase` with the passphrase included.  See `Passphrase::revealed`.
+///
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+pub struct Revealed<'a> {
+    phrase: &'a str,
+    #[serde(flatten)]
+    details: &'a Passphrase,
 }
 
 ///
@@ -139,58 +824,165 @@ impl PassGenConfig {
 /// println!("{}", pass);
 /// ```
 ///
+/// # Panics
+///
+/// Panics if `config.blocklist` and `config.policy` exclude every word in `config.word_list`.
+/// Configs created with `PassGenConfig::builder` or deserialized are checked for this (see
+/// `PassGenConfig::validate`).
+///
+#[cfg(feature = "std")]
 pub fn generate(config: PassGenConfig) -> String {
-    match config.word_list {
-        WordList::EffLong => generate_passphrase(
-            &defaults::eff_long::WORD_LIST,
-            Option::from(config.word_count).unwrap_or(defaults::eff_long::WORD_COUNT),
-            defaults::eff_long::ROLLS_PER_WORD,
-        ),
-        WordList::EffShort => generate_passphrase(
-            &defaults::eff_short::WORD_LIST,
-            Option::from(config.word_count).unwrap_or(defaults::eff_short::WORD_COUNT),
-            defaults::eff_short::ROLLS_PER_WORD,
-        ),
-        WordList::EffShort2 => generate_passphrase(
-            &defaults::eff_short_2::WORD_LIST,
-            Option::from(config.word_count).unwrap_or(defaults::eff_short_2::WORD_COUNT),
-            defaults::eff_short_2::ROLLS_PER_WORD,
-        ),
+    generate_passphrase(config).into_string()
+}
+
+///
+/// Generates a passphrase based on the given `PassGenConfig` object and returns it along with
+/// details about its strength.
+///
+/// ```
+/// use lib_dicepass_gen::*;
+///
+/// let config = PassGenConfig::from_eff_short(WordCount::Default);
+/// let pass = generate_passphrase(config);
+///
+/// assert_eq!(pass.word_count, 8);
+/// assert!(pass.entropy > 82.0);
+/// println!("{}", pass.as_str());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `config.blocklist` and `config.policy` exclude every word in `config.word_list`.
+/// Configs created with `PassGenConfig::builder` or deserialized are checked for this (see
+/// `PassGenConfig::validate`).
+///
+#[cfg(feature = "std")]
+pub fn generate_passphrase(config: PassGenConfig) -> Passphrase {
+    match config.rng {
+        RngSource::Os => generate_with(&config, &mut os_rng()),
+        RngSource::Seeded(seed) => {
+            let mut rng = ChaChaRng::from_seed(&seed[..]);
+            generate_with(&config, &mut rng)
+        }
     }
 }
 
-fn generate_passphrase(
-    sequence_map: &'static HashMap<&'static str, &'static str>,
-    length: u32,
-    rolls_per_word: u32,
-) -> String {
-    let mut rng = OsRng::new().expect("couldn't get rng");
-    let mut words = Vec::new();
+///
+/// Returns the operating system's secure random number generator (`crypto.getRandomValues` under
+/// the `wasm` feature).
+///
+#[cfg(feature = "std")]
+pub(crate) fn os_rng() -> impl Rng {
+    #[cfg(feature = "wasm")]
+    let rng = ::wasm::GetrandomRng;
+    #[cfg(not(feature = "wasm"))]
+    let rng = OsRng::new().expect("couldn't get rng");
+    rng
+}
+
+///
+/// Generates a passphrase based on the given `PassGenConfig` object, drawing randomness from
+/// `rng` instead of the source described by `config.rng`.
+///
+/// This is the only way to generate a passphrase without the `std` feature, for example on a
+/// microcontroller with a hardware random number generator exposed through `rand::Rng`.
+///
+/// ```
+/// extern crate lib_dicepass_gen;
+/// extern crate rand;
+///
+/// use lib_dicepass_gen::*;
+/// use rand::chacha::ChaChaRng;
+/// use rand::SeedableRng;
+///
+/// # fn main() {
+/// let config = PassGenConfig::from_eff_long(WordCount::Custom(4));
+/// let pass = generate_with(&config, &mut ChaChaRng::from_seed(&[1, 2, 3, 4]));
+/// assert_eq!(pass.word_count, 4);
+///
+/// // the same seed always produces the same passphrase
+/// let again = generate_with(&config, &mut ChaChaRng::from_seed(&[1, 2, 3, 4]));
+/// assert_eq!(pass.as_str(), again.as_str());
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `config.blocklist` and `config.policy` exclude every word in `config.word_list`.
+/// Configs created with `PassGenConfig::builder` or deserialized are checked for this (see
+/// `PassGenConfig::validate`).
+///
+pub fn generate_with<R: Rng>(config: &PassGenConfig, rng: &mut R) -> Passphrase {
+    if config.available_words() == 0 {
+        panic!("the blocklist and policy exclude every word in the word list");
+    }
+
+    let length = config.words();
+    let mut passphrase = Vec::new();
 
-    for _ in 0..length {
-        let sequence = gen_sequence(rolls_per_word, &mut rng);
-        if let Some(value) = sequence_map.get(sequence.as_str()) {
-            words.push(*value);
+    match config.sampling {
+        Sampling::Dice(die) => {
+            let plan = DicePlan::new(config.word_list.len(), die);
+            while passphrase.len() < length as usize {
+                let sequence = plan.roll(rng);
+                if let Ok(Some(index)) = plan.index(&sequence) {
+                    match config.word_list.word(index) {
+                        Some(word) if config.allows(word) => passphrase.push(word),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Sampling::Uniform => {
+            let allowed: Vec<&str> = config
+                .word_list
+                .iter()
+                .filter(|word| config.allows(word))
+                .collect();
+            for _ in 0..length {
+                passphrase.push(allowed[gen_index(allowed.len(), rng)]);
+            }
         }
     }
 
-    words.as_slice().join(" ")
+    if config.checksum {
+        let check = checksum::checksum_word(&config.word_list, &passphrase)
+            .expect("passphrase words come from the word list");
+        passphrase.push(check);
+    }
+
+    let bits_per_word = config.bits_per_word();
+    Passphrase {
+        phrase: passphrase
+            .iter()
+            .map(|word| config.casing.apply(word))
+            .collect::<Vec<_>>()
+            .join(&config.separator),
+        word_count: passphrase.len() as u32,
+        bits_per_word,
+        entropy: bits_per_word * f64::from(length),
+    }
 }
 
-fn gen_sequence(num_rolls: u32, rng: &mut OsRng) -> String {
-    let mut sequence = String::new();
-    for _ in 0..num_rolls {
-        let roll = rng.gen_range(1u32, 7u32).to_string();
-        sequence.push_str(&roll);
+pub(crate) fn gen_index<R: Rng>(len: usize, rng: &mut R) -> usize {
+    let len = len as u64;
+    let zone = (1u64 << 32) - (1u64 << 32) % len;
+    loop {
+        let value = u64::from(rng.next_u32());
+        if value < zone {
+            return (value % len) as usize;
+        }
     }
-    sequence
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use super::WordCount::*;
+    #[cfg(feature = "std")]
+    use rand::OsRng;
 
+    #[cfg(feature = "std")]
     #[test]
     fn gen_eff_long() {
         let pass_config = PassGenConfig::from_eff_long(Default);
@@ -201,6 +993,7 @@ mod test {
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn gen_eff_long_custom() {
         let word_count = 10;
@@ -209,6 +1002,7 @@ mod test {
         assert_eq!(pass.split_whitespace().count(), word_count as usize);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn gen_eff_short() {
         let pass_config = PassGenConfig::from_eff_short(Default);
@@ -219,6 +1013,7 @@ mod test {
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn gen_eff_short_custom() {
         let word_count = 10;
@@ -227,6 +1022,7 @@ mod test {
         assert_eq!(pass.split_whitespace().count(), word_count as usize);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn gen_eff_short_2() {
         let pass_config = PassGenConfig::from_eff_short_2(Default);
@@ -237,6 +1033,7 @@ mod test {
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn gen_eff_short_2_custom() {
         let word_count = 10;
@@ -244,4 +1041,259 @@ mod test {
         let pass = generate(pass_config);
         assert_eq!(pass.split_whitespace().count(), word_count as usize);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_with_blocklist() {
+        let mut pass_config = PassGenConfig::from_eff_short(Custom(200));
+        pass_config.blocklist = Blocklist::sensitive();
+        pass_config.blocklist.insert("acid");
+        let pass = generate(pass_config.clone());
+        assert_eq!(pass.split_whitespace().count(), 200);
+        assert!(pass
+            .split_whitespace()
+            .all(|word| !pass_config.blocklist.contains(word)));
+    }
+
+    #[test]
+    fn available_words_with_blocklist() {
+        let mut pass_config = PassGenConfig::from_eff_short_2(Default);
+        assert_eq!(pass_config.available_words(), 1296);
+
+        pass_config.blocklist.extend(vec!["aardvark", "not-a-word"]);
+        assert_eq!(pass_config.available_words(), 1295);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_custom() {
+        let list = CustomList::new(vec!["alpha", "bravo", "charlie"]).unwrap();
+        let pass_config = PassGenConfig::from_custom(list, Custom(30));
+        let pass = generate(pass_config);
+        assert_eq!(pass.split_whitespace().count(), 30);
+        assert!(pass
+            .split_whitespace()
+            .all(|word| ["alpha", "bravo", "charlie"].contains(&word)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_custom_dice() {
+        let list = CustomList::new((0..10).map(|i| i.to_string())).unwrap();
+        let mut pass_config = PassGenConfig::from_custom(list, Custom(30));
+        pass_config.sampling = Sampling::Dice(Die::D6);
+        let pass = generate(pass_config);
+        assert_eq!(pass.split_whitespace().count(), 30);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_eff_long_d20() {
+        let mut pass_config = PassGenConfig::from_eff_long(Default);
+        pass_config.sampling = Sampling::Dice(Die::D20);
+        let pass = generate(pass_config);
+        assert_eq!(
+            pass.split_whitespace().count(),
+            super::defaults::eff_long::WORD_COUNT as usize
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_eff_uniform() {
+        let mut pass_config = PassGenConfig::from_eff_long(Default);
+        pass_config.sampling = Sampling::Uniform;
+        let pass = generate(pass_config);
+        assert_eq!(
+            pass.split_whitespace().count(),
+            super::defaults::eff_long::WORD_COUNT as usize
+        );
+    }
+
+    #[test]
+    fn custom_defaults() {
+        let list = CustomList::new((0..2048).map(|i| i.to_string())).unwrap();
+        let word_list = WordList::Custom(list);
+        assert_eq!(word_list.rolls_per_word(), 5);
+        assert_eq!(word_list.default_word_count(), 6);
+    }
+
+    #[test]
+    fn word_matches_words() {
+        let custom = WordList::Custom(CustomList::new(vec!["red", "green", "blue"]).unwrap());
+        let pseudo: WordList = "pseudo_1".parse().unwrap();
+        for list in &[WordList::EffLong, WordList::EffShort2, custom, pseudo] {
+            let words = list.words();
+            assert_eq!(list.word(0), Some(words[0]));
+            assert_eq!(list.word(words.len() - 1), words.last().cloned());
+            assert_eq!(list.word(words.len()), None);
+            assert_eq!(list.iter().count(), words.len());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lookup_matches_word_list() {
+        assert_eq!(
+            WordList::EffShort.lookup(Die::D6, &[1, 1, 1, 1]),
+            Ok(Some(super::defaults::eff_short::WORD_LIST["1111"]))
+        );
+        assert_eq!(
+            WordList::EffShort2.lookup(Die::D6, &[6, 5, 4, 3]),
+            Ok(Some(super::defaults::eff_short_2::WORD_LIST["6543"]))
+        );
+        assert_eq!(
+            WordList::EffLong.lookup(Die::D6, &[6, 5, 4, 3]),
+            Err(Error::WrongRollCount {
+                expected: 5,
+                actual: 4,
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn passphrase_details() {
+        let pass = generate_passphrase(PassGenConfig::from_eff_long(Custom(6)));
+        assert_eq!(pass.word_count, 6);
+        assert_eq!(pass.as_str().split_whitespace().count(), 6);
+        assert!((pass.entropy - 77.55).abs() < 0.01);
+        assert!(!format!("{:?}", pass).contains(pass.as_str()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn config_round_trip() {
+        let mut pass_config = PassGenConfig::from_eff_short_2(Custom(6));
+        pass_config.sampling = Sampling::Dice(Die::D20);
+        pass_config.blocklist.insert("aardvark");
+
+        let json = ::serde_json::to_string(&pass_config).unwrap();
+        let parsed: PassGenConfig = ::serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.word_list, WordList::EffShort2);
+        assert_eq!(parsed.word_count, Custom(6));
+        assert_eq!(parsed.sampling, Sampling::Dice(Die::D20));
+        assert_eq!(parsed.blocklist, pass_config.blocklist);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn config_from_json() {
+        let json = r#"{"word_list": {"custom": ["red", "green", "blue"]}, "blocklist": ["red"]}"#;
+        let parsed: PassGenConfig = ::serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.word_count, Default);
+        assert_eq!(parsed.available_words(), 2);
+
+        let json = r#"{"word_list": "eff_long", "sampling": {"dice": 1}}"#;
+        assert!(::serde_json::from_str::<PassGenConfig>(json).is_err());
+
+        let json = r#"{"word_list": {"custom": ["red", "red"]}}"#;
+        assert!(::serde_json::from_str::<PassGenConfig>(json).is_err());
+        // deserialized configs are validated like built ones
+        for json in &[
+            r#"{"word_list": "eff_long", "word_count": {"custom": 0}}"#,
+            r#"{"word_list": "eff_long", "separator": "-"}"#,
+            r#"{"word_list": "eff_short", "policy": {"min_entropy": 100.0}}"#,
+            r#"{"word_list": {"custom": ["red", "green"]}, "blocklist": ["red", "green"]}"#,
+        ] {
+            assert!(::serde_json::from_str::<PassGenConfig>(json).is_err());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_separator_and_casing() {
+        let mut pass_config = PassGenConfig::from_eff_short(Custom(4));
+        pass_config.separator = ".".to_string();
+        pass_config.casing = Casing::Capitalized;
+        let pass = generate(pass_config);
+        let words: Vec<&str> = pass.split('.').collect();
+        assert_eq!(words.len(), 4);
+        assert!(words
+            .iter()
+            .all(|word| word.chars().next().unwrap().is_uppercase()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_with_policy() {
+        let mut pass_config = PassGenConfig::from_eff_long(Custom(100));
+        pass_config.policy.max_word_length = Some(5);
+        assert!(pass_config.available_words() < 7776);
+        let pass = generate(pass_config);
+        assert!(pass.split_whitespace().all(|word| word.len() <= 5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_seeded() {
+        let mut pass_config = PassGenConfig::from_eff_long(Default);
+        pass_config.rng = RngSource::Seeded([7; 8]);
+        assert_eq!(generate(pass_config.clone()), generate(pass_config.clone()));
+
+        pass_config.rng = RngSource::Seeded([8; 8]);
+        let other = generate(pass_config.clone());
+        pass_config.rng = RngSource::Seeded([7; 8]);
+        assert_ne!(generate(pass_config), other);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_with_rng() {
+        let mut pass_config = PassGenConfig::from_eff_short(Custom(6));
+        pass_config.rng = RngSource::Seeded([3; 8]);
+        let mut rng = ChaChaRng::from_seed(&[3; 8]);
+        assert_eq!(
+            generate_with(&pass_config, &mut rng),
+            generate_passphrase(pass_config)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gen_with_checksum() {
+        let mut pass_config = PassGenConfig::from_eff_long(Custom(4));
+        pass_config.separator = ".".to_string();
+        pass_config.casing = Casing::Capitalized;
+        pass_config.checksum = true;
+        let pass = generate_passphrase(pass_config);
+        assert_eq!(pass.word_count, 5);
+        assert_eq!(pass.entropy, pass.bits_per_word * 4.0);
+
+        let words: Vec<&str> = pass.as_str().split('.').collect();
+        assert!(checksum::validate(&WordList::EffLong, &words).is_valid());
+    }
+
+    #[test]
+    fn casing() {
+        assert_eq!("capitalized".parse(), Ok(Casing::Capitalized));
+        assert!("title".parse::<Casing>().is_err());
+        assert_eq!(Casing::Lower.apply("Acid"), "acid");
+        assert_eq!(Casing::Upper.apply("acid"), "ACID");
+        assert_eq!(Casing::Capitalized.apply("acid"), "Acid");
+        assert_eq!(Casing::Capitalized.apply(""), "");
+    }
+
+    #[test]
+    fn parse_word_list() {
+        assert_eq!("eff_short_2".parse(), Ok(WordList::EffShort2));
+        for name in &WORD_LIST_NAMES {
+            assert!(name.parse::<WordList>().is_ok());
+        }
+        assert_eq!(
+            "eff_medium".parse::<WordList>(),
+            Err(Error::UnknownWordList("eff_medium".to_string()))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn index_in_range() {
+        let mut rng = OsRng::new().unwrap();
+        for len in 1..50 {
+            for _ in 0..20 {
+                assert!(gen_index(len, &mut rng) < len);
+            }
+        }
+    }
 }
@@ -0,0 +1,239 @@
+//!
+//! Contains logic for generating pronounceable pseudo-words from a table of syllables.
+//!
+//! Each syllable is a consonant followed by a vowel, chosen from 16 consonants and 4 vowels, so
+//! there are exactly 64 syllables and each provides exactly 6 bits of entropy.  A pseudo-word of
+//! two syllables ("buzo", "tika") provides 12 bits from only four letters, about as much as a
+//! word from `EffLong`, so passphrases are much shorter at the cost of being harder to remember.
+//!
+//! Pseudo-words are used through `WordList::Pseudo`, so the usual `PassGenConfig` options
+//! (`WordCount`, blocklists, policies, separators and casing) all apply:
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::pseudo::PseudoWords;
+//!
+//! let words = PseudoWords::new(2).unwrap();
+//! let config = PassGenConfig::from_pseudo(words, WordCount::Default);
+//! assert_eq!(config.bits_per_word(), 12.0);
+//!
+//! let pass = generate_passphrase(config);
+//! println!("{}", pass.as_str());
+//! // should result in something like:
+//! // "buzo tika madu rovi hage jupo"
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use custom_list::CustomList;
+use error::Error;
+
+///
+/// The consonants which start each syllable.  Letters which are easily confused when read aloud
+/// or written by hand (c, q, w, x, y) are left out.
+///
+pub const CONSONANTS: [char; 16] = [
+    'b', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z',
+];
+
+///
+/// The vowels which end each syllable.  "e" is left out so the table has a power of two size.
+///
+pub const VOWELS: [char; 4] = ['a', 'i', 'o', 'u'];
+
+///
+/// The entropy (in bits) provided by each syllable.
+///
+pub const BITS_PER_SYLLABLE: f64 = 6.0;
+
+///
+/// The largest number of syllables per pseudo-word.  Every possible pseudo-word is held in
+/// memory, and three syllables already give 262,144 of them.
+///
+pub const MAX_SYLLABLES: u32 = 3;
+
+///
+/// Every pronounceable pseudo-word with a given number of syllables, usable as a word list.
+///
+/// Serialized as its number of syllables.
+///
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u32", into = "u32"))]
+pub struct PseudoWords {
+    syllables: u32,
+    list: CustomList,
+}
+
+impl PseudoWords {
+    ///
+    /// Creates the list of pseudo-words with `syllables` syllables each, in table order.
+    ///
+    /// Returns an error unless `syllables` is from 1 to `MAX_SYLLABLES`.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::pseudo::PseudoWords;
+    ///
+    /// let words = PseudoWords::new(1).unwrap();
+    /// assert_eq!(words.len(), 64);
+    /// assert_eq!(words.words()[..3], ["ba", "bi", "bo"]);
+    /// ```
+    ///
+    pub fn new(syllables: u32) -> Result<PseudoWords, Error> {
+        if syllables == 0 || syllables > MAX_SYLLABLES {
+            return Err(Error::InvalidSyllableCount(syllables));
+        }
+
+        let mut words = vec![String::new()];
+        for _ in 0..syllables {
+            words = words
+                .iter()
+                .flat_map(|prefix| {
+                    CONSONANTS.iter().flat_map(move |&consonant| {
+                        VOWELS.iter().map(move |&vowel| {
+                            let mut word = prefix.clone();
+                            word.push(consonant);
+                            word.push(vowel);
+                            word
+                        })
+                    })
+                })
+                .collect();
+        }
+
+        Ok(PseudoWords {
+            syllables,
+            list: CustomList::new(words)?,
+        })
+    }
+
+    ///
+    /// Returns the number of syllables in each pseudo-word.
+    ///
+    pub fn syllables(&self) -> u32 {
+        self.syllables
+    }
+
+    ///
+    /// Returns the pseudo-words, in table order.
+    ///
+    pub fn words(&self) -> Vec<&str> {
+        self.list.words()
+    }
+
+    ///
+    /// Returns the pseudo-word at `index` in table order, or `None` if it's out of range.
+    ///
+    pub fn word(&self, index: usize) -> Option<&str> {
+        self.list.word(index)
+    }
+
+    ///
+    /// Returns the number of pseudo-words: 64 to the power of the number of syllables.
+    ///
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    ///
+    /// Always returns `false`; there are at least 64 pseudo-words.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    ///
+    /// Returns the entropy (in bits) provided by each pseudo-word chosen uniformly.
+    ///
+    pub fn bits_per_word(&self) -> f64 {
+        BITS_PER_SYLLABLE * f64::from(self.syllables)
+    }
+}
+
+impl Default for PseudoWords {
+    ///
+    /// Returns the list of two-syllable pseudo-words.
+    ///
+    fn default() -> PseudoWords {
+        PseudoWords::new(2).expect("two syllables is valid")
+    }
+}
+
+impl fmt::Debug for PseudoWords {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PseudoWords")
+            .field("syllables", &self.syllables)
+            .finish()
+    }
+}
+
+impl TryFrom<u32> for PseudoWords {
+    type Error = Error;
+
+    fn try_from(syllables: u32) -> Result<PseudoWords, Error> {
+        PseudoWords::new(syllables)
+    }
+}
+
+impl From<PseudoWords> for u32 {
+    fn from(words: PseudoWords) -> u32 {
+        words.syllables
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use math;
+    use pass_gen::{generate_with, PassGenConfig, WordCount, WordList};
+    use rand::chacha::ChaChaRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn syllable_counts() {
+        assert_eq!(PseudoWords::new(0), Err(Error::InvalidSyllableCount(0)));
+        assert_eq!(PseudoWords::new(4), Err(Error::InvalidSyllableCount(4)));
+
+        let words = PseudoWords::new(2).unwrap();
+        assert_eq!(words.len(), 4096);
+        assert_eq!(words.words()[0], "baba");
+        assert_eq!(words.words()[4095], "zuzu");
+        assert_eq!(math::log2(words.len() as f64), words.bits_per_word());
+    }
+
+    #[test]
+    fn gen_pseudo() {
+        let config = PassGenConfig::from_pseudo(PseudoWords::default(), WordCount::Default);
+        assert_eq!(config.words(), 6);
+        assert_eq!(config.entropy(), 72.0);
+
+        let mut rng = ChaChaRng::from_seed(&[3; 8]);
+        let pass = generate_with(&config, &mut rng);
+        let words: Vec<&str> = pass.as_str().split(' ').collect();
+        assert_eq!(words.len(), 6);
+        for word in words {
+            let chars: Vec<char> = word.chars().collect();
+            assert_eq!(chars.len(), 4);
+            assert!(CONSONANTS.contains(&chars[0]) && CONSONANTS.contains(&chars[2]));
+            assert!(VOWELS.contains(&chars[1]) && VOWELS.contains(&chars[3]));
+        }
+
+        assert_eq!(
+            "pseudo".parse(),
+            Ok(WordList::Pseudo(PseudoWords::default()))
+        );
+        assert_eq!(
+            "pseudo_1".parse::<WordList>().map(|list| list.len()),
+            Ok(64)
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//!
+//! Contains PyO3 bindings exposing this library as the `dicepass` Python module.
+//!
+//! Word lists are named as for `WordList::from_str` (`"eff_long"`, `"eff_short"`, `"eff_short_2"`
+//! or `"pseudo"`).  Errors are raised as `dicepass.DicepassError`, a subclass of `ValueError`,
+//! or as its subclass `dicepass.ConfigError` when a configuration fails validation.
+//!
+//! The extension module is built with [maturin](https://www.maturin.rs), which reads the
+//! crate's `pyproject.toml`:
+//!
+//! ```sh
+//! maturin develop
+//! python -c 'import dicepass; print(dicepass.generate("eff_short_2"))'
+//! ```
+//!
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use dice::Die;
+use error::Error;
+use pass_gen::{self, PassGenConfig, WordCount, WordList};
+
+create_exception!(
+    dicepass,
+    DicepassError,
+    PyValueError,
+    "Raised when passphrase generation or dice lookup fails."
+);
+create_exception!(
+    dicepass,
+    ConfigError,
+    DicepassError,
+    "Raised when a passphrase configuration fails validation."
+);
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> PyErr {
+        match err {
+            Error::Config(_) => ConfigError::new_err(err.to_string()),
+            _ => DicepassError::new_err(err.to_string()),
+        }
+    }
+}
+
+fn config(
+    word_list: &str,
+    word_count: Option<u32>,
+    separator: &str,
+) -> Result<PassGenConfig, Error> {
+    Ok(PassGenConfig::builder()
+        .word_list(word_list.parse()?)
+        .word_count(WordCount::from(word_count))
+        .separator(separator)
+        .build()?)
+}
+
+///
+/// Generates a passphrase from the named word list.  A `word_count` of `None` uses the list's
+/// default.
+///
+#[pyfunction]
+#[pyo3(signature = (word_list = "eff_long", word_count = None, separator = " "))]
+fn generate(word_list: &str, word_count: Option<u32>, separator: &str) -> PyResult<String> {
+    Ok(pass_gen::generate(config(
+        word_list, word_count, separator,
+    )?))
+}
+
+///
+/// Returns the names of the available word lists.
+///
+#[pyfunction]
+fn word_lists() -> Vec<&'static str> {
+    pass_gen::WORD_LIST_NAMES.to_vec()
+}
+
+///
+/// Looks up the word selected by a sequence of physical dice rolls, using dice with `sides`
+/// sides.  Returns `None` if the sequence doesn't correspond to a word and must be rolled again.
+///
+#[pyfunction]
+#[pyo3(signature = (word_list, rolls, sides = 6))]
+fn lookup(word_list: &str, rolls: Vec<u32>, sides: u32) -> PyResult<Option<String>> {
+    let word_list: WordList = word_list.parse()?;
+    let word = word_list.lookup(Die::new(sides)?, &rolls)?;
+    Ok(word.map(|word| word.to_string()))
+}
+
+///
+/// Returns the entropy (in bits) provided by each word from the named word list.
+///
+#[pyfunction]
+#[pyo3(signature = (word_list = "eff_long"))]
+fn bits_per_word(word_list: &str) -> PyResult<f64> {
+    Ok(config(word_list, None, " ")?.bits_per_word())
+}
+
+///
+/// Returns the total entropy (in bits) of a passphrase generated from the named word list.
+///
+#[pyfunction]
+#[pyo3(signature = (word_list = "eff_long", word_count = None))]
+fn entropy(word_list: &str, word_count: Option<u32>) -> PyResult<f64> {
+    let config = config(word_list, word_count, " ")?;
+    Ok(config.entropy())
+}
+
+///
+/// The `dicepass` Python module.
+///
+#[pymodule]
+fn dicepass(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(self::generate, m)?)?;
+    m.add_function(wrap_pyfunction!(self::word_lists, m)?)?;
+    m.add_function(wrap_pyfunction!(self::lookup, m)?)?;
+    m.add_function(wrap_pyfunction!(self::bits_per_word, m)?)?;
+    m.add_function(wrap_pyfunction!(self::entropy, m)?)?;
+    m.add("DicepassError", m.py().get_type::<DicepassError>())?;
+    m.add("ConfigError", m.py().get_type::<ConfigError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn run(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "dicepass").unwrap();
+            dicepass(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("dicepass", module).unwrap();
+            let code = ::std::ffi::CString::new(code).unwrap();
+            py.run(&code, None, Some(&locals)).unwrap();
+        });
+    }
+
+    #[test]
+    fn generate_from_python() {
+        run("assert len(dicepass.generate('eff_short_2').split()) == 8");
+        run("assert len(dicepass.generate(word_count=3, separator='.').split('.')) == 3");
+    }
+
+    #[test]
+    fn word_lists_from_python() {
+        run("assert 'pseudo_3' in dicepass.word_lists()");
+        run("for name in dicepass.word_lists(): assert dicepass.generate(name)");
+    }
+
+    #[test]
+    fn lookup_from_python() {
+        run("assert dicepass.lookup('eff_long', [1, 1, 1, 2, 1]) == 'ablaze'");
+        run("assert dicepass.lookup('eff_long', [20, 20, 20], sides=20) is None");
+    }
+
+    #[test]
+    fn entropy_from_python() {
+        run("assert abs(dicepass.bits_per_word('eff_long') - 12.925) < 0.001");
+        run("assert abs(dicepass.entropy('eff_short', 4) - 41.359) < 0.001");
+    }
+
+    #[test]
+    fn errors_from_python() {
+        run(r#"
+try:
+    dicepass.generate('eff_medium')
+    assert False
+except dicepass.DicepassError as e:
+    assert 'eff_medium' in str(e)
+"#);
+        run(r#"
+try:
+    dicepass.generate(word_count=0)
+    assert False
+except dicepass.ConfigError:
+    pass
+"#);
+        run(r#"
+try:
+    dicepass.lookup('eff_long', [7, 1, 1, 1, 1])
+    assert False
+except ValueError:
+    pass
+"#);
+    }
+}
@@ -0,0 +1,272 @@
+//!
+//! Contains logic for rendering a passphrase as a QR code, for copying it onto a phone without
+//! typing it.
+//!
+//! Codes are encoded locally by a pure Rust encoder and can be rendered as Unicode half-block
+//! characters for a terminal, or as SVG or PNG images.  A QR code reveals its passphrase to any
+//! camera that can see it, so only display it where the passphrase itself could be shown.
+//!
+//! ```
+//! use lib_dicepass_gen::*;
+//!
+//! let pass = generate_passphrase(PassGenConfig::from_eff_long(WordCount::Default));
+//! let code = pass.qr_code().unwrap();
+//!
+//! print!("{}", code.to_terminal(true));
+//! let svg = code.to_svg(8);
+//! let png = code.to_png(8);
+//! # assert!(svg.starts_with("<svg"));
+//! # assert_eq!(&png[1..4], b"PNG");
+//! ```
+//!
+
+use std::fmt;
+
+use png;
+use qrcode::{self, Color, EcLevel};
+
+use error::Error;
+use pass_gen::Passphrase;
+
+///
+/// The width (in modules) of the light border drawn around every code, as required for it to be
+/// recognized reliably.
+///
+pub const QUIET_ZONE: usize = 4;
+
+///
+/// The largest number of pixels per module in SVG and PNG images.  Larger module sizes are
+/// reduced to this, which keeps even the largest codes under 6,000 pixels wide.
+///
+pub const MAX_MODULE_SIZE: u32 = 32;
+
+///
+/// A QR code holding a passphrase.
+///
+/// The passphrase is left out of this type's `Debug` output.
+///
+#[derive(Clone)]
+pub struct QrCode {
+    code: qrcode::QrCode,
+}
+
+impl QrCode {
+    ///
+    /// Encodes `text` with medium (15%) error correction.
+    ///
+    /// Returns an error if `text` is too long to fit in a QR code (about 2,300 characters).
+    ///
+    pub fn new(text: &str) -> Result<QrCode, Error> {
+        qrcode::QrCode::with_error_correction_level(text, EcLevel::M)
+            .map(|code| QrCode { code })
+            .map_err(|err| Error::QrCode(err.to_string()))
+    }
+
+    ///
+    /// Returns the width (and height) of the code in modules, not counting the quiet zone.
+    ///
+    pub fn width(&self) -> usize {
+        self.code.width()
+    }
+
+    ///
+    /// Returns whether the module at (`x`, `y`) is dark, where both coordinates include the
+    /// quiet zone.
+    ///
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        let width = self.width();
+        if x < QUIET_ZONE || y < QUIET_ZONE || x >= width + QUIET_ZONE || y >= width + QUIET_ZONE {
+            return false;
+        }
+        self.code[(x - QUIET_ZONE, y - QUIET_ZONE)] == Color::Dark
+    }
+
+    fn full_width(&self) -> usize {
+        self.width() + 2 * QUIET_ZONE
+    }
+
+    ///
+    /// Returns the code drawn with Unicode half-block characters, two rows of modules per line
+    /// of text.
+    ///
+    /// Scanners expect dark modules on a light background.  On a terminal with a dark
+    /// background, pass `dark_background` so that light modules are drawn with blocks instead.
+    ///
+    pub fn to_terminal(&self, dark_background: bool) -> String {
+        let size = self.full_width();
+        let mut text = String::new();
+        for y in (0..size).step_by(2) {
+            for x in 0..size {
+                let top = self.is_dark(x, y) != dark_background;
+                let bottom = (y + 1 < size && self.is_dark(x, y + 1)) != dark_background;
+                text.push(match (top, bottom) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                });
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    ///
+    /// Returns the code as an SVG image, with each module `module_size` pixels wide.
+    /// `module_size` is clamped to between 1 and `MAX_MODULE_SIZE`.
+    ///
+    pub fn to_svg(&self, module_size: u32) -> String {
+        let size = self.full_width();
+        let pixels = size as u32 * module_size.clamp(1, MAX_MODULE_SIZE);
+        let mut path = String::new();
+        for y in 0..size {
+            let mut x = 0;
+            while x < size {
+                if !self.is_dark(x, y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < size && self.is_dark(x, y) {
+                    x += 1;
+                }
+                path.push_str(&format!("M{},{}h{}v1h-{}z", start, y, x - start, x - start));
+            }
+        }
+        format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{px}\" height=\"{px}\" ",
+                "viewBox=\"0 0 {size} {size}\" shape-rendering=\"crispEdges\">",
+                "<rect width=\"{size}\" height=\"{size}\" fill=\"#fff\"/>",
+                "<path d=\"{path}\" fill=\"#000\"/></svg>\n"
+            ),
+            px = pixels,
+            size = size,
+            path = path
+        )
+    }
+
+    ///
+    /// Returns the code as an 8-bit greyscale PNG image, with each module `module_size` pixels
+    /// wide.  `module_size` is clamped to between 1 and `MAX_MODULE_SIZE`.
+    ///
+    pub fn to_png(&self, module_size: u32) -> Vec<u8> {
+        let size = self.full_width();
+        let scale = module_size.clamp(1, MAX_MODULE_SIZE) as usize;
+        let pixels = size * scale;
+        let mut data = Vec::with_capacity(pixels * pixels);
+        for y in 0..pixels {
+            for x in 0..pixels {
+                data.push(if self.is_dark(x / scale, y / scale) {
+                    0
+                } else {
+                    255
+                });
+            }
+        }
+
+        let mut png = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut png, pixels as u32, pixels as u32);
+            encoder.set_color(png::ColorType::Grayscale);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder
+                .write_header()
+                .and_then(|mut writer| writer.write_image_data(&data))
+                .expect("writing to a Vec can't fail");
+        }
+        png
+    }
+}
+
+impl fmt::Debug for QrCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QrCode")
+            .field("text", &"<redacted>")
+            .field("width", &self.width())
+            .finish()
+    }
+}
+
+impl Passphrase {
+    ///
+    /// Returns this passphrase encoded as a QR code.
+    ///
+    pub fn qr_code(&self) -> Result<QrCode, Error> {
+        QrCode::new(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn code() -> QrCode {
+        QrCode::new("ranking partition chewer crayfish hardener").unwrap()
+    }
+
+    #[test]
+    fn terminal() {
+        let code = code();
+        let size = code.width() + 2 * QUIET_ZONE;
+        let text = code.to_terminal(false);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), size.div_ceil(2));
+        assert!(lines.iter().all(|line| line.chars().count() == size));
+        // the quiet zone is blank, and the top left finder pattern starts with a dark row above a
+        // row which is only dark at its ends
+        assert!(lines[0].chars().all(|c| c == ' '));
+        let finder: String = lines[2].chars().skip(QUIET_ZONE).take(7).collect();
+        assert_eq!(finder, "█▀▀▀▀▀█");
+
+        let inverted = code.to_terminal(true);
+        assert!(inverted.lines().next().unwrap().chars().all(|c| c == '█'));
+    }
+
+    #[test]
+    fn images() {
+        let code = code();
+        let size = code.width() + 2 * QUIET_ZONE;
+        let svg = code.to_svg(10);
+        assert!(svg.contains(&format!("width=\"{}\"", size * 10)));
+        assert!(svg.contains(&format!("M{},{}h7v1h-7z", QUIET_ZONE, QUIET_ZONE)));
+
+        let png = code.to_png(3);
+        let decoder = png::Decoder::new(&png[..]);
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(info.width as usize, size * 3);
+        assert_eq!(pixels[0], 255);
+        assert_eq!(pixels[QUIET_ZONE * 3 * (info.width as usize + 1)], 0);
+    }
+
+    #[test]
+    fn module_size_limits() {
+        let code = code();
+        let size = code.width() + 2 * QUIET_ZONE;
+        let width = |png: Vec<u8>| {
+            png::Decoder::new(&png[..])
+                .read_info()
+                .unwrap()
+                .info()
+                .width as usize
+        };
+        assert_eq!(width(code.to_png(0)), size);
+        assert_eq!(
+            width(code.to_png(u32::MAX)),
+            size * MAX_MODULE_SIZE as usize
+        );
+
+        assert!(code.to_svg(0).contains(&format!("width=\"{}\"", size)));
+        let svg = code.to_svg(u32::MAX);
+        assert!(svg.contains(&format!("width=\"{}\"", size * MAX_MODULE_SIZE as usize)));
+    }
+
+    #[test]
+    fn too_long() {
+        let text = "a".repeat(5000);
+        assert!(matches!(QrCode::new(&text), Err(Error::QrCode(_))));
+        assert!(format!("{:?}", code()).contains("<redacted>"));
+    }
+}
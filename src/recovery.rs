@@ -0,0 +1,456 @@
+//!
+//! Contains logic for generating sheets of single-use recovery passphrases, such as those handed
+//! out when two-factor authentication is enabled.
+//!
+//! A `RecoverySheet` holds a set of distinct passphrases, which can be formatted as a numbered
+//! list in plain text, Markdown or HTML for the user, and hashed for the server to store in
+//! place of the passphrases themselves.
+//!
+//! Recovery passphrases are only accepted once and attempts to use them are usually rate
+//! limited, so they can be shorter than a passphrase protecting an encrypted file.
+//! `recovery::default_config` gives four `EffShort2` words (~41 bits).
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::recovery::{self, HashScheme};
+//!
+//! let sheet = recovery::generate_sheet(&recovery::default_config(), 10).unwrap();
+//! println!("{}", sheet.to_text());
+//!
+//! let hashes = sheet.hashes(&HashScheme::Sha256).unwrap();
+//! assert!(hashes[0].verify(sheet.passphrases[0].as_str()));
+//! assert!(!hashes[1].verify(sheet.passphrases[0].as_str()));
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use error::{ConfigError, Error};
+#[cfg(feature = "kdf")]
+use kdf::Kdf;
+use pass_gen::{self, PassGenConfig, Passphrase, WordCount};
+
+///
+/// The length (in bytes) of the random salt used for each hash.
+///
+pub const SALT_LEN: usize = 16;
+
+///
+/// The length (in bytes) of each hash.
+///
+pub const HASH_LEN: usize = 32;
+
+///
+/// Returns the config used for recovery passphrases unless another is given: four `EffShort2`
+/// words, so each can be typed by its three letter prefix.
+///
+pub fn default_config() -> PassGenConfig {
+    PassGenConfig::from_eff_short_2(WordCount::Custom(4))
+}
+
+///
+/// A set of distinct recovery passphrases.
+///
+#[derive(Debug, Clone)]
+pub struct RecoverySheet {
+    ///
+    /// The heading used when the sheet is formatted.  Defaults to "Recovery codes".
+    ///
+    pub title: String,
+
+    ///
+    /// The passphrases, in the order they're numbered.
+    ///
+    pub passphrases: Vec<Passphrase>,
+}
+
+impl RecoverySheet {
+    ///
+    /// Returns the sheet as plain text, with one numbered passphrase per line.
+    ///
+    pub fn to_text(&self) -> String {
+        let width = self.passphrases.len().to_string().len();
+        let mut text = format!("{}\n\n", self.title);
+        for (number, pass) in self.numbered() {
+            let _ = writeln!(text, "{:>width$}. {}", number, pass.as_str(), width = width);
+        }
+        let _ = writeln!(text, "\n{}", NOTE);
+        text
+    }
+
+    ///
+    /// Returns the sheet as Markdown: a heading followed by a numbered list.
+    ///
+    pub fn to_markdown(&self) -> String {
+        let mut text = format!("# {}\n\n", self.title);
+        for (number, pass) in self.numbered() {
+            let _ = writeln!(text, "{}. `{}`", number, pass.as_str());
+        }
+        let _ = writeln!(text, "\n{}", NOTE);
+        text
+    }
+
+    ///
+    /// Returns the sheet as an HTML fragment: a heading followed by an ordered list.
+    ///
+    pub fn to_html(&self) -> String {
+        let mut text = format!("<h1>{}</h1>\n<ol>\n", escape_html(&self.title));
+        for (_, pass) in self.numbered() {
+            let _ = writeln!(
+                text,
+                "  <li><code>{}</code></li>",
+                escape_html(pass.as_str())
+            );
+        }
+        let _ = writeln!(text, "</ol>\n<p>{}</p>", NOTE);
+        text
+    }
+
+    ///
+    /// Returns a salted hash of each passphrase for the server to store, in the same order, using
+    /// the operating system's secure random number generator for the salts.
+    ///
+    /// Returns `Error::InvalidKdfParams` if `scheme` is a `Kdf` whose parameters are rejected; see
+    /// `Kdf::derive_key`.
+    ///
+    #[cfg(feature = "std")]
+    pub fn hashes(&self, scheme: &HashScheme) -> Result<Vec<StoredHash>, Error> {
+        self.hashes_with(scheme, &mut pass_gen::os_rng())
+    }
+
+    ///
+    /// Returns a salted hash of each passphrase for the server to store, in the same order, using
+    /// `rng` for the salts.
+    ///
+    /// Returns `Error::InvalidKdfParams` if `scheme` is a `Kdf` whose parameters are rejected; see
+    /// `Kdf::derive_key`.
+    ///
+    pub fn hashes_with<R: Rng>(
+        &self,
+        scheme: &HashScheme,
+        rng: &mut R,
+    ) -> Result<Vec<StoredHash>, Error> {
+        self.passphrases
+            .iter()
+            .map(|pass| {
+                let mut salt = [0; SALT_LEN];
+                rng.fill_bytes(&mut salt);
+                Ok(StoredHash {
+                    scheme: *scheme,
+                    hash: scheme.hash(pass.as_str(), &salt)?,
+                    salt: salt.to_vec(),
+                })
+            })
+            .collect()
+    }
+
+    fn numbered(&self) -> impl Iterator<Item = (usize, &Passphrase)> {
+        self.passphrases
+            .iter()
+            .enumerate()
+            .map(|(index, pass)| (index + 1, pass))
+    }
+}
+
+const NOTE: &str = "Each code can be used once. Keep this sheet somewhere safe.";
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+///
+/// Describes how recovery passphrases are hashed for storage.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HashScheme {
+    ///
+    /// SHA-256 of the salt followed by the passphrase.  Fast, which is acceptable when attempts
+    /// are rate limited and the passphrases have enough entropy that a stolen hash can't be
+    /// reversed by brute force.
+    ///
+    Sha256,
+
+    ///
+    /// A slow key derivation function such as Argon2id, for extra protection if the hashes are
+    /// stolen.  Requires the `kdf` feature.
+    ///
+    #[cfg(feature = "kdf")]
+    Kdf(Kdf),
+}
+
+impl HashScheme {
+    fn hash(&self, passphrase: &str, salt: &[u8]) -> Result<Vec<u8>, Error> {
+        match *self {
+            HashScheme::Sha256 => {
+                let mut hasher = Sha256::new();
+                hasher.update(salt);
+                hasher.update(passphrase.as_bytes());
+                Ok(hasher.finalize().to_vec())
+            }
+            #[cfg(feature = "kdf")]
+            HashScheme::Kdf(ref kdf) => Ok(kdf
+                .derive_key(passphrase.as_bytes(), salt, HASH_LEN)?
+                .as_bytes()
+                .to_vec()),
+        }
+    }
+}
+
+///
+/// A salted hash of a recovery passphrase, along with everything needed to check a passphrase
+/// against it.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StoredHash {
+    ///
+    /// How the hash was computed.
+    ///
+    pub scheme: HashScheme,
+
+    ///
+    /// The random salt.
+    ///
+    pub salt: Vec<u8>,
+
+    ///
+    /// The hash of the salt and passphrase.
+    ///
+    pub hash: Vec<u8>,
+}
+
+impl StoredHash {
+    ///
+    /// Returns whether `passphrase` is the one this hash was computed from.  The comparison takes
+    /// the same time wherever the hashes differ.
+    ///
+    /// The passphrase must be entered exactly as generated; normalize user input first (for
+    /// example with `complete::Completer::expand_phrase`) if needed.
+    ///
+    pub fn verify(&self, passphrase: &str) -> bool {
+        match self.scheme.hash(passphrase, &self.salt) {
+            Ok(hash) => {
+                hash.len() == self.hash.len()
+                    && hash
+                        .iter()
+                        .zip(&self.hash)
+                        .fold(0, |diff, (a, b)| diff | (a ^ b))
+                        == 0
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+///
+/// Generates `count` distinct passphrases from `config` using the operating system's secure
+/// random number generator.
+///
+/// See `generate_sheet_with`.
+///
+#[cfg(feature = "std")]
+pub fn generate_sheet(config: &PassGenConfig, count: usize) -> Result<RecoverySheet, Error> {
+    generate_sheet_with(config, count, &mut pass_gen::os_rng())
+}
+
+///
+/// Generates `count` distinct passphrases from `config` using `rng`.
+///
+/// Each passphrase is generated with `pass_gen::generate_with`, and any that repeat an earlier
+/// one are generated again.  Returns an error if `config` can't produce `count` distinct
+/// passphrases, if it fails `PassGenConfig::validate`, or if its separator is empty, since then
+/// different words could join into the same passphrase.
+///
+/// ```
+/// extern crate lib_dicepass_gen;
+/// extern crate rand;
+///
+/// use lib_dicepass_gen::*;
+/// use lib_dicepass_gen::recovery;
+/// use rand::chacha::ChaChaRng;
+///
+/// # fn main() {
+/// let list = CustomList::new(vec!["heads", "tails"]).unwrap();
+/// let config = PassGenConfig::from_custom(list, WordCount::Custom(2));
+/// let mut rng = ChaChaRng::new_unseeded();
+/// let sheet = recovery::generate_sheet_with(&config, 4, &mut rng).unwrap();
+/// assert_eq!(sheet.passphrases.len(), 4);
+/// assert!(recovery::generate_sheet_with(&config, 5, &mut rng).is_err());
+/// # }
+/// ```
+///
+pub fn generate_sheet_with<R: Rng>(
+    config: &PassGenConfig,
+    count: usize,
+    rng: &mut R,
+) -> Result<RecoverySheet, Error> {
+    config.validate()?;
+    // distinct word sequences must give distinct passphrases for the count below to hold
+    if config.separator.is_empty() && config.words() + u32::from(config.checksum) > 1 {
+        return Err(ConfigError::EmptySeparator.into());
+    }
+
+    let possible = (config.available_words() as u64).checked_pow(config.words());
+    if possible.is_some_and(|possible| count as u64 > possible) {
+        return Err(Error::TooManyPassphrases(count));
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut passphrases = Vec::new();
+    while passphrases.len() < count {
+        let pass = pass_gen::generate_with(config, rng);
+        if seen.insert(pass.as_str().to_string()) {
+            passphrases.push(pass);
+        }
+    }
+
+    Ok(RecoverySheet {
+        title: "Recovery codes".to_string(),
+        passphrases,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use custom_list::CustomList;
+    use rand::chacha::ChaChaRng;
+    use rand::SeedableRng;
+
+    fn sheet(count: usize) -> RecoverySheet {
+        let mut rng = ChaChaRng::from_seed(&[9; 8]);
+        generate_sheet_with(&default_config(), count, &mut rng).unwrap()
+    }
+
+    #[test]
+    fn distinct() {
+        let list = CustomList::new(vec!["a", "b", "c"]).unwrap();
+        let config = PassGenConfig::from_custom(list, WordCount::Custom(2));
+        let mut rng = ChaChaRng::from_seed(&[9; 8]);
+
+        let sheet = generate_sheet_with(&config, 9, &mut rng).unwrap();
+        let unique: BTreeSet<&str> = sheet.passphrases.iter().map(|pass| pass.as_str()).collect();
+        assert_eq!(unique.len(), 9);
+        assert_eq!(
+            generate_sheet_with(&config, 10, &mut rng).unwrap_err(),
+            Error::TooManyPassphrases(10)
+        );
+    }
+
+    #[test]
+    fn ambiguous_configs() {
+        // "a" + "aa" and "aa" + "a" would both be "aaa"
+        let list = CustomList::new(vec!["a", "aa"]).unwrap();
+        let mut config = PassGenConfig::from_custom(list, WordCount::Custom(2));
+        config.separator = String::new();
+        let mut rng = ChaChaRng::from_seed(&[9; 8]);
+        assert_eq!(
+            generate_sheet_with(&config, 4, &mut rng).unwrap_err(),
+            Error::Config(ConfigError::EmptySeparator)
+        );
+
+        config.separator = "a".to_string();
+        assert!(matches!(
+            generate_sheet_with(&config, 4, &mut rng),
+            Err(Error::Config(ConfigError::SeparatorInWord { .. }))
+        ));
+
+        // a single word needs no separator
+        config.separator = String::new();
+        config.word_count = WordCount::Custom(1);
+        assert_eq!(
+            generate_sheet_with(&config, 2, &mut rng)
+                .unwrap()
+                .passphrases
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn formats() {
+        let mut short = sheet(10);
+        short.passphrases.truncate(2);
+        short.title = "Codes <for> you".to_string();
+        let first = short.passphrases[0].as_str().to_string();
+        let second = short.passphrases[1].as_str().to_string();
+
+        let text = short.to_text();
+        assert!(text.starts_with("Codes <for> you\n\n"));
+        assert!(text.contains(&format!("1. {}\n2. {}\n", first, second)));
+
+        let markdown = short.to_markdown();
+        assert!(markdown.starts_with("# Codes <for> you\n\n"));
+        assert!(markdown.contains(&format!("2. `{}`\n", second)));
+
+        let html = short.to_html();
+        assert!(html.starts_with("<h1>Codes &lt;for&gt; you</h1>\n<ol>\n"));
+        assert!(html.contains(&format!("  <li><code>{}</code></li>\n", first)));
+
+        let wide = sheet(10).to_text();
+        assert!(wide.contains("\n 9. ") && wide.contains("\n10. "));
+    }
+
+    #[test]
+    fn sha256_hashes() {
+        let sheet = sheet(3);
+        let mut rng = ChaChaRng::from_seed(&[10; 8]);
+        let hashes = sheet.hashes_with(&HashScheme::Sha256, &mut rng).unwrap();
+        assert_eq!(hashes.len(), 3);
+        for (hash, pass) in hashes.iter().zip(&sheet.passphrases) {
+            assert_eq!(hash.salt.len(), SALT_LEN);
+            assert_eq!(hash.hash.len(), HASH_LEN);
+            assert!(hash.verify(pass.as_str()));
+            assert!(!hash.verify(&pass.as_str().to_uppercase()));
+        }
+        assert_ne!(hashes[0].salt, hashes[1].salt);
+    }
+
+    #[cfg(feature = "kdf")]
+    #[test]
+    fn kdf_hashes() {
+        let sheet = sheet(2);
+        let scheme = HashScheme::Kdf(Kdf::Argon2id {
+            memory_kib: 64,
+            iterations: 1,
+            parallelism: 1,
+        });
+        let mut rng = ChaChaRng::from_seed(&[11; 8]);
+        let hashes = sheet.hashes_with(&scheme, &mut rng).unwrap();
+        assert!(hashes[0].verify(sheet.passphrases[0].as_str()));
+        assert!(!hashes[0].verify(sheet.passphrases[1].as_str()));
+
+        let invalid = HashScheme::Kdf(Kdf::Argon2id {
+            memory_kib: 0,
+            iterations: 1,
+            parallelism: 1,
+        });
+        assert!(matches!(
+            sheet.hashes_with(&invalid, &mut rng),
+            Err(Error::InvalidKdfParams(_))
+        ));
+    }
+}
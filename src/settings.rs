@@ -0,0 +1,426 @@
+//!
+//! Contains logic for loading the `dicepass` command's defaults from a configuration file and
+//! environment variables.
+//!
+//! Settings are layered: the configuration file is read first, then `DICEPASS_*` environment
+//! variables override it, then command line flags override both.  Each layer is a `Settings`,
+//! combined with `Settings::merge`, and the result is turned into a `PassGenConfig` with
+//! `Settings::config`.
+//!
+//! The configuration file lives at `~/.config/dicepass/config.toml` (or under
+//! `$XDG_CONFIG_HOME`), or wherever `DICEPASS_CONFIG` points:
+//!
+//! ```toml
+//! list = "work"          # a built in list, a name from [lists], or a path to a word list
+//! words = 6              # or: entropy = 80.0
+//! separator = "."
+//! casing = "capitalized" # lower, upper or capitalized
+//!
+//! [lists]
+//! work = "/usr/share/dicepass/work-words.txt"
+//! ```
+//!
+//! The matching environment variables are `DICEPASS_LIST`, `DICEPASS_WORDS`,
+//! `DICEPASS_ENTROPY`, `DICEPASS_SEPARATOR` and `DICEPASS_CASING`.
+//!
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml;
+
+use custom_list::CustomList;
+use error::Error;
+use pass_gen::{Casing, PassGenConfig, WordCount, WordList};
+
+///
+/// The environment variable naming a configuration file to use instead of the default one.
+///
+pub const CONFIG_VAR: &str = "DICEPASS_CONFIG";
+
+///
+/// The prefix of the environment variables holding settings.
+///
+pub const VAR_PREFIX: &str = "DICEPASS_";
+
+///
+/// The settings read from environment variables, each from `VAR_PREFIX` followed by its name in
+/// capitals (`DICEPASS_LIST` and so on).
+///
+pub const ENV_SETTINGS: [&str; 5] = ["list", "words", "entropy", "separator", "casing"];
+
+///
+/// The word list used when none is set.
+///
+pub const DEFAULT_LIST: &str = "eff_long";
+
+///
+/// Options for generating passphrases, any of which may be unset.
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    ///
+    /// The word list: the name of a built in list (see `WordList::from_str`), a name from
+    /// `lists`, or the path of a file with one word per line.
+    ///
+    pub list: Option<String>,
+
+    ///
+    /// The number of words.  Only one of `words` and `entropy` may be set.
+    ///
+    pub words: Option<u32>,
+
+    ///
+    /// The minimum entropy (in bits), from which the number of words is chosen.
+    ///
+    pub entropy: Option<f64>,
+
+    ///
+    /// The string placed between words.
+    ///
+    pub separator: Option<String>,
+
+    ///
+    /// How each word is capitalized.
+    ///
+    pub casing: Option<Casing>,
+
+    ///
+    /// Named custom word lists, by path.  Relative paths in a configuration file are relative
+    /// to the file's directory.
+    ///
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub lists: BTreeMap<String, PathBuf>,
+}
+
+impl Settings {
+    ///
+    /// Returns the default configuration file path: `dicepass/config.toml` under
+    /// `$XDG_CONFIG_HOME`, or under `~/.config` if that isn't set.
+    ///
+    pub fn default_path() -> Option<PathBuf> {
+        let base = match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+        Some(base.join("dicepass").join("config.toml"))
+    }
+
+    ///
+    /// Parses settings from the contents of a configuration file.  `origin` names the file in
+    /// error messages.
+    ///
+    pub fn from_toml(text: &str, origin: &str) -> Result<Settings, Error> {
+        let settings: Settings = toml::from_str(text).map_err(|err| Error::InvalidSetting {
+            origin: origin.to_string(),
+            reason: err.message().to_string(),
+        })?;
+        if settings.words.is_some() && settings.entropy.is_some() {
+            return Err(Error::InvalidSetting {
+                origin: origin.to_string(),
+                reason: "only one of words and entropy may be set".to_string(),
+            });
+        }
+        Ok(settings)
+    }
+
+    ///
+    /// Reads settings from a configuration file.
+    ///
+    pub fn from_file(path: &Path) -> Result<Settings, Error> {
+        let origin = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|err| Error::InvalidSetting {
+            origin: origin.clone(),
+            reason: err.to_string(),
+        })?;
+        let mut settings = Settings::from_toml(&text, &origin)?;
+        if let Some(dir) = path.parent() {
+            for list in settings.lists.values_mut() {
+                *list = dir.join(&*list);
+            }
+        }
+        Ok(settings)
+    }
+
+    ///
+    /// Reads settings from the environment variables named by `ENV_SETTINGS`, given as name and
+    /// value pairs (usually `std::env::vars()`).  Any other variables, including other
+    /// `DICEPASS_*` variables such as `DICEPASS_CONFIG`, are ignored.
+    ///
+    pub fn from_env<I, K, V>(vars: I) -> Result<Settings, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut settings = Settings::default();
+        for (name, value) in vars {
+            let name = name.as_ref();
+            let key = match name.strip_prefix(VAR_PREFIX) {
+                Some(rest) => ENV_SETTINGS.iter().find(|key| key.to_uppercase() == rest),
+                None => None,
+            };
+            if let Some(key) = key {
+                settings.set(key, value.as_ref(), name)?;
+            }
+        }
+        Ok(settings)
+    }
+
+    ///
+    /// Sets the setting named `key` (`list`, `words`, `entropy`, `separator` or `casing`) from
+    /// its text form.  Setting `words` unsets `entropy` and vice versa.  `origin` names where
+    /// the value came from in error messages.
+    ///
+    pub fn set(&mut self, key: &str, value: &str, origin: &str) -> Result<(), Error> {
+        let invalid = |reason: String| Error::InvalidSetting {
+            origin: origin.to_string(),
+            reason,
+        };
+        match key {
+            "list" => self.list = Some(value.to_string()),
+            "words" => {
+                let words = value
+                    .parse()
+                    .map_err(|_| invalid(format!("\"{}\" is not a number of words", value)))?;
+                self.words = Some(words);
+                self.entropy = None;
+            }
+            "entropy" => {
+                let bits = value
+                    .parse()
+                    .map_err(|_| invalid(format!("\"{}\" is not a number of bits", value)))?;
+                self.entropy = Some(bits);
+                self.words = None;
+            }
+            "separator" => self.separator = Some(value.to_string()),
+            "casing" => {
+                let casing = value.parse().map_err(|_| {
+                    invalid(format!("\"{}\" is not lower, upper or capitalized", value))
+                })?;
+                self.casing = Some(casing);
+            }
+            _ => return Err(invalid(format!("unknown setting \"{}\"", key))),
+        }
+        Ok(())
+    }
+
+    ///
+    /// Overrides these settings with every setting in `overlay` that is set.  Setting either
+    /// `words` or `entropy` in `overlay` replaces both.
+    ///
+    pub fn merge(&mut self, overlay: Settings) {
+        if overlay.list.is_some() {
+            self.list = overlay.list;
+        }
+        if overlay.words.is_some() || overlay.entropy.is_some() {
+            self.words = overlay.words;
+            self.entropy = overlay.entropy;
+        }
+        if overlay.separator.is_some() {
+            self.separator = overlay.separator;
+        }
+        if overlay.casing.is_some() {
+            self.casing = overlay.casing;
+        }
+        self.lists.extend(overlay.lists);
+    }
+
+    ///
+    /// Returns the word list named by `list`, reading it from a file if it's a custom list.
+    /// Defaults to `DEFAULT_LIST`.
+    ///
+    pub fn word_list(&self) -> Result<WordList, Error> {
+        let name = self.list.as_deref().unwrap_or(DEFAULT_LIST);
+        if let Ok(list) = name.parse() {
+            return Ok(list);
+        }
+
+        let path = self
+            .lists
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| PathBuf::from(name));
+        let text = fs::read_to_string(&path).map_err(|err| Error::InvalidSetting {
+            origin: "list".to_string(),
+            reason: format!("couldn't read {}: {}", path.display(), err),
+        })?;
+        Ok(WordList::Custom(CustomList::parse(&text)?))
+    }
+
+    ///
+    /// Returns the `PassGenConfig` described by these settings, with defaults for anything
+    /// unset.
+    ///
+    pub fn config(&self) -> Result<PassGenConfig, Error> {
+        let mut builder = PassGenConfig::builder().word_list(self.word_list()?);
+        if let Some(words) = self.words {
+            builder = builder.word_count(WordCount::Custom(words));
+        }
+        if let Some(bits) = self.entropy {
+            builder = builder.entropy(bits);
+        }
+        if let Some(ref separator) = self.separator {
+            builder = builder.separator(separator.as_str());
+        }
+        if let Some(casing) = self.casing {
+            builder = builder.casing(casing);
+        }
+        Ok(builder.build()?)
+    }
+
+    ///
+    /// Returns these settings with the defaults used by `config` filled in for the list, number
+    /// of words (unless `entropy` is set), separator and casing.
+    ///
+    pub fn resolved(&self) -> Result<Settings, Error> {
+        let config = self.config()?;
+        Ok(Settings {
+            list: Some(
+                self.list
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_LIST.to_string()),
+            ),
+            words: match self.entropy {
+                Some(_) => None,
+                None => Some(config.words()),
+            },
+            entropy: self.entropy,
+            separator: Some(config.separator),
+            casing: Some(config.casing),
+            lists: self.lists.clone(),
+        })
+    }
+
+    ///
+    /// Returns these settings in configuration file format.
+    ///
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("settings can always be serialized")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_file() {
+        let settings = Settings::from_toml(
+            "list = \"eff_short\"\nwords = 6\ncasing = \"upper\"\n[lists]\nwork = \"w.txt\"\n",
+            "config.toml",
+        )
+        .unwrap();
+        assert_eq!(settings.list, Some("eff_short".to_string()));
+        assert_eq!(settings.words, Some(6));
+        assert_eq!(settings.casing, Some(Casing::Upper));
+        assert_eq!(settings.lists["work"], PathBuf::from("w.txt"));
+        assert_eq!(
+            Settings::from_toml(&settings.to_toml(), "").unwrap(),
+            settings
+        );
+
+        let err = Settings::from_toml("words = 6\nentropy = 80.0\n", "config.toml");
+        assert!(err.is_err());
+        let err = Settings::from_toml("colour = \"red\"\n", "config.toml").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid setting in config.toml: unknown field"));
+    }
+
+    #[test]
+    fn layers() {
+        let mut settings = Settings::from_toml(
+            "list = \"eff_short\"\nentropy = 80.0\nseparator = \"_\"\n",
+            "",
+        )
+        .unwrap();
+        let env = Settings::from_env(vec![
+            ("HOME", "/home/alice"),
+            ("DICEPASS_CONFIG", "/etc/dicepass.toml"),
+            ("DICEPASS_WORDS", "4"),
+            ("DICEPASS_CASING", "capitalized"),
+        ])
+        .unwrap();
+        let mut flags = Settings::default();
+        flags.set("separator", ".", "--separator").unwrap();
+
+        settings.merge(env);
+        settings.merge(flags);
+        assert_eq!(settings.words, Some(4));
+        assert_eq!(settings.entropy, None);
+
+        let config = settings.config().unwrap();
+        assert_eq!(config.word_list, WordList::EffShort);
+        assert_eq!(config.word_count, WordCount::Custom(4));
+        assert_eq!(config.separator, ".");
+        assert_eq!(config.casing, Casing::Capitalized);
+    }
+
+    #[test]
+    fn resolved_defaults() {
+        let resolved = Settings::default().resolved().unwrap();
+        assert_eq!(resolved.list, Some("eff_long".to_string()));
+        assert_eq!(resolved.words, Some(5));
+        assert_eq!(resolved.entropy, None);
+        assert_eq!(resolved.separator, Some(" ".to_string()));
+        assert_eq!(resolved.casing, Some(Casing::Lower));
+        assert_eq!(resolved.resolved().unwrap(), resolved);
+
+        let mut settings = Settings::default();
+        settings.set("list", "eff_short", "--list").unwrap();
+        settings.set("entropy", "60", "--entropy").unwrap();
+        let resolved = settings.resolved().unwrap();
+        assert_eq!(resolved.words, None);
+        assert_eq!(resolved.entropy, Some(60.0));
+        assert_eq!(
+            resolved.to_toml(),
+            "list = \"eff_short\"\nentropy = 60.0\nseparator = \" \"\ncasing = \"lower\"\n"
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(
+            Settings::from_env(vec![("DICEPASS_WORDS", "five")]),
+            Err(Error::InvalidSetting {
+                origin: "DICEPASS_WORDS".to_string(),
+                reason: "\"five\" is not a number of words".to_string(),
+            })
+        );
+        assert_eq!(
+            Settings::from_env(vec![
+                ("DICEPASS_COLOUR", "red"),
+                ("DICEPASS_GENERATE_HEADER", "1")
+            ]),
+            Ok(Settings::default())
+        );
+        assert!(Settings::default()
+            .set("casing", "title", "--casing")
+            .is_err());
+    }
+
+    #[test]
+    fn custom_list_files() {
+        let dir = env::temp_dir().join(format!("dicepass-settings-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("words.txt"), "red\ngreen\nblue\n").unwrap();
+        fs::write(
+            dir.join("config.toml"),
+            "list = \"colours\"\nwords = 3\n[lists]\ncolours = \"words.txt\"\n",
+        )
+        .unwrap();
+
+        let settings = Settings::from_file(&dir.join("config.toml")).unwrap();
+        let config = settings.config().unwrap();
+        assert_eq!(config.word_list.words(), vec!["red", "green", "blue"]);
+
+        let mut missing = settings.clone();
+        missing.list = Some(dir.join("missing.txt").display().to_string());
+        assert!(missing.config().is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
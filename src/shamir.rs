@@ -0,0 +1,505 @@
+//!
+//! Contains logic for splitting a passphrase into word-encoded shares with Shamir's secret
+//! sharing scheme, so that any `threshold` of them recover the passphrase while fewer reveal
+//! nothing about it.
+//!
+//! The passphrase's word indices are written as 16 bit big-endian integers followed by a
+//! Fletcher-16 checksum, and each byte of that secret is shared independently over GF(256) (the
+//! field used by AES).  A share is a four byte header (the threshold, the share's x coordinate
+//! and the number of words in the passphrase) followed by its y coordinates.  Shares are written
+//! with words from the same list as the passphrase: each word encodes `floor(log2(len))` bits,
+//! so only the first `2^bits` words of the list appear in shares (the first 4096 for `EffLong`
+//! and the first 1024 for the short lists).
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::shamir;
+//!
+//! let pass = generate(PassGenConfig::from_eff_long(WordCount::Default));
+//! let words: Vec<&str> = pass.split(' ').collect();
+//!
+//! // any 3 of these 5 shares recover the passphrase
+//! let shares = shamir::split(&WordList::EffLong, &words, 3, 5).unwrap();
+//! let recovered = shamir::combine(&WordList::EffLong, &[&shares[4], &shares[0], &shares[2]]);
+//! assert_eq!(recovered.unwrap().join(" "), pass);
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+use error::{ConfigError, Error};
+use pass_gen::WordList;
+
+const HEADER_LEN: usize = 4;
+const MAX_LIST_LEN: usize = 1 << 16;
+
+///
+/// Splits a passphrase into `shares` word-encoded shares, any `threshold` of which recover it.
+///
+/// `words` are the passphrase's words, which must all appear in `word_list` (ignoring case).
+/// Each returned share is a string of words from `word_list` separated by single spaces.
+///
+/// Returns an error if `threshold` is less than 2 or greater than `shares`, if `words` is empty
+/// or contains a word that isn't in `word_list`, or if `word_list` contains more than 65536
+/// words.
+///
+#[cfg(feature = "std")]
+pub fn split(
+    word_list: &WordList,
+    words: &[&str],
+    threshold: u8,
+    shares: u8,
+) -> Result<Vec<String>, Error> {
+    split_with(
+        word_list,
+        words,
+        threshold,
+        shares,
+        &mut ::pass_gen::os_rng(),
+    )
+}
+
+///
+/// Splits a passphrase into word-encoded shares as `split` does, drawing the random polynomial
+/// coefficients from `rng`.
+///
+/// `rng` must be a cryptographically secure random number generator; anyone who can predict its
+/// output can recover the passphrase from a single share.
+///
+pub fn split_with<R: Rng>(
+    word_list: &WordList,
+    words: &[&str],
+    threshold: u8,
+    shares: u8,
+    rng: &mut R,
+) -> Result<Vec<String>, Error> {
+    if threshold < 2 || threshold > shares {
+        return Err(Error::InvalidThreshold { threshold, shares });
+    }
+    if words.is_empty() {
+        return Err(ConfigError::ZeroWords.into());
+    }
+    if words.len() > usize::from(u16::MAX) {
+        return Err(Error::InvalidInput(words.len().to_string()));
+    }
+
+    let list = ShareList::new(word_list)?;
+    let mut secret = Vec::with_capacity(words.len() * 2 + 2);
+    for word in words {
+        let index = list.index(word)?;
+        secret.extend_from_slice(&(index as u16).to_be_bytes());
+    }
+    let checksum = fletcher16(&secret);
+    secret.extend_from_slice(&checksum);
+
+    let mut points: Vec<Vec<u8>> = (1..=shares)
+        .map(|x| {
+            let mut share = Vec::with_capacity(HEADER_LEN + secret.len());
+            share.push(threshold);
+            share.push(x);
+            share.extend_from_slice(&(words.len() as u16).to_be_bytes());
+            share
+        })
+        .collect();
+
+    let mut coefficients = vec![0u8; usize::from(threshold)];
+    for &byte in &secret {
+        coefficients[0] = byte;
+        for coefficient in coefficients[1..].iter_mut() {
+            *coefficient = rng.gen();
+        }
+        for share in points.iter_mut() {
+            let x = share[1];
+            share.push(evaluate(&coefficients, x));
+        }
+    }
+
+    Ok(points.iter().map(|share| list.encode(share)).collect())
+}
+
+///
+/// Recovers a passphrase's words from shares created by `split`.
+///
+/// Words in each share may be separated by any whitespace and are matched ignoring case.  Only
+/// the first `threshold` shares are used.  Returns an error if fewer than `threshold` shares are
+/// given, if a share is malformed or contains a word that isn't in `word_list`, or if the shares
+/// weren't split from the same passphrase.
+///
+pub fn combine<'a, S: AsRef<str>>(
+    word_list: &'a WordList,
+    shares: &[S],
+) -> Result<Vec<&'a str>, Error> {
+    let list = ShareList::new(word_list)?;
+    let decoded = shares
+        .iter()
+        .map(|share| list.decode(share.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let first = match decoded.first() {
+        Some(first) => first,
+        None => {
+            return Err(Error::InsufficientShares {
+                required: 2,
+                actual: 0,
+            })
+        }
+    };
+    let threshold = usize::from(first[0]);
+    if decoded.len() < threshold {
+        return Err(Error::InsufficientShares {
+            required: threshold,
+            actual: decoded.len(),
+        });
+    }
+
+    let used = &decoded[..threshold];
+    for (i, share) in used.iter().enumerate() {
+        if share[..1] != first[..1] || share[2..HEADER_LEN] != first[2..HEADER_LEN] {
+            return Err(Error::InvalidShare(
+                "the shares were split from different passphrases".to_string(),
+            ));
+        }
+        if used[..i].iter().any(|other| other[1] == share[1]) {
+            return Err(Error::InvalidShare(
+                "the same share was given more than once".to_string(),
+            ));
+        }
+    }
+
+    let xs: Vec<u8> = used.iter().map(|share| share[1]).collect();
+    let secret: Vec<u8> = (HEADER_LEN..first.len())
+        .map(|position| {
+            let ys: Vec<u8> = used.iter().map(|share| share[position]).collect();
+            interpolate(&xs, &ys)
+        })
+        .collect();
+
+    let (indices, checksum) = secret.split_at(secret.len() - 2);
+    if fletcher16(indices) != checksum {
+        return Err(Error::InvalidShare(
+            "the checksum doesn't match; the shares were split from different passphrases"
+                .to_string(),
+        ));
+    }
+
+    let words = word_list.words();
+    indices
+        .chunks(2)
+        .map(|pair| {
+            let index = usize::from(u16::from_be_bytes([pair[0], pair[1]]));
+            words.get(index).cloned().ok_or_else(|| {
+                Error::InvalidShare("a word index is past the end of the word list".to_string())
+            })
+        })
+        .collect()
+}
+
+///
+/// A word list as used to encode shares.
+///
+struct ShareList<'a> {
+    words: Vec<&'a str>,
+    indices: BTreeMap<String, usize>,
+    bits: u32,
+}
+
+impl<'a> ShareList<'a> {
+    fn new(word_list: &'a WordList) -> Result<ShareList<'a>, Error> {
+        let words = word_list.words();
+        if words.len() > MAX_LIST_LEN {
+            return Err(Error::WordListTooLarge(words.len()));
+        }
+
+        let indices = words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (word.to_lowercase(), index))
+            .collect();
+        let bits = usize::BITS - 1 - words.len().leading_zeros();
+        Ok(ShareList {
+            words,
+            indices,
+            bits,
+        })
+    }
+
+    fn index(&self, word: &str) -> Result<usize, Error> {
+        self.indices
+            .get(&word.to_lowercase())
+            .cloned()
+            .ok_or_else(|| Error::InvalidWord(word.to_string()))
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        let mut words = Vec::new();
+        let mut acc = 0u32;
+        let mut pending = 0;
+        for &byte in bytes {
+            acc = (acc << 8) | u32::from(byte);
+            pending += 8;
+            while pending >= self.bits {
+                pending -= self.bits;
+                words.push(self.words[(acc >> pending) as usize]);
+                acc &= (1 << pending) - 1;
+            }
+        }
+        if pending > 0 {
+            words.push(self.words[(acc << (self.bits - pending)) as usize]);
+        }
+        words.join(" ")
+    }
+
+    fn decode(&self, share: &str) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        let mut acc = 0u32;
+        let mut pending = 0;
+        let mut words = 0;
+        for word in share.split_whitespace() {
+            let index = self.index(word)?;
+            if index >> self.bits != 0 {
+                return Err(Error::InvalidShare(format!(
+                    "\"{}\" never appears in a share",
+                    word
+                )));
+            }
+            acc = (acc << self.bits) | index as u32;
+            pending += self.bits;
+            while pending >= 8 {
+                pending -= 8;
+                bytes.push((acc >> pending) as u8);
+                acc &= (1 << pending) - 1;
+            }
+            words += 1;
+        }
+
+        let invalid = || Error::InvalidShare("the share has the wrong number of words".to_string());
+        if bytes.len() < HEADER_LEN {
+            return Err(invalid());
+        }
+        let len = HEADER_LEN + usize::from(u16::from_be_bytes([bytes[2], bytes[3]])) * 2 + 2;
+        let expected_words = (len * 8).div_ceil(self.bits as usize);
+        if words != expected_words || bytes[len.min(bytes.len())..].iter().any(|b| *b != 0) {
+            return Err(invalid());
+        }
+        if acc != 0 {
+            return Err(Error::InvalidShare(
+                "the share's padding isn't zero".to_string(),
+            ));
+        }
+        if bytes[0] < 2 || bytes[1] == 0 || bytes[2..HEADER_LEN] == [0, 0] {
+            return Err(Error::InvalidShare(
+                "the share's header is invalid".to_string(),
+            ));
+        }
+
+        bytes.truncate(len);
+        Ok(bytes)
+    }
+}
+
+///
+/// Multiplies two elements of GF(256) modulo the AES polynomial, without branching on either.
+///
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        let carry = a >> 7;
+        a = (a << 1) ^ (0x1b & carry.wrapping_neg());
+        b >>= 1;
+    }
+    product
+}
+
+///
+/// Returns the multiplicative inverse of a non-zero element of GF(256), as `a^254`.
+///
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut power = a;
+    let mut exponent = 254u8;
+    while exponent != 0 {
+        if exponent & 1 != 0 {
+            result = gf_mul(result, power);
+        }
+        power = gf_mul(power, power);
+        exponent >>= 1;
+    }
+    result
+}
+
+fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0, |y, coefficient| gf_mul(y, x) ^ coefficient)
+}
+
+///
+/// Returns the value at 0 of the polynomial through the points `(xs[i], ys[i])`.
+///
+fn interpolate(xs: &[u8], ys: &[u8]) -> u8 {
+    let mut value = 0;
+    for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+        let mut basis = 1;
+        for (j, &xj) in xs.iter().enumerate() {
+            if i != j {
+                basis = gf_mul(basis, gf_mul(xj, gf_inv(xj ^ xi)));
+            }
+        }
+        value ^= gf_mul(yi, basis);
+    }
+    value
+}
+
+fn fletcher16(data: &[u8]) -> [u8; 2] {
+    let (mut low, mut high) = (0u16, 0u16);
+    for &byte in data {
+        low = (low + u16::from(byte)) % 255;
+        high = (high + low) % 255;
+    }
+    [high as u8, low as u8]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::chacha::ChaChaRng;
+    use rand::SeedableRng;
+
+    const WORDS: [&str; 5] = ["gumball", "yo-yo", "unweave", "awkward", "frenzy"];
+
+    fn shares(seed: u32, threshold: u8, count: u8) -> Vec<String> {
+        let mut rng = ChaChaRng::from_seed(&[seed]);
+        split_with(&WordList::EffLong, &WORDS, threshold, count, &mut rng).unwrap()
+    }
+
+    #[test]
+    fn every_threshold_subset_recovers() {
+        let shares = shares(1, 3, 5);
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [&shares[c], &shares[a], &shares[b]];
+                    assert_eq!(combine(&WordList::EffLong, &subset), Ok(WORDS.to_vec()));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn shares_use_the_word_list() {
+        let list = WordList::EffShort2;
+        let all = list.words();
+        let words = [all[0], all[1295], all[700]];
+        let shares = split(&list, &words, 2, 3).unwrap();
+
+        for share in &shares {
+            // 4 header bytes, 6 index bytes and 2 checksum bytes at 10 bits per word
+            assert_eq!(share.split(' ').count(), 10);
+            assert!(share.split(' ').all(|word| all[..1024].contains(&word)));
+        }
+
+        let upper = shares[1].to_uppercase();
+        let recovered = combine(&list, &[upper.as_str(), shares[2].as_str()]).unwrap();
+        assert_eq!(recovered, words);
+    }
+
+    #[test]
+    fn insufficient_shares() {
+        let shares = shares(2, 3, 5);
+        assert_eq!(
+            combine(&WordList::EffLong, &shares[..2]),
+            Err(Error::InsufficientShares {
+                required: 3,
+                actual: 2,
+            })
+        );
+        assert_eq!(
+            combine::<&str>(&WordList::EffLong, &[]),
+            Err(Error::InsufficientShares {
+                required: 2,
+                actual: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn mismatched_shares() {
+        let first = shares(3, 2, 3);
+        let second = shares(4, 2, 3);
+        match combine(&WordList::EffLong, &[&first[0], &second[1]]) {
+            Err(Error::InvalidShare(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match combine(&WordList::EffLong, &[&first[0], &first[0]]) {
+            Err(Error::InvalidShare(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn malformed_shares() {
+        let shares = shares(5, 2, 2);
+        let mut words: Vec<&str> = shares[0].split(' ').collect();
+
+        words.pop();
+        let truncated = words.join(" ");
+        words[0] = "zoom";
+        let outside_share_words = words.join(" ");
+        words[0] = "notaword";
+        let typo = words.join(" ");
+
+        for share in &[truncated, outside_share_words] {
+            match combine(&WordList::EffLong, &[share, &shares[1]]) {
+                Err(Error::InvalidShare(_)) => {}
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+        assert_eq!(
+            combine(&WordList::EffLong, &[&typo, &shares[1]]),
+            Err(Error::InvalidWord("notaword".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_split() {
+        let mut rng = ChaChaRng::from_seed(&[6]);
+        assert_eq!(
+            split_with(&WordList::EffLong, &WORDS, 4, 3, &mut rng),
+            Err(Error::InvalidThreshold {
+                threshold: 4,
+                shares: 3,
+            })
+        );
+        assert_eq!(
+            split_with(&WordList::EffLong, &WORDS, 1, 3, &mut rng),
+            Err(Error::InvalidThreshold {
+                threshold: 1,
+                shares: 3,
+            })
+        );
+        assert_eq!(
+            split_with(&WordList::EffLong, &["abacus", "notaword"], 2, 3, &mut rng),
+            Err(Error::InvalidWord("notaword".to_string()))
+        );
+    }
+
+    #[test]
+    fn field_inverse() {
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+}
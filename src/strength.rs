@@ -0,0 +1,394 @@
+//!
+//! Contains logic for describing how strong a passphrase is: its entropy, a rating suitable for a
+//! strength meter, and how long an attacker would take to guess it.
+//!
+//! Crack times assume the attacker knows exactly how the passphrase was generated (the word
+//! list, word count and separator) and has to search half of the `2^entropy` possible
+//! passphrases on average.  They are estimates for comparison, not guarantees.
+//!
+//! ```
+//! use lib_dicepass_gen::*;
+//! use lib_dicepass_gen::strength::{GuessRate, Rating};
+//!
+//! let strength = PassGenConfig::from_eff_long(WordCount::Default).strength();
+//! assert_eq!(strength.rating, Rating::Strong);
+//!
+//! for crack_time in &strength.crack_times {
+//!     println!("{:?}: {}", crack_time.rate, crack_time);
+//! }
+//! // prints something like:
+//! // OnlineThrottled: 16 trillion years
+//! // OfflineSlowHash: 45 million years
+//! // OfflineFastHash: 45 years
+//! ```
+//!
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use math;
+use pass_gen::{PassGenConfig, Passphrase};
+
+///
+/// How quickly an attacker can test guesses.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GuessRate {
+    ///
+    /// Guessing through a login form which limits attempts: 100 guesses per hour.
+    ///
+    OnlineThrottled,
+
+    ///
+    /// Guessing offline against a slow password hash such as Argon2, scrypt or bcrypt: 10^4
+    /// guesses per second.
+    ///
+    OfflineSlowHash,
+
+    ///
+    /// Guessing offline against a fast hash such as SHA-256 on many GPUs: 10^10 guesses per
+    /// second.
+    ///
+    OfflineFastHash,
+
+    ///
+    /// A custom number of guesses per second.
+    ///
+    Custom(f64),
+}
+
+impl GuessRate {
+    ///
+    /// The preset guess rates, slowest first.
+    ///
+    pub const PRESETS: [GuessRate; 3] = [
+        GuessRate::OnlineThrottled,
+        GuessRate::OfflineSlowHash,
+        GuessRate::OfflineFastHash,
+    ];
+
+    ///
+    /// Returns the number of guesses tested per second.
+    ///
+    pub fn guesses_per_second(&self) -> f64 {
+        match *self {
+            GuessRate::OnlineThrottled => 100.0 / 3600.0,
+            GuessRate::OfflineSlowHash => 1e4,
+            GuessRate::OfflineFastHash => 1e10,
+            GuessRate::Custom(rate) => rate,
+        }
+    }
+
+    ///
+    /// Returns whether the rate is a finite, positive number of guesses per second.  The presets
+    /// are always valid.
+    ///
+    pub fn is_valid(&self) -> bool {
+        let rate = self.guesses_per_second();
+        rate.is_finite() && rate > 0.0
+    }
+}
+
+///
+/// A rough rating of a passphrase's strength, for display in a strength meter.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Rating {
+    ///
+    /// Under 40 bits: could be guessed offline in minutes.
+    ///
+    Weak,
+
+    ///
+    /// 40 to 60 bits: resists online guessing but not a determined offline attack.
+    ///
+    Fair,
+
+    ///
+    /// 60 to 80 bits: resists offline guessing against a slow hash.
+    ///
+    Strong,
+
+    ///
+    /// 80 bits or more: resists any practical attack.
+    ///
+    VeryStrong,
+}
+
+impl Rating {
+    ///
+    /// Returns the rating for a passphrase with `entropy` bits of entropy.
+    ///
+    pub fn from_entropy(entropy: f64) -> Rating {
+        if entropy >= 80.0 {
+            Rating::VeryStrong
+        } else if entropy >= 60.0 {
+            Rating::Strong
+        } else if entropy >= 40.0 {
+            Rating::Fair
+        } else {
+            Rating::Weak
+        }
+    }
+}
+
+///
+/// The average time needed to guess a passphrase at a given rate.
+///
+/// Displays as an approximate duration, such as "3 hours" or "42 million years".
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrackTime {
+    ///
+    /// The rate guesses are tested at.
+    ///
+    pub rate: GuessRate,
+
+    ///
+    /// The average number of seconds needed to find the passphrase.  May be infinite for very
+    /// large entropies, and is NaN when the entropy or rate is invalid.
+    ///
+    pub seconds: f64,
+}
+
+impl CrackTime {
+    ///
+    /// Returns the average time needed to guess a passphrase with `entropy` bits of entropy at
+    /// `rate`.
+    ///
+    /// If `entropy` is NaN or `rate` is not valid (see `GuessRate::is_valid`), the crack time is
+    /// unknown: `seconds` is NaN and the time displays as "unknown".
+    ///
+    pub fn new(entropy: f64, rate: GuessRate) -> CrackTime {
+        let seconds = if entropy.is_nan() || !rate.is_valid() {
+            f64::NAN
+        } else {
+            math::exp2(entropy - 1.0) / rate.guesses_per_second()
+        };
+        CrackTime { rate, seconds }
+    }
+}
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const YEAR: f64 = 365.2425 * DAY;
+const MONTH: f64 = YEAR / 12.0;
+
+const UNITS: [(f64, &str); 6] = [
+    (YEAR, "year"),
+    (MONTH, "month"),
+    (DAY, "day"),
+    (HOUR, "hour"),
+    (MINUTE, "minute"),
+    (1.0, "second"),
+];
+
+const SCALES: [(f64, &str); 4] = [
+    (1e15, "quadrillion "),
+    (1e12, "trillion "),
+    (1e9, "billion "),
+    (1e6, "million "),
+];
+
+impl fmt::Display for CrackTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.seconds.is_nan() || self.seconds < 0.0 {
+            return write!(f, "unknown");
+        }
+        if self.seconds < 1.0 {
+            return write!(f, "less than a second");
+        }
+        if self.seconds / YEAR >= 1e18 {
+            return write!(f, "more than a quintillion years");
+        }
+
+        let &(unit, name) = UNITS
+            .iter()
+            .find(|&&(unit, _)| self.seconds >= unit)
+            .expect("seconds is at least 1");
+        let mut count = self.seconds / unit;
+        let mut scale = "";
+        if let Some(&(size, scale_name)) = SCALES.iter().find(|&&(size, _)| count >= size) {
+            count /= size;
+            scale = scale_name;
+        }
+
+        let plural = if scale.is_empty() && count < 1.5 {
+            ""
+        } else {
+            "s"
+        };
+        write!(f, "{:.0} {}{}{}", count, scale, name, plural)
+    }
+}
+
+///
+/// A summary of how strong a passphrase is.
+///
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Strength {
+    ///
+    /// The total entropy (in bits).
+    ///
+    pub entropy: f64,
+
+    ///
+    /// A rough rating of the passphrase's strength.
+    ///
+    pub rating: Rating,
+
+    ///
+    /// The crack time at each of `GuessRate::PRESETS`, slowest rate first.
+    ///
+    pub crack_times: Vec<CrackTime>,
+}
+
+impl Strength {
+    ///
+    /// Returns the `Strength` of a passphrase with `entropy` bits of entropy.
+    ///
+    pub fn new(entropy: f64) -> Strength {
+        Strength {
+            entropy,
+            rating: Rating::from_entropy(entropy),
+            crack_times: GuessRate::PRESETS
+                .iter()
+                .map(|&rate| CrackTime::new(entropy, rate))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Returns the crack time at a rate other than the presets.
+    ///
+    pub fn crack_time(&self, rate: GuessRate) -> CrackTime {
+        CrackTime::new(self.entropy, rate)
+    }
+
+    ///
+    /// Returns how full a strength meter should be, from 0 to 1.  The meter is full at 128 bits,
+    /// beyond which brute force is considered infeasible.
+    ///
+    pub fn meter(&self) -> f64 {
+        (self.entropy / 128.0).clamp(0.0, 1.0)
+    }
+}
+
+impl PassGenConfig {
+    ///
+    /// Returns the strength of passphrases generated from this config.
+    ///
+    pub fn strength(&self) -> Strength {
+        Strength::new(self.entropy())
+    }
+}
+
+impl Passphrase {
+    ///
+    /// Returns the strength of this passphrase.
+    ///
+    pub fn strength(&self) -> Strength {
+        Strength::new(self.entropy)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use pass_gen::{WordCount, WordList};
+
+    fn display(seconds: f64) -> String {
+        CrackTime {
+            rate: GuessRate::Custom(1.0),
+            seconds,
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn crack_times() {
+        // 2^10 passphrases take 2^9 guesses on average
+        let time = CrackTime::new(10.0, GuessRate::Custom(2.0));
+        assert_eq!(time.seconds, 256.0);
+
+        let time = CrackTime::new(20.0, GuessRate::OnlineThrottled);
+        assert!((time.seconds - 524_288.0 * 36.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(display(0.5), "less than a second");
+        assert_eq!(display(1.0), "1 second");
+        assert_eq!(display(59.0), "59 seconds");
+        assert_eq!(display(90.0), "2 minutes");
+        assert_eq!(display(3.0 * DAY), "3 days");
+        assert_eq!(display(YEAR), "1 year");
+        assert_eq!(display(4.2e7 * YEAR), "42 million years");
+        assert_eq!(display(1e6 * YEAR), "1 million years");
+        assert_eq!(display(1e30 * YEAR), "more than a quintillion years");
+        assert_eq!(display(f64::INFINITY), "more than a quintillion years");
+        assert_eq!(display(f64::NAN), "unknown");
+        assert_eq!(display(-1.0), "unknown");
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        for &rate in &[f64::NAN, f64::INFINITY, 0.0, -5.0] {
+            assert!(!GuessRate::Custom(rate).is_valid());
+            let time = CrackTime::new(60.0, GuessRate::Custom(rate));
+            assert!(time.seconds.is_nan());
+            assert_eq!(time.to_string(), "unknown");
+        }
+        assert!(GuessRate::PRESETS.iter().all(GuessRate::is_valid));
+
+        let time = CrackTime::new(f64::NAN, GuessRate::OfflineFastHash);
+        assert_eq!(time.to_string(), "unknown");
+        assert_eq!(
+            CrackTime::new(f64::INFINITY, GuessRate::OfflineFastHash).to_string(),
+            "more than a quintillion years"
+        );
+    }
+
+    #[test]
+    fn ratings() {
+        let rate = |words| {
+            PassGenConfig::from_eff_long(WordCount::Custom(words))
+                .strength()
+                .rating
+        };
+        assert_eq!(rate(3), Rating::Weak);
+        assert_eq!(rate(4), Rating::Fair);
+        assert_eq!(rate(5), Rating::Strong);
+        assert_eq!(rate(7), Rating::VeryStrong);
+        assert!(Rating::Weak < Rating::VeryStrong);
+    }
+
+    #[test]
+    fn strength() {
+        let strength = PassGenConfig::from_eff_short(WordCount::Default).strength();
+        assert!((strength.entropy - 8.0 * WordList::EffShort.bits_per_word()).abs() < 1e-9);
+        assert_eq!(strength.crack_times.len(), 3);
+        assert!(strength.crack_times[0].seconds > strength.crack_times[2].seconds);
+        assert!(strength.meter() > 0.5 && strength.meter() < 1.0);
+        assert_eq!(Strength::new(200.0).meter(), 1.0);
+
+        let times: Vec<String> = PassGenConfig::from_eff_long(WordCount::Default)
+            .strength()
+            .crack_times
+            .iter()
+            .map(|time| time.to_string())
+            .collect();
+        assert_eq!(
+            times,
+            vec!["16 trillion years", "45 million years", "45 years"]
+        );
+    }
+}
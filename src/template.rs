@@ -0,0 +1,366 @@
+//!
+//! Contains logic for generating grammatical passphrases from sentence templates.
+//!
+//! A template such as `"{adj} {noun} {verb} {adj} {noun}"` is filled in by choosing each slot
+//! uniformly from a word list for its part of speech, giving passphrases like "brave otter
+//! juggles tiny teapot" which are easier to picture and remember than unrelated words.
+//!
+//! Each slot provides the entropy of its own list, so a template is usually weaker than the same
+//! number of `EffLong` words: the default template provides ~42 bits with the bundled lists.  Use
+//! a longer template (for example two sentences) where more is needed, and check the result with
+//! `Template::entropy`.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use lib_dicepass_gen::template::{TaggedLists, Template};
+//!
+//! let template: Template = "{adj} {noun} {verb} {adj} {noun}".parse().unwrap();
+//! let lists = TaggedLists::default();
+//!
+//! let pass = template.generate(&lists);
+//! assert_eq!(pass.word_count, 5);
+//! assert_eq!(pass.entropy, template.entropy(&lists));
+//! println!("{}", pass.as_str());
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use rand::Rng;
+
+use custom_list::CustomList;
+use defaults::parts_of_speech;
+use error::Error;
+use math;
+use pass_gen::{self, Passphrase};
+
+///
+/// The part of speech of a template slot.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PartOfSpeech {
+    ///
+    /// An adjective, written `{adj}` in a template.
+    ///
+    Adjective,
+
+    ///
+    /// A singular noun, written `{noun}` in a template.
+    ///
+    Noun,
+
+    ///
+    /// A transitive verb in the third person singular, written `{verb}` in a template.
+    ///
+    Verb,
+}
+
+impl PartOfSpeech {
+    fn tag(self) -> &'static str {
+        match self {
+            PartOfSpeech::Adjective => "adj",
+            PartOfSpeech::Noun => "noun",
+            PartOfSpeech::Verb => "verb",
+        }
+    }
+}
+
+impl FromStr for PartOfSpeech {
+    type Err = Error;
+
+    ///
+    /// Parses a slot tag: `adj`, `noun` or `verb`.
+    ///
+    fn from_str(tag: &str) -> Result<PartOfSpeech, Error> {
+        match tag {
+            "adj" => Ok(PartOfSpeech::Adjective),
+            "noun" => Ok(PartOfSpeech::Noun),
+            "verb" => Ok(PartOfSpeech::Verb),
+            _ => Err(Error::InvalidTemplate(format!("unknown slot {{{}}}", tag))),
+        }
+    }
+}
+
+///
+/// A word list for each part of speech.
+///
+/// `TaggedLists::default()` uses the bundled lists in `defaults::parts_of_speech`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TaggedLists {
+    ///
+    /// The words for `{adj}` slots.
+    ///
+    pub adjectives: CustomList,
+
+    ///
+    /// The words for `{noun}` slots.
+    ///
+    pub nouns: CustomList,
+
+    ///
+    /// The words for `{verb}` slots.
+    ///
+    pub verbs: CustomList,
+}
+
+impl TaggedLists {
+    ///
+    /// Returns the word list for `part`.
+    ///
+    pub fn get(&self, part: PartOfSpeech) -> &CustomList {
+        match part {
+            PartOfSpeech::Adjective => &self.adjectives,
+            PartOfSpeech::Noun => &self.nouns,
+            PartOfSpeech::Verb => &self.verbs,
+        }
+    }
+}
+
+impl Default for TaggedLists {
+    fn default() -> TaggedLists {
+        let list = |words: &[&str]| CustomList::new(words.iter()).expect("bundled lists are valid");
+        TaggedLists {
+            adjectives: list(parts_of_speech::ADJECTIVES),
+            nouns: list(parts_of_speech::NOUNS),
+            verbs: list(parts_of_speech::VERBS),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Slot(PartOfSpeech),
+}
+
+///
+/// A sentence template: literal text with `{adj}`, `{noun}` and `{verb}` slots to fill in.
+/// Literal braces are written `{{` and `}}`.
+///
+/// The default template is `"{adj} {noun} {verb} {adj} {noun}"`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    ///
+    /// Returns the parts of speech of this template's slots, in order.
+    ///
+    pub fn slots(&self) -> Vec<PartOfSpeech> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match *segment {
+                Segment::Slot(part) => Some(part),
+                Segment::Text(_) => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the entropy (in bits) provided by each slot when filled from `lists`.
+    ///
+    pub fn slot_entropy(&self, lists: &TaggedLists) -> Vec<f64> {
+        self.slots()
+            .into_iter()
+            .map(|part| math::log2(lists.get(part).len() as f64))
+            .collect()
+    }
+
+    ///
+    /// Returns the total entropy (in bits) of a passphrase generated from this template and
+    /// `lists`.
+    ///
+    /// ```
+    /// use lib_dicepass_gen::template::{TaggedLists, Template};
+    ///
+    /// let lists = TaggedLists::default();
+    /// let short: Template = "{noun} {verb} {noun}".parse().unwrap();
+    /// assert!(short.entropy(&lists) < Template::default().entropy(&lists));
+    /// ```
+    ///
+    pub fn entropy(&self, lists: &TaggedLists) -> f64 {
+        self.slot_entropy(lists).iter().sum()
+    }
+
+    ///
+    /// Generates a passphrase from this template, filling each slot from `lists` using the
+    /// operating system's secure random number generator.
+    ///
+    #[cfg(feature = "std")]
+    pub fn generate(&self, lists: &TaggedLists) -> Passphrase {
+        self.generate_with(lists, &mut pass_gen::os_rng())
+    }
+
+    ///
+    /// Generates a passphrase from this template, filling each slot from `lists` using `rng`.
+    ///
+    pub fn generate_with<R: Rng>(&self, lists: &TaggedLists, rng: &mut R) -> Passphrase {
+        let mut phrase = String::new();
+        for segment in &self.segments {
+            match *segment {
+                Segment::Text(ref text) => phrase.push_str(text),
+                Segment::Slot(part) => {
+                    let list = lists.get(part);
+                    let index = pass_gen::gen_index(list.len(), rng);
+                    phrase.push_str(list.word(index).expect("index is less than the length"));
+                }
+            }
+        }
+        Passphrase::new(phrase, self.slots().len() as u32, self.entropy(lists))
+    }
+}
+
+impl Default for Template {
+    fn default() -> Template {
+        "{adj} {noun} {verb} {adj} {noun}"
+            .parse()
+            .expect("the default template is valid")
+    }
+}
+
+impl FromStr for Template {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Template, Error> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| Error::InvalidTemplate("unclosed {".to_string()))?;
+                    if !literal.is_empty() {
+                        segments.push(Segment::Text(literal.split_off(0)));
+                    }
+                    segments.push(Segment::Slot(rest[..end].parse()?));
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => return Err(Error::InvalidTemplate("unmatched }".to_string())),
+                _ => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Text(literal));
+        }
+
+        let template = Template { segments };
+        if template.slots().is_empty() {
+            return Err(Error::InvalidTemplate("no slots".to_string()));
+        }
+        Ok(template)
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.segments {
+            match *segment {
+                Segment::Text(ref text) => {
+                    write!(f, "{}", text.replace('{', "{{").replace('}', "}}"))?
+                }
+                Segment::Slot(part) => write!(f, "{{{}}}", part.tag())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for Template {
+    type Error = Error;
+
+    fn try_from(text: String) -> Result<Template, Error> {
+        text.parse()
+    }
+}
+
+impl From<Template> for String {
+    fn from(template: Template) -> String {
+        template.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::chacha::ChaChaRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn parse() {
+        let template: Template = "{adj} {noun}, {{quoted}}!".parse().unwrap();
+        assert_eq!(
+            template.slots(),
+            vec![PartOfSpeech::Adjective, PartOfSpeech::Noun]
+        );
+        assert_eq!(template.to_string(), "{adj} {noun}, {{quoted}}!");
+
+        for &(text, reason) in &[
+            ("{adj} {adverb}", "unknown slot {adverb}"),
+            ("{adj} {noun", "unclosed {"),
+            ("{adj} noun}", "unmatched }"),
+            ("no slots here", "no slots"),
+        ] {
+            assert_eq!(
+                text.parse::<Template>(),
+                Err(Error::InvalidTemplate(reason.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn fills_slots_from_lists() {
+        let lists = TaggedLists {
+            adjectives: CustomList::new(vec!["brave", "tiny"]).unwrap(),
+            nouns: CustomList::new(vec!["otter", "teapot"]).unwrap(),
+            verbs: CustomList::new(vec!["juggles", "polishes"]).unwrap(),
+        };
+        let template: Template = "{adj}-{noun}-{verb}-{noun}.".parse().unwrap();
+        let mut rng = ChaChaRng::from_seed(&[1; 8]);
+        for _ in 0..20 {
+            let pass = template.generate_with(&lists, &mut rng);
+            let words: Vec<&str> = pass.as_str().trim_end_matches('.').split('-').collect();
+            assert!(lists.adjectives.words().contains(&words[0]));
+            assert!(lists.nouns.words().contains(&words[1]));
+            assert!(lists.verbs.words().contains(&words[2]));
+            assert!(lists.nouns.words().contains(&words[3]));
+            assert_eq!(pass.entropy, 4.0);
+            assert_eq!(pass.bits_per_word, 1.0);
+        }
+    }
+
+    #[test]
+    fn bundled_lists() {
+        let lists = TaggedLists::default();
+        let entropy = Template::default().slot_entropy(&lists);
+        assert_eq!(entropy.len(), 5);
+        assert!(entropy.iter().all(|&bits| bits >= 8.0));
+        assert!((Template::default().entropy(&lists) - 42.0).abs() < 1.0);
+    }
+}
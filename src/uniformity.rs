@@ -0,0 +1,186 @@
+//!
+//! Statistical tests that word selection is uniform.
+//!
+//! Each test counts outcomes from a seeded `ChaChaRng` and applies a chi-square goodness of fit
+//! test against the uniform distribution.  The seeds are fixed, so the tests are deterministic;
+//! the threshold is the 1 - 10^-6 quantile of the chi-square distribution, so a fresh seed would
+//! pass a correct sampler with overwhelming probability while a biased one (for example by
+//! reducing random values modulo the list length) fails.
+//!
+//! The tests drawing millions of samples are `#[ignore]`d; run them with
+//! `cargo test --release -- --ignored`.
+//!
+
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use rand::chacha::ChaChaRng;
+use rand::{Rng, SeedableRng};
+
+use custom_list::CustomList;
+use dice::{DicePlan, Die};
+use pass_gen::{self, PassGenConfig, Sampling, WordCount, WordList};
+
+/// The standard normal quantile for a one-sided p value of 10^-6.
+const Z: f64 = 4.753;
+
+/// Returns the chi-square statistic of `counts` against equal expected counts.
+fn chi_square(counts: &[u64]) -> f64 {
+    let total: u64 = counts.iter().sum();
+    let expected = total as f64 / counts.len() as f64;
+    counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+/// Returns the critical chi-square value for `df` degrees of freedom (Wilson-Hilferty).
+fn critical_value(df: usize) -> f64 {
+    let df = df as f64;
+    let a = 2.0 / (9.0 * df);
+    df * (1.0 - a + Z * a.sqrt()).powi(3)
+}
+
+fn assert_uniform(counts: &[u64]) {
+    let statistic = chi_square(counts);
+    let critical = critical_value(counts.len() - 1);
+    assert!(
+        statistic < critical,
+        "chi-square statistic {:.1} exceeds {:.1} for {} buckets",
+        statistic,
+        critical,
+        counts.len()
+    );
+}
+
+fn rng(seed: u32) -> ChaChaRng {
+    ChaChaRng::from_seed(&[seed; 8])
+}
+
+/// Generates `samples` words from `config` and returns how often each word of its list appeared.
+fn word_counts(config: &PassGenConfig, samples: usize, seed: u32) -> Vec<u64> {
+    let words = config.word_list.words();
+    let indices: BTreeMap<&str, usize> = words
+        .iter()
+        .enumerate()
+        .map(|(index, &word)| (word, index))
+        .collect();
+
+    let mut config = config.clone();
+    config.word_count = WordCount::Custom(1000);
+    let mut rng = rng(seed);
+    let mut counts = vec![0; words.len()];
+    for _ in 0..samples / 1000 {
+        let pass = pass_gen::generate_with(&config, &mut rng);
+        for word in pass.as_str().split(' ') {
+            counts[indices[word]] += 1;
+        }
+    }
+    counts
+}
+
+#[test]
+fn die_faces() {
+    for die in &[Die::D4, Die::D6, Die::D8, Die::D10, Die::D12, Die::D20] {
+        let plan = DicePlan::mixed(die.sides() as usize, vec![*die]).unwrap();
+        let mut rng = rng(die.sides());
+        let mut counts = vec![0; die.sides() as usize];
+        for _ in 0..100_000 {
+            counts[plan.roll(&mut rng)[0] as usize - 1] += 1;
+        }
+        assert_uniform(&counts);
+    }
+}
+
+#[test]
+fn dice_positions() {
+    // every die of a sequence must be uniform, not just the sequence as a whole
+    let plan = DicePlan::new(7776, Die::D6);
+    let mut rng = rng(1);
+    let mut counts = vec![vec![0; 6]; plan.rolls()];
+    for _ in 0..50_000 {
+        for (position, roll) in plan.roll(&mut rng).into_iter().enumerate() {
+            counts[position][roll as usize - 1] += 1;
+        }
+    }
+    for position in &counts {
+        assert_uniform(position);
+    }
+}
+
+#[test]
+fn modulo_bias_is_detected() {
+    // with 3 * 2^30 values, reducing a u32 modulo the length makes the first third twice as
+    // likely; rejection sampling doesn't
+    let len = 3usize << 30;
+    let bucket = |value: usize| value / (1 << 28);
+    let mut rng = rng(2);
+    let mut biased = vec![0; 12];
+    let mut unbiased = vec![0; 12];
+    for _ in 0..10_000 {
+        biased[bucket(rng.next_u32() as usize % len)] += 1;
+        unbiased[bucket(pass_gen::gen_index(len, &mut rng))] += 1;
+    }
+    assert!(chi_square(&biased) > critical_value(11));
+    assert_uniform(&unbiased);
+}
+
+#[test]
+fn short_list_words() {
+    let config = PassGenConfig::from_eff_short(WordCount::Default);
+    assert_uniform(&word_counts(&config, 200_000, 3));
+}
+
+#[test]
+#[ignore]
+fn eff_long_words() {
+    let config = PassGenConfig::from_eff_long(WordCount::Default);
+    assert_uniform(&word_counts(&config, 4_000_000, 4));
+}
+
+#[test]
+#[ignore]
+fn rejected_dice_words() {
+    // 20^3 = 8000 outcomes for 7776 words, so 224 sequences are rerolled
+    let mut config = PassGenConfig::from_eff_long(WordCount::Default);
+    config.sampling = Sampling::Dice(Die::D20);
+    assert_uniform(&word_counts(&config, 4_000_000, 5));
+}
+
+#[test]
+#[ignore]
+fn uniform_custom_words() {
+    let words: Vec<_> = (0..1000).map(|i| i.to_string()).collect();
+    let config = PassGenConfig::from_custom(CustomList::new(words).unwrap(), WordCount::Default);
+    assert_uniform(&word_counts(&config, 2_000_000, 6));
+}
+
+#[test]
+fn known_answers() {
+    let generate = |list: WordList, sampling: Sampling| {
+        let mut config = PassGenConfig::from_eff_long(WordCount::Custom(5));
+        config.word_list = list;
+        config.sampling = sampling;
+        pass_gen::generate_with(&config, &mut rng(42)).into_string()
+    };
+
+    // these change if the sampler consumes randomness differently, which would also change every
+    // passphrase generated with `RngSource::Seeded`
+    assert_eq!(
+        generate(WordList::EffLong, Sampling::Dice(Die::D6)),
+        "ranking partition chewer crayfish hardener"
+    );
+    assert_eq!(
+        generate(WordList::EffLong, Sampling::Dice(Die::D20)),
+        "stopper target ambiguous supreme greasily"
+    );
+    assert_eq!(
+        generate(WordList::EffLong, Sampling::Uniform),
+        "slobbery osmosis skewer diocese same"
+    );
+    assert_eq!(
+        generate(WordList::EffShort2, Sampling::Dice(Die::D6)),
+        "outhouse rupture boiler oyster apricot"
+    );
+}
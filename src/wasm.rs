@@ -0,0 +1,168 @@
+//!
+//! Contains `wasm-bindgen` bindings for generating passphrases in the browser.
+//!
+//! Word lists are named as for `WordList::from_str` (`"eff_long"`, `"eff_short"`, `"eff_short_2"`
+//! or `"pseudo"`), and a missing word count uses the list's default.  Errors are thrown as
+//! JavaScript `Error`s.
+//!
+//! When this module is enabled all randomness comes from `getrandom`, which uses
+//! `crypto.getRandomValues` on `wasm32-unknown-unknown`, so passphrases never leave the browser.
+//!
+
+use rand::Rng;
+use wasm_bindgen::prelude::*;
+
+use complete::Completer;
+use error::Error;
+use pass_gen::{self, PassGenConfig, WordCount, WordList};
+
+///
+/// A random number generator backed by `getrandom`.  On `wasm32-unknown-unknown` this calls
+/// `crypto.getRandomValues`; elsewhere it uses the operating system's generator.
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GetrandomRng;
+
+impl Rng for GetrandomRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ::getrandom::getrandom(dest).expect("couldn't get random values");
+    }
+}
+
+fn config(list: &str, word_count: Option<u32>) -> Result<PassGenConfig, Error> {
+    Ok(PassGenConfig::builder()
+        .word_list(list.parse()?)
+        .word_count(WordCount::from(word_count))
+        .build()?)
+}
+
+fn js_error(err: Error) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+///
+/// Generates a passphrase from the named word list.
+///
+#[wasm_bindgen]
+pub fn generate(list: &str, word_count: Option<u32>) -> Result<String, JsError> {
+    config(list, word_count)
+        .map(pass_gen::generate)
+        .map_err(js_error)
+}
+
+///
+/// Returns the names of the available word lists.
+///
+#[wasm_bindgen(js_name = wordLists)]
+pub fn word_lists() -> Vec<String> {
+    pass_gen::WORD_LIST_NAMES
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+///
+/// Returns the default number of words for the named word list.
+///
+#[wasm_bindgen(js_name = defaultWordCount)]
+pub fn default_word_count(list: &str) -> Result<u32, JsError> {
+    list.parse::<WordList>()
+        .map(|list| list.default_word_count())
+        .map_err(js_error)
+}
+
+///
+/// Returns the entropy (in bits) provided by each word from the named word list.
+///
+#[wasm_bindgen(js_name = bitsPerWord)]
+pub fn bits_per_word(list: &str) -> Result<f64, JsError> {
+    config(list, None)
+        .map(|config| config.bits_per_word())
+        .map_err(js_error)
+}
+
+///
+/// Returns the total entropy (in bits) of a passphrase generated from the named word list.
+///
+#[wasm_bindgen]
+pub fn entropy(list: &str, word_count: Option<u32>) -> Result<f64, JsError> {
+    config(list, word_count)
+        .map(|config| config.entropy())
+        .map_err(js_error)
+}
+
+///
+/// Returns the words from the named word list that `word` could be: its completions, or the
+/// nearest words if it has none.
+///
+#[wasm_bindgen]
+pub fn suggest(list: &str, word: &str) -> Result<Vec<String>, JsError> {
+    let list = list.parse::<WordList>().map_err(js_error)?;
+    let suggestions = Completer::new(&list).suggest(word);
+    Ok(suggestions.into_iter().map(String::from).collect())
+}
+
+///
+/// Expands a passphrase typed as space separated word prefixes into full words from the named
+/// word list.
+///
+#[wasm_bindgen(js_name = expandPrefixes)]
+pub fn expand_prefixes(list: &str, phrase: &str) -> Result<String, JsError> {
+    let list = list.parse::<WordList>().map_err(js_error)?;
+    let words = Completer::new(&list)
+        .expand_phrase(phrase)
+        .map_err(js_error)?;
+    Ok(words.join(" "))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn generate_default() {
+        let pass = generate("eff_short_2", None).unwrap();
+        assert_eq!(pass.split_whitespace().count(), 8);
+    }
+
+    #[wasm_bindgen_test]
+    fn generate_custom() {
+        let pass = generate("eff_long", Some(7)).unwrap();
+        assert_eq!(pass.split_whitespace().count(), 7);
+    }
+
+    #[wasm_bindgen_test]
+    fn list_names() {
+        let names = word_lists();
+        assert!(names.contains(&"pseudo".to_string()));
+        assert!(names.iter().all(|name| default_word_count(name).is_ok()));
+    }
+
+    #[wasm_bindgen_test]
+    fn report_entropy() {
+        assert!((bits_per_word("eff_long").unwrap() - 12.92).abs() < 0.01);
+        assert!((entropy("eff_short", Some(4)).unwrap() - 41.36).abs() < 0.01);
+        assert_eq!(default_word_count("eff_long").unwrap(), 5);
+    }
+
+    #[wasm_bindgen_test]
+    fn prefixes() {
+        assert_eq!(suggest("eff_long", "acor").unwrap(), vec!["acorn"]);
+        let pass = generate("eff_short_2", None).unwrap();
+        let prefixes: Vec<&str> = pass.split(' ').map(|word| &word[..3]).collect();
+        assert_eq!(expand_prefixes("eff_short_2", &prefixes.join(" ")).unwrap(), pass);
+    }
+}